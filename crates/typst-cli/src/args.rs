@@ -224,6 +224,27 @@ pub struct CompileArgs {
     #[arg(long = "format", short = 'f')]
     pub format: Option<OutputFormat>,
 
+    /// Path to a CSV or JSON file with records to compile the document for,
+    /// one output per record.
+    ///
+    /// The fields of each record are visible through `sys.inputs`. A CSV file
+    /// must have a header row naming the fields and a JSON file must contain
+    /// an array of objects. Like values given with `--input`, all fields are
+    /// strings: Other JSON values are passed in their JSON representation.
+    ///
+    /// The output path must be different for each record: Use `{r}` for
+    /// record numbers, `{0r}` for zero padded record numbers, and `{name}` for
+    /// the value of a record's `name` field. For example,
+    /// `letter-{0r}-{name}.pdf` creates `letter-01-Alice.pdf`,
+    /// `letter-02-Bob.pdf`, and so on.
+    #[arg(
+        long = "merge",
+        value_name = "DATA",
+        value_hint = ValueHint::FilePath,
+        conflicts_with = "open"
+    )]
+    pub merge: Option<PathBuf>,
+
     /// World arguments.
     #[clap(flatten)]
     pub world: WorldArgs,
@@ -246,15 +267,59 @@ pub struct CompileArgs {
     #[arg(long = "pdf-standard", value_delimiter = ',')]
     pub pdf_standard: Vec<PdfStandard>,
 
+    /// The color space that all colors in an exported PDF are converted into.
+    /// By default, grayscale and CMYK colors are kept and all other colors are
+    /// converted into RGB. The conversion into CMYK does not use an ICC
+    /// profile.
+    #[arg(long = "pdf-color-space", value_name = "SPACE")]
+    pub pdf_color_space: Option<PdfColorSpace>,
+
+    /// File path to an ICC profile that describes the CMYK colors in an
+    /// exported PDF, typically the profile of the target printing process.
+    /// It only tags the colors and is not used to convert them.
+    #[arg(long = "cmyk-profile", value_name = "PATH")]
+    pub cmyk_profile: Option<PathBuf>,
+
     /// The PPI (pixels per inch) to use for PNG export.
     #[arg(long = "ppi", default_value_t = 144.0)]
     pub ppi: f32,
 
+    /// Arranges the pages two-up on sheets for printing, either in reading
+    /// order or reordered into a booklet for saddle stitching.
+    ///
+    /// Each page of the output is one side of a sheet. The `--pages` option
+    /// then selects sides of sheets rather than pages of the document.
+    #[arg(long = "impose", value_name = "LAYOUT")]
+    pub impose: Option<ImpositionLayout>,
+
+    /// The number of sheets that are folded together into one signature of a
+    /// booklet. By default, all sheets form a single signature.
+    #[arg(long = "signature", value_name = "SHEETS", requires = "impose")]
+    pub signature: Option<NonZeroUsize>,
+
+    /// How far the pages on the innermost sheet of each signature are shifted
+    /// towards the spine, in points, to compensate for creep when folding.
+    #[arg(long = "creep", value_name = "PT", default_value_t = 0.0, requires = "impose")]
+    pub creep: f64,
+
+    /// Adds crop marks and fold marks around the imposed pages.
+    #[arg(long = "crop-marks", requires = "impose")]
+    pub crop_marks: bool,
+
     /// File path to which a Makefile with the current compilation's
     /// dependencies will be written.
     #[clap(long = "make-deps", value_name = "PATH")]
     pub make_deps: Option<PathBuf>,
 
+    /// File path to which the numbers and pages of all referenceable elements
+    /// will be written as JSON.
+    ///
+    /// Load the file with `set ref(external: json(PATH).labels)` to keep
+    /// references to other parts of the document intact when compiling only
+    /// a part of it.
+    #[clap(long = "save-state", value_name = "PATH")]
+    pub save_state: Option<PathBuf>,
+
     /// Processing arguments.
     #[clap(flatten)]
     pub process: ProcessArgs,
@@ -308,6 +373,11 @@ pub struct WorldArgs {
         value_parser = parse_source_date_epoch,
     )]
     pub creation_timestamp: Option<DateTime<Utc>>,
+
+    /// Show a placeholder for images that can't be loaded or decoded instead
+    /// of failing.
+    #[clap(long = "image-placeholders")]
+    pub image_placeholders: bool,
 }
 
 /// Arguments for configuration the process of compilation itself.
@@ -445,6 +515,18 @@ pub enum OutputFormat {
 
 display_possible_values!(OutputFormat);
 
+/// How to arrange pages on sheets.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
+pub enum ImpositionLayout {
+    /// Two pages side by side, in reading order.
+    #[value(name = "2-up")]
+    TwoUp,
+    /// Two pages side by side, ordered for folding into a booklet.
+    Booklet,
+}
+
+display_possible_values!(ImpositionLayout);
+
 /// Which format to use for diagnostics.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, ValueEnum)]
 pub enum DiagnosticFormat {
@@ -510,6 +592,17 @@ pub enum PdfStandard {
 
 display_possible_values!(PdfStandard);
 
+/// A color space that all colors in a PDF can be converted into.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
+pub enum PdfColorSpace {
+    /// Red, green, and blue, for screens.
+    Rgb,
+    /// Cyan, magenta, yellow, and key, for print.
+    Cmyk,
+}
+
+display_possible_values!(PdfColorSpace);
+
 // Output file format for query command
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, ValueEnum)]
 pub enum SerializationFormat {
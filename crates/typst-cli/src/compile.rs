@@ -6,23 +6,24 @@ use std::path::{Path, PathBuf};
 use chrono::{DateTime, Datelike, Timelike, Utc};
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 use codespan_reporting::term;
-use ecow::eco_format;
+use ecow::{eco_format, EcoVec};
 use parking_lot::RwLock;
 use pathdiff::diff_paths;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use typst::diag::{
     bail, At, Severity, SourceDiagnostic, SourceResult, StrResult, Warned,
 };
-use typst::foundations::{Datetime, Smart};
+use typst::foundations::{Bytes, Datetime, Dict, IntoValue, Smart};
 use typst::html::HtmlDocument;
-use typst::layout::{Frame, Page, PageRanges, PagedDocument};
+use typst::layout::{Abs, Frame, Imposition, Page, PageRanges, PagedDocument};
 use typst::syntax::{FileId, Lines, Span};
+use typst::Document;
 use typst::WorldExt;
 use typst_pdf::{PdfOptions, PdfStandards, Timestamp};
 
 use crate::args::{
-    CompileArgs, CompileCommand, DiagnosticFormat, Input, Output, OutputFormat,
-    PdfStandard, WatchCommand,
+    CompileArgs, CompileCommand, DiagnosticFormat, ImpositionLayout, Input, Output,
+    OutputFormat, PdfColorSpace, PdfStandard, WatchCommand,
 };
 #[cfg(feature = "http-server")]
 use crate::server::HtmlServer;
@@ -30,7 +31,7 @@ use crate::timings::Timer;
 
 use crate::watch::Status;
 use crate::world::SystemWorld;
-use crate::{set_failed, terminal};
+use crate::{merge, set_failed, terminal};
 
 type CodespanResult<T> = Result<T, CodespanError>;
 type CodespanError = codespan_reporting::files::Error;
@@ -56,6 +57,11 @@ pub struct CompileConfig {
     pub output_format: OutputFormat,
     /// Which pages to export.
     pub pages: Option<PageRanges>,
+    /// Path to records to compile the document for, one output per record.
+    pub merge: Option<PathBuf>,
+    /// The inputs given on the command line, to which the fields of merge
+    /// records are added.
+    pub inputs: Dict,
     /// The document's creation date formatted as a UNIX timestamp, with UTC suffix.
     pub creation_timestamp: Option<DateTime<Utc>>,
     /// The format to emit diagnostics in.
@@ -65,10 +71,18 @@ pub struct CompileConfig {
     pub open: Option<Option<String>>,
     /// A list of standards the PDF should conform to.
     pub pdf_standards: PdfStandards,
+    /// The color space that all colors in the PDF are converted into.
+    pub pdf_color_space: Smart<typst_pdf::PdfColorSpace>,
+    /// An ICC profile describing the CMYK colors in the PDF.
+    pub cmyk_profile: Option<Bytes>,
     /// A path to write a Makefile rule describing the current compilation.
     pub make_deps: Option<PathBuf>,
+    /// A path to write the numbering state of the document to.
+    pub save_state: Option<PathBuf>,
     /// The PPI (pixels per inch) to use for PNG export.
     pub ppi: f32,
+    /// How to arrange the pages on sheets before exporting them.
+    pub imposition: Option<Imposition>,
     /// The export cache for images, used for caching output files in `typst
     /// watch` sessions with images.
     pub export_cache: ExportCache,
@@ -129,10 +143,27 @@ impl CompileConfig {
             PageRanges::new(export_ranges.iter().map(|r| r.0.clone()).collect())
         });
 
+        let imposition = args.impose.map(|layout| Imposition {
+            booklet: layout == ImpositionLayout::Booklet,
+            signature: args.signature,
+            creep: Abs::pt(args.creep),
+            marks: args.crop_marks,
+        });
+
         let pdf_standards = PdfStandards::new(
             &args.pdf_standard.iter().copied().map(Into::into).collect::<Vec<_>>(),
         )?;
 
+        let cmyk_profile = args
+            .cmyk_profile
+            .as_ref()
+            .map(|path| {
+                fs::read(path).map(Bytes::new).map_err(|err| {
+                    eco_format!("failed to read CMYK profile {} ({err})", path.display())
+                })
+            })
+            .transpose()?;
+
         #[cfg(feature = "http-server")]
         let server = match watch {
             Some(command)
@@ -149,10 +180,23 @@ impl CompileConfig {
             output,
             output_format,
             pages,
+            merge: args.merge.clone(),
+            inputs: args
+                .world
+                .inputs
+                .iter()
+                .map(|(k, v)| (k.as_str().into(), v.as_str().into_value()))
+                .collect(),
             pdf_standards,
+            pdf_color_space: args
+                .pdf_color_space
+                .map_or(Smart::Auto, |space| Smart::Custom(space.into())),
+            cmyk_profile,
             creation_timestamp: args.world.creation_timestamp,
             make_deps: args.make_deps.clone(),
+            save_state: args.save_state.clone(),
             ppi: args.ppi,
+            imposition,
             diagnostic_format: args.process.diagnostic_format,
             open: args.open.clone(),
             export_cache: ExportCache::new(),
@@ -213,14 +257,73 @@ pub fn compile_once(
     Ok(())
 }
 
-/// Compile and then export the document.
+/// Compile and then export the document, once for each merge record if there
+/// are any.
 fn compile_and_export(
     world: &mut SystemWorld,
     config: &mut CompileConfig,
+) -> Warned<SourceResult<Vec<Output>>> {
+    let Some(path) = &config.merge else {
+        return compile_and_export_one(world, config, None);
+    };
+
+    let template = config.output.clone();
+    let prepared = merge::load_records(path).at(Span::detached()).and_then(|records| {
+        let outputs = merge::outputs(&template, &records).at(Span::detached())?;
+        Ok((records, outputs))
+    });
+    let (records, outputs) = match prepared {
+        Ok(prepared) => prepared,
+        Err(errors) => return Warned { output: Err(errors), warnings: EcoVec::new() },
+    };
+
+    let mut warnings = EcoVec::new();
+    let mut exported = vec![];
+    let mut output = Ok(());
+    for (i, (record, path)) in records.into_iter().zip(outputs).enumerate() {
+        let hint = eco_format!("this happened while compiling record {}", i + 1);
+        let mut inputs = config.inputs.clone();
+        inputs.extend(record);
+
+        config.output = path;
+        let warned = compile_and_export_one(world, config, Some(inputs));
+        warnings.extend(warned.warnings.into_iter().map(|w| w.with_hint(hint.clone())));
+        match warned.output {
+            Ok(outputs) => exported.extend(outputs),
+            Err(errors) => {
+                output =
+                    Err(errors.into_iter().map(|e| e.with_hint(hint.clone())).collect());
+                break;
+            }
+        }
+    }
+
+    config.output = template;
+    Warned { output: output.map(|()| exported), warnings }
+}
+
+/// Compile a document with the given inputs, or with the world's own inputs if
+/// they are `None`.
+fn compile_document<D: Document>(
+    world: &SystemWorld,
+    inputs: Option<Dict>,
+) -> Warned<SourceResult<D>> {
+    match inputs {
+        Some(inputs) => typst::compile_batch(world, [inputs]).next().unwrap(),
+        None => typst::compile(world),
+    }
+}
+
+/// Compile and then export a single document.
+fn compile_and_export_one(
+    world: &mut SystemWorld,
+    config: &mut CompileConfig,
+    inputs: Option<Dict>,
 ) -> Warned<SourceResult<Vec<Output>>> {
     match config.output_format {
         OutputFormat::Html => {
-            let Warned { output, warnings } = typst::compile::<HtmlDocument>(world);
+            let Warned { output, warnings } =
+                compile_document::<HtmlDocument>(world, inputs);
             let result = output.and_then(|document| export_html(&document, config));
             Warned {
                 output: result.map(|()| vec![config.output.clone()]),
@@ -228,8 +331,14 @@ fn compile_and_export(
             }
         }
         _ => {
-            let Warned { output, warnings } = typst::compile::<PagedDocument>(world);
-            let result = output.and_then(|document| export_paged(&document, config));
+            let Warned { output, warnings } =
+                compile_document::<PagedDocument>(world, inputs);
+            let result = output.and_then(|document| {
+                if let Some(path) = &config.save_state {
+                    crate::state::save_state(&*world, &document, path)?;
+                }
+                export_paged(&document, config)
+            });
             Warned { output: result, warnings }
         }
     }
@@ -255,6 +364,15 @@ fn export_paged(
     document: &PagedDocument,
     config: &CompileConfig,
 ) -> SourceResult<Vec<Output>> {
+    let imposed;
+    let document = match &config.imposition {
+        Some(imposition) => {
+            imposed = imposition.impose(document);
+            &imposed
+        }
+        None => document,
+    };
+
     match config.output_format {
         OutputFormat::Pdf => {
             export_pdf(document, config).map(|()| vec![config.output.clone()])
@@ -291,6 +409,8 @@ fn export_pdf(document: &PagedDocument, config: &CompileConfig) -> SourceResult<
         timestamp,
         page_ranges: config.pages.clone(),
         standards: config.pdf_standards.clone(),
+        color_space: config.pdf_color_space,
+        cmyk_profile: config.cmyk_profile.clone(),
     };
     let buffer = typst_pdf::pdf(document, &options)?;
     config
@@ -757,6 +877,15 @@ impl<'a> codespan_reporting::files::Files<'a> for SystemWorld {
     }
 }
 
+impl From<PdfColorSpace> for typst_pdf::PdfColorSpace {
+    fn from(space: PdfColorSpace) -> Self {
+        match space {
+            PdfColorSpace::Rgb => typst_pdf::PdfColorSpace::Rgb,
+            PdfColorSpace::Cmyk => typst_pdf::PdfColorSpace::Cmyk,
+        }
+    }
+}
+
 impl From<PdfStandard> for typst_pdf::PdfStandard {
     fn from(standard: PdfStandard) -> Self {
         match standard {
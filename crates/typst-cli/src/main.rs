@@ -4,10 +4,12 @@ mod download;
 mod fonts;
 mod greet;
 mod init;
+mod merge;
 mod package;
 mod query;
 #[cfg(feature = "http-server")]
 mod server;
+mod state;
 mod terminal;
 mod timings;
 #[cfg(feature = "self-update")]
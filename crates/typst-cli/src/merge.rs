@@ -0,0 +1,204 @@
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use ecow::{eco_format, EcoString};
+use typst::diag::{bail, HintedStrResult, StrResult};
+use typst::foundations::{Dict, IntoValue, Repr, Value};
+
+use crate::args::Output;
+
+/// Placeholders in output paths that are reserved for page-wise export.
+const RESERVED: [&str; 4] = ["p", "0p", "n", "t"];
+
+/// Load the records of a mail merge from a CSV or JSON file.
+///
+/// A CSV file must have a header row, whose columns become the keys of the
+/// records. A JSON file must contain an array of objects.
+///
+/// Like inputs given with `--input`, all fields are strings.
+pub fn load_records(path: &Path) -> StrResult<Vec<Dict>> {
+    let data = std::fs::read(path).map_err(|err| {
+        eco_format!("failed to read merge data {} ({err})", path.display())
+    })?;
+
+    let extension = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+    if extension.eq_ignore_ascii_case("csv") {
+        parse_csv(&data)
+    } else if extension.eq_ignore_ascii_case("json") {
+        parse_json(&data)
+    } else {
+        bail!(
+            "could not infer format of merge data {}.\n\
+             merge data must be a CSV or JSON file",
+            path.display()
+        )
+    }
+}
+
+/// Parse CSV data with a header row into records of strings.
+fn parse_csv(data: &[u8]) -> StrResult<Vec<Dict>> {
+    let format_error =
+        |err: csv::Error| eco_format!("failed to parse merge data as CSV ({err})");
+
+    let mut reader = csv::ReaderBuilder::new().from_reader(data);
+    let headers = reader.headers().map_err(format_error)?.clone();
+    reader
+        .records()
+        .map(|row| {
+            let row = row.map_err(format_error)?;
+            Ok(headers
+                .iter()
+                .zip(&row)
+                .map(|(key, value)| (key.into(), value.into_value()))
+                .collect())
+        })
+        .collect()
+}
+
+/// Parse JSON data with an array of objects into records of strings.
+///
+/// Fields that are not strings are converted to their JSON representation.
+fn parse_json(data: &[u8]) -> StrResult<Vec<Dict>> {
+    let records: Vec<serde_json::Map<String, serde_json::Value>> =
+        serde_json::from_slice(data)
+            .map_err(|err| eco_format!("failed to parse merge data as JSON ({err})"))?;
+
+    Ok(records
+        .into_iter()
+        .map(|record| {
+            record
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        serde_json::Value::String(s) => s,
+                        v => v.to_string(),
+                    };
+                    (key.as_str().into(), value.as_str().into_value())
+                })
+                .collect()
+        })
+        .collect())
+}
+
+/// Determine the output of each merge record.
+pub fn outputs(template: &Output, records: &[Dict]) -> HintedStrResult<Vec<Output>> {
+    let Output::Path(template) = template else {
+        if records.len() > 1 {
+            bail!("cannot export multiple merge records to stdout");
+        }
+        return Ok(vec![Output::Stdout; records.len()]);
+    };
+
+    let mut outputs = Vec::<Output>::with_capacity(records.len());
+    for (i, record) in records.iter().enumerate() {
+        let path = format_output(template, i, records.len(), record);
+        let same = |output: &Output| matches!(output, Output::Path(p) if *p == path);
+        if let Some(j) = outputs.iter().position(same) {
+            bail!(
+                "merge records {} and {} have the same output path {}",
+                j + 1,
+                i + 1,
+                path.display();
+                hint: "use `{{r}}`, `{{0r}}`, or a field name in the output path"
+            );
+        }
+        outputs.push(Output::Path(path));
+    }
+
+    Ok(outputs)
+}
+
+/// Fill in the record placeholders of an output path.
+///
+/// `{r}` is replaced with the number of the record, `{0r}` with the same
+/// number padded with zeros, and `{key}` with the record's value for `key`.
+pub fn format_output(path: &Path, index: usize, total: usize, record: &Dict) -> PathBuf {
+    // Find the base 10 width of number `i`
+    fn width(i: usize) -> usize {
+        1 + i.checked_ilog10().unwrap_or(0) as usize
+    }
+
+    let mut output = path
+        .to_string_lossy()
+        .replace("{r}", &format!("{}", index + 1))
+        .replace("{0r}", &format!("{:01$}", index + 1, width(total)));
+
+    for (key, value) in record {
+        if RESERVED.contains(&key.as_str()) {
+            continue;
+        }
+
+        let placeholder = eco_format!("{{{key}}}");
+        if output.contains(placeholder.as_str()) {
+            output = output.replace(placeholder.as_str(), &display(value));
+        }
+    }
+
+    PathBuf::from(output)
+}
+
+/// Display a record value so that it can be part of a file name.
+fn display(value: &Value) -> EcoString {
+    let text = match value {
+        Value::Str(s) => s.clone().into(),
+        v => v.repr(),
+    };
+    text.chars()
+        .map(|c| if std::path::is_separator(c) { '_' } else { c })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use typst::foundations::dict;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_json() {
+        let data = br#"[{"name": "Alice", "age": 42, "vip": true, "tags": ["a"]}]"#;
+        let records = parse_json(data).unwrap();
+        assert_eq!(
+            records,
+            [dict! {
+                "name" => "Alice",
+                "age" => "42",
+                "vip" => "true",
+                "tags" => r#"["a"]"#,
+            }]
+        );
+        assert!(parse_json(b"{}").is_err());
+    }
+
+    #[test]
+    fn test_format_output() {
+        let record = dict! { "name" => "A/B", "p" => "x" };
+        let format =
+            |path: &str, index| format_output(Path::new(path), index, 12, &record);
+        assert_eq!(format("out-{r}.pdf", 0), Path::new("out-1.pdf"));
+        assert_eq!(format("out-{0r}.pdf", 2), Path::new("out-03.pdf"));
+        assert_eq!(format("{name}.pdf", 0), Path::new("A_B.pdf"));
+        assert_eq!(format("{p}-{unknown}.pdf", 0), Path::new("{p}-{unknown}.pdf"));
+    }
+
+    #[test]
+    fn test_outputs() {
+        let records = [dict! { "name" => "A" }, dict! { "name" => "B" }];
+        let template = Output::Path("{name}.pdf".into());
+        let paths: Vec<_> = outputs(&template, &records)
+            .unwrap()
+            .into_iter()
+            .map(|output| output.to_string())
+            .collect();
+        assert_eq!(paths, ["A.pdf", "B.pdf"]);
+
+        let template = Output::Path("out.pdf".into());
+        let err = outputs(&template, &records).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "merge records 1 and 2 have the same output path out.pdf"
+        );
+        assert!(outputs(&Output::Stdout, &records).is_err());
+        assert!(outputs(&Output::Stdout, &records[..1]).is_ok());
+    }
+}
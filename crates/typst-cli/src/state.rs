@@ -0,0 +1,34 @@
+use std::path::Path;
+
+use ecow::eco_format;
+use serde::Serialize;
+use typst::diag::{At, SourceResult};
+use typst::foundations::Dict;
+use typst::layout::PagedDocument;
+use typst::syntax::Span;
+use typst::World;
+
+/// The numbering state of a document, as written by `--save-state`.
+#[derive(Serialize)]
+struct State {
+    /// The referenceable elements by label, in the form expected by
+    /// `ref.external`.
+    labels: Dict,
+}
+
+/// Write the numbers and pages of all labelled, numbered elements to a JSON
+/// file.
+pub fn save_state(
+    world: &dyn World,
+    document: &PagedDocument,
+    path: &Path,
+) -> SourceResult<()> {
+    let labels = typst::external_targets(world, document)?;
+    let json = serde_json::to_string_pretty(&State { labels })
+        .map_err(|err| eco_format!("failed to serialize state ({err})"))
+        .at(Span::detached())?;
+
+    std::fs::write(path, json)
+        .map_err(|err| eco_format!("failed to write state file ({err})"))
+        .at(Span::detached())
+}
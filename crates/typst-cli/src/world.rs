@@ -12,6 +12,7 @@ use typst::foundations::{Bytes, Datetime, Dict, IntoValue};
 use typst::syntax::{FileId, Lines, Source, VirtualPath};
 use typst::text::{Font, FontBook};
 use typst::utils::LazyHash;
+use typst::visualize::ImageElem;
 use typst::{Library, World};
 use typst_kit::fonts::{FontSlot, Fonts};
 use typst_kit::package::PackageStorage;
@@ -120,7 +121,12 @@ impl SystemWorld {
                 })
                 .collect();
 
-            Library::builder().with_inputs(inputs).with_features(features).build()
+            let mut library =
+                Library::builder().with_inputs(inputs).with_features(features).build();
+            if world_args.image_placeholders {
+                library.styles.set(ImageElem::set_placeholder(true));
+            }
+            library
         };
 
         let fonts = Fonts::searcher()
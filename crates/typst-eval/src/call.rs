@@ -23,62 +23,91 @@ impl Eval for ast::FuncCall<'_> {
     type Output = Value;
 
     fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
-        let span = self.span();
-        let callee = self.callee();
-        let callee_span = callee.span();
-        let args = self.args();
-
-        vm.engine.route.check_call_depth().at(span)?;
-
-        // Try to evaluate as a call to an associated function or field.
-        let (callee_value, args_value) = if let ast::Expr::FieldAccess(access) = callee {
-            let target = access.target();
-            let field = access.field();
-            match eval_field_call(target, field, args, span, vm)? {
-                FieldCall::Normal(callee, args) => {
-                    if vm.inspected == Some(callee_span) {
-                        vm.trace(callee.clone());
-                    }
-                    (callee, args)
+        match prepare_call(self, vm)? {
+            PreparedCall::Func(func, args) => call_func(vm, func, args, self.span()),
+            PreparedCall::Resolved(value) => Ok(value),
+        }
+    }
+}
+
+/// A function call whose callee and arguments were evaluated.
+pub(crate) enum PreparedCall {
+    /// The function to call and the arguments to call it with.
+    Func(Func, Args),
+    /// The call was already resolved while evaluating it.
+    Resolved(Value),
+}
+
+/// Evaluate the callee and arguments of a function call without calling the
+/// function.
+pub(crate) fn prepare_call(
+    call: ast::FuncCall,
+    vm: &mut Vm,
+) -> SourceResult<PreparedCall> {
+    let span = call.span();
+    let callee = call.callee();
+    let callee_span = callee.span();
+    let args = call.args();
+
+    // Try to evaluate as a call to an associated function or field.
+    let (callee_value, args_value) = if let ast::Expr::FieldAccess(access) = callee {
+        let target = access.target();
+        let field = access.field();
+        match eval_field_call(target, field, args, span, vm)? {
+            FieldCall::Normal(callee, args) => {
+                if vm.inspected == Some(callee_span) {
+                    vm.trace(callee.clone());
                 }
-                FieldCall::Resolved(value) => return Ok(value),
+                (callee, args)
             }
-        } else {
-            // Function call order: we evaluate the callee before the arguments.
-            (callee.eval(vm)?, args.eval(vm)?.spanned(span))
-        };
+            FieldCall::Resolved(value) => return Ok(PreparedCall::Resolved(value)),
+        }
+    } else {
+        // Function call order: we evaluate the callee before the arguments.
+        (callee.eval(vm)?, args.eval(vm)?.spanned(span))
+    };
 
-        let func_result = callee_value.clone().cast::<Func>();
+    let func_result = callee_value.clone().cast::<Func>();
 
-        if func_result.is_err() && in_math(callee) {
-            return wrap_args_in_math(
-                callee_value,
-                callee_span,
-                args_value,
-                args.trailing_comma(),
-            );
-        }
+    if func_result.is_err() && in_math(callee) {
+        return wrap_args_in_math(
+            callee_value,
+            callee_span,
+            args_value,
+            args.trailing_comma(),
+        )
+        .map(PreparedCall::Resolved);
+    }
 
-        let func = func_result
-            .map_err(|err| hint_if_shadowed_std(vm, &self.callee(), err))
-            .at(callee_span)?;
-
-        let point = || Tracepoint::Call(func.name().map(Into::into));
-        let f = || {
-            func.call(&mut vm.engine, vm.context, args_value).trace(
-                vm.world(),
-                point,
-                span,
-            )
-        };
+    let func = func_result
+        .map_err(|err| hint_if_shadowed_std(vm, &callee, err))
+        .at(callee_span)?;
+
+    Ok(PreparedCall::Func(func, args_value))
+}
 
-        // Stacker is broken on WASM.
-        #[cfg(target_arch = "wasm32")]
-        return f();
+/// Call a function with the given arguments from the call at `span`.
+pub(crate) fn call_func(
+    vm: &mut Vm,
+    func: Func,
+    args: Args,
+    span: Span,
+) -> SourceResult<Value> {
+    let max_depth = vm.engine.world.library().limits.max_call_depth;
+    vm.engine.route.check_call_depth(max_depth).at(span)?;
 
-        #[cfg(not(target_arch = "wasm32"))]
-        stacker::maybe_grow(32 * 1024, 2 * 1024 * 1024, f)
-    }
+    let point = || Tracepoint::Call(func.name().map(Into::into));
+    let f = || {
+        func.call(&mut vm.engine, vm.context, args)
+            .trace(vm.world(), point, span)
+    };
+
+    // Stacker is broken on WASM.
+    #[cfg(target_arch = "wasm32")]
+    return f();
+
+    #[cfg(not(target_arch = "wasm32"))]
+    stacker::maybe_grow(32 * 1024, 2 * 1024 * 1024, f)
 }
 
 impl Eval for ast::Args<'_> {
@@ -178,11 +207,71 @@ pub fn eval_closure(
     world: Tracked<dyn World + '_>,
     introspector: Tracked<Introspector>,
     traced: Tracked<Traced>,
-    sink: TrackedMut<Sink>,
+    mut sink: TrackedMut<Sink>,
     route: Tracked<Route>,
     context: Tracked<Context>,
     mut args: Args,
 ) -> SourceResult<Value> {
+    // Closures returned as a call from a closure are called here instead of
+    // within the returning closure. This way, recursion through `return`
+    // takes neither stack space nor call depth. Instead, it is limited like
+    // the iterations of a loop.
+    let max = world.library().limits.max_while_iterations;
+    let mut tail: Option<(Func, Span)> = None;
+    for _ in 0..=max {
+        let engine = Engine {
+            routines,
+            world,
+            introspector,
+            traced,
+            sink: TrackedMut::reborrow_mut(&mut sink),
+            route: Route::extend(route),
+        };
+
+        let evaluated = match &tail {
+            None => eval_closure_body(func, closure, engine, context, args),
+            Some((callee, span)) => {
+                let closure = callee.to_closure().unwrap();
+                let point = || Tracepoint::Call(callee.name().map(Into::into));
+                eval_closure_body(callee, closure, engine, context, args)
+                    .trace(world, point, *span)
+            }
+        };
+
+        match evaluated? {
+            Evaluated::Value(value) => return Ok(value),
+            Evaluated::Tail(callee, next, span) => {
+                tail = Some((callee, span));
+                args = next;
+            }
+        }
+    }
+
+    let span = tail.map_or(Span::detached(), |(_, span)| span);
+    bail!(
+        span, "maximum number of tail calls exceeded";
+        hint: "maybe a function returns a call of itself without a base case";
+        hint: "the limit is {max} consecutive tail calls",
+    );
+}
+
+/// The result of evaluating a closure's body.
+enum Evaluated {
+    /// The closure returned a value.
+    Value(Value),
+    /// The closure returned a call of another closure, which is yet to be
+    /// performed.
+    Tail(Func, Args, Span),
+}
+
+/// Evaluate the body of a closure with the given arguments.
+fn eval_closure_body(
+    func: &Func,
+    closure: &LazyHash<Closure>,
+    engine: Engine,
+    context: Tracked<Context>,
+    mut args: Args,
+) -> SourceResult<Evaluated> {
     let (name, params, body) = match closure.node.cast::<ast::Closure>() {
         Some(node) => (node.name(), node.params(), node.body()),
         None => (None, ast::Params::default(), closure.node.cast().unwrap()),
@@ -193,16 +282,6 @@ pub fn eval_closure(
     let mut scopes = Scopes::new(None);
     scopes.top = closure.captured.clone();
 
-    // Prepare the engine.
-    let engine = Engine {
-        routines,
-        world,
-        introspector,
-        traced,
-        sink,
-        route: Route::extend(route),
-    };
-
     // Prepare VM.
     let mut vm = Vm::new(engine, context, scopes, body.span());
 
@@ -263,14 +342,20 @@ pub fn eval_closure(
 
     // Handle control flow.
     let output = body.eval(&mut vm)?;
+    if let Some((func, args, span)) = vm.tail.take() {
+        return Ok(Evaluated::Tail(func, args, span));
+    }
+
     match vm.flow {
-        Some(FlowEvent::Return(_, Some(explicit), _)) => return Ok(explicit),
+        Some(FlowEvent::Return(_, Some(explicit), _)) => {
+            return Ok(Evaluated::Value(explicit))
+        }
         Some(FlowEvent::Return(_, None, _)) => {}
         Some(flow) => bail!(flow.forbidden()),
         None => {}
     }
 
-    Ok(output)
+    Ok(Evaluated::Value(output))
 }
 
 /// This used only as the return value of `eval_field_call`.
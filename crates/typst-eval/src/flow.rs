@@ -4,10 +4,7 @@ use typst_syntax::ast::{self, AstNode};
 use typst_syntax::{Span, SyntaxKind, SyntaxNode};
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::{destructure, Eval, Vm};
-
-/// The maximum number of loop iterations.
-const MAX_ITERATIONS: usize = 10_000;
+use crate::{call_func, destructure, prepare_call, Eval, PreparedCall, Vm};
 
 /// A control flow event that occurred during evaluation.
 #[derive(Debug, Clone, PartialEq)]
@@ -71,6 +68,7 @@ impl Eval for ast::WhileLoop<'_> {
 
         let condition = self.condition();
         let body = self.body();
+        let max = vm.engine.world.library().limits.max_while_iterations;
 
         while condition.eval(vm)?.cast::<bool>().at(condition.span())? {
             if i == 0
@@ -78,7 +76,7 @@ impl Eval for ast::WhileLoop<'_> {
                 && !can_diverge(body.to_untyped())
             {
                 bail!(condition.span(), "condition is always true");
-            } else if i >= MAX_ITERATIONS {
+            } else if i >= max {
                 bail!(self.span(), "loop seems to be infinite");
             }
 
@@ -215,7 +213,28 @@ impl Eval for ast::FuncReturn<'_> {
     type Output = Value;
 
     fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
-        let value = self.body().map(|body| body.eval(vm)).transpose()?;
+        let value = match self.body() {
+            // A returned call of a closure is left to the caller of the current
+            // function, such that recursion through `return` doesn't nest calls.
+            Some(ast::Expr::FuncCall(call)) => match prepare_call(call, vm)? {
+                PreparedCall::Func(func, args)
+                    if func.to_closure().is_some() && vm.flow.is_none() =>
+                {
+                    // The returned value is a placeholder that is replaced
+                    // by the result of the call.
+                    vm.tail = Some((func, args, call.span()));
+                    vm.flow =
+                        Some(FlowEvent::Return(self.span(), Some(Value::None), false));
+                    return Ok(Value::None);
+                }
+                PreparedCall::Func(func, args) => {
+                    Some(call_func(vm, func, args, call.span())?)
+                }
+                PreparedCall::Resolved(value) => Some(value),
+            },
+            body => body.map(|body| body.eval(vm)).transpose()?,
+        };
+
         if vm.flow.is_none() {
             vm.flow = Some(FlowEvent::Return(self.span(), value, false));
         }
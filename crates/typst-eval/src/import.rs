@@ -1,10 +1,14 @@
 use comemo::TrackedMut;
-use ecow::{eco_format, eco_vec, EcoString};
+use ecow::{eco_format, eco_vec, EcoString, EcoVec};
 use typst_library::diag::{
     bail, error, warning, At, FileError, SourceResult, Trace, Tracepoint,
 };
 use typst_library::engine::Engine;
-use typst_library::foundations::{Binding, Content, Module, Value};
+use typst_library::foundations::{
+    Binding, Content, Label, Module, Repr, SequenceElem, StyleChain, StyledElem, Styles,
+    Value,
+};
+use typst_library::model::HeadingElem;
 use typst_library::World;
 use typst_syntax::ast::{self, AstNode, BareImportError};
 use typst_syntax::package::{PackageManifest, PackageSpec};
@@ -91,7 +95,25 @@ impl Eval for ast::ModuleImport<'_> {
                 }
             }
             Some(ast::Imports::Wildcard) => {
+                let exclusions = self.exclusions();
+                if let Some(exclusions) = exclusions {
+                    let errors: EcoVec<_> = exclusions
+                        .iter()
+                        .filter(|ident| scope.get(ident).is_none())
+                        .map(|ident| error!(ident.span(), "unresolved import"))
+                        .collect();
+                    if !errors.is_empty() {
+                        return Err(errors);
+                    }
+                }
+
                 for (var, binding) in scope.iter() {
+                    let excluded = exclusions.is_some_and(|exclusions| {
+                        exclusions.iter().any(|ident| ident.as_str() == var.as_str())
+                    });
+                    if excluded {
+                        continue;
+                    }
                     vm.scopes.top.bind(var.clone(), binding.clone());
                 }
             }
@@ -178,7 +200,66 @@ impl Eval for ast::ModuleInclude<'_> {
             Value::Module(module) => module,
             v => bail!(span, "expected path or module, found {}", v.ty()),
         };
-        Ok(module.content())
+
+        let content = module.content();
+        let Some(part) = self.part() else { return Ok(content) };
+        let span = part.span();
+        let label = part.eval(vm)?.cast::<Label>().at(span)?;
+        match select_part(&content, label) {
+            Some(content) => Ok(content),
+            None => bail!(
+                span,
+                "label `{}` does not exist in the included content",
+                label.repr()
+            ),
+        }
+    }
+}
+
+/// Select the part of the content with the given label.
+///
+/// If the label is attached to a heading, the part extends up to the next
+/// heading of the same or a higher level, so that it includes the heading's
+/// whole section. Otherwise, it is just the labelled element. In both cases,
+/// the styles from surrounding set and show rules are retained.
+fn select_part(content: &Content, label: Label) -> Option<Content> {
+    let mut pieces = vec![];
+    flatten(content, &Styles::new(), &mut pieces);
+
+    let start = pieces.iter().position(|(piece, _)| piece.label() == Some(label))?;
+    let level = |(piece, styles): &(Content, Styles)| {
+        piece
+            .to_packed::<HeadingElem>()
+            .map(|heading| heading.resolve_level(StyleChain::new(styles)))
+    };
+
+    let end = match level(&pieces[start]) {
+        Some(outer) => pieces[start + 1..]
+            .iter()
+            .position(|piece| level(piece).is_some_and(|inner| inner <= outer))
+            .map_or(pieces.len(), |i| start + 1 + i),
+        None => start + 1,
+    };
+
+    Some(Content::sequence(
+        pieces
+            .drain(start..end)
+            .map(|(piece, styles)| piece.styled_with_map(styles)),
+    ))
+}
+
+/// Flatten content into its elements with the styles that apply to each.
+fn flatten(content: &Content, outer: &Styles, pieces: &mut Vec<(Content, Styles)>) {
+    if let Some(sequence) = content.to_packed::<SequenceElem>() {
+        for child in &sequence.children {
+            flatten(child, outer, pieces);
+        }
+    } else if let Some(styled) = content.to_packed::<StyledElem>() {
+        let mut styles = styled.styles.clone();
+        styles.apply(outer.clone());
+        flatten(&styled.child, &styles, pieces);
+    } else {
+        pieces.push((content.clone(), outer.clone()));
     }
 }
 
@@ -204,6 +285,13 @@ fn import_file(engine: &mut Engine, id: FileId, span: Span) -> SourceResult<Modu
         bail!(span, "cyclic import");
     }
 
+    // Bound the nesting of imports. The main file is part of the route, too.
+    if let Some(max) = engine.world.library().limits.max_import_depth {
+        if engine.route.files() > max {
+            bail!(span, "maximum import depth exceeded");
+        }
+    }
+
     // Evaluate the file.
     let point = || Tracepoint::Import;
     eval(
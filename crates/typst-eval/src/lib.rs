@@ -21,6 +21,7 @@ pub use self::vm::Vm;
 
 use self::access::*;
 use self::binding::*;
+use self::call::{call_func, prepare_call, PreparedCall};
 use self::methods::*;
 
 use comemo::{Track, Tracked, TrackedMut};
@@ -1,4 +1,4 @@
-use typst_library::diag::{warning, At, SourceResult};
+use typst_library::diag::{bail, warning, At, SourceResult};
 use typst_library::foundations::{
     Element, Fields, Func, Recipe, Selector, ShowableSelector, Styles, Transformation,
 };
@@ -49,6 +49,15 @@ impl Eval for ast::ShowRule<'_> {
             expr => expr.eval(vm)?.cast::<Transformation>().at(transform.span())?,
         };
 
+        // Without a selector, there is nothing to revoke the show rules for.
+        if selector.is_none() && matches!(transform, Transformation::Auto) {
+            bail!(
+                self.transform().span(),
+                "show rule with `auto` requires a selector";
+                hint: "use `show sel: auto` to revoke the show rules for `sel`"
+            );
+        }
+
         let recipe = Recipe::new(selector, transform, self.span());
         check_show_par_set_block(vm, &recipe);
 
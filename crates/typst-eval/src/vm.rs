@@ -1,7 +1,9 @@
 use comemo::Tracked;
 use typst_library::diag::warning;
 use typst_library::engine::Engine;
-use typst_library::foundations::{Binding, Context, IntoValue, Scopes, Value};
+use typst_library::foundations::{
+    Args, Binding, Context, Func, IntoValue, Scopes, Value,
+};
 use typst_library::World;
 use typst_syntax::ast::{self, AstNode};
 use typst_syntax::Span;
@@ -17,6 +19,9 @@ pub struct Vm<'a> {
     pub engine: Engine<'a>,
     /// A control flow event that is currently happening.
     pub flow: Option<FlowEvent>,
+    /// A call of a closure that is returned from the current function and
+    /// left to the caller to perform. Set together with a return event.
+    pub tail: Option<(Func, Args, Span)>,
     /// The stack of scopes.
     pub scopes: Scopes<'a>,
     /// A span that is currently under inspection.
@@ -34,7 +39,14 @@ impl<'a> Vm<'a> {
         target: Span,
     ) -> Self {
         let inspected = target.id().and_then(|id| engine.traced.get(id));
-        Self { engine, context, flow: None, scopes, inspected }
+        Self {
+            engine,
+            context,
+            flow: None,
+            tail: None,
+            scopes,
+            inspected,
+        }
     }
 
     /// Access the underlying world.
@@ -1,5 +1,7 @@
 use std::num::NonZeroUsize;
 
+use typst::foundations::Selector;
+use typst::introspection::Location;
 use typst::layout::{Frame, FrameItem, PagedDocument, Point, Position, Size};
 use typst::model::{Destination, Url};
 use typst::syntax::{FileId, LinkedNode, Side, Source, Span, SyntaxKind};
@@ -175,6 +177,29 @@ pub fn jump_from_cursor(
         .collect()
 }
 
+/// Find a stable anchor for a position in the document.
+///
+/// Returns the location of the last introspectable element (e.g. a paragraph,
+/// heading, or figure) that starts at or before the given position. Locations are derived
+/// from the elements themselves and their place in the document rather than
+/// from the layout, so they typically survive recompilations after edits
+/// elsewhere. A preview can thus keep its scroll position attached to the same
+/// element by resolving the anchor with [`jump_to_anchor`] after recompiling.
+pub fn anchor_at(document: &PagedDocument, position: Position) -> Option<Location> {
+    document.introspector.last_before(position)?.location()
+}
+
+/// Find the current position of an anchor obtained through [`anchor_at`].
+///
+/// Returns `None` if the anchored element does not exist in the document
+/// anymore.
+pub fn jump_to_anchor(document: &PagedDocument, anchor: Location) -> Option<Position> {
+    document
+        .introspector
+        .query_first(&Selector::Location(anchor))
+        .map(|_| document.introspector.position(anchor))
+}
+
 /// Find the position of a span in a frame.
 fn find_in_frame(frame: &Frame, span: Span) -> Option<Point> {
     for (mut pos, item) in frame.items() {
@@ -220,9 +245,11 @@ mod tests {
     use std::borrow::Borrow;
     use std::num::NonZeroUsize;
 
+    use typst::foundations::{NativeElement, Selector};
     use typst::layout::{Abs, Point, Position};
+    use typst::model::ParElem;
 
-    use super::{jump_from_click, jump_from_cursor, Jump};
+    use super::{anchor_at, jump_from_click, jump_from_cursor, jump_to_anchor, Jump};
     use crate::tests::{FilePos, TestWorld, WorldLike};
 
     fn point(x: f64, y: f64) -> Point {
@@ -392,6 +419,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_anchor() {
+        let world = TestWorld::new("= A\nText\n= B\nText");
+        let doc = typst::compile(&world).output.unwrap();
+        assert_eq!(anchor_at(&doc, pos(1, 0.0, 0.0).unwrap()), None);
+
+        let anchor = anchor_at(&doc, pos(1, 0.0, 1000.0).unwrap()).unwrap();
+        let found = jump_to_anchor(&doc, anchor).unwrap();
+        assert!(found.point.y > Abs::pt(10.0));
+        assert_eq!(anchor_at(&doc, found), Some(anchor));
+
+        let first = anchor_at(&doc, pos(1, 0.0, found.point.y.to_pt() - 1.0).unwrap());
+        assert_ne!(first, Some(anchor));
+    }
+
+    #[test]
+    fn test_anchor_paragraph() {
+        let world = TestWorld::new("First\n\nSecond\n\nThird");
+        let doc = typst::compile(&world).output.unwrap();
+        let anchor = anchor_at(&doc, pos(1, 0.0, 1000.0).unwrap()).unwrap();
+        let elem = doc.introspector.query_first(&Selector::Location(anchor)).unwrap();
+        assert_eq!(elem.func(), ParElem::elem());
+        assert_eq!(elem.plain_text(), "Third");
+    }
+
     #[test]
     fn test_jump_from_cursor() {
         let s = "*Hello* #box[ABC] World";
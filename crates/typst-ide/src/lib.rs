@@ -11,7 +11,9 @@ mod utils;
 pub use self::analyze::{analyze_expr, analyze_import, analyze_labels};
 pub use self::complete::{autocomplete, Completion, CompletionKind};
 pub use self::definition::{definition, Definition};
-pub use self::jump::{jump_from_click, jump_from_cursor, Jump};
+pub use self::jump::{
+    anchor_at, jump_from_click, jump_from_cursor, jump_to_anchor, Jump,
+};
 pub use self::matchers::{deref_target, named_items, DerefTarget, NamedItem};
 pub use self::tooltip::{tooltip, Tooltip};
 
@@ -0,0 +1,236 @@
+use ecow::{eco_format, EcoString};
+use typst_library::diag::{bail, SourceResult};
+use typst_library::engine::Engine;
+use typst_library::foundations::{Content, NativeElement, Packed, StyleChain};
+use typst_library::introspection::{Locator, SplitLocator};
+use typst_library::layout::{Abs, Axes, Frame, FrameItem, Point, Region, Size};
+use typst_library::text::{
+    BottomEdge, BottomEdgeMetric, TextElem, TextSize, TopEdge, TopEdgeMetric,
+};
+use typst_library::visualize::{
+    BoardElem, BoardKind, Color, Curve, FillRule, FixedStroke, Geometry, Paint, Shape,
+    Stroke,
+};
+use typst_syntax::Span;
+
+/// Layout the board diagram.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_board(
+    elem: &Packed<BoardElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    _: Region,
+) -> SourceResult<Frame> {
+    let span = elem.span();
+    let position = &elem.position;
+    let kind = elem.kind(styles);
+    let (width, height) = (position.width(), position.height());
+    let square = elem.size(styles) / width as f64;
+    let size = Size::new(square * width as f64, square * height as f64);
+    let flipped = elem.flipped(styles);
+    let light = elem.light(styles);
+    let dark = elem.dark(styles);
+
+    let mut layouter = BoardLayouter {
+        engine,
+        locator: locator.split(),
+        styles,
+        span,
+        frame: Frame::hard(size),
+        square,
+        ink: FixedStroke::from_pair(Color::BLACK, square / 30.0),
+    };
+
+    // Draw the board itself.
+    if kind == BoardKind::Go {
+        layouter.rect(Point::zero(), size, light.clone());
+        for x in 0..width {
+            let start = Point::new(square * (x as f64 + 0.5), square / 2.0);
+            layouter.line(start, Point::with_y(size.y - square));
+        }
+        for y in 0..height {
+            let start = Point::new(square / 2.0, square * (y as f64 + 0.5));
+            layouter.line(start, Point::with_x(size.x - square));
+        }
+    } else {
+        for y in 0..height {
+            for x in 0..width {
+                let paint = if (x + y) % 2 == 0 { &light } else { &dark };
+                layouter.rect(layouter.origin(x, y), Size::splat(square), paint.clone());
+            }
+        }
+    }
+
+    // Draw the pieces.
+    for y in 0..height {
+        for x in 0..width {
+            let (px, py) = if flipped { (width - 1 - x, height - 1 - y) } else { (x, y) };
+            let Some(piece) = position.get(px, py) else { continue };
+            if !kind.pieces().contains(piece) {
+                bail!(span, "invalid piece `{piece}` for this kind of board");
+            }
+
+            let origin = layouter.origin(x, y);
+            let white = match kind {
+                BoardKind::Chess => piece.is_ascii_uppercase(),
+                BoardKind::Go | BoardKind::Checkers => matches!(piece, 'w' | 'W'),
+            };
+            match kind {
+                BoardKind::Chess => layouter.chess_piece(origin, piece, white)?,
+                BoardKind::Go => layouter.disc(origin, 0.95, white),
+                BoardKind::Checkers => {
+                    layouter.disc(origin, 0.8, white);
+                    if piece.is_ascii_uppercase() {
+                        let crown = if white { Color::BLACK } else { Color::WHITE };
+                        let stroke = FixedStroke::from_pair(crown, square / 20.0);
+                        layouter.circle(origin, 0.45, None, stroke);
+                    }
+                }
+            }
+        }
+    }
+
+    // Label the files and ranks.
+    if elem.coordinates(styles) && kind != BoardKind::Go {
+        let inset = square / 20.0;
+        for x in 0..width {
+            let file = if flipped { width - 1 - x } else { x };
+            let letter = (b'a' + (file % 26) as u8) as char;
+            let y = height - 1;
+            let fill = if (x + y) % 2 == 0 { &dark } else { &light };
+            let label = layouter.label(eco_format!("{letter}"), fill.clone())?;
+            let corner = layouter.origin(x + 1, y + 1);
+            let pos = corner - Point::new(label.width() + inset, label.height() + inset);
+            layouter.frame.push_frame(pos, label);
+        }
+        for y in 0..height {
+            let rank = if flipped { y + 1 } else { height - y };
+            let fill = if y % 2 == 0 { &dark } else { &light };
+            let label = layouter.label(eco_format!("{rank}"), fill.clone())?;
+            let pos = layouter.origin(0, y) + Point::splat(inset);
+            layouter.frame.push_frame(pos, label);
+        }
+    }
+
+    Ok(layouter.frame)
+}
+
+/// Draws the parts of a board diagram into one frame.
+struct BoardLayouter<'a, 'b, 'x, 'y> {
+    engine: &'a mut Engine<'b>,
+    locator: SplitLocator<'x>,
+    styles: StyleChain<'y>,
+    span: Span,
+    frame: Frame,
+    /// The size of one square.
+    square: Abs,
+    /// The stroke for lines and the outlines of pieces.
+    ink: FixedStroke,
+}
+
+impl BoardLayouter<'_, '_, '_, '_> {
+    /// The top left corner of a square.
+    fn origin(&self, x: usize, y: usize) -> Point {
+        Point::new(self.square * x as f64, self.square * y as f64)
+    }
+
+    /// Draw a filled rectangle.
+    fn rect(&mut self, pos: Point, size: Size, fill: Paint) {
+        let shape = Geometry::Rect(size).filled(fill);
+        self.frame.push(pos, FrameItem::Shape(shape, self.span));
+    }
+
+    /// Draw a line.
+    fn line(&mut self, start: Point, delta: Point) {
+        let shape = Geometry::Line(delta).stroked(self.ink.clone());
+        self.frame.push(start, FrameItem::Shape(shape, self.span));
+    }
+
+    /// Draw a circle centered in a square, with a diameter relative to the
+    /// square's size.
+    fn circle(
+        &mut self,
+        origin: Point,
+        scale: f64,
+        fill: Option<Paint>,
+        stroke: FixedStroke,
+    ) {
+        let diameter = self.square * scale;
+        let shape = Shape {
+            geometry: Geometry::Curve(Curve::ellipse(Size::splat(diameter))),
+            fill,
+            fill_rule: FillRule::default(),
+            stroke: Some(stroke),
+        };
+        let pos = origin + Point::splat((self.square - diameter) / 2.0);
+        self.frame.push(pos, FrameItem::Shape(shape, self.span));
+    }
+
+    /// Draw a black or white disc, like a go stone or a checkers man.
+    fn disc(&mut self, origin: Point, scale: f64, white: bool) {
+        let fill = if white { Color::WHITE } else { Color::BLACK };
+        self.circle(origin, scale, Some(fill.into()), self.ink.clone());
+    }
+
+    /// Draw a chess piece with the chess symbols of the current font.
+    fn chess_piece(
+        &mut self,
+        origin: Point,
+        piece: char,
+        white: bool,
+    ) -> SourceResult<()> {
+        let symbol = match piece.to_ascii_lowercase() {
+            'k' => '♚',
+            'q' => '♛',
+            'r' => '♜',
+            'b' => '♝',
+            'n' => '♞',
+            _ => '♟',
+        };
+
+        // Always use the filled symbols, and fill them according to the side,
+        // so that white pieces are opaque. The variation selector prevents an
+        // emoji presentation.
+        let fill = if white { Color::WHITE } else { Color::BLACK };
+        let outline = Stroke::from_pair(Color::BLACK, (self.square / 40.0).into());
+        let content = TextElem::packed(eco_format!("{symbol}\u{FE0E}"))
+            .spanned(self.span)
+            .styled(TextElem::set_size(TextSize((self.square * 0.8).into())))
+            .styled(TextElem::set_fill(fill.into()))
+            .styled(TextElem::set_stroke(Some(outline)))
+            .styled(TextElem::set_top_edge(TopEdge::Metric(TopEdgeMetric::Bounds)))
+            .styled(TextElem::set_bottom_edge(BottomEdge::Metric(
+                BottomEdgeMetric::Bounds,
+            )));
+
+        let frame = self.layout(&content)?;
+        let offset = Point::new(
+            (self.square - frame.width()) / 2.0,
+            (self.square - frame.height()) / 2.0,
+        );
+        self.frame.push_frame(origin + offset, frame);
+        Ok(())
+    }
+
+    /// Lay out a coordinate label.
+    fn label(&mut self, text: EcoString, fill: Paint) -> SourceResult<Frame> {
+        let content = TextElem::packed(text)
+            .spanned(self.span)
+            .styled(TextElem::set_size(TextSize((self.square * 0.22).into())))
+            .styled(TextElem::set_fill(fill));
+        self.layout(&content)
+    }
+
+    /// Lay out content at its natural size.
+    fn layout(&mut self, content: &Content) -> SourceResult<Frame> {
+        let pod = Region::new(Size::splat(Abs::inf()), Axes::splat(false));
+        crate::layout_frame(
+            self.engine,
+            content,
+            self.locator.next(&content.span()),
+            self.styles,
+            pod,
+        )
+    }
+}
@@ -0,0 +1,479 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+use typst_library::diag::{bail, SourceResult};
+use typst_library::engine::Engine;
+use typst_library::foundations::{repr, Content, Packed, StyleChain};
+use typst_library::introspection::{Locator, SplitLocator};
+use typst_library::layout::{Abs, Axes, Frame, FrameItem, Point, Region, Size};
+use typst_library::text::TextElem;
+use typst_library::visualize::{
+    ChartElem, ChartKind, Curve, FixedStroke, Geometry, Paint,
+};
+use typst_syntax::Span;
+use typst_utils::round_with_precision;
+
+/// Layout the chart.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_chart(
+    elem: &Packed<ChartElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    _: Region,
+) -> SourceResult<Frame> {
+    let span = elem.span();
+    let palette = elem.palette(styles);
+    if palette.is_empty() {
+        bail!(span, "chart palette must not be empty");
+    }
+
+    let size = Size::new(elem.width(styles), elem.height(styles));
+    let em = TextElem::size_in(styles);
+    let mut layouter = ChartLayouter {
+        engine,
+        locator: locator.split(),
+        styles,
+        span,
+        frame: Frame::hard(size),
+        gap: em * 0.5,
+        tick: em * 0.3,
+        axis: FixedStroke::from_pair(
+            TextElem::fill_in(styles).as_decoration(),
+            Abs::pt(0.5),
+        ),
+    };
+
+    let kind = elem.kind(styles);
+    let labels = elem.labels(styles);
+    let color = |i: usize| -> Paint {
+        elem.series
+            .get(i)
+            .and_then(|series| series.fill.clone())
+            .unwrap_or_else(|| palette[i % palette.len()].clone())
+    };
+
+    // Collect the legend entries.
+    let entries: Vec<(Paint, Content)> = if kind == ChartKind::Pie {
+        labels
+            .iter()
+            .enumerate()
+            .map(|(i, label)| (palette[i % palette.len()].clone(), label.clone()))
+            .collect()
+    } else {
+        elem.series
+            .iter()
+            .enumerate()
+            .filter_map(|(i, series)| Some((color(i), series.label.clone()?)))
+            .collect()
+    };
+
+    let mut area = Area {
+        left: Abs::zero(),
+        top: Abs::zero(),
+        right: size.x,
+        bottom: size.y,
+    };
+    if elem.legend(styles) && !entries.is_empty() {
+        area.right -= layouter.legend(entries, size.x)? + layouter.gap;
+    }
+
+    if kind == ChartKind::Pie {
+        let values: Vec<f64> = elem
+            .series
+            .first()
+            .map(|series| series.data.iter().map(|point| point.y.get()).collect())
+            .unwrap_or_default();
+        layouter.pie(&values, &palette, area)?;
+    } else {
+        layouter.cartesian(elem, kind, &labels, color, area)?;
+    }
+
+    Ok(layouter.frame)
+}
+
+/// The region of the chart that is still free.
+#[derive(Debug, Copy, Clone)]
+struct Area {
+    left: Abs,
+    top: Abs,
+    right: Abs,
+    bottom: Abs,
+}
+
+impl Area {
+    fn width(self) -> Abs {
+        self.right - self.left
+    }
+
+    fn height(self) -> Abs {
+        self.bottom - self.top
+    }
+}
+
+/// Lays out the parts of a chart into one frame.
+struct ChartLayouter<'a, 'b, 'x, 'y> {
+    engine: &'a mut Engine<'b>,
+    locator: SplitLocator<'x>,
+    styles: StyleChain<'y>,
+    span: Span,
+    frame: Frame,
+    /// The spacing between labels and other parts of the chart.
+    gap: Abs,
+    /// The length of axis ticks.
+    tick: Abs,
+    /// The stroke for axes and ticks.
+    axis: FixedStroke,
+}
+
+impl ChartLayouter<'_, '_, '_, '_> {
+    /// Lay out a label at its natural size.
+    fn label(&mut self, content: &Content) -> SourceResult<Frame> {
+        let pod = Region::new(Size::splat(Abs::inf()), Axes::splat(false));
+        crate::layout_frame(
+            self.engine,
+            content,
+            self.locator.next(&content.span()),
+            self.styles,
+            pod,
+        )
+    }
+
+    /// Lay out a numeric tick label, rounded to the precision of the step.
+    fn number(&mut self, value: f64, step: f64) -> SourceResult<Frame> {
+        let digits = (-step.log10().floor()).max(0.0) as i16;
+        let text = repr::display_float(round_with_precision(value, digits));
+        self.label(&TextElem::packed(text).spanned(self.span))
+    }
+
+    /// Draw the legend at the right edge and return its width.
+    fn legend(
+        &mut self,
+        entries: Vec<(Paint, Content)>,
+        right: Abs,
+    ) -> SourceResult<Abs> {
+        let frames = entries
+            .iter()
+            .map(|(_, label)| self.label(label))
+            .collect::<SourceResult<Vec<_>>>()?;
+
+        let swatch = self.tick * 2.5;
+        let width = swatch
+            + self.gap
+            + frames.iter().map(Frame::width).fold(Abs::zero(), Abs::max);
+
+        let mut y = Abs::zero();
+        for ((paint, _), frame) in entries.into_iter().zip(frames) {
+            let x = right - width;
+            let height = frame.height().max(swatch);
+            let shape = Geometry::Rect(Size::splat(swatch)).filled(paint);
+            let pos = Point::new(x, y + (height - swatch) / 2.0);
+            self.frame.push(pos, FrameItem::Shape(shape, self.span));
+            let pos =
+                Point::new(x + swatch + self.gap, y + (height - frame.height()) / 2.0);
+            self.frame.push_frame(pos, frame);
+            y += height + self.gap / 2.0;
+        }
+
+        Ok(width)
+    }
+
+    /// Draw a pie chart into the area.
+    fn pie(&mut self, values: &[f64], palette: &[Paint], area: Area) -> SourceResult<()> {
+        if values.iter().any(|&v| v < 0.0) {
+            bail!(self.span, "pie chart values must not be negative");
+        }
+
+        let total: f64 = values.iter().sum();
+        if total <= 0.0 {
+            return Ok(());
+        }
+
+        let radius = area.width().min(area.height()) / 2.0;
+        let center =
+            Point::new(area.left + area.width() / 2.0, area.top + area.height() / 2.0);
+
+        let mut start = -FRAC_PI_2;
+        for (i, &value) in values.iter().enumerate() {
+            let sweep = value / total * TAU;
+            let mut curve = Curve::new();
+            curve.move_(center);
+            curve.line(polar(center, radius, start));
+            arc(&mut curve, center, radius, start, sweep);
+            curve.close();
+
+            let paint = palette[i % palette.len()].clone();
+            let shape = Geometry::Curve(curve).filled(paint);
+            self.frame.push(Point::zero(), FrameItem::Shape(shape, self.span));
+            start += sweep;
+        }
+
+        Ok(())
+    }
+
+    /// Draw a line, bar, or scatter chart with axes into the area.
+    fn cartesian(
+        &mut self,
+        elem: &Packed<ChartElem>,
+        kind: ChartKind,
+        labels: &[Content],
+        color: impl Fn(usize) -> Paint,
+        mut area: Area,
+    ) -> SourceResult<()> {
+        let series: Vec<Vec<(f64, f64)>> = elem
+            .series
+            .iter()
+            .map(|series| {
+                series
+                    .data
+                    .iter()
+                    .enumerate()
+                    .map(|(i, point)| {
+                        let x = point.x.map_or(i as f64, |x| x.get());
+                        (x, point.y.get())
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let points = series.iter().flatten();
+        let categories = series.iter().map(Vec::len).max().unwrap_or(0).max(labels.len());
+
+        // Determine the value ranges of both axes.
+        let (y_min, y_max) = points
+            .clone()
+            .fold((0.0_f64, 0.0_f64), |(lo, hi), &(_, y)| (lo.min(y), hi.max(y)));
+        let y_axis = Scale::new(y_min, y_max);
+
+        // Labels are placed at the x coordinates of the first series' points,
+        // or at their index where the series has no point.
+        let label_xs: Vec<f64> = (0..labels.len())
+            .map(|i| {
+                series
+                    .first()
+                    .and_then(|points| points.get(i))
+                    .map_or(i as f64, |&(x, _)| x)
+            })
+            .collect();
+
+        let x_axis = if kind == ChartKind::Bar {
+            None
+        } else {
+            let (lo, hi) = points
+                .map(|&(x, _)| x)
+                .chain(label_xs.iter().copied())
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), x| {
+                    (lo.min(x), hi.max(x))
+                });
+            Some(if !lo.is_finite() {
+                Scale::new(0.0, 1.0)
+            } else if labels.is_empty() {
+                Scale::new(lo, hi)
+            } else {
+                Scale::exact(lo, hi)
+            })
+        };
+
+        // Lay out the axis titles and tick labels.
+        if let Some(title) = elem.y_label(self.styles) {
+            let frame = self.label(&title)?;
+            area.top += frame.height() + self.gap;
+            self.frame.push_frame(Point::zero(), frame);
+        }
+
+        if let Some(title) = elem.x_label(self.styles) {
+            let frame = self.label(&title)?;
+            area.bottom -= frame.height() + self.gap;
+            let x = area.left + (area.width() - frame.width()) / 2.0;
+            self.frame.push_frame(Point::new(x, area.bottom + self.gap), frame);
+        }
+
+        let y_ticks = y_axis
+            .ticks()
+            .map(|v| Ok((v, self.number(v, y_axis.step)?)))
+            .collect::<SourceResult<Vec<_>>>()?;
+
+        let x_ticks = match x_axis {
+            Some(_) if !labels.is_empty() => labels
+                .iter()
+                .zip(&label_xs)
+                .map(|(label, &x)| Ok((x, self.label(label)?)))
+                .collect::<SourceResult<Vec<_>>>()?,
+            Some(scale) => scale
+                .ticks()
+                .map(|v| Ok((v, self.number(v, scale.step)?)))
+                .collect::<SourceResult<Vec<_>>>()?,
+            None => (0..categories)
+                .map(|i| {
+                    let frame = match labels.get(i) {
+                        Some(label) => self.label(label)?,
+                        None => self.number((i + 1) as f64, 1.0)?,
+                    };
+                    Ok((i as f64, frame))
+                })
+                .collect::<SourceResult<Vec<_>>>()?,
+        };
+
+        // Reserve space for the tick labels.
+        let max = |ticks: &[(f64, Frame)], f: fn(&Frame) -> Abs| {
+            ticks.iter().map(|(_, frame)| f(frame)).fold(Abs::zero(), Abs::max)
+        };
+        let label_height = max(&y_ticks, Frame::height);
+        area.left += max(&y_ticks, Frame::width) + self.gap + self.tick;
+        area.top += label_height / 2.0;
+        area.bottom -= max(&x_ticks, Frame::height) + self.gap + self.tick;
+        area.right -= max(&x_ticks, Frame::width) / 2.0;
+
+        if area.width() <= Abs::zero() || area.height() <= Abs::zero() {
+            bail!(self.span, "chart is too small to fit its labels");
+        }
+
+        let map_y = |y: f64| area.bottom - area.height() * y_axis.fraction(y);
+        let slot = area.width() / categories.max(1) as f64;
+        let map_x = |x: f64| match x_axis {
+            Some(scale) => area.left + area.width() * scale.fraction(x),
+            None => area.left + slot * (x + 0.5),
+        };
+
+        // Draw the data.
+        let dot = self.tick * 1.2;
+        for (i, points) in series.iter().enumerate() {
+            let paint = color(i);
+            match kind {
+                ChartKind::Line => {
+                    let mut curve = Curve::new();
+                    for (k, &(x, y)) in points.iter().enumerate() {
+                        let p = Point::new(map_x(x), map_y(y));
+                        if k == 0 {
+                            curve.move_(p);
+                        } else {
+                            curve.line(p);
+                        }
+                    }
+                    if !curve.is_empty() {
+                        let thickness = self.axis.thickness * 3.0;
+                        let stroke = FixedStroke::from_pair(paint, thickness);
+                        let shape = Geometry::Curve(curve).stroked(stroke);
+                        let item = FrameItem::Shape(shape, self.span);
+                        self.frame.push(Point::zero(), item);
+                    }
+                }
+                ChartKind::Scatter => {
+                    for &(x, y) in points {
+                        let pos = Point::new(map_x(x) - dot / 2.0, map_y(y) - dot / 2.0);
+                        let curve = Curve::ellipse(Size::splat(dot));
+                        let shape = Geometry::Curve(curve).filled(paint.clone());
+                        self.frame.push(pos, FrameItem::Shape(shape, self.span));
+                    }
+                }
+                ChartKind::Bar => {
+                    let width = slot * 0.8 / series.len() as f64;
+                    for (k, &(_, y)) in points.iter().enumerate() {
+                        let x = area.left + slot * (k as f64 + 0.1) + width * i as f64;
+                        let top = map_y(y.max(0.0));
+                        let size = Size::new(width, map_y(y.min(0.0)) - top);
+                        let shape = Geometry::Rect(size).filled(paint.clone());
+                        let item = FrameItem::Shape(shape, self.span);
+                        self.frame.push(Point::new(x, top), item);
+                    }
+                }
+                ChartKind::Pie => unreachable!(),
+            }
+        }
+
+        // Draw the axes with their ticks and labels.
+        let origin = Point::new(area.left, area.bottom);
+        self.line(origin, Point::with_x(area.width()));
+        self.line(origin, Point::with_y(-area.height()));
+
+        for (v, frame) in y_ticks {
+            let y = map_y(v);
+            self.line(Point::new(area.left - self.tick, y), Point::with_x(self.tick));
+            let x = area.left - self.tick - self.gap - frame.width();
+            self.frame.push_frame(Point::new(x, y - frame.height() / 2.0), frame);
+        }
+
+        for (v, frame) in x_ticks {
+            let x = map_x(v);
+            self.line(Point::new(x, area.bottom), Point::with_y(self.tick));
+            let y = area.bottom + self.tick + self.gap;
+            self.frame.push_frame(Point::new(x - frame.width() / 2.0, y), frame);
+        }
+
+        Ok(())
+    }
+
+    /// Draw a straight line in the axis stroke.
+    fn line(&mut self, start: Point, delta: Point) {
+        let shape = Geometry::Line(delta).stroked(self.axis.clone());
+        self.frame.push(start, FrameItem::Shape(shape, self.span));
+    }
+}
+
+/// The linear scale of an axis, extended to round tick values.
+#[derive(Debug, Copy, Clone)]
+struct Scale {
+    min: f64,
+    max: f64,
+    step: f64,
+}
+
+impl Scale {
+    /// Create a scale that covers the given range with about five ticks.
+    fn new(min: f64, max: f64) -> Self {
+        let (min, max) = if min == max { (min - 1.0, max + 1.0) } else { (min, max) };
+        let raw = (max - min) / 5.0;
+        let magnitude = 10_f64.powf(raw.log10().floor());
+        let step = [1.0, 2.0, 5.0, 10.0]
+            .into_iter()
+            .map(|factor| factor * magnitude)
+            .find(|&step| step >= raw)
+            .unwrap_or(10.0 * magnitude);
+        Self {
+            min: (min / step).floor() * step,
+            max: (max / step).ceil() * step,
+            step,
+        }
+    }
+
+    /// Create a scale that covers exactly the given range, for axes whose
+    /// ticks are placed at given values instead.
+    fn exact(min: f64, max: f64) -> Self {
+        let max = if min == max { min + 1.0 } else { max };
+        Self { min, max, step: 1.0 }
+    }
+
+    /// The values at which ticks are placed.
+    fn ticks(self) -> impl Iterator<Item = f64> {
+        let count = ((self.max - self.min) / self.step).round() as usize;
+        (0..=count).map(move |i| self.min + i as f64 * self.step)
+    }
+
+    /// The relative position of a value on the axis.
+    fn fraction(self, value: f64) -> f64 {
+        (value - self.min) / (self.max - self.min)
+    }
+}
+
+/// The point at the given angle on a circle.
+fn polar(center: Point, radius: Abs, angle: f64) -> Point {
+    center + Point::new(radius * angle.cos(), radius * angle.sin())
+}
+
+/// Append a circular arc to the curve, approximated by cubic Bézier curves
+/// that span at most a quarter circle each.
+fn arc(curve: &mut Curve, center: Point, radius: Abs, start: f64, sweep: f64) {
+    let segments = (sweep / FRAC_PI_2).ceil().max(1.0);
+    let delta = sweep / segments;
+    let k = 4.0 / 3.0 * (delta / 4.0).tan();
+    let mut angle = start;
+    for _ in 0..segments as usize {
+        let end = angle + delta;
+        let from = polar(center, radius, angle);
+        let to = polar(center, radius, end);
+        let handle = radius * k;
+        let c1 = from + Point::new(handle * -angle.sin(), handle * angle.cos());
+        let c2 = to + Point::new(handle * end.sin(), handle * -end.cos());
+        curve.cubic(c1, c2, to);
+        angle = end;
+    }
+}
@@ -0,0 +1,110 @@
+use typst_library::diag::{At, SourceResult};
+use typst_library::engine::Engine;
+use typst_library::foundations::{Packed, StyleChain};
+use typst_library::introspection::Locator;
+use typst_library::layout::{Frame, FrameItem, Point, Region, Size};
+use typst_library::visualize::{BarcodeElem, Curve, Geometry, QrCode, QrCodeElem};
+
+/// Layout the QR code.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_qrcode(
+    elem: &Packed<QrCodeElem>,
+    _: &mut Engine,
+    _: Locator,
+    styles: StyleChain,
+    _: Region,
+) -> SourceResult<Frame> {
+    let span = elem.span();
+    let data = elem.data.clone().into_bytes();
+    let code = QrCode::encode(data.as_slice(), elem.ec_level(styles)).at(span)?;
+
+    let margin = if elem.quiet_zone(styles) { 4 } else { 0 };
+    let size = elem.size(styles);
+    let module = size / (code.size() + 2 * margin) as f64;
+
+    let mut curve = Curve::new();
+    for y in 0..code.size() {
+        let row = (0..code.size()).map(|x| code.get(x, y));
+        for (start, end) in runs(row) {
+            let pos = Point::new(
+                module * (margin + start) as f64,
+                module * (margin + y) as f64,
+            );
+            add_rect(&mut curve, pos, Size::new(module * (end - start) as f64, module));
+        }
+    }
+
+    let mut frame = Frame::hard(Size::splat(size));
+    if !curve.is_empty() {
+        let shape = Geometry::Curve(curve).filled(elem.fill(styles));
+        frame.push(Point::zero(), FrameItem::Shape(shape, span));
+    }
+    Ok(frame)
+}
+
+/// Layout the barcode.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_barcode(
+    elem: &Packed<BarcodeElem>,
+    _: &mut Engine,
+    _: Locator,
+    styles: StyleChain,
+    _: Region,
+) -> SourceResult<Frame> {
+    let span = elem.span();
+    let kind = elem.kind(styles);
+    let modules = kind.encode(&elem.data).at(span)?;
+
+    let (before, after) =
+        if elem.quiet_zone(styles) { kind.quiet_zone() } else { (0, 0) };
+    let module = elem.module(styles);
+    let height = elem.height(styles);
+
+    let mut curve = Curve::new();
+    for (start, end) in runs(modules.iter().copied()) {
+        add_rect(
+            &mut curve,
+            Point::with_x(module * (before + start) as f64),
+            Size::new(module * (end - start) as f64, height),
+        );
+    }
+
+    let width = module * (before + modules.len() + after) as f64;
+    let mut frame = Frame::hard(Size::new(width, height));
+    if !curve.is_empty() {
+        let shape = Geometry::Curve(curve).filled(elem.fill(styles));
+        frame.push(Point::zero(), FrameItem::Shape(shape, span));
+    }
+    Ok(frame)
+}
+
+/// Find the half-open ranges of consecutive dark modules.
+fn runs(modules: impl Iterator<Item = bool>) -> Vec<(usize, usize)> {
+    let mut runs = vec![];
+    let mut start = None;
+    let mut len = 0;
+    for (i, dark) in modules.enumerate() {
+        match (dark, start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                runs.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+        len = i + 1;
+    }
+    if let Some(s) = start {
+        runs.push((s, len));
+    }
+    runs
+}
+
+/// Add a closed rectangle to the curve.
+fn add_rect(curve: &mut Curve, pos: Point, size: Size) {
+    curve.move_(pos);
+    curve.line(pos + Point::with_x(size.x));
+    curve.line(pos + size.to_point());
+    curve.line(pos + Point::with_y(size.y));
+    curve.close();
+}
@@ -87,20 +87,66 @@ pub fn layout_columns(
     styles: StyleChain,
     regions: Regions,
 ) -> SourceResult<Fragment> {
-    layout_fragment_impl(
-        engine.routines,
-        engine.world,
-        engine.introspector,
-        engine.traced,
-        TrackedMut::reborrow_mut(&mut engine.sink),
-        engine.route.track(),
-        &elem.body,
-        locator.track(),
-        styles,
-        regions,
-        elem.count(styles),
-        elem.gutter(styles),
-    )
+    let count = elem.count(styles);
+    let gutter = elem.gutter(styles);
+    let mut layout = |regions: Regions| {
+        layout_fragment_impl(
+            engine.routines,
+            engine.world,
+            engine.introspector,
+            engine.traced,
+            TrackedMut::reborrow_mut(&mut engine.sink),
+            engine.route.track(),
+            &elem.body,
+            locator.track(),
+            styles,
+            regions,
+            count,
+            gutter,
+        )
+    };
+
+    let fragment = layout(regions)?;
+    if !elem.balance(styles)
+        || count.get() == 1
+        || regions.expand.y
+        || !regions.size.y.is_finite()
+    {
+        return Ok(fragment);
+    }
+
+    // Determine the heights of the regions the content was laid out into.
+    let n = fragment.len();
+    let mut heights: Vec<Abs> = regions.iter().take(n).map(|size| size.y).collect();
+    if heights.len() < n {
+        return Ok(fragment);
+    }
+
+    // Search for the smallest height of the last region that still fits all
+    // of the content. Then, the columns in that region are as balanced as
+    // possible.
+    let mut best = fragment;
+    let (mut lo, mut hi) = (Abs::zero(), heights[n - 1]);
+    while hi - lo > Abs::pt(0.5) {
+        let mid = (lo + hi) / 2.0;
+        heights[n - 1] = mid;
+        let candidate = layout(Regions {
+            size: Size::new(regions.size.x, heights[0]),
+            full: regions.full,
+            backlog: &heights[1..],
+            last: None,
+            expand: regions.expand,
+        })?;
+
+        if candidate.len() <= n {
+            best = candidate;
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+
+    Ok(best)
 }
 
 /// The cached, internal implementation of [`layout_fragment`].
@@ -0,0 +1,140 @@
+use typst_library::diag::{bail, SourceResult};
+use typst_library::engine::Engine;
+use typst_library::foundations::{Packed, StyleChain};
+use typst_library::introspection::Locator;
+use typst_library::layout::{Abs, Axes, Frame, FrameItem, Point, Region, Size};
+use typst_library::text::TextElem;
+use typst_library::visualize::{
+    Color, Curve, FixedStroke, GanttElem, GanttUnit, Geometry,
+};
+
+/// Layout the Gantt chart.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_gantt(
+    elem: &Packed<GanttElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    region: Region,
+) -> SourceResult<Frame> {
+    let span = elem.span();
+    let width = elem.width(styles).relative_to(region.size.x);
+    if !width.is_finite() {
+        bail!(span, "cannot create a gantt chart with infinite width");
+    }
+
+    let tasks = &elem.tasks;
+    if tasks.is_empty() {
+        return Ok(Frame::hard(Size::with_x(width)));
+    }
+
+    // Determine the time span of the chart.
+    let days: Vec<(f64, f64)> = tasks.iter().map(|task| task.days()).collect();
+    let first = days.iter().map(|&(start, _)| start).fold(f64::INFINITY, f64::min);
+    let mut last = days.iter().map(|&(_, end)| end).fold(f64::NEG_INFINITY, f64::max);
+    if last <= first {
+        last = first + 1.0;
+    }
+
+    let row = elem.row_height(styles);
+    let em = TextElem::size_in(styles);
+    let gap = em * 0.5;
+    let grid = FixedStroke::from_pair(Color::GRAY, Abs::pt(0.5));
+    let mut locator = locator.split();
+    let pod = Region::new(Size::splat(Abs::inf()), Axes::splat(false));
+
+    // Lay out the task labels and the labels of the time axis.
+    let labels = tasks
+        .iter()
+        .map(|task| {
+            crate::layout_frame(
+                engine,
+                &task.label,
+                locator.next(&task.label.span()),
+                styles,
+                pod,
+            )
+        })
+        .collect::<SourceResult<Vec<_>>>()?;
+
+    let unit = elem.unit(styles).unwrap_or_else(|| GanttUnit::for_span(last - first));
+    let ticks = unit
+        .ticks(first, last, TextElem::region_in(styles))
+        .into_iter()
+        .map(|(day, text)| {
+            let text = TextElem::packed(text).spanned(span);
+            let frame =
+                crate::layout_frame(engine, &text, locator.next(&span), styles, pod)?;
+            Ok((day, frame))
+        })
+        .collect::<SourceResult<Vec<_>>>()?;
+
+    let left = labels.iter().map(Frame::width).fold(Abs::zero(), Abs::max) + gap;
+    let top = ticks
+        .iter()
+        .map(|(_, frame)| frame.height())
+        .fold(Abs::zero(), Abs::max)
+        + gap;
+    let area = width - left;
+    if area <= Abs::zero() {
+        bail!(span, "gantt chart is too narrow to fit its labels");
+    }
+
+    let height = top + row * tasks.len() as f64;
+    let map = |day: f64| left + area * ((day - first) / (last - first));
+    let mut frame = Frame::hard(Size::new(width, height));
+
+    // Draw the time axis with a grid line for every label.
+    for (day, label) in ticks {
+        let x = map(day);
+        let line = Geometry::Line(Point::with_y(height - top + gap / 2.0));
+        let pos = Point::new(x, top - gap / 2.0);
+        frame.push(pos, FrameItem::Shape(line.stroked(grid.clone()), span));
+        if x + gap / 4.0 + label.width() <= width {
+            frame.push_frame(Point::with_x(x + gap / 4.0), label);
+        }
+    }
+
+    // Draw the tasks with their labels.
+    let thickness = row * 0.6;
+    let default_fill = elem.fill(styles);
+    for (i, ((task, (start, end)), label)) in
+        tasks.iter().zip(days).zip(labels).enumerate()
+    {
+        let y = top + row * i as f64;
+        frame.push_frame(Point::with_y(y + (row - label.height()) / 2.0), label);
+
+        let fill = task.fill.clone().unwrap_or_else(|| default_fill.clone());
+        let center = y + row / 2.0;
+        if task.end.is_some() {
+            let size = Size::new(map(end) - map(start), thickness);
+            let shape = Geometry::Rect(size).filled(fill);
+            let pos = Point::new(map(start), center - thickness / 2.0);
+            frame.push(pos, FrameItem::Shape(shape, span));
+        } else {
+            // Keep milestones at the edges of the time span within the chart.
+            let d = thickness / 2.0;
+            let x = map(start).max(left + d).min(width - d);
+            let mut curve = Curve::new();
+            curve.move_(Point::new(x, center - d));
+            curve.line(Point::new(x + d, center));
+            curve.line(Point::new(x, center + d));
+            curve.line(Point::new(x - d, center));
+            curve.close();
+            let shape = Geometry::Curve(curve).filled(fill);
+            frame.push(Point::zero(), FrameItem::Shape(shape, span));
+        }
+    }
+
+    // Mark the current date.
+    if let Some(today) = elem.today(styles).and_then(|today| today.julian_day()) {
+        if (first..=last).contains(&today) {
+            let stroke = FixedStroke::from_pair(Color::RED, Abs::pt(1.0));
+            let line = Geometry::Line(Point::with_y(height - top + gap / 2.0));
+            let pos = Point::new(map(today), top - gap / 2.0);
+            frame.push(pos, FrameItem::Shape(line.stroked(stroke), span));
+        }
+    }
+
+    Ok(frame)
+}
@@ -1,81 +1,65 @@
-use std::ffi::OsStr;
-
-use typst_library::diag::{warning, At, LoadedWithin, SourceResult, StrResult};
+use ecow::{eco_format, EcoVec};
+use typst_library::diag::{
+    bail, warning, At, HintedStrResult, LoadedWithin, Severity, SourceDiagnostic,
+    SourceResult,
+};
 use typst_library::engine::Engine;
-use typst_library::foundations::{Bytes, Derived, Packed, Smart, StyleChain};
+use typst_library::foundations::{
+    repr, Derived, NativeElement, Packed, Smart, StyleChain,
+};
 use typst_library::introspection::Locator;
 use typst_library::layout::{
     Abs, Axes, FixedAlignment, Frame, FrameItem, Point, Region, Size,
 };
-use typst_library::loading::DataSource;
-use typst_library::text::families;
+use typst_library::loading::{DataSource, LoadSource};
+use typst_library::text::{families, LinebreakElem, TextElem, TextSize};
 use typst_library::visualize::{
-    Curve, ExchangeFormat, Image, ImageElem, ImageFit, ImageFormat, ImageKind,
-    RasterImage, SvgImage, VectorFormat,
+    Color, Curve, FillRule, FixedStroke, Geometry, Image, ImageElem, ImageFit,
+    ImageFormat, ImageKind, RasterImage, Shape, SvgImage, SvgOptions, VectorFormat,
 };
+use typst_syntax::Span;
+
+/// The maximum number of pixels of an SVG's raster fallback if the library
+/// doesn't limit the number of image pixels.
+const MAX_RASTER_FALLBACK_PIXELS: u64 = 1 << 26;
 
 /// Layout the image.
 #[typst_macros::time(span = elem.span())]
 pub fn layout_image(
     elem: &Packed<ImageElem>,
     engine: &mut Engine,
-    _: Locator,
+    locator: Locator,
     styles: StyleChain,
     region: Region,
 ) -> SourceResult<Frame> {
     let span = elem.span();
-
-    // Take the format that was explicitly defined, or parse the extension,
-    // or try to detect the format.
-    let Derived { source, derived: loaded } = &elem.source;
-    let format = match elem.format(styles) {
-        Smart::Custom(v) => v,
-        Smart::Auto => determine_format(source, &loaded.data).at(span)?,
-    };
-
-    // Warn the user if the image contains a foreign object. Not perfect
-    // because the svg could also be encoded, but that's an edge case.
-    if format == ImageFormat::Vector(VectorFormat::Svg) {
-        let has_foreign_object =
-            memchr::memmem::find(&loaded.data, b"<foreignObject").is_some();
-
-        if has_foreign_object {
-            engine.sink.warn(warning!(
-                span,
-                "image contains foreign object";
-                hint: "SVG images with foreign objects might render incorrectly in typst";
-                hint: "see https://github.com/typst/typst/issues/1421 for more information"
-            ));
+    let mut image = match load_image(elem, engine, styles) {
+        Ok(image) => image,
+        Err(errors) if elem.placeholder(styles) => {
+            return layout_placeholder(elem, engine, locator, styles, region, errors);
         }
-    }
+        Err(errors) => return Err(errors),
+    };
 
-    // Construct the image itself.
-    let kind = match format {
-        ImageFormat::Raster(format) => ImageKind::Raster(
-            RasterImage::new(
-                loaded.data.clone(),
-                format,
-                elem.icc(styles).as_ref().map(|icc| icc.derived.clone()),
-            )
-            .at(span)?,
-        ),
-        ImageFormat::Vector(VectorFormat::Svg) => ImageKind::Svg(
-            SvgImage::with_fonts(
-                loaded.data.clone(),
-                engine.world,
-                &families(styles).map(|f| f.as_str()).collect::<Vec<_>>(),
-            )
-            .within(loaded)?,
+    // Determine the pixel aspect ratio of the visible part of the image.
+    let crop = elem.crop(styles);
+    let (crop_pos, crop_size) = match crop {
+        Some(crop) => (
+            Axes::new(crop.x.get(), crop.y.get()),
+            Axes::new(crop.width.get(), crop.height.get()),
         ),
+        None => (Axes::splat(0.0), Axes::splat(1.0)),
     };
-
-    let image = Image::new(kind, elem.alt(styles), elem.scaling(styles));
-
-    // Determine the image's pixel aspect ratio.
-    let pxw = image.width();
-    let pxh = image.height();
+    let pxw = image.width() * crop_size.x;
+    let pxh = image.height() * crop_size.y;
     let px_ratio = pxw / pxh;
 
+    // Determine an explicitly set resolution.
+    let dpi = elem.dpi(styles).custom();
+    if dpi.is_some_and(|dpi| !(dpi > 0.0 && dpi.is_finite())) {
+        bail!(span, "image resolution must be positive");
+    }
+
     // Determine the region's aspect ratio.
     let region_ratio = region.size.x / region.size.y;
 
@@ -97,7 +81,7 @@ pub fn layout_image(
         // DPI bounded by the available space.
         //
         // Division by DPI is fine since it's guaranteed to be positive.
-        let dpi = image.dpi().unwrap_or(Image::DEFAULT_DPI);
+        let dpi = dpi.or(image.dpi()).unwrap_or(Image::DEFAULT_DPI);
         let natural = Axes::new(pxw, pxh).map(|v| Abs::inches(v / dpi));
         Size::new(
             natural.x.min(region.size.x).min(region.size.y * px_ratio),
@@ -118,11 +102,38 @@ pub fn layout_image(
         ImageFit::Stretch => target,
     };
 
+    // Let exporters rasterize the SVG's filter effects at the requested
+    // resolution.
+    if let (ImageKind::Svg(svg), Some(dpi)) = (image.kind(), elem.raster_fallback(styles))
+    {
+        if !(dpi > 0.0 && dpi.is_finite()) {
+            bail!(span, "raster fallback resolution must be positive");
+        }
+        if svg.has_filters() {
+            let max = engine
+                .world
+                .library()
+                .limits
+                .max_image_pixels
+                .unwrap_or(MAX_RASTER_FALLBACK_PIXELS);
+            check_raster_fallback(svg, dpi, max).at(span)?;
+            image = image.with_raster_fallback(Some(dpi));
+        }
+    }
+
     // First, place the image in a frame of exactly its size and then resize
     // the frame to the target size, center aligning the image in the
-    // process.
+    // process. When cropping, the whole image is scaled such that the
+    // visible part has the fitted size and the rest is clipped away.
     let mut frame = Frame::soft(fitted);
-    frame.push(Point::zero(), FrameItem::Image(image, fitted, span));
+    if crop.is_some() {
+        let full = Size::new(fitted.x / crop_size.x, fitted.y / crop_size.y);
+        let pos = Point::new(-full.x * crop_pos.x, -full.y * crop_pos.y);
+        frame.push(pos, FrameItem::Image(image, full, span));
+        frame.clip(Curve::rect(fitted));
+    } else {
+        frame.push(Point::zero(), FrameItem::Image(image, fitted, span));
+    }
     frame.resize(target, Axes::splat(FixedAlignment::Center));
 
     // Create a clipping group if only part of the image should be visible.
@@ -133,24 +144,187 @@ pub fn layout_image(
     Ok(frame)
 }
 
-/// Try to determine the image format based on the data.
-fn determine_format(source: &DataSource, data: &Bytes) -> StrResult<ImageFormat> {
-    if let DataSource::Path(path) = source {
-        let ext = std::path::Path::new(path.as_str())
-            .extension()
-            .and_then(OsStr::to_str)
-            .unwrap_or_default()
-            .to_lowercase();
-
-        match ext.as_str() {
-            "png" => return Ok(ExchangeFormat::Png.into()),
-            "jpg" | "jpeg" => return Ok(ExchangeFormat::Jpg.into()),
-            "gif" => return Ok(ExchangeFormat::Gif.into()),
-            "svg" | "svgz" => return Ok(VectorFormat::Svg.into()),
-            "webp" => return Ok(ExchangeFormat::Webp.into()),
-            _ => {}
+/// Load and decode the image.
+fn load_image(
+    elem: &Packed<ImageElem>,
+    engine: &mut Engine,
+    styles: StyleChain,
+) -> SourceResult<Image> {
+    let span = elem.span();
+
+    // Take the format that was explicitly defined, or parse the extension,
+    // or try to detect the format.
+    let Derived { source, derived: loaded } = &elem.source;
+    let loaded = loaded.as_ref().map_err(Clone::clone)?;
+    let format = match elem.format(styles) {
+        Smart::Custom(v) => v,
+        Smart::Auto => ImageFormat::determine(source, &loaded.data).at(span)?,
+    };
+
+    // Construct the image itself.
+    let kind = match format {
+        ImageFormat::Raster(format) => {
+            let raster = RasterImage::with_frame(
+                loaded.data.clone(),
+                format,
+                elem.icc(styles).as_ref().map(|icc| icc.derived.clone()),
+                elem.frame(styles),
+            )
+            .at(span)?;
+            check_pixels(engine, &raster).at(span)?;
+            ImageKind::Raster(raster)
+        }
+        ImageFormat::Vector(VectorFormat::Svg) => {
+            if elem.frame(styles).get() > 1 {
+                bail!(span, "SVG images have only one frame");
+            }
+
+            // References in SVGs given as bytes are relative to the file
+            // with the image call.
+            let base = match loaded.source.v {
+                LoadSource::Path(id) => Some(id),
+                LoadSource::Bytes => span.id(),
+            };
+            let families = families(styles).map(|f| f.as_str()).collect::<Vec<_>>();
+            let svg = SvgImage::with_options(
+                loaded.data.clone(),
+                engine.world,
+                &SvgOptions { base, families: &families },
+            )
+            .within(loaded)?;
+            warn_unsupported(engine, span, &svg);
+            ImageKind::Svg(svg)
         }
+    };
+
+    Ok(Image::new(kind, elem.alt(styles), elem.scaling(styles)))
+}
+
+/// Ensure that a raster image doesn't exceed the configured pixel limit.
+fn check_pixels(engine: &Engine, raster: &RasterImage) -> HintedStrResult<()> {
+    let Some(max) = engine.world.library().limits.max_image_pixels else {
+        return Ok(());
+    };
+
+    let (width, height) = (raster.width(), raster.height());
+    if u64::from(width) * u64::from(height) > max {
+        bail!(
+            "image is too large ({width}×{height} pixels)";
+            hint: "at most {max} pixels are allowed"
+        );
+    }
+
+    Ok(())
+}
+
+/// Layout a clearly marked placeholder with the image's path and error, and
+/// emit the errors as warnings instead.
+fn layout_placeholder(
+    elem: &Packed<ImageElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    region: Region,
+    errors: EcoVec<SourceDiagnostic>,
+) -> SourceResult<Frame> {
+    let span = elem.span();
+    let message = errors.first().map(|error| error.message.clone()).unwrap_or_default();
+    for mut error in errors {
+        error.severity = Severity::Warning;
+        error.hint("the image was replaced by a placeholder");
+        engine.sink.warn(error);
+    }
+
+    // Take the forced size or fall back to a default size.
+    let default = Size::new(Abs::cm(4.0), Abs::cm(3.0));
+    let size = Size::new(
+        if region.expand.x { region.size.x } else { default.x.min(region.size.x) },
+        if region.expand.y { region.size.y } else { default.y.min(region.size.y) },
+    );
+
+    // Draw a crossed-out box.
+    let mut frame = Frame::hard(size);
+    let red = Color::from_u8(0xCF, 0x22, 0x2E, 0xFF);
+    let stroke = FixedStroke::from_pair(red, Abs::pt(1.0));
+    let shape = Shape {
+        geometry: Geometry::Rect(size),
+        fill: Some(Color::from_u8(0xFF, 0xEB, 0xE9, 0xFF).into()),
+        fill_rule: FillRule::default(),
+        stroke: Some(stroke.clone()),
+    };
+    frame.push(Point::zero(), FrameItem::Shape(shape, span));
+    for (start, delta) in [
+        (Point::zero(), Point::new(size.x, size.y)),
+        (Point::with_y(size.y), Point::new(size.x, -size.y)),
+    ] {
+        let line = Geometry::Line(delta).stroked(stroke.clone());
+        frame.push(start, FrameItem::Shape(line, span));
     }
 
-    Ok(ImageFormat::detect(data).ok_or("unknown image format")?)
+    // Label the box with the path and the error.
+    let path = match &elem.source.source {
+        DataSource::Path(path) if path.starts_with("data:") => "data URI".into(),
+        DataSource::Path(path) => path.clone(),
+        DataSource::Bytes(_) => "bytes".into(),
+    };
+    let label = (TextElem::packed(path).spanned(span)
+        + LinebreakElem::shared().clone()
+        + TextElem::packed(message).spanned(span))
+    .styled(TextElem::set_size(TextSize(Abs::pt(8.0).into())))
+    .styled(TextElem::set_fill(red.into()));
+    let inset = Abs::pt(4.0);
+    let pod = Region::new(
+        (size - Size::splat(2.0 * inset)).max(Size::zero()),
+        Axes::splat(false),
+    );
+    let text = crate::layout_frame(engine, &label, locator, styles, pod)?;
+    let pos = Point::new((size.x - text.width()) / 2.0, (size.y - text.height()) / 2.0);
+    frame.push_frame(pos, text);
+    frame.clip(Curve::rect(size));
+
+    Ok(frame)
+}
+
+/// Warn the user about elements of an SVG that are not rendered.
+fn warn_unsupported(engine: &mut Engine, span: Span, svg: &SvgImage) {
+    let mut elements = svg.unsupported_elements().to_vec();
+    if let Some(i) = elements.iter().position(|&name| name == "foreignObject") {
+        elements.remove(i);
+        engine.sink.warn(warning!(
+            span,
+            "image contains foreign object";
+            hint: "SVG images with foreign objects might render incorrectly in typst";
+            hint: "see https://github.com/typst/typst/issues/1421 for more information"
+        ));
+    }
+
+    if !elements.is_empty() {
+        let list = elements.iter().map(|name| eco_format!("`{name}`"));
+        engine.sink.warn(warning!(
+            span,
+            "image contains unsupported SVG elements: {}",
+            repr::separated_list(&list.collect::<Vec<_>>(), "and");
+            hint: "these elements are ignored when rendering the image"
+        ));
+    }
+}
+
+/// Ensure that the rasterized filter effects of an SVG stay within the pixel
+/// limit, even if they cover the whole image.
+fn check_raster_fallback(
+    svg: &SvgImage,
+    dpi: f64,
+    max_pixels: u64,
+) -> HintedStrResult<()> {
+    let scale = dpi / Image::USVG_DEFAULT_DPI;
+    let size = |v: f64| (v * scale).ceil().max(1.0);
+    let (width, height) = (size(svg.width()), size(svg.height()));
+    if width * height > max_pixels as f64 {
+        bail!(
+            "raster fallback resolution is too high";
+            hint: "the image would have {width}×{height} pixels";
+            hint: "at most {max_pixels} pixels are allowed"
+        );
+    }
+    Ok(())
 }
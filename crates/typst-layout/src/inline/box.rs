@@ -2,9 +2,10 @@ use std::cell::LazyCell;
 
 use typst_library::diag::SourceResult;
 use typst_library::engine::Engine;
-use typst_library::foundations::{Packed, StyleChain};
+use typst_library::foundations::{Packed, Smart, StyleChain};
 use typst_library::introspection::Locator;
-use typst_library::layout::{BoxElem, Frame, FrameKind, Size};
+use typst_library::layout::{Abs, BoxElem, Frame, FrameKind, Size, VAlignment};
+use typst_library::text::{families, variant, TextEdgeBounds, TextElem};
 use typst_library::visualize::Stroke;
 use typst_utils::Numeric;
 
@@ -75,6 +76,17 @@ pub fn layout_box(
         frame.label(label);
     }
 
+    // Align the box relative to the surrounding text.
+    if let Smart::Custom(align) = elem.align(styles) {
+        let (top, bottom) = text_edges(engine, styles);
+        let height = frame.height();
+        frame.set_baseline(match align {
+            VAlignment::Top => top,
+            VAlignment::Horizon => (height + top - bottom) / 2.0,
+            VAlignment::Bottom => height - bottom,
+        });
+    }
+
     // Apply baseline shift. Do this after setting the size and applying the
     // inset, so that a relative shift is resolved relative to the final
     // height.
@@ -85,3 +97,22 @@ pub fn layout_box(
 
     Ok(frame)
 }
+
+/// The distances of the text's top and bottom edges from the baseline, measured
+/// with the first available font.
+fn text_edges(engine: &Engine, styles: StyleChain) -> (Abs, Abs) {
+    let size = TextElem::size_in(styles);
+    let world = engine.world;
+    let variant = variant(styles);
+    families(styles)
+        .find_map(|family| world.font(world.book().select(family.as_str(), variant)?))
+        .map(|font| {
+            font.edges(
+                TextElem::top_edge_in(styles),
+                TextElem::bottom_edge_in(styles),
+                size,
+                TextEdgeBounds::Zero,
+            )
+        })
+        .unwrap_or((size, Abs::zero()))
+}
@@ -36,8 +36,9 @@ pub enum Item<'a> {
     Absolute(Abs, bool),
     /// Fractional spacing between other items.
     Fractional(Fr, Option<(&'a Packed<BoxElem>, Locator<'a>, StyleChain<'a>)>),
-    /// Layouted inline-level content.
-    Frame(Frame),
+    /// Layouted inline-level content. For inline equations, also the styles
+    /// they were laid out with, so that text decorations can extend over them.
+    Frame(Frame, Option<StyleChain<'a>>),
     /// A tag.
     Tag(&'a Tag),
     /// An item that is invisible and needs to be skipped, e.g. a Unicode
@@ -207,7 +208,7 @@ pub fn collect<'a>(
                     InlineItem::Frame(mut frame) => {
                         frame.modify(&FrameModifiers::get_in(styles));
                         apply_baseline_shift(&mut frame, styles);
-                        collector.push_item(Item::Frame(frame));
+                        collector.push_item(Item::Frame(frame, Some(styles)));
                     }
                 }
             }
@@ -222,7 +223,7 @@ pub fn collect<'a>(
                     layout_box(elem, engine, loc, styles, region)
                 })?;
                 apply_baseline_shift(&mut frame, styles);
-                collector.push_item(Item::Frame(frame));
+                collector.push_item(Item::Frame(frame, None));
             }
         } else if let Some(elem) = child.to_packed::<TagElem>() {
             collector.push_item(Item::Tag(&elem.tag));
@@ -1,93 +1,274 @@
 use kurbo::{BezPath, Line, ParamCurve};
+use smallvec::SmallVec;
 use ttf_parser::{GlyphId, OutlineBuilder};
-use typst_library::layout::{Abs, Em, Frame, FrameItem, Point, Size};
+use typst_library::foundations::StyleChain;
+use typst_library::layout::{Abs, Em, Frame, FrameItem, HideElem, Point, Size};
 use typst_library::text::{
-    BottomEdge, DecoLine, Decoration, TextEdgeBounds, TextItem, TopEdge,
+    BottomEdge, DecoLine, Decoration, TextEdgeBounds, TextElem, TextItem, TopEdge,
 };
 use typst_library::visualize::{FixedStroke, Geometry};
 use typst_syntax::Span;
 
 use crate::shapes::styled_rect;
 
-/// Add line decorations to a single run of shaped text.
-pub fn decorate(
-    frame: &mut Frame,
-    deco: &Decoration,
-    text: &TextItem,
+/// Collects the pieces of a line in visual order, so that decorations can be
+/// drawn across them.
+///
+/// Consecutive pieces with the same decoration share one continuous line. The
+/// line also continues across spacing between them and across inline
+/// equations. Its position and thickness are determined by the largest text
+/// it decorates, so that it stays straight across differently styled text.
+/// Pieces with a different baseline shift get lines of their own, which are
+/// positioned relative to the unshifted baseline like for a single text run.
+#[derive(Default)]
+pub struct LineDecos {
+    pieces: Vec<Piece>,
+}
+
+/// A horizontal piece of a line.
+struct Piece {
+    /// The start of the piece in the line, before alignment.
+    x: Abs,
+    /// The width of the piece.
     width: Abs,
-    shift: Abs,
-    pos: Point,
-) {
-    let font_metrics = text.font.metrics();
+    /// What the piece consists of.
+    kind: PieceKind,
+}
 
-    if let DecoLine::Highlight { fill, stroke, top_edge, bottom_edge, radius } =
-        &deco.line
-    {
-        let (top, bottom) = determine_edges(text, *top_edge, *bottom_edge);
-        let size = Size::new(width + 2.0 * deco.extent, top + bottom);
-        let rects = styled_rect(size, radius, fill.clone(), stroke);
-        let origin = Point::new(pos.x - deco.extent, pos.y - top - shift);
-        frame.prepend_multiple(
-            rects
-                .into_iter()
-                .map(|shape| (origin, FrameItem::Shape(shape, Span::detached()))),
-        );
-        return;
+/// The kind of a line piece.
+enum PieceKind {
+    /// Spacing, which continues the decorations around it.
+    Gap,
+    /// Content that is decorated on its own and thus interrupts decorations.
+    Opaque,
+    /// Decorated content with its baseline shift and the text items in it. The
+    /// positions of the text items are relative to the line's baseline.
+    Solid { decos: SmallVec<[Decoration; 1]>, shift: Abs, texts: Vec<(Point, TextItem)> },
+}
+
+impl LineDecos {
+    /// Add spacing.
+    pub fn gap(&mut self, x: Abs, width: Abs) {
+        self.pieces.push(Piece { x, width, kind: PieceKind::Gap });
     }
 
-    let (stroke, metrics, offset, evade, background) = match &deco.line {
-        DecoLine::Strikethrough { stroke, offset, background } => {
-            (stroke, font_metrics.strikethrough, offset, false, *background)
+    /// Add content that is decorated on its own.
+    pub fn opaque(&mut self, x: Abs, width: Abs) {
+        self.pieces.push(Piece { x, width, kind: PieceKind::Opaque });
+    }
+
+    /// Add the frame of a shaped text run or of an inline equation.
+    pub fn frame(&mut self, x: Abs, frame: &Frame, styles: StyleChain) {
+        let decos = TextElem::deco_in(styles);
+        if decos.is_empty() || HideElem::hidden_in(styles) {
+            self.opaque(x, frame.width());
+            return;
         }
-        DecoLine::Overline { stroke, offset, evade, background } => {
-            (stroke, font_metrics.overline, offset, *evade, *background)
+
+        let shift = TextElem::baseline_in(styles);
+        let mut texts = vec![];
+        collect_texts(&mut texts, frame, Point::with_y(-frame.baseline()));
+        self.pieces.push(Piece {
+            x,
+            width: frame.width(),
+            kind: PieceKind::Solid { decos, shift, texts },
+        });
+    }
+
+    /// Draw the decorations into the line's frame, whose baseline is at
+    /// `baseline`. All pieces are shifted by `dx` to account for alignment.
+    pub fn apply(self, frame: &mut Frame, dx: Abs, baseline: Abs) {
+        let origin = Point::new(dx, baseline);
+
+        // Highlights are drawn for each text item on its own.
+        for piece in &self.pieces {
+            let PieceKind::Solid { decos, texts, .. } = &piece.kind else { continue };
+            for deco in decos {
+                for (pos, text) in texts {
+                    highlight(frame, deco, text, origin + Point::with_x(piece.x + pos.x));
+                }
+            }
         }
-        DecoLine::Underline { stroke, offset, evade, background } => {
-            (stroke, font_metrics.underline, offset, *evade, *background)
+
+        // Determine the stretches that share a line.
+        let none = SmallVec::new();
+        let mut open: Vec<Stretch> = vec![];
+        let mut done: Vec<Stretch> = vec![];
+        for (i, piece) in self.pieces.iter().enumerate() {
+            let (decos, shift) = match &piece.kind {
+                PieceKind::Gap => continue,
+                PieceKind::Opaque => (&none, Abs::zero()),
+                PieceKind::Solid { decos, shift, .. } => (decos, *shift),
+            };
+
+            let gap = |stretch: &Stretch| {
+                self.pieces[stretch.last + 1..i]
+                    .iter()
+                    .all(|piece| matches!(piece.kind, PieceKind::Gap))
+            };
+
+            // Close the stretches that this piece doesn't continue.
+            let (continued, closed) =
+                std::mem::take(&mut open).into_iter().partition(|stretch| {
+                    decos.contains(stretch.deco) && stretch.shift == shift && gap(stretch)
+                });
+            open = continued;
+            done.extend(closed);
+
+            for deco in decos {
+                if matches!(deco.line, DecoLine::Highlight { .. }) {
+                    continue;
+                }
+
+                match open.iter_mut().find(|stretch| stretch.deco == deco) {
+                    Some(stretch) => stretch.last = i,
+                    None => open.push(Stretch { deco, shift, first: i, last: i }),
+                }
+            }
         }
-        _ => return,
-    };
+        done.extend(open);
 
-    let offset = offset.unwrap_or(-metrics.position.at(text.size)) - shift;
-    let stroke = stroke.clone().unwrap_or(FixedStroke::from_pair(
-        text.fill.as_decoration(),
-        metrics.thickness.at(text.size),
-    ));
+        for stretch in done {
+            stretch.draw(frame, &self.pieces, origin);
+        }
+    }
+}
 
-    let gap_padding = 0.08 * text.size;
-    let min_width = 0.162 * text.size;
+/// Collect the text items in a frame, including those in untransformed groups,
+/// offset by `origin`.
+fn collect_texts(texts: &mut Vec<(Point, TextItem)>, frame: &Frame, origin: Point) {
+    for (pos, item) in frame.items() {
+        match item {
+            FrameItem::Text(text) => texts.push((origin + *pos, text.clone())),
+            FrameItem::Group(group) if group.transform.is_identity() => {
+                collect_texts(texts, &group.frame, origin + *pos);
+            }
+            _ => {}
+        }
+    }
+}
 
-    let start = pos.x - deco.extent;
-    let end = pos.x + width + deco.extent;
+/// A stretch of line pieces that share a continuous decoration.
+struct Stretch<'a> {
+    /// The shared decoration.
+    deco: &'a Decoration,
+    /// The shared baseline shift of the decorated pieces.
+    shift: Abs,
+    /// The index of the first decorated piece.
+    first: usize,
+    /// The index of the last decorated piece.
+    last: usize,
+}
 
-    let mut push_segment = |from: Abs, to: Abs, prepend: bool| {
-        let origin = Point::new(from, pos.y + offset);
-        let target = Point::new(to - from, Abs::zero());
+impl Stretch<'_> {
+    /// Draw the stretch's line into the frame. The `origin` is the start of
+    /// the line's baseline.
+    fn draw(&self, frame: &mut Frame, pieces: &[Piece], origin: Point) {
+        // Collect the text items of the stretch in line coordinates.
+        let texts: Vec<_> = pieces[self.first..=self.last]
+            .iter()
+            .filter_map(|piece| match &piece.kind {
+                PieceKind::Solid { texts, .. } => Some((piece.x, texts)),
+                _ => None,
+            })
+            .flat_map(|(x, texts)| {
+                texts
+                    .iter()
+                    .map(move |(pos, text)| (origin + Point::with_x(x) + *pos, text))
+            })
+            .collect();
+
+        // The largest text determines the line's position and thickness. A
+        // stretch without text, like a lone equation, has nothing to measure.
+        let Some(&(pos, largest)) = texts.iter().max_by_key(|(_, text)| text.size) else {
+            return;
+        };
+
+        let font_metrics = largest.font.metrics();
+        let (stroke, metrics, offset, evade, background) = match &self.deco.line {
+            DecoLine::Strikethrough { stroke, offset, background } => {
+                (stroke, font_metrics.strikethrough, offset, false, *background)
+            }
+            DecoLine::Overline { stroke, offset, evade, background } => {
+                (stroke, font_metrics.overline, offset, *evade, *background)
+            }
+            DecoLine::Underline { stroke, offset, evade, background } => {
+                (stroke, font_metrics.underline, offset, *evade, *background)
+            }
+            DecoLine::Highlight { .. } => return,
+        };
+
+        // Like for a single text run, the line is positioned relative to the
+        // baseline without the shift.
+        let y = pos.y - self.shift + offset.unwrap_or(-metrics.position.at(largest.size));
+        let stroke = stroke.clone().unwrap_or(FixedStroke::from_pair(
+            largest.fill.as_decoration(),
+            metrics.thickness.at(largest.size),
+        ));
+
+        let gap_padding = 0.08 * largest.size;
+        let min_width = 0.162 * largest.size;
+
+        let start = origin.x + pieces[self.first].x - self.deco.extent;
+        let end =
+            origin.x + pieces[self.last].x + pieces[self.last].width + self.deco.extent;
+
+        let mut push_segment = |from: Abs, to: Abs| {
+            let origin = Point::new(from, y);
+            let target = Point::new(to - from, Abs::zero());
+
+            if target.x >= min_width || !evade {
+                let shape = Geometry::Line(target).stroked(stroke.clone());
+
+                if background {
+                    frame.prepend(origin, FrameItem::Shape(shape, Span::detached()));
+                } else {
+                    frame.push(origin, FrameItem::Shape(shape, Span::detached()));
+                }
+            }
+        };
 
-        if target.x >= min_width || !evade {
-            let shape = Geometry::Line(target).stroked(stroke.clone());
+        if !evade {
+            push_segment(start, end);
+            return;
+        }
+
+        let mut intersections = vec![];
+        for (pos, text) in texts {
+            intersect(&mut intersections, text, pos, y - pos.y);
+        }
 
-            if prepend {
-                frame.prepend(origin, FrameItem::Shape(shape, Span::detached()));
+        // Add start and end points, taking padding into account.
+        intersections.push(start - gap_padding);
+        intersections.push(end + gap_padding);
+        // When emitting the decorative line segments, we move from left to
+        // right. The intersections are not necessarily in this order, yet.
+        intersections.sort();
+
+        for edge in intersections.windows(2) {
+            let l = edge[0];
+            let r = edge[1];
+
+            // If we are too close, don't draw the segment
+            if r - l < gap_padding {
+                continue;
             } else {
-                frame.push(origin, FrameItem::Shape(shape, Span::detached()));
+                push_segment(l + gap_padding, r - gap_padding);
             }
         }
-    };
-
-    if !evade {
-        push_segment(start, end, background);
-        return;
     }
+}
 
+/// Find where the glyphs of a text item at `pos` intersect a horizontal line
+/// at `offset` from the text's baseline.
+fn intersect(intersections: &mut Vec<Abs>, text: &TextItem, pos: Point, offset: Abs) {
+    let font_metrics = text.font.metrics();
     let line = Line::new(
         kurbo::Point::new(pos.x.to_raw(), offset.to_raw()),
-        kurbo::Point::new((pos.x + width).to_raw(), offset.to_raw()),
+        kurbo::Point::new((pos.x + text.width()).to_raw(), offset.to_raw()),
     );
 
     let mut x = pos.x;
-    let mut intersections = vec![];
-
     for glyph in text.glyphs.iter() {
         let dx = glyph.x_offset.at(text.size) + x;
         let mut builder =
@@ -115,25 +296,24 @@ pub fn decorate(
             );
         }
     }
+}
 
-    // Add start and end points, taking padding into account.
-    intersections.push(start - gap_padding);
-    intersections.push(end + gap_padding);
-    // When emitting the decorative line segments, we move from left to
-    // right. The intersections are not necessarily in this order, yet.
-    intersections.sort();
-
-    for edge in intersections.windows(2) {
-        let l = edge[0];
-        let r = edge[1];
+/// Draw a highlight behind a text item whose baseline starts at `pos`.
+fn highlight(frame: &mut Frame, deco: &Decoration, text: &TextItem, pos: Point) {
+    let DecoLine::Highlight { fill, stroke, top_edge, bottom_edge, radius } = &deco.line
+    else {
+        return;
+    };
 
-        // If we are too close, don't draw the segment
-        if r - l < gap_padding {
-            continue;
-        } else {
-            push_segment(l + gap_padding, r - gap_padding, background);
-        }
-    }
+    let (top, bottom) = determine_edges(text, *top_edge, *bottom_edge);
+    let size = Size::new(text.width() + 2.0 * deco.extent, top + bottom);
+    let rects = styled_rect(size, radius, fill.clone(), stroke);
+    let origin = Point::new(pos.x - deco.extent, pos.y - top);
+    frame.prepend_multiple(
+        rects
+            .into_iter()
+            .map(|shape| (origin, FrameItem::Shape(shape, Span::detached()))),
+    );
 }
 
 // Return the top/bottom edge of the text given the metric of the font.
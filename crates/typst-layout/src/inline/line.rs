@@ -4,7 +4,7 @@ use std::ops::{Deref, DerefMut};
 use typst_library::engine::Engine;
 use typst_library::introspection::{SplitLocator, Tag};
 use typst_library::layout::{Abs, Dir, Em, Fr, Frame, FrameItem, Point};
-use typst_library::model::ParLineMarker;
+use typst_library::model::{JustifyLast, ParLineMarker};
 use typst_library::text::{Lang, TextElem};
 use typst_utils::Numeric;
 
@@ -33,6 +33,8 @@ pub struct Line<'a> {
     pub width: Abs,
     /// Whether the line should be justified.
     pub justify: bool,
+    /// Whether the line ends the paragraph or ends with a mandatory break.
+    pub last: bool,
     /// Whether the line ends with a hyphen or dash, either naturally or through
     /// hyphenation.
     pub dash: Option<Dash>,
@@ -45,6 +47,7 @@ impl Line<'_> {
             items: Items::new(),
             width: Abs::zero(),
             justify: false,
+            last: false,
             dash: None,
         }
     }
@@ -93,7 +96,7 @@ impl Line<'_> {
     pub fn has_negative_width_items(&self) -> bool {
         self.items.iter().any(|item| match item {
             Item::Absolute(amount, _) => *amount < Abs::zero(),
-            Item::Frame(frame) => frame.width() < Abs::zero(),
+            Item::Frame(frame, _) => frame.width() < Abs::zero(),
             _ => false,
         })
     }
@@ -135,6 +138,7 @@ pub fn line<'a>(
     // Whether the line is justified.
     let justify = full.ends_with(LINE_SEPARATOR)
         || (p.config.justify && breakpoint != Breakpoint::Mandatory);
+    let last = !justify && breakpoint == Breakpoint::Mandatory;
 
     // Process dashes.
     let dash = if breakpoint.is_hyphen() || full.ends_with(SHY) {
@@ -173,7 +177,7 @@ pub fn line<'a>(
     // Compute the line's width.
     let width = items.iter().map(Item::natural_width).sum();
 
-    Line { items, width, justify, dash }
+    Line { items, width, justify, last, dash }
 }
 
 /// Collects / reshapes all items for the line with the given `range`.
@@ -464,6 +468,18 @@ pub fn commit(
         }
     }
 
+    // The last line may be justified, too, possibly only if it is close to
+    // being full.
+    let justify = line.justify
+        || (line.last
+            && match p.config.justify_last {
+                JustifyLast::Never => false,
+                JustifyLast::Always => true,
+                JustifyLast::Close(ratio) => {
+                    line.width >= ratio.get() * (width - p.config.hanging_indent)
+                }
+            });
+
     // Determine how much additional space is needed. The justification_ratio is
     // for the first step justification, extra_justification is for the last
     // step. For more info on multi-step justification, see Procedures for
@@ -479,7 +495,7 @@ pub fn commit(
         // Attempt to reduce the length of the line, using shrinkability.
         justification_ratio = (remaining / shrinkability).max(-1.0);
         remaining = (remaining + shrinkability).min(Abs::zero());
-    } else if line.justify && fr.is_zero() {
+    } else if justify && fr.is_zero() {
         // Attempt to increase the length of the line, using stretchability.
         if stretchability > Abs::zero() {
             justification_ratio = (remaining / stretchability).min(1.0);
@@ -499,6 +515,7 @@ pub fn commit(
 
     // Build the frames and determine the height and baseline.
     let mut frames = vec![];
+    let mut decos = LineDecos::default();
     for &(idx, ref item) in line.items.indexed_iter() {
         let mut push = |offset: &mut Abs, frame: Frame, idx: usize| {
             let width = frame.width();
@@ -510,6 +527,7 @@ pub fn commit(
 
         match &**item {
             Item::Absolute(v, _) => {
+                decos.gap(offset, *v);
                 offset += *v;
             }
             Item::Fractional(v, elem) => {
@@ -520,8 +538,10 @@ pub fn commit(
                         layout_box(elem, engine, loc.relayout(), styles, region)
                     })?;
                     apply_baseline_shift(&mut frame, *styles);
+                    decos.opaque(offset, frame.width());
                     push(&mut offset, frame, idx);
                 } else {
+                    decos.gap(offset, amount);
                     offset += amount;
                 }
             }
@@ -532,9 +552,14 @@ pub fn commit(
                     justification_ratio,
                     extra_justification,
                 );
+                decos.frame(offset, &frame, shaped.styles);
                 push(&mut offset, frame, idx);
             }
-            Item::Frame(frame) => {
+            Item::Frame(frame, styles) => {
+                match styles {
+                    Some(styles) => decos.frame(offset, frame, *styles),
+                    None => decos.opaque(offset, frame.width()),
+                }
                 push(&mut offset, frame.clone(), idx);
             }
             Item::Tag(tag) => {
@@ -565,12 +590,16 @@ pub fn commit(
     frames.sort_unstable_by_key(|(_, _, idx)| *idx);
 
     // Construct the line's frame.
+    let align = if line.last { p.config.last_align } else { p.config.align };
+    let dx = align.position(remaining);
     for (offset, frame, _) in frames {
-        let x = offset + p.config.align.position(remaining);
         let y = top - frame.baseline();
-        output.push_frame(Point::new(x, y), frame);
+        output.push_frame(Point::new(offset + dx, y), frame);
     }
 
+    // Draw the text decorations across the line.
+    decos.apply(&mut output, dx, top);
+
     Ok(output)
 }
 
@@ -12,14 +12,19 @@ pub use self::box_::layout_box;
 pub use self::shaping::create_shape_plan;
 
 use comemo::{Track, Tracked, TrackedMut};
-use typst_library::diag::SourceResult;
+use typst_library::diag::{warning, SourceResult};
 use typst_library::engine::{Engine, Route, Sink, Traced};
-use typst_library::foundations::{Packed, Resolve, Smart, StyleChain};
-use typst_library::introspection::{Introspector, Locator, LocatorLink, SplitLocator};
-use typst_library::layout::{Abs, AlignElem, Dir, FixedAlignment, Fragment, Size};
+use typst_library::foundations::{NativeElement, Packed, Resolve, Smart, StyleChain};
+use typst_library::introspection::{
+    Counter, Introspector, Locator, LocatorLink, SplitLocator,
+};
+use typst_library::layout::{
+    Abs, AlignElem, Dir, Em, FixAlignment, FixedAlignment, Fragment, HAlignment, HElem,
+    Size,
+};
 use typst_library::model::{
-    EnumElem, FirstLineIndent, Linebreaks, ListElem, ParElem, ParLine, ParLineMarker,
-    TermsElem,
+    EnumElem, FirstLineIndent, JustifyLast, Linebreaks, ListElem, ParElem, ParLine,
+    ParLineMarker, TermsElem,
 };
 use typst_library::routines::{Arenas, Pair, RealizationKind, Routines};
 use typst_library::text::{Costs, Lang, TextElem};
@@ -27,7 +32,7 @@ use typst_library::World;
 use typst_utils::{Numeric, SliceExt};
 
 use self::collect::{collect, Item, Segment, SpanMapper};
-use self::deco::decorate;
+use self::deco::LineDecos;
 use self::finalize::finalize;
 use self::line::{apply_baseline_shift, commit, line, Line};
 use self::linebreak::{linebreak, Breakpoint};
@@ -94,13 +99,22 @@ fn layout_par_impl(
         route: Route::extend(route),
     };
 
+    // Prefix numbered paragraphs with their number.
+    let mut body = elem.body.clone();
+    if let (Some(numbering), Some(loc)) = (elem.numbering(styles), elem.location()) {
+        let number = Counter::of(ParElem::elem())
+            .display_at_loc(&mut engine, loc, styles, numbering)?
+            .spanned(elem.span());
+        body = number + HElem::new(Em::new(0.5).into()).pack() + body;
+    }
+
     let arenas = Arenas::default();
     let children = (engine.routines.realize)(
         RealizationKind::LayoutPar,
         &mut engine,
         &mut locator,
         &arenas,
-        &elem.body,
+        &body,
         styles,
     )?;
 
@@ -114,9 +128,12 @@ fn layout_par_impl(
         Some(situation),
         &ConfigBase {
             justify: elem.justify(styles),
+            justify_last: elem.justify_last(styles),
+            last_line: elem.last_line(styles),
             linebreaks: elem.linebreaks(styles),
             first_line_indent: elem.first_line_indent(styles),
             hanging_indent: elem.hanging_indent(styles),
+            hyphenate_below: elem.hyphenate_below(styles),
         },
     )
 }
@@ -140,9 +157,12 @@ pub fn layout_inline<'a>(
         None,
         &ConfigBase {
             justify: ParElem::justify_in(shared),
+            justify_last: ParElem::justify_last_in(shared),
+            last_line: ParElem::last_line_in(shared),
             linebreaks: ParElem::linebreaks_in(shared),
             first_line_indent: ParElem::first_line_indent_in(shared),
             hanging_indent: ParElem::hanging_indent_in(shared),
+            hyphenate_below: ParElem::hyphenate_below_in(shared),
         },
     )
 }
@@ -160,7 +180,7 @@ fn layout_inline_impl<'a>(
     base: &ConfigBase,
 ) -> SourceResult<Fragment> {
     // Prepare configuration that is shared across the whole inline layout.
-    let config = configuration(base, children, shared, par);
+    let config = configuration(base, children, shared, par, region.x);
 
     // Collect all text into one string for BiDi analysis.
     let (text, segments, spans) = collect(children, engine, locator, &config, region)?;
@@ -170,7 +190,11 @@ fn layout_inline_impl<'a>(
     let p = prepare(engine, &config, &text, segments, spans)?;
 
     // Break the text into lines.
-    let lines = linebreak(engine, &p, region.x - config.hanging_indent);
+    let width = region.x - config.hanging_indent;
+    let lines = linebreak(engine, &p, width);
+    if base.hyphenate_below.is_some() {
+        warn_overflow(engine, &p, &lines, width);
+    }
 
     // Turn the selected lines into frames.
     finalize(engine, &p, &lines, region, expand, locator)
@@ -182,13 +206,17 @@ fn configuration(
     children: &[Pair],
     shared: StyleChain,
     situation: Option<ParSituation>,
+    width: Abs,
 ) -> Config {
     let justify = base.justify;
     let font_size = TextElem::size_in(shared);
     let dir = TextElem::dir_in(shared);
+    let auto_hyphenate = justify || base.hyphenate_below.is_some_and(|min| width < min);
+    let align = AlignElem::alignment_in(shared).fix(dir).x;
 
     Config {
         justify,
+        justify_last: if justify { base.justify_last } else { JustifyLast::Never },
         linebreaks: base.linebreaks.unwrap_or_else(|| {
             if justify {
                 Linebreaks::Optimized
@@ -230,11 +258,13 @@ fn configuration(
                 ParLine::number_clearance_in(shared),
             ))
         }),
-        align: AlignElem::alignment_in(shared).fix(dir).x,
+        align,
+        last_align: base.last_line.map_or(align, |last| last.fix(dir)),
         font_size,
         dir,
+        auto_hyphenate,
         hyphenate: shared_get(children, shared, TextElem::hyphenate_in)
-            .map(|uniform| uniform.unwrap_or(justify)),
+            .map(|uniform| uniform.unwrap_or(auto_hyphenate)),
         lang: shared_get(children, shared, TextElem::lang_in),
         fallback: TextElem::fallback_in(shared),
         cjk_latin_spacing: TextElem::cjk_latin_spacing_in(shared).is_auto(),
@@ -242,6 +272,39 @@ fn configuration(
     }
 }
 
+/// Warn about the first line that is wider than the available width because
+/// a word in it could not be broken.
+fn warn_overflow(engine: &mut Engine, p: &Preparation, lines: &[Line], width: Abs) {
+    if !width.is_finite() {
+        return;
+    }
+
+    for line in lines {
+        // Overflowing inline-level content like boxes is not a problem of
+        // line breaking, so we only consider lines made of text.
+        if line.items.iter().any(|item| matches!(item, Item::Frame(..))) {
+            continue;
+        }
+
+        let natural =
+            if line.justify { line.width - line.shrinkability() } else { line.width };
+
+        if width.fits(natural) {
+            continue;
+        }
+
+        let Some(text) = line.items.iter().find_map(Item::text) else { continue };
+        let (span, _) = p.spans.span_at(text.base);
+        engine.sink.warn(warning!(
+            span,
+            "text does not fit into the available width";
+            hint: "a word is too wide and could not be broken";
+            hint: "try enabling hyphenation or making the container wider"
+        ));
+        break;
+    }
+}
+
 /// Distinguishes between a few different kinds of paragraphs.
 ///
 /// In the form `Option<ParSituation>`, `None` implies that we are creating an
@@ -259,15 +322,21 @@ pub enum ParSituation {
 /// Raw values from a `ParElem` or style chain. Used to initialize a [`Config`].
 struct ConfigBase {
     justify: bool,
+    justify_last: JustifyLast,
+    last_line: Smart<HAlignment>,
     linebreaks: Smart<Linebreaks>,
     first_line_indent: FirstLineIndent,
     hanging_indent: Abs,
+    hyphenate_below: Option<Abs>,
 }
 
 /// Shared configuration for the whole inline layout.
 struct Config {
     /// Whether to justify text.
     justify: bool,
+    /// Whether to justify the last line of the paragraph and lines before
+    /// mandatory breaks. Only set for justified text.
+    justify_last: JustifyLast,
     /// How to determine line breaks.
     linebreaks: Linebreaks,
     /// The indent the first line of a paragraph should have.
@@ -278,10 +347,16 @@ struct Config {
     numbering_marker: Option<Packed<ParLineMarker>>,
     /// The resolved horizontal alignment.
     align: FixedAlignment,
+    /// The resolved horizontal alignment of the last line and of lines before
+    /// mandatory breaks.
+    last_align: FixedAlignment,
     /// The text size.
     font_size: Abs,
     /// The dominant direction.
     dir: Dir,
+    /// Whether text with automatic hyphenation is hyphenated. This is the case
+    /// for justified text and, if enabled, text in narrow containers.
+    auto_hyphenate: bool,
     /// A uniform hyphenation setting (only `Some(_)` if it's the same for all
     /// children, otherwise `None`).
     hyphenate: Option<bool>,
@@ -17,7 +17,7 @@ use typst_utils::SliceExt;
 use unicode_bidi::{BidiInfo, Level as BidiLevel};
 use unicode_script::{Script, UnicodeScript};
 
-use super::{decorate, Item, Range, SpanMapper};
+use super::{Item, Range, SpanMapper};
 use crate::modifiers::FrameModifyText;
 
 /// The result of shaping text.
@@ -223,7 +223,6 @@ impl<'a> ShapedText<'a> {
         frame.set_baseline(top);
 
         let shift = TextElem::baseline_in(self.styles);
-        let decos = TextElem::deco_in(self.styles);
         let fill = TextElem::fill_in(self.styles);
         let stroke = TextElem::stroke_in(self.styles);
         let span_offset = TextElem::span_offset_in(self.styles);
@@ -314,16 +313,7 @@ impl<'a> ShapedText<'a> {
             };
 
             let width = item.width();
-            if decos.is_empty() {
-                frame.push(pos, FrameItem::Text(item));
-            } else {
-                // Apply line decorations.
-                frame.push(pos, FrameItem::Text(item.clone()));
-                for deco in &decos {
-                    decorate(&mut frame, deco, &item, width, shift, pos);
-                }
-            }
-
+            frame.push(pos, FrameItem::Text(item));
             offset += width;
         }
 
@@ -685,6 +675,13 @@ fn shape<'a>(
     track_and_space(&mut ctx);
     calculate_adjustability(&mut ctx, lang, region);
 
+    if !TextElem::justifiable_in(styles) {
+        for glyph in &mut ctx.glyphs {
+            glyph.adjustability = Adjustability::default();
+            glyph.is_justifiable = false;
+        }
+    }
+
     #[cfg(debug_assertions)]
     assert_all_glyphs_in_range(&ctx.glyphs, text, base..(base + text.len()));
     #[cfg(debug_assertions)]
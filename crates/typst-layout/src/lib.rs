@@ -1,24 +1,37 @@
 //! Typst's layout engine.
 
+mod board;
+mod chart;
+mod codes;
 mod flow;
+mod gantt;
 mod grid;
 mod image;
 mod inline;
 mod lists;
 mod math;
 mod modifiers;
+mod music;
 mod pad;
 mod pages;
 mod repeat;
 mod shapes;
+mod slide;
 mod stack;
+mod text_path;
 mod transforms;
+mod watermark;
 
+pub use self::board::layout_board;
+pub use self::chart::layout_chart;
+pub use self::codes::{layout_barcode, layout_qrcode};
 pub use self::flow::{layout_columns, layout_fragment, layout_frame};
+pub use self::gantt::layout_gantt;
 pub use self::grid::{layout_grid, layout_table};
 pub use self::image::layout_image;
 pub use self::lists::{layout_enum, layout_list};
 pub use self::math::{layout_equation_block, layout_equation_inline};
+pub use self::music::layout_fretboard;
 pub use self::pad::layout_pad;
 pub use self::pages::layout_document;
 pub use self::repeat::layout_repeat;
@@ -26,5 +39,8 @@ pub use self::shapes::{
     layout_circle, layout_curve, layout_ellipse, layout_line, layout_path,
     layout_polygon, layout_rect, layout_square,
 };
+pub use self::slide::layout_slide;
 pub use self::stack::layout_stack;
+pub use self::text_path::layout_text_path;
 pub use self::transforms::{layout_move, layout_rotate, layout_scale, layout_skew};
+pub use self::watermark::layout_watermark;
@@ -0,0 +1,134 @@
+use typst_library::diag::{bail, SourceResult};
+use typst_library::engine::Engine;
+use typst_library::foundations::{Packed, Smart, StyleChain};
+use typst_library::introspection::Locator;
+use typst_library::layout::{Abs, Axes, Frame, FrameItem, Point, Region, Size};
+use typst_library::text::TextElem;
+use typst_library::visualize::{Curve, FixedStroke, FretboardElem, Geometry};
+
+/// The maximum number of frets shown in a chord diagram.
+const MAX_ROWS: usize = 36;
+
+/// Layout the chord diagram.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_fretboard(
+    elem: &Packed<FretboardElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    _: Region,
+) -> SourceResult<Frame> {
+    let span = elem.span();
+    let strings = elem.frets.strings();
+    let base = elem.base(styles).get();
+    let rows = match elem.rows(styles) {
+        Smart::Custom(rows) => rows.get(),
+        Smart::Auto => (elem.frets.highest() + 1).saturating_sub(base).max(4),
+    };
+
+    if rows > MAX_ROWS {
+        bail!(
+            span, "fretboard cannot show more than {MAX_ROWS} frets";
+            hint: "use `base` to start the diagram at a higher fret"
+        );
+    }
+
+    let s = elem.spacing(styles);
+    let fill = elem.fill(styles);
+    let thin = FixedStroke::from_pair(fill.clone(), s / 20.0);
+    let thick = FixedStroke::from_pair(fill.clone(), s / 5.0);
+    let mut locator = locator.split();
+    let pod = Region::new(Size::splat(Abs::inf()), Axes::splat(false));
+
+    // Lay out the name and the base fret label.
+    let name = match elem.name(styles) {
+        Some(name) => Some(crate::layout_frame(
+            engine,
+            &name,
+            locator.next(&name.span()),
+            styles,
+            pod,
+        )?),
+        None => None,
+    };
+
+    let label = if base > 1 {
+        let text = TextElem::packed(base.to_string()).spanned(span);
+        Some(crate::layout_frame(engine, &text, locator.next(&span), styles, pod)?)
+    } else {
+        None
+    };
+
+    // The position of the top left corner of the grid.
+    let grid_width = s * (strings.len() - 1) as f64;
+    let margin = label.as_ref().map_or(s / 2.0, |label| label.width() + s / 4.0);
+    let name_height = name.as_ref().map_or(Abs::zero(), |name| name.height() + s / 4.0);
+    let width = (margin + grid_width + s / 2.0)
+        .max(name.as_ref().map_or(Abs::zero(), Frame::width));
+    let left = margin + (width - margin - grid_width - s / 2.0) / 2.0;
+    let top = name_height + s;
+    let height = top + s * rows as f64 + s / 2.0;
+
+    let mut frame = Frame::hard(Size::new(width, height));
+    if let Some(name) = name {
+        let x = left + (grid_width - name.width()) / 2.0;
+        frame.push_frame(Point::with_x(x), name);
+    }
+
+    if let Some(label) = label {
+        let y = top + (s - label.height()) / 2.0;
+        frame.push_frame(Point::new(left - label.width() - s / 4.0, y), label);
+    }
+
+    // Draw the strings and frets.
+    for i in 0..strings.len() {
+        let line = Geometry::Line(Point::with_y(s * rows as f64));
+        let pos = Point::new(left + s * i as f64, top);
+        frame.push(pos, FrameItem::Shape(line.stroked(thin.clone()), span));
+    }
+
+    for j in 0..=rows {
+        let stroke = if j == 0 && base == 1 { &thick } else { &thin };
+        let line = Geometry::Line(Point::with_x(grid_width));
+        let pos = Point::new(left, top + s * j as f64);
+        frame.push(pos, FrameItem::Shape(line.stroked(stroke.clone()), span));
+    }
+
+    // Draw the markers.
+    let marker = s / 2.0;
+    for (i, fret) in strings.iter().enumerate() {
+        let x = left + s * i as f64;
+        match *fret {
+            None => {
+                let y = top - s / 2.0;
+                let d = marker / 2.0;
+                for (from, to) in [
+                    (Point::new(x - d, y - d), Point::new(d * 2.0, d * 2.0)),
+                    (Point::new(x - d, y + d), Point::new(d * 2.0, -d * 2.0)),
+                ] {
+                    let shape = Geometry::Line(to).stroked(thin.clone());
+                    frame.push(from, FrameItem::Shape(shape, span));
+                }
+            }
+            Some(0) => {
+                let curve = Curve::ellipse(Size::splat(marker));
+                let shape = Geometry::Curve(curve).stroked(thin.clone());
+                let pos = Point::new(x - marker / 2.0, top - s / 2.0 - marker / 2.0);
+                frame.push(pos, FrameItem::Shape(shape, span));
+            }
+            Some(fret) => {
+                if fret < base || fret - base >= rows {
+                    bail!(span, "fret {fret} is not within the diagram");
+                }
+                let dot = s * 0.7;
+                let curve = Curve::ellipse(Size::splat(dot));
+                let shape = Geometry::Curve(curve).filled(fill.clone());
+                let y = top + s * ((fret - base) as f64 + 0.5);
+                let pos = Point::new(x - dot / 2.0, y - dot / 2.0);
+                frame.push(pos, FrameItem::Shape(shape, span));
+            }
+        }
+    }
+
+    Ok(frame)
+}
@@ -17,13 +17,16 @@ pub fn finalize(
         mut margin,
         binding,
         two_sided,
-        header,
-        footer,
+        mut header,
+        mut footer,
+        header_skip,
+        footer_skip,
         background,
         foreground,
         fill,
         numbering,
         supplement,
+        counter: page_counter,
     }: LayoutedPage,
 ) -> SourceResult<Page> {
     // If two sided, left becomes inside and right becomes outside.
@@ -33,6 +36,19 @@ pub fn finalize(
         std::mem::swap(&mut margin.left, &mut margin.right);
     }
 
+    // Leave out the marginals on pages where they are skipped.
+    let physical = counter.physical();
+    if let Some(filter) = &header_skip {
+        if filter.matches(engine, physical, &inner)? {
+            header = None;
+        }
+    }
+    if let Some(filter) = &footer_skip {
+        if filter.matches(engine, physical, &inner)? {
+            footer = None;
+        }
+    }
+
     // Create a frame for the full page.
     let mut frame = Frame::hard(inner.size() + margin.sum_by_axis());
 
@@ -67,8 +83,15 @@ pub fn finalize(
     counter.visit(engine, &frame)?;
 
     // Get this page's number and then bump the counter for the next page.
-    let number = counter.logical();
-    counter.step();
+    let number = counter.number(&page_counter);
+    counter.step(&page_counter);
 
-    Ok(Page { frame, fill, numbering, supplement, number })
+    Ok(Page {
+        frame,
+        fill,
+        numbering,
+        supplement,
+        counter: page_counter,
+        number,
+    })
 }
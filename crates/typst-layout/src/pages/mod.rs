@@ -20,6 +20,8 @@ use self::collect::{collect, Item};
 use self::finalize::finalize;
 use self::run::{layout_blank_page, layout_page_run, LayoutedPage};
 
+pub(crate) use self::run::{resolve_binding, resolve_margin};
+
 /// Layout content into a document.
 ///
 /// This first performs root-level realization and then lays out the resulting
@@ -1,3 +1,5 @@
+use std::num::NonZeroUsize;
+
 use comemo::{Track, Tracked, TrackedMut};
 use typst_library::diag::SourceResult;
 use typst_library::engine::{Engine, Route, Sink, Traced};
@@ -5,19 +7,20 @@ use typst_library::foundations::{
     Content, NativeElement, Resolve, Smart, StyleChain, Styles,
 };
 use typst_library::introspection::{
-    Counter, CounterDisplayElem, CounterKey, Introspector, Locator, LocatorLink, TagElem,
+    Counter, CounterDisplayElem, Introspector, Locator, LocatorLink, TagElem,
 };
 use typst_library::layout::{
     Abs, AlignElem, Alignment, Axes, Binding, ColumnsElem, Dir, Frame, HAlignment,
-    Length, OuterVAlignment, PageElem, Paper, Region, Regions, Rel, Sides, Size,
-    VAlignment,
+    Length, OuterVAlignment, PageElem, PageFilter, Paper, Region, Regions, Rel, Sides,
+    Size, VAlignment,
 };
 use typst_library::model::Numbering;
 use typst_library::routines::{Pair, Routines};
 use typst_library::text::{LocalName, TextElem};
 use typst_library::visualize::Paint;
 use typst_library::World;
-use typst_utils::Numeric;
+use typst_syntax::Span;
+use typst_utils::{NonZeroExt, Numeric};
 
 use crate::flow::{layout_flow, FlowMode};
 
@@ -32,11 +35,14 @@ pub struct LayoutedPage {
     pub two_sided: bool,
     pub header: Option<Frame>,
     pub footer: Option<Frame>,
+    pub header_skip: Option<PageFilter>,
+    pub footer_skip: Option<PageFilter>,
     pub background: Option<Frame>,
     pub foreground: Option<Frame>,
     pub fill: Smart<Option<Paint>>,
     pub numbering: Option<Numbering>,
     pub supplement: Content,
+    pub counter: Counter,
 }
 
 /// Layout a single page suitable  for parity adjustment.
@@ -108,20 +114,9 @@ fn layout_page_run_impl(
         std::mem::swap(&mut size.x, &mut size.y);
     }
 
-    let mut min = width.min(height);
-    if !min.is_finite() {
-        min = Paper::A4.width();
-    }
-
     // Determine the margins.
-    let default = Rel::<Length>::from((2.5 / 21.0) * min);
-    let margin = PageElem::margin_in(styles);
-    let two_sided = margin.two_sided.unwrap_or(false);
-    let margin = margin
-        .sides
-        .map(|side| side.and_then(Smart::custom).unwrap_or(default))
-        .resolve(styles)
-        .relative_to(size);
+    let margin = resolve_margin(styles, size);
+    let two_sided = PageElem::margin_in(styles).two_sided.unwrap_or(false);
 
     let fill = PageElem::fill_in(styles);
     let foreground = PageElem::foreground_in(styles);
@@ -134,11 +129,8 @@ fn layout_page_run_impl(
         Smart::Custom(content) => content.unwrap_or_default(),
     };
     let number_align = PageElem::number_align_in(styles);
-    let binding =
-        PageElem::binding_in(styles).unwrap_or_else(|| match TextElem::dir_in(styles) {
-            Dir::LTR => Binding::Left,
-            _ => Binding::Right,
-        });
+    let counter = PageElem::counter_in(styles);
+    let binding = resolve_binding(styles);
 
     // Construct the numbering (for header or footer).
     let numbering_marginal = numbering.as_ref().map(|numbering| {
@@ -147,8 +139,8 @@ fn layout_page_run_impl(
             Numbering::Func(_) => true,
         };
 
-        let mut counter = CounterDisplayElem::new(
-            Counter::new(CounterKey::Page),
+        let mut display = CounterDisplayElem::new(
+            counter.clone(),
             Smart::Custom(numbering.clone()),
             both,
         )
@@ -157,14 +149,25 @@ fn layout_page_run_impl(
         // We interpret the Y alignment as selecting header or footer
         // and then ignore it for aligning the actual number.
         if let Some(x) = number_align.x() {
-            counter = counter.aligned(x.into());
+            display = display.aligned(x.into());
         }
 
-        counter
+        display
     });
 
+    // Pages that aren't numbered by the page counter step their own counter
+    // in the background, so that it is stepped before anything on the page.
+    let background = if counter.is_page() {
+        background.clone()
+    } else {
+        let step = counter.clone().step(Span::detached(), NonZeroUsize::ONE);
+        Some(step + background.clone().unwrap_or_default())
+    };
+
     let header = PageElem::header_in(styles);
     let footer = PageElem::footer_in(styles);
+    let header_skip = PageElem::header_skip_in(styles);
+    let footer_skip = PageElem::footer_skip_in(styles);
     let (header, footer) = if matches!(number_align.y(), Some(OuterVAlignment::Top)) {
         (header.as_ref().unwrap_or(&numbering_marginal), footer.as_ref().unwrap_or(&None))
     } else {
@@ -210,9 +213,12 @@ fn layout_page_run_impl(
             fill: fill.clone(),
             numbering: numbering.clone(),
             supplement: supplement.clone(),
+            counter: counter.clone(),
             header: layout_marginal(header, header_size, Alignment::BOTTOM)?,
             footer: layout_marginal(footer, footer_size, Alignment::TOP)?,
-            background: layout_marginal(background, full_size, mid)?,
+            header_skip: header_skip.clone(),
+            footer_skip: footer_skip.clone(),
+            background: layout_marginal(&background, full_size, mid)?,
             foreground: layout_marginal(foreground, full_size, mid)?,
             margin,
             binding,
@@ -223,6 +229,30 @@ fn layout_page_run_impl(
     Ok(layouted)
 }
 
+/// Resolves the margins of a page with the given size.
+pub fn resolve_margin(styles: StyleChain, size: Size) -> Sides<Abs> {
+    let mut min = size.x.min(size.y);
+    if !min.is_finite() {
+        min = Paper::A4.width();
+    }
+
+    let default = Rel::<Length>::from((2.5 / 21.0) * min);
+    PageElem::margin_in(styles)
+        .sides
+        .map(|side| side.and_then(Smart::custom).unwrap_or(default))
+        .resolve(styles)
+        .relative_to(size)
+}
+
+/// Resolves on which side pages are bound, defaulting to the side where text
+/// starts.
+pub fn resolve_binding(styles: StyleChain) -> Binding {
+    PageElem::binding_in(styles).unwrap_or_else(|| match TextElem::dir_in(styles) {
+        Dir::LTR => Binding::Left,
+        _ => Binding::Right,
+    })
+}
+
 /// Determines the styles used for a page run itself and page-level content like
 /// marginals and footnotes.
 ///
@@ -0,0 +1,44 @@
+use std::num::NonZeroUsize;
+
+use typst_library::diag::SourceResult;
+use typst_library::engine::Engine;
+use typst_library::foundations::{Packed, StyleChain};
+use typst_library::introspection::Locator;
+use typst_library::layout::{Fragment, Regions};
+use typst_library::model::SlideElem;
+
+/// Layout the slide, once per step.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_slide(
+    elem: &Packed<SlideElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    regions: Regions,
+) -> SourceResult<Fragment> {
+    let last = elem.resolve_steps(styles).get();
+    let first = if elem.show_notes(styles) { last } else { 1 };
+
+    let mut frames = vec![];
+    let mut regions = regions;
+    for step in first..=last {
+        // Each step starts in a fresh region.
+        if step > first {
+            regions.next();
+        }
+
+        // All steps are laid out with the same locator so that their elements
+        // receive the same locations. The introspector then only discovers
+        // each element once, on the first step where it is shown.
+        let body = elem.body.clone().styled(SlideElem::set_step(NonZeroUsize::new(step)));
+        let fragment =
+            crate::layout_fragment(engine, &body, locator.relayout(), styles, regions)?;
+
+        for _ in 1..fragment.len() {
+            regions.next();
+        }
+        frames.extend(fragment);
+    }
+
+    Ok(Fragment::frames(frames))
+}
@@ -0,0 +1,178 @@
+use kurbo::{BezPath, ParamCurve, ParamCurveArclen, PathSeg};
+use typst_library::diag::{bail, SourceResult};
+use typst_library::engine::Engine;
+use typst_library::foundations::{Packed, StyleChain};
+use typst_library::introspection::Locator;
+use typst_library::layout::{
+    Abs, Angle, Axes, Frame, FrameItem, GroupItem, Point, Region, Size, Transform,
+};
+use typst_library::text::TextItem;
+use typst_library::visualize::{Curve, CurveItem, Geometry, TextPathElem};
+
+/// The accuracy with which arc lengths along the path are computed, in
+/// points.
+const ACCURACY: f64 = 1e-3;
+
+/// Layout text along a path.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_text_path(
+    elem: &Packed<TextPathElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    region: Region,
+) -> SourceResult<Frame> {
+    let span = elem.span();
+    let mut locator = locator.split();
+    let mut frame = crate::layout_frame(
+        engine,
+        &elem.path,
+        locator.next(&elem.path.span()),
+        styles,
+        region,
+    )?;
+
+    let Some(path) = find_path(&frame, Transform::identity()) else {
+        bail!(span, "text path must contain a shape");
+    };
+
+    let segments: Vec<(PathSeg, f64)> =
+        path.segments().map(|seg| (seg, seg.arclen(ACCURACY))).collect();
+    let length = Abs::raw(segments.iter().map(|&(_, len)| len).sum());
+
+    // Lay out the body on a single line and collect its glyphs.
+    let pod = Region::new(Size::splat(Abs::inf()), Axes::splat(false));
+    let body = crate::layout_frame(
+        engine,
+        &elem.body,
+        locator.next(&elem.body.span()),
+        styles,
+        pod,
+    )?;
+    let mut texts = vec![];
+    collect_text(&body, Point::zero(), &mut texts);
+
+    // Place each glyph centered on the point of the path below its middle
+    // and rotate it to the direction of the path there.
+    let start = elem.start(styles).relative_to(length);
+    let baseline = body.baseline();
+    for (pos, text) in texts {
+        let mut x = pos.x;
+        for glyph in &text.glyphs {
+            let advance = glyph.x_advance.at(text.size);
+            let middle = start + x + advance / 2.0;
+            x += advance;
+
+            if middle - advance / 2.0 < Abs::zero() || middle + advance / 2.0 > length {
+                continue;
+            }
+
+            let Some((point, angle)) = locate(&segments, middle) else { continue };
+            let mut single = TextItem {
+                text: text.text[glyph.range()].into(),
+                glyphs: vec![glyph.clone()],
+                ..text.clone()
+            };
+            single.glyphs[0].range = 0..single.text.len() as u16;
+
+            let mut inner = Frame::soft(Size::zero());
+            inner.push(
+                Point::new(-advance / 2.0, pos.y - baseline),
+                FrameItem::Text(single),
+            );
+
+            let mut group = GroupItem::new(inner);
+            group.transform = Transform::rotate(angle);
+            frame.push(point, FrameItem::Group(group));
+        }
+    }
+
+    Ok(frame)
+}
+
+/// Find the first shape in a frame and convert it into a path in the frame's
+/// coordinate system.
+fn find_path(frame: &Frame, ts: Transform) -> Option<BezPath> {
+    for (pos, item) in frame.items() {
+        let ts = ts.pre_concat(Transform::translate(pos.x, pos.y));
+        match item {
+            FrameItem::Group(group) => {
+                if let Some(path) =
+                    find_path(&group.frame, ts.pre_concat(group.transform))
+                {
+                    return Some(path);
+                }
+            }
+            FrameItem::Shape(shape, _) => {
+                let curve = match &shape.geometry {
+                    Geometry::Line(delta) => {
+                        let mut curve = Curve::new();
+                        curve.move_(Point::zero());
+                        curve.line(*delta);
+                        curve
+                    }
+                    Geometry::Rect(size) => Curve::rect(*size),
+                    Geometry::Curve(curve) => curve.clone(),
+                };
+
+                let convert = |p: Point| {
+                    let p = p.transform(ts);
+                    kurbo::Point::new(p.x.to_raw(), p.y.to_raw())
+                };
+
+                let mut path = BezPath::new();
+                for item in &curve.0 {
+                    match *item {
+                        CurveItem::Move(p) => path.move_to(convert(p)),
+                        CurveItem::Line(p) => path.line_to(convert(p)),
+                        CurveItem::Cubic(p1, p2, p3) => {
+                            path.curve_to(convert(p1), convert(p2), convert(p3))
+                        }
+                        CurveItem::Close => path.close_path(),
+                    }
+                }
+                return Some(path);
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Collect the text items in a frame with their positions.
+fn collect_text<'a>(
+    frame: &'a Frame,
+    offset: Point,
+    texts: &mut Vec<(Point, &'a TextItem)>,
+) {
+    for (pos, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => collect_text(&group.frame, offset + *pos, texts),
+            FrameItem::Text(text) => texts.push((offset + *pos, text)),
+            _ => {}
+        }
+    }
+}
+
+/// The point at the given distance along the path and the direction of the
+/// path there.
+fn locate(segments: &[(PathSeg, f64)], distance: Abs) -> Option<(Point, Angle)> {
+    let mut remaining = distance.to_raw();
+    for &(seg, len) in segments {
+        if remaining > len {
+            remaining -= len;
+            continue;
+        }
+
+        // Determine the direction from two nearby points, which also works
+        // where the derivative vanishes at the ends of a Bézier segment.
+        let t = seg.inv_arclen(remaining.max(0.0), ACCURACY);
+        let h = 1e-4;
+        let a = seg.eval((t - h).max(0.0));
+        let b = seg.eval((t + h).min(1.0));
+        let p = seg.eval(t);
+        let angle = Angle::rad((b.y - a.y).atan2(b.x - a.x));
+        return Some((Point::new(Abs::raw(p.x), Abs::raw(p.y)), angle));
+    }
+    None
+}
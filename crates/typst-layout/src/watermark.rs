@@ -0,0 +1,88 @@
+use typst_library::diag::{bail, At, SourceResult};
+use typst_library::engine::Engine;
+use typst_library::foundations::{Context, IntoValue, Packed, Smart, StyleChain};
+use typst_library::introspection::Locator;
+use typst_library::layout::{
+    Abs, Angle, Axes, Frame, PageElem, Point, Ratio, Region, Size, Transform,
+    WatermarkElem,
+};
+
+/// Layout the watermark.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_watermark(
+    elem: &Packed<WatermarkElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    region: Region,
+) -> SourceResult<Frame> {
+    let span = elem.span();
+    let size = region.size;
+    if !size.is_finite() {
+        bail!(span, "cannot fit watermark into infinite area");
+    }
+
+    let mut frame = Frame::soft(size);
+
+    // Check whether the watermark should be shown on this page.
+    if let Some(pages) = elem.pages(styles) {
+        let loc = elem.location().unwrap();
+        let page = engine.introspector.page(loc);
+        let context = Context::new(Some(loc), Some(styles));
+        let show = pages
+            .call(engine, context.track(), [page.get().into_value()])?
+            .cast::<bool>()
+            .at(span)?;
+        if !show {
+            return Ok(frame);
+        }
+    }
+
+    // Determine the area to fill.
+    let (origin, area) = if elem.margins(styles) {
+        let mut margin = crate::pages::resolve_margin(styles, size);
+
+        // Swap the inside and outside margins on the same pages as `finalize`.
+        if PageElem::margin_in(styles).two_sided.unwrap_or(false) {
+            let page = engine.introspector.page(elem.location().unwrap());
+            if crate::pages::resolve_binding(styles).swap(page) {
+                std::mem::swap(&mut margin.left, &mut margin.right);
+            }
+        }
+
+        (Point::new(margin.left, margin.top), size - margin.sum_by_axis())
+    } else {
+        (Point::zero(), size)
+    };
+
+    // Lay out the body at its natural size.
+    let pod = Region::new(Size::splat(Abs::inf()), Axes::splat(false));
+    let mut body = crate::layout_frame(engine, &elem.body, locator, styles, pod)?;
+    if body.is_empty() || !area.x.is_finite() || area.x <= Abs::zero() {
+        return Ok(frame);
+    }
+
+    let angle = elem
+        .angle(styles)
+        .unwrap_or_else(|| -Angle::rad((area.y / area.x).atan()));
+
+    // Scale the body such that its rotated bounds fit into the area.
+    let (w, h) = (body.width(), body.height());
+    let (cos, sin) = (angle.cos().abs(), angle.sin().abs());
+    let bounds = Size::new(w * cos + h * sin, w * sin + h * cos);
+    if bounds.x <= Abs::zero() || bounds.y <= Abs::zero() {
+        return Ok(frame);
+    }
+
+    let scale =
+        Ratio::new(elem.size(styles).get() * (area.x / bounds.x).min(area.y / bounds.y));
+    let center = origin + area.to_point() / 2.0;
+    let ts = Transform::translate(center.x, center.y)
+        .pre_concat(Transform::rotate(angle))
+        .pre_concat(Transform::scale(scale, scale))
+        .pre_concat(Transform::translate(-w / 2.0, -h / 2.0));
+    body.transform(ts);
+    frame.push_frame(Point::zero(), body);
+
+    Ok(frame)
+}
@@ -8,7 +8,7 @@ use ecow::EcoVec;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 use typst_syntax::{FileId, Span};
 
-use crate::diag::{bail, HintedStrResult, SourceDiagnostic, SourceResult, StrResult};
+use crate::diag::{bail, HintedStrResult, SourceDiagnostic, SourceResult};
 use crate::foundations::{Styles, Value};
 use crate::introspection::Introspector;
 use crate::routines::Routines;
@@ -293,7 +293,9 @@ impl<'a> Route<'a> {
 /// The maximum nesting depths. They are different so that even if show rule and
 /// call checks are interleaved, for show rule problems we always get the show
 /// rule error. The lower the max depth for a kind of error, the higher its
-/// precedence compared to the others.
+/// precedence compared to the others. The maximum function call depth is
+/// configured through the library's [`Limits`](crate::Limits) and defaults
+/// to 80.
 impl Route<'_> {
     /// The maximum stack nesting depth.
     const MAX_SHOW_RULE_DEPTH: usize = 64;
@@ -304,9 +306,6 @@ impl Route<'_> {
     /// The maximum HTML nesting depth.
     const MAX_HTML_DEPTH: usize = 72;
 
-    /// The maximum function call nesting depth.
-    const MAX_CALL_DEPTH: usize = 80;
-
     /// Ensures that we are within the maximum show rule depth.
     pub fn check_show_depth(&self) -> HintedStrResult<()> {
         if !self.within(Route::MAX_SHOW_RULE_DEPTH) {
@@ -341,10 +340,14 @@ impl Route<'_> {
         Ok(())
     }
 
-    /// Ensures that we are within the maximum function call depth.
-    pub fn check_call_depth(&self) -> StrResult<()> {
-        if !self.within(Route::MAX_CALL_DEPTH) {
-            bail!("maximum function call depth exceeded");
+    /// Ensures that we are within the given maximum function call depth.
+    pub fn check_call_depth(&self, max: usize) -> HintedStrResult<()> {
+        if !self.within(max) {
+            bail!(
+                "maximum function call depth exceeded";
+                hint: "maybe a function calls itself without a base case";
+                hint: "the limit is {max} nested calls",
+            );
         }
         Ok(())
     }
@@ -358,6 +361,11 @@ impl<'a> Route<'a> {
         self.id == Some(id) || self.outer.is_some_and(|outer| outer.contains(id))
     }
 
+    /// The number of files that are part of the route.
+    pub fn files(&self) -> usize {
+        usize::from(self.id.is_some()) + self.outer.map_or(0, |outer| outer.files())
+    }
+
     /// Whether the route's depth is less than or equal to the given depth.
     pub fn within(&self, depth: usize) -> bool {
         // We only need atomicity and no synchronization of other operations, so
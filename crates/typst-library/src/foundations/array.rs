@@ -15,6 +15,7 @@ use crate::foundations::{
     cast, func, ops, repr, scope, ty, Args, Bytes, CastInfo, Context, Dict, FromValue,
     Func, IntoValue, Reflect, Repr, Str, Value, Version,
 };
+use crate::text::{Collator, Lang};
 
 /// Create a new [`Array`] from values.
 #[macro_export]
@@ -859,6 +860,25 @@ impl Array {
         /// ```
         #[named]
         by: Option<Func>,
+        /// If given, strings are sorted according to the alphabetical order of
+        /// this language instead of by their Unicode code points. This also
+        /// applies to strings within arrays returned by the `key` function.
+        /// Has no effect when a comparison function is given with `by`.
+        ///
+        /// Without a specific language, accented letters are sorted together
+        /// with their base letter and lowercase letters come before uppercase
+        /// ones. Some languages treat certain letters or digraphs as separate
+        /// letters of their alphabet.
+        ///
+        /// ```example
+        /// #let words = ("Zoo", "Äpfel", "apple", "öl", "chata", "hrad")
+        /// #words.sorted() \
+        /// #words.sorted(locale: "de") \
+        /// #words.sorted(locale: "sv") \
+        /// #words.sorted(locale: "cs")
+        /// ```
+        #[named]
+        locale: Option<Lang>,
     ) -> SourceResult<Array> {
         match by {
             Some(by) => {
@@ -936,6 +956,26 @@ impl Array {
                     Some(f) => f.call(engine, context, [x]),
                     None => Ok(x),
                 };
+                // With a collator, compute the sort keys of all elements up
+                // front, as they are expensive to compute.
+                if let Some(collator) = locale.map(Collator::new) {
+                    let mut keyed = Vec::with_capacity(self.0.len());
+                    for x in self.0 {
+                        let key = ops::Collated::new(key_of(x.clone())?, &collator);
+                        keyed.push((key, x));
+                    }
+                    let mut result = Ok(());
+                    keyed.sort_by(|(a, _), (b, _)| {
+                        ops::compare_collated(a, b).unwrap_or_else(|err| {
+                            if result.is_ok() {
+                                result = Err(err).at(span);
+                            }
+                            Ordering::Equal
+                        })
+                    });
+                    return result.map(|()| keyed.into_iter().map(|(_, x)| x).collect());
+                }
+
                 // If no comparison function is provided, we know the order is
                 // valid, so we can use the standard library sort and prevent an
                 // extra allocation.
@@ -7,22 +7,23 @@ use std::ops::{Add, AddAssign, ControlFlow, Deref, DerefMut};
 use std::sync::Arc;
 
 use comemo::Tracked;
-use ecow::{eco_format, EcoString};
+use ecow::{eco_format, EcoString, EcoVec};
 use serde::{Serialize, Serializer};
-use typst_syntax::Span;
+use typst_syntax::{Span, Spanned};
 use typst_utils::{fat, singleton, LazyHash, SmallBitSet};
 
-use crate::diag::{SourceResult, StrResult};
+use crate::diag::{bail, At, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    elem, func, scope, ty, Context, Dict, Element, Fields, IntoValue, Label,
-    NativeElement, Recipe, RecipeIndex, Repr, Selector, Str, Style, StyleChain, Styles,
-    Value,
+    elem, func, scope, ty, Arg, Args, Array, Binding, Context, Dict, Element, Fields,
+    IntoValue, Label, NativeElement, Recipe, RecipeIndex, Repr, Selector, Str, Style,
+    StyleChain, Styles, SymbolElem, Value,
 };
 use crate::introspection::Location;
 use crate::layout::{AlignElem, Alignment, Axes, Length, MoveElem, PadElem, Rel, Sides};
 use crate::model::{Destination, EmphElem, LinkElem, StrongElem};
-use crate::text::UnderlineElem;
+use crate::text::{SpaceElem, TextElem, UnderlineElem};
+use crate::Library;
 
 /// A piece of document content.
 ///
@@ -607,6 +608,334 @@ impl Content {
     pub fn location(&self) -> Option<Location> {
         self.inner.location
     }
+
+    /// Converts the content into a tree of dictionaries.
+    ///
+    /// Each element becomes a dictionary with the name of its element
+    /// function under the `func` key and its [fields]($content.fields) under
+    /// their respective names. Element functions that are defined within
+    /// another one are named by their full path, e.g. `{"list.item"}`. Content
+    /// within fields, arrays, and dictionaries is converted recursively. The
+    /// result is plain data that can be inspected, transformed, saved to a
+    /// file, and turned back into content with
+    /// [`content.from-dict`]($content.from-dict).
+    ///
+    /// ```example
+    /// #[= Hello _World_].to-dict()
+    /// ```
+    #[func]
+    pub fn to_dict(&self, engine: &mut Engine) -> Dict {
+        let library = engine.world.library();
+        self.to_tree(&|elem| element_path(library, elem).into_value())
+    }
+
+    /// Reconstructs content from a tree of dictionaries, as produced by
+    /// [`to-dict`]($content.to-dict).
+    ///
+    /// Every dictionary with a `func` key is turned into an element by calling
+    /// that element function with the remaining entries as arguments. The
+    /// function can be given directly or by its path, e.g. `{"heading"}` or
+    /// `{"list.item"}`. A nested element function may also be given by just
+    /// its name if that is unambiguous. Fields that the element has but that
+    /// can't be set, like those computed during layout, are ignored. Entries
+    /// that aren't fields of the element at all are an error.
+    ///
+    /// ```example
+    /// // Remove all emphasis from some content.
+    /// #let strip(data) = if type(data) == dictionary {
+    ///   if data.func == "emph" {
+    ///     strip(data.body)
+    ///   } else {
+    ///     data.pairs().map(((k, v)) => (k, strip(v))).to-dict()
+    ///   }
+    /// } else if type(data) == array {
+    ///   data.map(strip)
+    /// } else {
+    ///   data
+    /// }
+    ///
+    /// #let body = [An _important_ point.]
+    /// #content.from-dict(strip(body.to-dict()))
+    /// ```
+    #[func]
+    pub fn from_dict(
+        engine: &mut Engine,
+        span: Span,
+        /// The dictionary to convert.
+        dict: Dict,
+    ) -> SourceResult<Content> {
+        content_from_dict(engine, span, dict)
+    }
+
+    /// Computes a hash of the content as a string of hexadecimal digits.
+    ///
+    /// Content that is [equal]($scripting/#operators) has the same hash, so
+    /// the hash can serve as a dictionary key to deduplicate content or to
+    /// cache computations on it. Like equality, the hash only depends on the
+    /// content's fields and not on where it was written. It is stable within
+    /// a Typst version, but may change between versions.
+    ///
+    /// ```example
+    /// #let seen = (:)
+    /// #for note in ([A], [B], [A]) {
+    ///   let key = note.hash()
+    ///   if key not in seen {
+    ///     seen.insert(key, note)
+    ///   }
+    /// }
+    /// #seen.values().len() unique notes
+    /// ```
+    #[func(name = "hash")]
+    pub fn fingerprint(&self) -> Str {
+        let data = canonicalize(self.to_tree(&|elem| elem.into_value()).into_value());
+        eco_format!("{:032x}", typst_utils::hash128(&data)).into()
+    }
+}
+
+impl Content {
+    /// Convert the content into a tree of dictionaries, representing each
+    /// element function with `func`.
+    fn to_tree(&self, func: &dyn Fn(Element) -> Value) -> Dict {
+        let mut dict = Dict::new();
+        dict.insert("func".into(), func(self.func()));
+        for (key, value) in self.fields() {
+            dict.insert(key, content_to_data(value, func));
+        }
+        dict
+    }
+}
+
+/// Recursively convert the content in a field value into dictionaries.
+fn content_to_data(value: Value, func: &dyn Fn(Element) -> Value) -> Value {
+    match value {
+        Value::Content(content) => content.to_tree(func).into_value(),
+        Value::Array(array) => array
+            .into_iter()
+            .map(|value| content_to_data(value, func))
+            .collect::<Array>()
+            .into_value(),
+        Value::Dict(dict) => dict
+            .into_iter()
+            .map(|(key, value)| (key, content_to_data(value, func)))
+            .collect::<Dict>()
+            .into_value(),
+        value => value,
+    }
+}
+
+/// Recursively prepare content that was converted into dictionaries for
+/// hashing.
+///
+/// Removes the labels, as they don't take part in equality. Also replaces
+/// values whose hash depends on memory addresses or interning order (like
+/// functions, types, and labels) with their representation, so that the hash
+/// is the same across compilations.
+fn canonicalize(value: Value) -> Value {
+    match value {
+        Value::Array(array) => {
+            array.into_iter().map(canonicalize).collect::<Array>().into_value()
+        }
+        Value::Dict(mut dict) => {
+            if matches!(dict.get("func"), Ok(Value::Func(_))) {
+                dict.take("label").ok();
+            }
+            dict.into_iter()
+                .map(|(key, value)| (key, canonicalize(value)))
+                .collect::<Dict>()
+                .into_value()
+        }
+        value @ (Value::Func(_)
+        | Value::Type(_)
+        | Value::Module(_)
+        | Value::Label(_)
+        | Value::Styles(_)
+        | Value::Args(_)
+        | Value::Dyn(_)) => value.repr().into_value(),
+        value => value,
+    }
+}
+
+/// Elements without a public element function.
+const INTERNAL_ELEMENTS: [fn() -> Element; 4] =
+    [SequenceElem::elem, StyledElem::elem, SpaceElem::elem, SymbolElem::elem];
+
+/// All element functions of the standard library together with their path,
+/// e.g. `heading` or `list.item`.
+fn library_elements(
+    library: &Library,
+) -> impl Iterator<Item = (EcoString, Element)> + '_ {
+    fn element(binding: &Binding) -> Option<Element> {
+        match binding.read() {
+            Value::Func(func) => func.element(),
+            _ => None,
+        }
+    }
+
+    let modules = [&library.global, &library.math];
+    let top = modules
+        .into_iter()
+        .flat_map(|module| module.scope().iter())
+        .filter_map(|(name, binding)| Some((name.clone(), element(binding)?)));
+    let nested = top.clone().flat_map(|(parent, elem)| {
+        elem.scope().iter().filter_map(move |(name, binding)| {
+            Some((eco_format!("{parent}.{name}"), element(binding)?))
+        })
+    });
+    top.chain(nested)
+}
+
+/// The path under which an element function is available in the standard
+/// library.
+fn element_path(library: &Library, elem: Element) -> EcoString {
+    if INTERNAL_ELEMENTS.iter().any(|internal| internal() == elem) {
+        return elem.name().into();
+    }
+
+    library_elements(library)
+        .find(|&(_, candidate)| candidate == elem)
+        .map(|(path, _)| path)
+        .unwrap_or_else(|| elem.name().into())
+}
+
+/// Find an element function in the standard library by its path or, if it is
+/// unambiguous, just by its name.
+fn find_element(engine: &Engine, span: Span, name: &str) -> SourceResult<Element> {
+    if let Some(internal) = INTERNAL_ELEMENTS
+        .iter()
+        .map(|internal| internal())
+        .find(|e| e.name() == name)
+    {
+        return Ok(internal);
+    }
+
+    let library = engine.world.library();
+    if let Some((_, elem)) = library_elements(library).find(|(path, _)| path == name) {
+        return Ok(elem);
+    }
+
+    let mut candidates: Vec<(EcoString, Element)> = vec![];
+    for (path, elem) in library_elements(library) {
+        if path.rsplit('.').next() == Some(name)
+            && !candidates.iter().any(|&(_, other)| other == elem)
+        {
+            candidates.push((path, elem));
+        }
+    }
+
+    match candidates.as_slice() {
+        [] => bail!(span, "unknown element `{name}`"),
+        [(_, elem)] => Ok(*elem),
+        _ => bail!(
+            span, "element name `{name}` is ambiguous";
+            hint: "use one of {}",
+            candidates
+                .iter()
+                .map(|(path, _)| eco_format!("`{path}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Recursively convert dictionaries with a `func` key into content.
+fn content_from_data(
+    engine: &mut Engine,
+    span: Span,
+    value: Value,
+) -> SourceResult<Value> {
+    Ok(match value {
+        Value::Dict(dict) if dict.contains("func") => {
+            content_from_dict(engine, span, dict)?.into_value()
+        }
+        Value::Dict(dict) => dict
+            .into_iter()
+            .map(|(key, value)| Ok((key, content_from_data(engine, span, value)?)))
+            .collect::<SourceResult<Dict>>()?
+            .into_value(),
+        Value::Array(array) => array
+            .into_iter()
+            .map(|value| content_from_data(engine, span, value))
+            .collect::<SourceResult<Array>>()?
+            .into_value(),
+        value => value,
+    })
+}
+
+/// Convert a dictionary produced by [`Content::to_dict`] back into content.
+fn content_from_dict(
+    engine: &mut Engine,
+    span: Span,
+    dict: Dict,
+) -> SourceResult<Content> {
+    let mut fields = Dict::new();
+    for (key, value) in dict {
+        fields.insert(key, content_from_data(engine, span, value)?);
+    }
+
+    let elem = match fields.take("func").at(span)? {
+        Value::Str(name) => find_element(engine, span, &name)?,
+        value => value.cast::<Element>().at(span)?,
+    };
+    let label = fields
+        .take("label")
+        .ok()
+        .map(Value::cast::<Label>)
+        .transpose()
+        .at(span)?;
+
+    // Fields that the element has but can't be set, like those that are
+    // computed during layout, are ignored. Anything else is likely a typo.
+    if let Some((key, _)) = fields.iter().find(|(key, _)| elem.field_id(key).is_none()) {
+        bail!(span, "{} does not have field {}", elem.name(), key.repr());
+    }
+
+    let mut take = |key: &str| fields.take(key).at(span);
+
+    // Elements that can't be constructed through their element function.
+    let content = match elem {
+        _ if elem == SequenceElem::elem() => {
+            Content::sequence(take("children")?.cast::<Vec<Content>>().at(span)?)
+        }
+        _ if elem == StyledElem::elem() => {
+            let child = take("child")?.cast::<Content>().at(span)?;
+            child.styled_with_map(take("styles")?.cast().at(span)?)
+        }
+        _ if elem == SpaceElem::elem() => SpaceElem::shared().clone(),
+        _ if elem == TextElem::elem() => {
+            TextElem::packed(take("text")?.cast::<EcoString>().at(span)?)
+        }
+        _ if elem == SymbolElem::elem() => {
+            SymbolElem::packed(take("text")?.cast::<char>().at(span)?)
+        }
+        _ => {
+            let mut args = Args { span, items: EcoVec::new() };
+            for param in elem.params() {
+                let Ok(value) = fields.take(param.name) else { continue };
+                if param.variadic {
+                    for item in value.cast::<Array>().at(span)? {
+                        args.push(span, item);
+                    }
+                } else if param.positional {
+                    args.push(span, value);
+                } else {
+                    args.items.push(Arg {
+                        span,
+                        name: Some(param.name.into()),
+                        value: Spanned::new(value, span),
+                    });
+                }
+            }
+
+            let content = elem.construct(engine, &mut args)?;
+            args.finish()?;
+            content
+        }
+    };
+
+    Ok(match label {
+        Some(label) => content.labelled(label),
+        None => content,
+    })
 }
 
 impl Default for Content {
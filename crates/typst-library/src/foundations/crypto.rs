@@ -0,0 +1,252 @@
+//! Hashing and identifiers.
+
+use std::fmt::Write;
+
+use ecow::EcoString;
+use sha2::Digest;
+
+use crate::diag::bail;
+use crate::foundations::{cast, func, IntoValue, Module, Scope, Str};
+use crate::loading::Readable;
+
+/// A module with hash functions and identifier generation.
+///
+/// These functions are meant for deriving stable identifiers, checksums, and
+/// cache keys in documents. MD5 and SHA-1 are not collision-resistant and
+/// should not be used for security purposes.
+pub fn module() -> Module {
+    let mut scope = Scope::new();
+    scope.define_func::<sha256>();
+    scope.define_func::<sha1>();
+    scope.define_func::<md5>();
+    scope.define_func::<uuid>();
+    Module::new("crypto", scope)
+}
+
+/// Computes the SHA-256 hash of a string or bytes.
+///
+/// The hash is returned as a lowercase hexadecimal string.
+///
+/// ```example
+/// #crypto.sha256("typst")
+/// ```
+#[func(title = "SHA-256")]
+pub fn sha256(
+    /// The data to hash. Strings are hashed in their UTF-8 encoding.
+    data: Readable,
+) -> Str {
+    hex(&sha256_digest(data.into_bytes().as_slice())).into()
+}
+
+/// Computes the SHA-1 hash of a string or bytes.
+///
+/// The hash is returned as a lowercase hexadecimal string.
+///
+/// ```example
+/// #crypto.sha1("typst")
+/// ```
+#[func(title = "SHA-1")]
+pub fn sha1(
+    /// The data to hash. Strings are hashed in their UTF-8 encoding.
+    data: Readable,
+) -> Str {
+    hex(&sha1_digest(data.into_bytes().as_slice())).into()
+}
+
+/// Computes the MD5 hash of a string or bytes.
+///
+/// The hash is returned as a lowercase hexadecimal string.
+///
+/// ```example
+/// #crypto.md5("typst")
+/// ```
+#[func(title = "MD5")]
+pub fn md5(
+    /// The data to hash. Strings are hashed in their UTF-8 encoding.
+    data: Readable,
+) -> Str {
+    hex(&md5_digest(data.into_bytes().as_slice())).into()
+}
+
+/// Generates a name-based UUID (version 5).
+///
+/// The same name and namespace always result in the same UUID, so the result
+/// is stable across compilations. This makes it suitable for document
+/// identifiers and invoice numbers derived from other data.
+///
+/// ```example
+/// #crypto.uuid("typst.app", namespace: "dns") \
+/// #crypto.uuid("invoice-2025-042")
+/// ```
+#[func(title = "UUID")]
+pub fn uuid(
+    /// The name to derive the UUID from.
+    name: Readable,
+    /// The namespace the name belongs to.
+    ///
+    /// Either one of the predefined namespaces `{"dns"}`, `{"url"}`,
+    /// `{"oid"}`, and `{"x500"}` or a UUID string. Defaults to the nil UUID.
+    #[named]
+    #[default]
+    namespace: UuidNamespace,
+) -> Str {
+    let mut input = namespace.0.to_vec();
+    input.extend_from_slice(name.into_bytes().as_slice());
+    let mut bytes: [u8; 16] = sha1_digest(&input)[..16].try_into().unwrap();
+    bytes[6] = (bytes[6] & 0x0F) | 0x50;
+    bytes[8] = (bytes[8] & 0x3F) | 0x80;
+    format_uuid(&bytes).into()
+}
+
+/// The namespace of a name-based UUID.
+#[derive(Default)]
+pub struct UuidNamespace([u8; 16]);
+
+cast! {
+    UuidNamespace,
+    self => Str::from(format_uuid(&self.0)).into_value(),
+    v: Str => Self(match v.as_str() {
+        "dns" => parse_uuid("6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap(),
+        "url" => parse_uuid("6ba7b811-9dad-11d1-80b4-00c04fd430c8").unwrap(),
+        "oid" => parse_uuid("6ba7b812-9dad-11d1-80b4-00c04fd430c8").unwrap(),
+        "x500" => parse_uuid("6ba7b814-9dad-11d1-80b4-00c04fd430c8").unwrap(),
+        s => match parse_uuid(s) {
+            Some(bytes) => bytes,
+            None => bail!("expected a predefined namespace or a UUID"),
+        },
+    }),
+}
+
+/// Parse a UUID in its hyphenated hexadecimal form, with groups of 8, 4, 4, 4,
+/// and 12 digits.
+fn parse_uuid(s: &str) -> Option<[u8; 16]> {
+    let s = s.as_bytes();
+    if s.len() != 36 {
+        return None;
+    }
+
+    let mut digits = Vec::with_capacity(32);
+    for (i, &c) in s.iter().enumerate() {
+        if matches!(i, 8 | 13 | 18 | 23) {
+            if c != b'-' {
+                return None;
+            }
+        } else {
+            digits.push(hex_digit(c)?);
+        }
+    }
+
+    let mut bytes = [0; 16];
+    for (byte, pair) in bytes.iter_mut().zip(digits.chunks(2)) {
+        *byte = (pair[0] << 4) | pair[1];
+    }
+    Some(bytes)
+}
+
+/// The value of an ASCII hexadecimal digit.
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Format a UUID in its hyphenated hexadecimal form.
+fn format_uuid(bytes: &[u8; 16]) -> EcoString {
+    let hex = hex(bytes);
+    let mut uuid = EcoString::new();
+    for (i, range) in [0..8, 8..12, 12..16, 16..20, 20..32].into_iter().enumerate() {
+        if i > 0 {
+            uuid.push('-');
+        }
+        uuid.push_str(&hex[range]);
+    }
+    uuid
+}
+
+/// Format bytes as lowercase hexadecimal digits.
+fn hex(bytes: &[u8]) -> EcoString {
+    let mut hex = EcoString::new();
+    for byte in bytes {
+        write!(hex, "{byte:02x}").unwrap();
+    }
+    hex
+}
+
+/// Compute the SHA-256 digest.
+fn sha256_digest(data: &[u8]) -> [u8; 32] {
+    sha2::Sha256::digest(data).into()
+}
+
+/// Compute the SHA-1 digest.
+fn sha1_digest(data: &[u8]) -> [u8; 20] {
+    ::sha1::Sha1::digest(data).into()
+}
+
+/// Compute the MD5 digest.
+fn md5_digest(data: &[u8]) -> [u8; 16] {
+    ::md5::Md5::digest(data).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digests() {
+        assert_eq!(
+            hex(&sha256_digest(b"")),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex(&sha256_digest(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(hex(&sha1_digest(b"abc")), "a9993e364706816aba3e25717850c26c9cd0d89d");
+        assert_eq!(hex(&md5_digest(b"")), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(
+            hex(&md5_digest(b"The quick brown fox jumps over the lazy dog")),
+            "9e107d9d372bb6826bd81d3542a419d6"
+        );
+
+        // Messages that need an extra block for the padding.
+        let long = [b'a'; 56];
+        assert_eq!(
+            hex(&sha256_digest(&long)),
+            "b35439a4ac6f0948b6d6f9e3c6af0f5f590ce20f1bde7090ef7970686ec6738a"
+        );
+    }
+
+    #[test]
+    fn test_uuid() {
+        let dns =
+            UuidNamespace(parse_uuid("6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap());
+        assert_eq!(
+            uuid(Readable::Str("python.org".into()), dns).as_str(),
+            "886313e1-3b8a-5372-9b90-0c9aee199e5d"
+        );
+        assert_eq!(parse_uuid("not-a-uuid"), None);
+    }
+
+    #[test]
+    fn test_parse_uuid() {
+        assert_eq!(
+            parse_uuid("6BA7B810-9DAD-11D1-80B4-00C04FD430C8"),
+            parse_uuid("6ba7b810-9dad-11d1-80b4-00c04fd430c8"),
+        );
+        assert_eq!(parse_uuid("00000000-0000-0000-0000-000000000001").unwrap()[15], 1);
+
+        // Hyphens in the wrong places.
+        assert_eq!(parse_uuid("6ba7b8109-dad-11d1-80b4-00c04fd430c8"), None);
+        assert_eq!(parse_uuid("6ba7b810-9dad11d1--80b4-00c04fd430c8"), None);
+        assert_eq!(parse_uuid("6ba7b810--9dad-11d1-80b400c04fd430c8"), None);
+
+        // Signs and other characters that are not hexadecimal digits.
+        assert_eq!(parse_uuid("+ba7b810-9dad-11d1-80b4-00c04fd430c8"), None);
+        assert_eq!(parse_uuid("6ba7b810-+dad-11d1-+0b4-00c04fd430c8"), None);
+        assert_eq!(parse_uuid("6ba7b810-9dad-11d1-80b4-00c04fd430cg"), None);
+        assert_eq!(parse_uuid("6ba7b810-9dad-11d1-80b4-00c04fd430c"), None);
+    }
+}
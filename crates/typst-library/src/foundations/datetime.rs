@@ -229,6 +229,22 @@ impl Datetime {
             Datetime::Time(_) => "time",
         }
     }
+
+    /// The Julian day number of the date, with the time of day as the
+    /// fractional part, or `None` for a time without a date.
+    pub fn julian_day(&self) -> Option<f64> {
+        match self {
+            Datetime::Date(date) => Some(f64::from(date.to_julian_day())),
+            Datetime::Datetime(datetime) => {
+                let (hour, minute, second) = datetime.as_hms();
+                let seconds = f64::from(hour) * 3600.0
+                    + f64::from(minute) * 60.0
+                    + f64::from(second);
+                Some(f64::from(datetime.date().to_julian_day()) + seconds / 86400.0)
+            }
+            Datetime::Time(_) => None,
+        }
+    }
 }
 
 #[scope]
@@ -339,6 +355,9 @@ impl Datetime {
     /// `[[hour]:[minute]:[second]]`. In the case of a datetime, it will be
     /// `[[year]-[month]-[day] [hour]:[minute]:[second]]`.
     ///
+    /// The format does not depend on the [text language]($text.lang) or
+    /// [region]($text.region).
+    ///
     /// See the [format syntax]($datetime/#format) for more information.
     #[func]
     pub fn display(
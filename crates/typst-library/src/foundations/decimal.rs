@@ -9,7 +9,7 @@ use typst_syntax::{ast, Span, Spanned};
 
 use crate::diag::{warning, At, SourceResult};
 use crate::engine::Engine;
-use crate::foundations::{cast, func, repr, scope, ty, Repr, Str};
+use crate::foundations::{cast, func, repr, scope, ty, Cast, Repr, Str};
 use crate::World;
 
 /// A fixed-point decimal number type.
@@ -192,6 +192,20 @@ impl Decimal {
         num.checked_mul(ten_to_digits).map(Self)
     }
 
+    /// Rounds this decimal to the specified amount of fractional digits with
+    /// the given rounding strategy.
+    pub fn round_with(self, digits: u32, rounding: Rounding) -> Self {
+        use rust_decimal::RoundingStrategy as Strategy;
+        let strategy = match rounding {
+            Rounding::HalfUp => Strategy::MidpointAwayFromZero,
+            Rounding::HalfDown => Strategy::MidpointTowardZero,
+            Rounding::HalfEven => Strategy::MidpointNearestEven,
+            Rounding::Up => Strategy::AwayFromZero,
+            Rounding::Down => Strategy::ToZero,
+        };
+        Self(self.0.round_dp_with_strategy(digits, strategy))
+    }
+
     /// Attempts to add two decimals.
     ///
     /// Returns `None` on overflow or underflow.
@@ -428,6 +442,25 @@ impl Hash for Decimal {
     }
 }
 
+/// How to round a decimal number.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum Rounding {
+    /// Round to the nearest number and midpoints away from zero, as in
+    /// commercial rounding (2.5 -> 3, -2.5 -> -3).
+    #[default]
+    HalfUp,
+    /// Round to the nearest number and midpoints towards zero
+    /// (2.5 -> 2, -2.5 -> -2).
+    HalfDown,
+    /// Round to the nearest number and midpoints to the nearest even number,
+    /// as in banker's rounding (2.5 -> 2, 3.5 -> 4).
+    HalfEven,
+    /// Round away from zero (2.1 -> 3, -2.1 -> -3).
+    Up,
+    /// Round towards zero, that is, truncate (2.9 -> 2, -2.9 -> -2).
+    Down,
+}
+
 /// A value that can be cast to a decimal.
 pub enum ToDecimal {
     /// A decimal to be converted to itself.
@@ -3,15 +3,18 @@ use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign};
 use std::sync::Arc;
 
+use comemo::Tracked;
 use ecow::{eco_format, EcoString};
 use indexmap::IndexMap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use typst_syntax::is_ident;
 use typst_utils::ArcExt;
 
-use crate::diag::{Hint, HintedStrResult, StrResult};
+use crate::diag::{bail, At, Hint, HintedStrResult, SourceResult, StrResult};
+use crate::engine::Engine;
 use crate::foundations::{
-    array, cast, func, repr, scope, ty, Array, Module, Repr, Str, Value,
+    array, cast, func, repr, scope, ty, Args, Array, Context, Func, Module, Repr, Str,
+    Type, Value,
 };
 
 /// Create a new [`Dict`] from key-value pairs.
@@ -48,6 +51,16 @@ pub use crate::__dict as dict;
 /// loop]($scripting/#loops). This will iterate in the order the pairs were
 /// inserted / declared.
 ///
+/// # Ordering
+/// Dictionaries always preserve insertion order. This holds for iteration,
+/// for [`keys`]($dictionary.keys), [`values`]($dictionary.values), and
+/// [`pairs`]($dictionary.pairs), and for how a dictionary is displayed and
+/// serialized. Updating the value of an existing key keeps the key in its
+/// place, while removing a key moves all later pairs up. Adding two
+/// dictionaries keeps the pairs of the left one first, followed by the new
+/// keys of the right one. If you need an order that does not depend on how the
+/// dictionary was built, use [`sorted-keys`]($dictionary.sorted-keys).
+///
 /// # Example
 /// ```example
 /// #let dict = (
@@ -254,6 +267,176 @@ impl Dict {
             .map(|(k, v)| Value::Array(array![k.clone(), v.clone()]))
             .collect()
     }
+
+    /// Returns the keys of the dictionary as an array sorted by their Unicode
+    /// code points.
+    ///
+    /// Iterating over the sorted keys yields the same order no matter in which
+    /// order the pairs were inserted.
+    ///
+    /// ```example
+    /// #let prices = (pear: 3, apple: 2, fig: 5)
+    /// #for key in prices.sorted-keys() [
+    ///   #key: #prices.at(key) \
+    /// ]
+    /// ```
+    #[func]
+    pub fn sorted_keys(&self) -> Array {
+        let mut keys: Vec<_> = self.0.keys().cloned().collect();
+        keys.sort();
+        keys.into_iter().map(Value::Str).collect()
+    }
+
+    /// Merges another dictionary into this one.
+    ///
+    /// The result contains the pairs of this dictionary in their order,
+    /// followed by the keys that only exist in the other dictionary. Without a
+    /// `conflict` function, the other dictionary's values win for keys that
+    /// exist in both, just like when adding the dictionaries with `+`.
+    ///
+    /// ```example
+    /// #let stock = (apples: 3, pears: 1)
+    /// #let delivery = (pears: 4, figs: 2)
+    /// #stock.merge(delivery) \
+    /// #stock.merge(delivery, conflict: (key, a, b) => a + b)
+    /// ```
+    #[func]
+    pub fn merge(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The dictionary to merge into this one.
+        other: Dict,
+        /// A function that resolves keys that exist in both dictionaries.
+        ///
+        /// It receives the key, the value from this dictionary, and the value
+        /// from the other dictionary and returns the merged value. To reject
+        /// conflicts, it can [panic]($panic).
+        #[named]
+        conflict: Option<Func>,
+    ) -> SourceResult<Dict> {
+        let Some(conflict) = conflict else { return Ok(self + other) };
+
+        let mut merged = self;
+        for (key, value) in other {
+            let value = match merged.0.get(&key) {
+                Some(existing) => conflict.call(
+                    engine,
+                    context,
+                    [Value::Str(key.clone()), existing.clone(), value],
+                )?,
+                None => value,
+            };
+            merged.insert(key, value);
+        }
+
+        Ok(merged)
+    }
+
+    /// Checks that the dictionary has the expected fields and returns them.
+    ///
+    /// This lets a template declare the parameters it expects, such that a
+    /// missing or mistyped parameter fails with a clear error upfront. Each
+    /// named argument declares one field: Either just its [type], which makes
+    /// the field required, or a dictionary with an optional `type` and an
+    /// optional `default` value for when the field is missing.
+    ///
+    /// The resulting dictionary contains exactly the expected fields, in the
+    /// order they were declared. Since [`sys.inputs`]($category/foundations/sys)
+    /// only contains strings, strings are converted into integers, floats, and
+    /// booleans where those are expected.
+    ///
+    /// ```example
+    /// #let record = (name: "Alice", amount: "30")
+    /// #let (name, amount, currency) = record.expect(
+    ///   name: str,
+    ///   amount: int,
+    ///   currency: (type: str, default: "EUR"),
+    /// )
+    ///
+    /// #name owes #(amount * 2) #currency.
+    /// ```
+    #[func]
+    pub fn expect(
+        &self,
+        args: &mut Args,
+        /// The expected fields.
+        #[external]
+        #[variadic]
+        fields: Vec<Value>,
+    ) -> SourceResult<Dict> {
+        let span = args.span;
+        let mut checked = Dict::new();
+        for arg in args.take().items {
+            let Some(name) = arg.name else {
+                bail!(arg.span, "expected named argument");
+            };
+
+            let (ty, default) = expected_field(arg.value.v).at(arg.value.span)?;
+            let value = match (self.0.get(&name), default) {
+                (Some(value), _) => check_field(&name, value.clone(), ty).at(span)?,
+                (None, Some(default)) => default,
+                (None, None) => bail!(
+                    span, "missing field {}", name.repr();
+                    hint: "the field is required because it has no default value"
+                ),
+            };
+
+            checked.insert(name, value);
+        }
+
+        Ok(checked)
+    }
+}
+
+/// Determines the type and default value of an expected field.
+fn expected_field(spec: Value) -> StrResult<(Option<Type>, Option<Value>)> {
+    match spec {
+        Value::Type(ty) => Ok((Some(ty), None)),
+        Value::Dict(mut dict) => {
+            let ty = match dict.take("type").ok() {
+                Some(Value::Type(ty)) => Some(ty),
+                Some(v) => {
+                    bail!("expected type for `type`, found {}", v.ty().long_name())
+                }
+                None => None,
+            };
+            let default = dict.take("default").ok();
+            dict.finish(&["type", "default"])?;
+            Ok((ty, default))
+        }
+        v => bail!("expected type or dictionary, found {}", v.ty().long_name()),
+    }
+}
+
+/// Checks that a field's value has the expected type, converting strings
+/// where necessary.
+fn check_field(name: &str, value: Value, ty: Option<Type>) -> HintedStrResult<Value> {
+    let Some(ty) = ty else { return Ok(value) };
+    if value.ty() == ty {
+        return Ok(value);
+    }
+
+    let converted = match &value {
+        Value::Int(n) if ty == Type::of::<f64>() => Some(Value::Float(*n as f64)),
+        Value::Str(s) if ty == Type::of::<i64>() => s.parse().ok().map(Value::Int),
+        Value::Str(s) if ty == Type::of::<f64>() => s.parse().ok().map(Value::Float),
+        Value::Str(s) if ty == Type::of::<bool>() => s.parse().ok().map(Value::Bool),
+        _ => None,
+    };
+
+    converted.ok_or_else(|| {
+        let found = match &value {
+            Value::Str(s) => s.repr(),
+            v => v.ty().long_name().into(),
+        };
+        eco_format!(
+            "expected {} for field {}, found {found}",
+            ty.long_name(),
+            name.repr(),
+        )
+        .into()
+    })
 }
 
 /// A value that can be cast to dictionary.
@@ -17,7 +17,6 @@ use crate::foundations::{
     cast, Args, Content, Dict, FieldAccessError, Func, ParamInfo, Repr, Scope, Selector,
     StyleChain, Styles, Value,
 };
-use crate::text::{Lang, Region};
 
 /// A document element.
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
@@ -132,8 +131,13 @@ impl Element {
     }
 
     /// The element's local name, if any.
-    pub fn local_name(&self, lang: Lang, region: Option<Region>) -> Option<&'static str> {
-        (self.0).0.local_name.map(|f| f(lang, region))
+    pub fn local_name(&self, styles: StyleChain) -> Option<EcoString> {
+        (self.0).0.local_name.map(|f| f(styles))
+    }
+
+    /// The plural of the element's local name, if it is known.
+    pub fn local_plural(&self, styles: StyleChain) -> Option<EcoString> {
+        (self.0).0.local_plural.and_then(|f| f(styles))
     }
 }
 
@@ -291,7 +295,9 @@ pub struct NativeElementData {
     /// Get the field with the given ID in the presence of styles (see [`Fields`]).
     pub field_from_styles: fn(u8, StyleChain) -> Result<Value, FieldAccessError>,
     /// Gets the localized name for this element (see [`LocalName`][crate::text::LocalName]).
-    pub local_name: Option<fn(Lang, Option<Region>) -> &'static str>,
+    pub local_name: Option<fn(StyleChain) -> EcoString>,
+    /// Gets the plural of the localized name for this element, if it is known.
+    pub local_plural: Option<fn(StyleChain) -> Option<EcoString>>,
     pub scope: LazyLock<Scope>,
     /// A list of parameter information for each field.
     pub params: LazyLock<Vec<ParamInfo>>,
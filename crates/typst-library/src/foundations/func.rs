@@ -80,6 +80,22 @@ use crate::foundations::{
 /// body evaluates to the result of joining all expressions preceding the
 /// `return`.
 ///
+/// Function calls can only be nested so deeply. When a function returns a call
+/// of a user-defined function with `return`, however, that call is performed
+/// after the returning function has finished. Recursive functions that return
+/// their recursive call like this are thus not limited by the call depth.
+/// Instead, such consecutive tail calls are limited like the iterations of a
+/// `while` loop, to 10 000 by default:
+///
+/// ```example
+/// #let sum(n, acc: 0) = {
+///   if n == 0 { return acc }
+///   return sum(n - 1, acc: acc + n)
+/// }
+///
+/// #sum(1000)
+/// ```
+///
 /// Functions that don't return any meaningful value return [`none`] instead.
 /// The return type of such functions is not explicitly specified in the
 /// documentation. (An example of this is [`array.push`]).
@@ -132,6 +148,25 @@ use crate::foundations::{
 /// The only exception are built-in methods like
 /// [`array.push(value)`]($array.push). These can modify the values they are
 /// called on.
+///
+/// Because functions are pure, Typst caches the results of calls to
+/// user-defined functions. When a function is called again with the same
+/// arguments, for example from many show rules, the cached result is reused
+/// instead of evaluating the function again. To run an expensive computation
+/// like processing a large data file only once per compilation, it is thus
+/// enough to wrap it in a function:
+///
+/// ```example
+/// #let table-of(path) = {
+///   let data = csv(path)
+///   table(columns: data.first().len(), ..data.flatten())
+/// }
+///
+/// // The CSV file is only parsed and
+/// // turned into a table once.
+/// #table-of("example.csv")
+/// #table-of("example.csv")
+/// ```
 #[ty(scope, cast, name = "function")]
 #[derive(Clone, Hash)]
 #[allow(clippy::derived_hash_with_manual_eq)]
@@ -279,6 +314,14 @@ impl Func {
         }
     }
 
+    /// Extract the closure, if it is one.
+    pub fn to_closure(&self) -> Option<&LazyHash<Closure>> {
+        match &self.repr {
+            Repr::Closure(closure) => Some(closure.as_ref()),
+            _ => None,
+        }
+    }
+
     /// Extract the plugin function, if it is one.
     pub fn to_plugin(&self) -> Option<&PluginFunc> {
         match &self.repr {
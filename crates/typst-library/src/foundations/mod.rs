@@ -1,6 +1,7 @@
 //! Foundational types and functions.
 
 pub mod calc;
+pub mod crypto;
 pub mod ops;
 pub mod repr;
 pub mod sys;
@@ -27,6 +28,7 @@ mod module;
 mod none;
 #[path = "plugin.rs"]
 mod plugin_;
+mod rng;
 mod scope;
 mod selector;
 mod str;
@@ -59,6 +61,7 @@ pub use self::module::*;
 pub use self::none::*;
 pub use self::plugin_::*;
 pub use self::repr::Repr;
+pub use self::rng::*;
 pub use self::scope::*;
 pub use self::selector::*;
 pub use self::str::*;
@@ -109,6 +112,7 @@ pub(super) fn define(global: &mut Scope, inputs: Dict, features: &Features) {
     global.define_type::<Symbol>();
     global.define_type::<Duration>();
     global.define_type::<Version>();
+    global.define_type::<Rng>();
     global.define_func::<repr::repr>();
     global.define_func::<panic>();
     global.define_func::<assert>();
@@ -118,6 +122,7 @@ pub(super) fn define(global: &mut Scope, inputs: Dict, features: &Features) {
         global.define_func::<target>();
     }
     global.define("calc", calc::module());
+    global.define("crypto", crypto::module());
     global.define("sys", sys::module(inputs));
     global.reset_category();
 }
@@ -10,7 +10,7 @@ use crate::foundations::{
     format_str, Datetime, IntoValue, Regex, Repr, SymbolElem, Value,
 };
 use crate::layout::{Alignment, Length, Rel};
-use crate::text::TextElem;
+use crate::text::{Collator, SortKey, TextElem};
 use crate::visualize::Stroke;
 
 /// Bail with a type mismatch error.
@@ -513,8 +513,70 @@ fn try_cmp_datetimes(a: &Datetime, b: &Datetime) -> StrResult<Ordering> {
         .ok_or_else(|| eco_format!("cannot compare {} and {}", a.kind(), b.kind()))
 }
 
+/// A value prepared for comparison with a collator.
+///
+/// The sort keys of its strings, also when nested in arrays, are computed
+/// once up front, so that they are not recomputed on every comparison.
+pub enum Collated {
+    /// A string with its sort key.
+    Str(SortKey),
+    /// An array of prepared values.
+    Array(Vec<Collated>),
+    /// Any other value.
+    Other(Value),
+}
+
+impl Collated {
+    /// Prepare a value for comparison with the given collator.
+    pub fn new(value: Value, collator: &Collator) -> Self {
+        match value {
+            Value::Str(s) => Self::Str(collator.key(&s)),
+            Value::Array(array) => Self::Array(
+                array.into_iter().map(|item| Self::new(item, collator)).collect(),
+            ),
+            value => Self::Other(value),
+        }
+    }
+
+    /// Turn the prepared value back into a regular value.
+    fn to_value(&self) -> Value {
+        match self {
+            Self::Str(key) => Value::Str(key.text().into()),
+            Self::Array(items) => {
+                Value::Array(items.iter().map(Self::to_value).collect())
+            }
+            Self::Other(value) => value.clone(),
+        }
+    }
+}
+
+/// Compare two values that were prepared with the same collator.
+///
+/// Strings, also when nested in arrays, are compared according to the
+/// collator's language. All other values are compared like in [`compare`].
+pub fn compare_collated(lhs: &Collated, rhs: &Collated) -> StrResult<Ordering> {
+    match (lhs, rhs) {
+        (Collated::Str(a), Collated::Str(b)) => Ok(a.cmp(b)),
+        (Collated::Array(a), Collated::Array(b)) => {
+            try_cmp_arrays_by(a, b, compare_collated)
+        }
+        (Collated::Other(a), Collated::Other(b)) => compare(a, b),
+        _ => compare(&lhs.to_value(), &rhs.to_value()),
+    }
+}
+
 /// Try to compare arrays of values lexicographically.
 fn try_cmp_arrays(a: &[Value], b: &[Value]) -> StrResult<Ordering> {
+    try_cmp_arrays_by(a, b, compare)
+}
+
+/// Try to compare arrays of values lexicographically with a custom element
+/// comparison.
+fn try_cmp_arrays_by<T>(
+    a: &[T],
+    b: &[T],
+    compare: impl Fn(&T, &T) -> StrResult<Ordering>,
+) -> StrResult<Ordering> {
     a.iter()
         .zip(b.iter())
         .find_map(|(first, second)| {
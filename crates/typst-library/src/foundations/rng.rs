@@ -0,0 +1,298 @@
+use std::fmt::{self, Debug, Formatter};
+
+use ecow::{eco_format, EcoString};
+
+use crate::diag::{bail, StrResult};
+use crate::foundations::{cast, func, scope, ty, Array, IntoValue, Repr, Str, Value};
+
+/// A seeded generator of pseudo-random numbers.
+///
+/// A generator always produces the same numbers for the same seed, so
+/// documents that use it compile to the same output every time. This makes it
+/// suitable for exam variants, procedural illustrations, and placeholder data.
+///
+/// Like all values in Typst, generators are immutable: Calling a method on the
+/// same generator twice yields the same result. To get multiple independent
+/// values, either request several at once with the `count` argument or derive
+/// new generators with [`fork`]($rng.fork).
+///
+/// ```example
+/// #let r = rng(42)
+/// #r.int(1, 6, count: 5) \
+/// #r.shuffle(("A", "B", "C", "D")) \
+/// #for i in range(3) [
+///   #r.fork(i).choice(("🍎", "🍐", "🍋"))
+/// ]
+/// ```
+#[ty(scope, cast)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Rng(u64);
+
+/// The maximum number of values that can be generated at once.
+const MAX_COUNT: usize = 100_000;
+
+impl Rng {
+    /// Advance the state and return the next 64 random bits (SplitMix64).
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Return a uniformly distributed number below `bound`, which must not be
+    /// zero.
+    fn below(&mut self, bound: u64) -> u64 {
+        // Reject the values that would make the result biased.
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let v = self.next();
+            if v < zone {
+                return v % bound;
+            }
+        }
+    }
+
+    /// Return a uniformly distributed float in `[0, 1)`.
+    fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Produce one value or an array of values.
+    fn repeat<T: IntoValue>(
+        mut self,
+        count: Option<usize>,
+        mut f: impl FnMut(&mut Self) -> T,
+    ) -> StrResult<Value> {
+        Ok(match count {
+            None => f(&mut self).into_value(),
+            Some(n) if n > MAX_COUNT => {
+                bail!("cannot generate more than {MAX_COUNT} values at once")
+            }
+            Some(n) => (0..n)
+                .map(|_| f(&mut self).into_value())
+                .collect::<Array>()
+                .into_value(),
+        })
+    }
+}
+
+#[scope]
+impl Rng {
+    /// Creates a new generator from a seed.
+    ///
+    /// ```example
+    /// #rng(2025).float()
+    /// ```
+    #[func(constructor)]
+    pub fn construct(
+        /// The seed. Generators with the same seed produce the same numbers.
+        seed: Seed,
+    ) -> Rng {
+        Rng(seed.0)
+    }
+
+    /// Generates a random integer between `min` and `max`, both inclusive.
+    ///
+    /// ```example
+    /// #rng(1).int(1, 6)
+    /// ```
+    #[func]
+    pub fn int(
+        self,
+        /// The smallest possible number.
+        min: i64,
+        /// The largest possible number.
+        max: i64,
+        /// If given, an array with this many numbers is generated instead of
+        /// a single one. At most 100000 numbers can be generated at once.
+        #[named]
+        count: Option<usize>,
+    ) -> StrResult<Value> {
+        if min > max {
+            bail!("minimum must not be larger than maximum");
+        }
+
+        let span = max.wrapping_sub(min) as u64;
+        self.repeat(count, |rng| {
+            let offset = match span.checked_add(1) {
+                Some(bound) => rng.below(bound),
+                None => rng.next(),
+            };
+            min.wrapping_add(offset as i64)
+        })
+    }
+
+    /// Generates a random float between `min` (inclusive) and `max`
+    /// (exclusive).
+    ///
+    /// ```example
+    /// #rng(1).float(min: -1.0, max: 1.0, count: 3)
+    /// ```
+    #[func]
+    pub fn float(
+        self,
+        /// The lower bound.
+        #[named]
+        #[default(0.0)]
+        min: f64,
+        /// The upper bound.
+        #[named]
+        #[default(1.0)]
+        max: f64,
+        /// If given, an array with this many numbers is generated instead of
+        /// a single one. At most 100000 numbers can be generated at once.
+        #[named]
+        count: Option<usize>,
+    ) -> StrResult<Value> {
+        if min > max {
+            bail!("minimum must not be larger than maximum");
+        }
+
+        self.repeat(count, |rng| min + rng.unit() * (max - min))
+    }
+
+    /// Picks a random element from an array.
+    ///
+    /// ```example
+    /// #rng(3).choice(("heads", "tails"))
+    /// ```
+    #[func]
+    pub fn choice(
+        mut self,
+        /// The array to pick from.
+        array: Array,
+    ) -> StrResult<Value> {
+        if array.is_empty() {
+            bail!("cannot pick from an empty array");
+        }
+
+        let index = self.below(array.len() as u64) as usize;
+        Ok(array.as_slice()[index].clone())
+    }
+
+    /// Returns the elements of an array in random order.
+    ///
+    /// ```example
+    /// #rng(4).shuffle(range(10))
+    /// ```
+    #[func]
+    pub fn shuffle(
+        mut self,
+        /// The array to shuffle.
+        array: Array,
+    ) -> Array {
+        let mut vec: Vec<Value> = array.into_iter().collect();
+        for i in (1..vec.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            vec.swap(i, j);
+        }
+        vec.into_iter().collect()
+    }
+
+    /// Picks a number of distinct elements from an array at random.
+    ///
+    /// The elements are returned in the order they were picked.
+    ///
+    /// ```example
+    /// #rng(5).sample(range(1, 50), 6)
+    /// ```
+    #[func]
+    pub fn sample(
+        mut self,
+        /// The array to pick from.
+        array: Array,
+        /// How many elements to pick.
+        count: usize,
+    ) -> StrResult<Array> {
+        let mut vec: Vec<Value> = array.into_iter().collect();
+        if count > vec.len() {
+            bail!(
+                "cannot sample {count} elements from an array with {} elements",
+                vec.len()
+            );
+        }
+
+        for i in 0..count {
+            let j = i + self.below((vec.len() - i) as u64) as usize;
+            vec.swap(i, j);
+        }
+        vec.truncate(count);
+        Ok(vec.into_iter().collect())
+    }
+
+    /// Derives a new, independent generator from this one and a key.
+    ///
+    /// The same generator and key always result in the same new generator,
+    /// while different keys result in unrelated ones. This is useful to get
+    /// a fresh generator per loop iteration.
+    ///
+    /// ```example
+    /// #let r = rng(7)
+    /// #range(4).map(i => r.fork(i).int(0, 9))
+    /// ```
+    #[func]
+    pub fn fork(
+        mut self,
+        /// The key to derive the new generator with.
+        key: Seed,
+    ) -> Rng {
+        let base = self.next();
+        Rng(Rng(base ^ key.0).next())
+    }
+}
+
+impl Debug for Rng {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Rng({:#x})", self.0)
+    }
+}
+
+impl Repr for Rng {
+    fn repr(&self) -> EcoString {
+        // An integer seed becomes the state as is, so this recreates the
+        // same generator.
+        eco_format!("rng({})", self.0 as i64)
+    }
+}
+
+/// A seed for a random number generator.
+pub struct Seed(u64);
+
+cast! {
+    Seed,
+    v: i64 => Self(v as u64),
+    v: Str => {
+        // FNV-1a, which is stable across platforms and versions.
+        let mut hash: u64 = 0xCBF29CE484222325;
+        for byte in v.as_bytes() {
+            hash = (hash ^ u64::from(*byte)).wrapping_mul(0x100000001B3);
+        }
+        Self(hash)
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_splitmix() {
+        // Reference values of SplitMix64 for seed 1234567.
+        let mut rng = Rng(1234567);
+        assert_eq!(rng.next(), 6457827717110365317);
+        assert_eq!(rng.next(), 3203168211198807973);
+    }
+
+    #[test]
+    fn test_rng_ranges() {
+        let mut rng = Rng(0);
+        for _ in 0..1000 {
+            assert!(rng.below(7) < 7);
+            assert!((0.0..1.0).contains(&rng.unit()));
+        }
+        let value = Rng(0).int(i64::MIN, i64::MAX, None).unwrap();
+        assert!(matches!(value, Value::Int(_)));
+    }
+}
@@ -254,8 +254,39 @@ pub struct Binding {
     span: Span,
     /// The category of the binding.
     category: Option<Category>,
-    /// A deprecation message for the definition.
-    deprecation: Option<&'static str>,
+    /// The deprecation of the definition, if any.
+    deprecation: Option<Deprecation>,
+}
+
+/// Information about the deprecation of a definition.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Deprecation {
+    /// A message explaining what to use instead.
+    message: &'static str,
+    /// The Typst version in which the definition will be removed.
+    until: Option<&'static str>,
+}
+
+impl Deprecation {
+    /// Create a deprecation with a message.
+    pub const fn new(message: &'static str) -> Self {
+        Self { message, until: None }
+    }
+
+    /// Set the version in which the definition will be removed.
+    pub const fn with_until(self, version: &'static str) -> Self {
+        Self { until: Some(version), ..self }
+    }
+
+    /// The message explaining what to use instead.
+    pub fn message(&self) -> &'static str {
+        self.message
+    }
+
+    /// The Typst version in which the definition will be removed, if known.
+    pub fn until(&self) -> Option<&'static str> {
+        self.until
+    }
 }
 
 /// The different kinds of slots.
@@ -284,9 +315,9 @@ impl Binding {
         Self::new(value, Span::detached())
     }
 
-    /// Marks this binding as deprecated, with the given `message`.
-    pub fn deprecated(&mut self, message: &'static str) -> &mut Self {
-        self.deprecation = Some(message);
+    /// Marks this binding as deprecated.
+    pub fn deprecated(&mut self, deprecation: Deprecation) -> &mut Self {
+        self.deprecation = Some(deprecation);
         self
     }
 
@@ -301,8 +332,8 @@ impl Binding {
     /// - pass `()` to ignore the message.
     /// - pass `(&mut engine, span)` to emit a warning into the engine.
     pub fn read_checked(&self, sink: impl DeprecationSink) -> &Value {
-        if let Some(message) = self.deprecation {
-            sink.emit(message);
+        if let Some(deprecation) = self.deprecation {
+            sink.emit(deprecation.message, deprecation.until);
         }
         &self.value
     }
@@ -337,9 +368,9 @@ impl Binding {
         self.span
     }
 
-    /// A deprecation message for the value, if any.
-    pub fn deprecation(&self) -> Option<&'static str> {
-        self.deprecation
+    /// The deprecation of the value, if any.
+    pub fn deprecation(&self) -> Option<&Deprecation> {
+        self.deprecation.as_ref()
     }
 
     /// The category of the value, if any.
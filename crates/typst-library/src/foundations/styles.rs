@@ -12,8 +12,8 @@ use typst_utils::LazyHash;
 use crate::diag::{SourceResult, Trace, Tracepoint};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, ty, Content, Context, Element, Func, NativeElement, OneOrMultiple, Repr,
-    Selector,
+    cast, ty, AutoValue, Content, Context, Element, Func, NativeElement, OneOrMultiple,
+    Repr, Selector,
 };
 use crate::text::{FontFamily, FontList, TextElem};
 
@@ -456,6 +456,7 @@ impl Recipe {
                 result?.display()
             }
             Transformation::Style(styles) => content.styled_with_map(styles.clone()),
+            Transformation::Auto => content,
         };
         if content.span().is_detached() {
             content = content.spanned(self.span);
@@ -489,6 +490,9 @@ pub enum Transformation {
     Func(Func),
     /// Apply styles to the content.
     Style(Styles),
+    /// Revoke the outer show rules for the match, so that it falls back to
+    /// its built-in appearance.
+    Auto,
 }
 
 impl Debug for Transformation {
@@ -497,6 +501,7 @@ impl Debug for Transformation {
             Self::Content(content) => content.fmt(f),
             Self::Func(func) => func.fmt(f),
             Self::Style(styles) => styles.fmt(f),
+            Self::Auto => f.pad("auto"),
         }
     }
 }
@@ -505,6 +510,7 @@ cast! {
     Transformation,
     content: Content => Self::Content(content),
     func: Func => Self::Func(func),
+    _: AutoValue => Self::Auto,
 }
 
 /// A chain of styles, similar to a linked list.
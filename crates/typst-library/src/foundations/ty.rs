@@ -58,6 +58,26 @@ use crate::foundations::{
 /// Note that `type` will return [`content`] for all document elements. To
 /// programmatically determine which kind of content you are dealing with, see
 /// [`content.func`].
+///
+/// # Checking and converting arguments
+/// Since types are values, functions can check and dispatch on the types of
+/// their arguments. Most types can also be called to convert a value into
+/// them. To check a whole dictionary of named parameters at once, use
+/// [`dictionary.expect`].
+///
+/// ```example
+/// #let describe(x) = {
+///   assert(
+///     type(x) in (int, str),
+///     message: "expected integer or string, found " + repr(type(x)),
+///   )
+///   if type(x) == str { x = int(x) }
+///   [#x is #if calc.even(x) [even] else [odd]]
+/// }
+///
+/// #describe(4) \
+/// #describe("7")
+/// ```
 #[ty(scope, cast)]
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Type(Static<NativeTypeData>);
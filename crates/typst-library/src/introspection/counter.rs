@@ -227,11 +227,12 @@ impl Counter {
         let (mut at_state, at_page) = sequence[offset].clone();
         let (mut final_state, final_page) = sequence.last().unwrap().clone();
         if self.is_page() {
+            let introspector = engine.introspector;
             let at_delta =
-                engine.introspector.page(location).get().saturating_sub(at_page.get());
+                introspector.counted_pages(at_page, introspector.page(location));
             at_state.step(NonZeroUsize::ONE, at_delta as u64);
             let final_delta =
-                engine.introspector.pages().get().saturating_sub(final_page.get());
+                introspector.counted_pages(final_page, introspector.pages());
             final_state.step(NonZeroUsize::ONE, final_delta as u64);
         }
         Ok(CounterState(smallvec![at_state.first(), final_state.first()]))
@@ -248,8 +249,9 @@ impl Counter {
         let offset = engine.introspector.query_count_before(&self.selector(), location);
         let (mut state, page) = sequence[offset].clone();
         if self.is_page() {
-            let delta =
-                engine.introspector.page(location).get().saturating_sub(page.get());
+            let delta = engine
+                .introspector
+                .counted_pages(page, engine.introspector.page(location));
             state.step(NonZeroUsize::ONE, delta as u64);
         }
         Ok(state)
@@ -308,7 +310,11 @@ impl Counter {
             route: Route::extend(route).unnested(),
         };
 
-        let mut state = CounterState::init(matches!(self.0, CounterKey::Page));
+        // The page counter starts at one, unless the first page is numbered by
+        // another counter.
+        let mut state = CounterState::init(
+            self.is_page() && introspector.is_page_counted(NonZeroUsize::ONE),
+        );
         let mut page = NonZeroUsize::ONE;
         let mut stops = eco_vec![(state.clone(), page)];
 
@@ -317,7 +323,7 @@ impl Counter {
                 let prev = page;
                 page = introspector.page(elem.location().unwrap());
 
-                let delta = page.get() - prev.get();
+                let delta = introspector.counted_pages(prev, page);
                 if delta > 0 {
                     state.step(NonZeroUsize::ONE, delta as u64);
                 }
@@ -348,7 +354,7 @@ impl Counter {
     }
 
     /// Whether this is the page counter.
-    fn is_page(&self) -> bool {
+    pub fn is_page(&self) -> bool {
         self.0 == CounterKey::Page
     }
 
@@ -499,7 +505,8 @@ impl Counter {
         let sequence = self.sequence(engine)?;
         let (mut state, page) = sequence.last().unwrap().clone();
         if self.is_page() {
-            let delta = engine.introspector.pages().get().saturating_sub(page.get());
+            let delta =
+                engine.introspector.counted_pages(page, engine.introspector.pages());
             state.step(NonZeroUsize::ONE, delta as u64);
         }
         Ok(state)
@@ -755,16 +762,23 @@ impl Show for Packed<CounterDisplayElem> {
 
 /// An specialized handler of the page counter that tracks both the physical
 /// and the logical page counter.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, PartialEq, Hash)]
 pub struct ManualPageCounter {
     physical: NonZeroUsize,
     logical: u64,
+    /// The states of other counters, which may number pages instead of the
+    /// page counter.
+    others: Vec<(CounterKey, u64)>,
 }
 
 impl ManualPageCounter {
     /// Create a new fast page counter, starting at 1.
     pub fn new() -> Self {
-        Self { physical: NonZeroUsize::ONE, logical: 1 }
+        Self {
+            physical: NonZeroUsize::ONE,
+            logical: 1,
+            others: vec![],
+        }
     }
 
     /// Get the current physical page counter state.
@@ -777,6 +791,17 @@ impl ManualPageCounter {
         self.logical
     }
 
+    /// Get the current state of the counter that numbers a page.
+    pub fn number(&self, counter: &Counter) -> u64 {
+        if counter.is_page() {
+            return self.logical;
+        }
+        self.others
+            .iter()
+            .find(|(key, _)| *key == counter.0)
+            .map_or(0, |&(_, value)| value)
+    }
+
     /// Advance past a page.
     pub fn visit(&mut self, engine: &mut Engine, page: &Frame) -> SourceResult<()> {
         for (_, item) in page.items() {
@@ -786,11 +811,19 @@ impl ManualPageCounter {
                     let Some(elem) = elem.to_packed::<CounterUpdateElem>() else {
                         continue;
                     };
-                    if elem.key == CounterKey::Page {
-                        let mut state = CounterState(smallvec![self.logical]);
-                        state.update(engine, elem.update.clone())?;
-                        self.logical = state.first();
-                    }
+                    let value = if elem.key == CounterKey::Page {
+                        &mut self.logical
+                    } else if let Some(i) =
+                        self.others.iter().position(|(key, _)| *key == elem.key)
+                    {
+                        &mut self.others[i].1
+                    } else {
+                        self.others.push((elem.key.clone(), 0));
+                        &mut self.others.last_mut().unwrap().1
+                    };
+                    let mut state = CounterState(smallvec![*value]);
+                    state.update(engine, elem.update.clone())?;
+                    *value = state.first();
                 }
                 _ => {}
             }
@@ -799,10 +832,13 @@ impl ManualPageCounter {
         Ok(())
     }
 
-    /// Step past a page _boundary._
-    pub fn step(&mut self) {
+    /// Step past a page _boundary._ The logical page counter only advances
+    /// if the page was numbered by it.
+    pub fn step(&mut self, counter: &Counter) {
         self.physical = self.physical.saturating_add(1);
-        self.logical += 1;
+        if counter.is_page() {
+            self.logical += 1;
+        }
     }
 }
 
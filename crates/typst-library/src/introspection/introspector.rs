@@ -11,8 +11,8 @@ use typst_utils::NonZeroExt;
 use crate::diag::{bail, StrResult};
 use crate::foundations::{Content, Label, Repr, Selector};
 use crate::html::HtmlNode;
-use crate::introspection::{Location, Tag};
-use crate::layout::{Frame, FrameItem, Page, Point, Position, Transform};
+use crate::introspection::{Counter, CounterKey, Location, Tag};
+use crate::layout::{Abs, Frame, FrameItem, Page, Point, Position, Transform};
 use crate::model::Numbering;
 
 /// Can be queried for elements and their positions.
@@ -24,6 +24,11 @@ pub struct Introspector {
     page_numberings: Vec<Option<Numbering>>,
     /// The page supplements, indexed by page number minus 1.
     page_supplements: Vec<Content>,
+    /// The counters that number the pages, indexed by page number minus 1.
+    page_counters: Vec<Counter>,
+    /// The number of pages up to and including each page that are numbered
+    /// by the page counter, indexed by page number.
+    counted_pages: Vec<usize>,
 
     /// All introspectable elements.
     elems: Vec<Pair>,
@@ -35,6 +40,9 @@ pub struct Introspector {
     locations: HashMap<Location, usize>,
     /// Accelerates lookup of elements by label.
     labels: MultiMap<Label, usize>,
+    /// Accelerates lookup of elements by position. Holds the indices of all
+    /// elements, sorted by page and then by vertical offset.
+    positions: Vec<usize>,
 
     /// Caches queries done on the introspector. This is important because
     /// even if all top-level queries are distinct, they often have shared
@@ -46,6 +54,11 @@ pub struct Introspector {
 /// A pair of content and its position.
 type Pair = (Content, Position);
 
+/// The key by which positions are ordered: by page and then vertically.
+fn position_key(position: Position) -> (NonZeroUsize, Abs) {
+    (position.page, position.point.y)
+}
+
 impl Introspector {
     /// Creates an introspector for a page list.
     #[typst_macros::time(name = "introspect pages")]
@@ -64,6 +77,16 @@ impl Introspector {
         self.elems.iter().map(|(c, _)| c)
     }
 
+    /// Finds the last element that starts at or before the given position,
+    /// comparing by page and then by vertical offset.
+    pub fn last_before(&self, position: Position) -> Option<&Content> {
+        let end = self.positions.partition_point(|&idx| {
+            position_key(self.get_pos_by_idx(idx)) <= position_key(position)
+        });
+        let idx = self.positions[..end].last()?;
+        Some(self.get_by_idx(*idx))
+    }
+
     /// Retrieves the element with the given index.
     #[track_caller]
     fn get_by_idx(&self, idx: usize) -> &Content {
@@ -100,6 +123,16 @@ impl Introspector {
     fn loc_index(&self, location: &Location) -> usize {
         self.locations.get(location).copied().unwrap_or(usize::MAX)
     }
+
+    /// The number of pages up to and including the given one that are
+    /// numbered by the page counter.
+    fn counted_up_to(&self, page: usize) -> usize {
+        // Like in `is_page_counted`, pages beyond the known ones are numbered
+        // by the page counter.
+        let known = self.page_counters.len();
+        let counted = self.counted_pages.get(page.min(known)).copied().unwrap_or(0);
+        counted + page.saturating_sub(known)
+    }
 }
 
 #[comemo::track]
@@ -281,6 +314,28 @@ impl Introspector {
         self.page_supplements.get(page.get() - 1).cloned().unwrap_or_default()
     }
 
+    /// Gets the counter that numbers the page of the given location.
+    pub fn page_counter(&self, location: Location) -> Counter {
+        let page = self.page(location);
+        self.page_counters
+            .get(page.get() - 1)
+            .cloned()
+            .unwrap_or_else(|| Counter::new(CounterKey::Page))
+    }
+
+    /// Whether the given page is numbered by the page counter rather than
+    /// another counter.
+    pub fn is_page_counted(&self, page: NonZeroUsize) -> bool {
+        self.page_counters.get(page.get() - 1).is_none_or(Counter::is_page)
+    }
+
+    /// The number of pages after `from` up to and including `to` that are
+    /// numbered by the page counter.
+    pub fn counted_pages(&self, from: NonZeroUsize, to: NonZeroUsize) -> usize {
+        self.counted_up_to(to.get())
+            .saturating_sub(self.counted_up_to(from.get()))
+    }
+
     /// Try to find a location for an element with the given `key` hash
     /// that is closest after the `anchor`.
     ///
@@ -356,6 +411,7 @@ struct IntrospectorBuilder {
     pages: usize,
     page_numberings: Vec<Option<Numbering>>,
     page_supplements: Vec<Content>,
+    page_counters: Vec<Counter>,
     seen: HashSet<Location>,
     insertions: MultiMap<Location, Vec<Pair>>,
     keys: MultiMap<u128, Location>,
@@ -374,12 +430,14 @@ impl IntrospectorBuilder {
         self.pages = pages.len();
         self.page_numberings.reserve(pages.len());
         self.page_supplements.reserve(pages.len());
+        self.page_counters.reserve(pages.len());
 
         // Discover all elements.
         let mut elems = Vec::new();
         for (i, page) in pages.iter().enumerate() {
             self.page_numberings.push(page.numbering.clone());
             self.page_supplements.push(page.supplement.clone());
+            self.page_counters.push(page.counter.clone());
             self.discover_in_frame(
                 &mut elems,
                 &page.frame,
@@ -480,14 +538,31 @@ impl IntrospectorBuilder {
             self.visit(&mut elems, pair);
         }
 
+        // Count the pages numbered by the page counter.
+        let mut counted = 0;
+        let mut counted_pages = Vec::with_capacity(self.page_counters.len() + 1);
+        counted_pages.push(counted);
+        for counter in &self.page_counters {
+            counted += counter.is_page() as usize;
+            counted_pages.push(counted);
+        }
+
+        // Sort the elements by position. The sort is stable, so elements at
+        // the same position stay in document order.
+        let mut positions: Vec<usize> = (0..elems.len()).collect();
+        positions.sort_by_key(|&idx| position_key(elems[idx].1));
+
         Introspector {
             pages: self.pages,
             page_numberings: self.page_numberings,
             page_supplements: self.page_supplements,
+            page_counters: self.page_counters,
+            counted_pages,
             elems,
             keys: self.keys,
             locations: self.locations,
             labels: self.labels,
+            positions,
             queries: QueryCache::default(),
         }
     }
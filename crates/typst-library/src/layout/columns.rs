@@ -8,10 +8,9 @@ use crate::layout::{BlockElem, Length, Ratio, Rel};
 /// Separates a region into multiple equally sized columns.
 ///
 /// The `column` function lets you separate the interior of any container into
-/// multiple columns. It will currently not balance the height of the columns.
-/// Instead, the columns will take up the height of their container or the
-/// remaining height on the page. Support for balanced columns is planned for
-/// the future.
+/// multiple columns. By default, the columns take up the height of their
+/// container or the remaining height on the page. To make them as equal in
+/// height as possible instead, enable [`balance`]($columns.balance).
 ///
 /// # Page-level columns { #page-level }
 /// If you need to insert columns across your whole document, use the `{page}`
@@ -41,6 +40,16 @@ use crate::layout::{BlockElem, Length, Ratio, Rel};
 ///
 /// #lorem(40)
 /// ```
+///
+/// To let a wide element interrupt the columns in the middle of a page, end
+/// the columns before it and continue them afterwards with balanced columns:
+///
+/// ```example:single
+/// #set page(height: 200pt)
+/// #columns(2, balance: true, lorem(30))
+/// #align(center, rect(width: 80%)[Wide figure])
+/// #columns(2, balance: true, lorem(20))
+/// ```
 #[elem(Show)]
 pub struct ColumnsElem {
     /// The number of columns.
@@ -53,6 +62,26 @@ pub struct ColumnsElem {
     #[default(Ratio::new(0.04).into())]
     pub gutter: Rel<Length>,
 
+    /// Whether to balance the heights of the columns.
+    ///
+    /// If enabled, the columns on the last page (or in the last region) the
+    /// content occupies are made as equal in height as possible instead of
+    /// filling one column after another.
+    ///
+    /// To find the balanced height, the content is laid out repeatedly with
+    /// different heights in a binary search. Balanced columns are therefore
+    /// slower to lay out than regular ones. Moreover, balancing has a few
+    /// limitations:
+    /// - It has no effect if the columns have a fixed height or are in a
+    ///   container of unbounded height, like a page with `{height: auto}`.
+    /// - Page-level columns set up with the [`columns`]($page.columns)
+    ///   parameter of the page can't be balanced.
+    /// - Elements can't span across balanced columns. To interrupt the
+    ///   columns with a wide element, end them before the element and
+    ///   continue with new columns afterwards.
+    #[default(false)]
+    pub balance: bool,
+
     /// The content that should be layouted into the columns.
     #[required]
     pub body: Content,
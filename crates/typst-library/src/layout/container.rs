@@ -7,7 +7,7 @@ use crate::foundations::{
 use crate::introspection::Locator;
 use crate::layout::{
     Abs, Corners, Em, Fr, Fragment, Frame, Length, Region, Regions, Rel, Sides, Size,
-    Spacing,
+    Spacing, VAlignment,
 };
 use crate::visualize::{Paint, Stroke};
 
@@ -46,6 +46,31 @@ pub struct BoxElem {
     /// The height of the box.
     pub height: Smart<Rel<Length>>,
 
+    /// How to align the box vertically relative to the surrounding text.
+    ///
+    /// - `{auto}`: The box's baseline sits on the text's baseline. For a box
+    ///   with text, that is the baseline of its first line. Otherwise, it is
+    ///   the box's bottom edge.
+    /// - `{top}`: The box's top edge is aligned with the text's
+    ///   [top edge]($text.top-edge).
+    /// - `{horizon}`: The box is centered between the text's top and
+    ///   [bottom edge]($text.bottom-edge).
+    /// - `{bottom}`: The box's bottom edge is aligned with the text's bottom
+    ///   edge.
+    ///
+    /// The [`baseline`]($box.baseline) shift is applied on top of this
+    /// alignment.
+    ///
+    /// ```example
+    /// #let icon = box(
+    ///   width: 0.5em, height: 0.5em, fill: blue,
+    /// )
+    /// Aligned #box(align: top, icon) to the
+    /// top, #box(align: horizon, icon) centered,
+    /// and #box(align: bottom, icon) at the bottom.
+    /// ```
+    pub align: Smart<VAlignment>,
+
     /// An amount to shift the box's baseline by.
     ///
     /// ```example
@@ -5,7 +5,7 @@ use std::ops::{Add, Div, Mul, Neg};
 use ecow::EcoString;
 use typst_utils::{Numeric, Scalar};
 
-use crate::foundations::{repr, ty, Repr};
+use crate::foundations::{func, repr, scope, ty, Repr};
 use crate::layout::Abs;
 
 /// Defines how the remaining space in a layout is distributed.
@@ -20,7 +20,11 @@ use crate::layout::Abs;
 /// ```example
 /// Left #h(1fr) Left-ish #h(2fr) Right
 /// ```
-#[ty(cast, name = "fraction")]
+///
+/// # Scripting
+/// Fractions can be added, subtracted, and multiplied with and divided by
+/// numbers. To get the plain number of a fraction, divide it by `{1fr}`.
+#[ty(scope, cast, name = "fraction")]
 #[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Fr(Scalar);
 
@@ -61,6 +65,26 @@ impl Fr {
     }
 }
 
+#[scope]
+impl Fr {
+    /// Creates a fraction from a number.
+    ///
+    /// ```example
+    /// #let weights = (1, 2.5, 1)
+    /// #grid(
+    ///   columns: weights.map(fraction),
+    ///   ..weights.map(w => rect(width: 100%)[#w])
+    /// )
+    /// ```
+    #[func(constructor)]
+    pub fn construct(
+        /// The number of fractional units.
+        value: f64,
+    ) -> Fr {
+        Self::new(value)
+    }
+}
+
 impl Numeric for Fr {
     fn zero() -> Self {
         Self::zero()
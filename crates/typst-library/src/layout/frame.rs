@@ -141,6 +141,38 @@ impl Frame {
     pub fn items(&self) -> std::slice::Iter<'_, (Point, FrameItem)> {
         self.items.iter()
     }
+
+    /// Walk over all items of the frame and its groups in painting order.
+    ///
+    /// The visitor receives each item together with the transformation from
+    /// the item's coordinate system to the one of this frame. See
+    /// [`FrameVisitor`] for more details.
+    pub fn visit<V: FrameVisitor + ?Sized>(&self, visitor: &mut V) {
+        self.visit_with(Transform::identity(), visitor);
+    }
+
+    /// Walk over the items with an accumulated transformation.
+    fn visit_with<V: FrameVisitor + ?Sized>(&self, ts: Transform, visitor: &mut V) {
+        for (pos, item) in self.items() {
+            let ts = ts.pre_concat(Transform::translate(pos.x, pos.y));
+            match item {
+                FrameItem::Group(group) => {
+                    let ts = ts.pre_concat(group.transform);
+                    if visitor.enter_group(ts, group) {
+                        group.frame.visit_with(ts, visitor);
+                        visitor.exit_group(group);
+                    }
+                }
+                FrameItem::Text(text) => visitor.visit_text(ts, text),
+                FrameItem::Shape(shape, span) => visitor.visit_shape(ts, shape, *span),
+                FrameItem::Image(image, size, span) => {
+                    visitor.visit_image(ts, image, *size, *span)
+                }
+                FrameItem::Link(dest, size) => visitor.visit_link(ts, dest, *size),
+                FrameItem::Tag(tag) => visitor.visit_tag(ts, tag),
+            }
+        }
+    }
 }
 
 /// Insert items and subframes.
@@ -430,6 +462,45 @@ impl Debug for Frame {
     }
 }
 
+/// Receives the items of a frame when walking over it with [`Frame::visit`].
+///
+/// This is the entry point for custom export backends (e.g. drawing onto a
+/// platform canvas or emitting printer commands): Nested groups are resolved
+/// by the walk and every item is reported with the full transformation that
+/// maps its local coordinates to the coordinates of the root frame. Text runs
+/// carry their font and glyph IDs, so a backend can draw them without
+/// re-shaping. All methods have empty default implementations, so a backend
+/// only needs to implement the ones for the items it supports.
+pub trait FrameVisitor {
+    /// Called when entering a group, before any of its items are visited.
+    ///
+    /// The transformation already includes the group's own transform. The
+    /// group's clip curve, if any, is in the same coordinate system. Return
+    /// `false` to skip the group's contents, in which case
+    /// [`exit_group`](Self::exit_group) is not called either.
+    fn enter_group(&mut self, _ts: Transform, _group: &GroupItem) -> bool {
+        true
+    }
+
+    /// Called after all items of a group were visited.
+    fn exit_group(&mut self, _group: &GroupItem) {}
+
+    /// Called for a run of shaped text. The origin is on the text's baseline.
+    fn visit_text(&mut self, _ts: Transform, _text: &TextItem) {}
+
+    /// Called for a geometric shape.
+    fn visit_shape(&mut self, _ts: Transform, _shape: &Shape, _span: Span) {}
+
+    /// Called for an image that should be scaled to the given size.
+    fn visit_image(&mut self, _ts: Transform, _image: &Image, _size: Size, _span: Span) {}
+
+    /// Called for a link area of the given size.
+    fn visit_link(&mut self, _ts: Transform, _dest: &Destination, _size: Size) {}
+
+    /// Called for an introspection tag.
+    fn visit_tag(&mut self, _ts: Transform, _tag: &Tag) {}
+}
+
 /// The hardness of a frame.
 ///
 /// This corresponds to whether or not the frame is considered to be the
@@ -557,3 +628,41 @@ impl From<Position> for Dict {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Ratio;
+
+    #[test]
+    fn test_visit_accumulates_transforms() {
+        struct Shapes(Vec<Transform>);
+
+        impl FrameVisitor for Shapes {
+            fn visit_shape(&mut self, ts: Transform, _: &Shape, _: Span) {
+                self.0.push(ts);
+            }
+        }
+
+        let size = Size::splat(Abs::pt(10.0));
+        let rect = || {
+            FrameItem::Shape(Geometry::Rect(size).filled(Color::BLACK), Span::detached())
+        };
+
+        let mut inner = Frame::soft(size);
+        inner.push(Point::with_x(Abs::pt(2.0)), rect());
+        let mut group = GroupItem::new(inner);
+        group.transform = Transform::scale(Ratio::new(2.0), Ratio::new(2.0));
+
+        let mut outer = Frame::soft(size);
+        outer.push(Point::zero(), rect());
+        outer.push(Point::with_y(Abs::pt(5.0)), FrameItem::Group(group));
+
+        let mut shapes = Shapes(vec![]);
+        outer.visit(&mut shapes);
+        assert_eq!(shapes.0.len(), 2);
+        assert!(shapes.0[0].is_identity());
+        assert_eq!((shapes.0[1].tx, shapes.0[1].ty), (Abs::pt(4.0), Abs::pt(5.0)));
+        assert_eq!(shapes.0[1].sx, Ratio::new(2.0));
+    }
+}
@@ -0,0 +1,229 @@
+use std::num::NonZeroUsize;
+
+use typst_syntax::Span;
+
+use crate::foundations::{Content, Smart};
+use crate::introspection::{Counter, CounterKey, Introspector};
+use crate::layout::{Abs, Frame, FrameItem, Page, PagedDocument, Point, Size};
+use crate::visualize::{Color, FixedStroke, Geometry};
+
+/// How to arrange the pages of a finished document on printed sheets.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Imposition {
+    /// Whether to reorder the pages for saddle stitching. Otherwise, the pages
+    /// are placed two-up in reading order.
+    pub booklet: bool,
+    /// The number of sheets that are folded together into one signature of
+    /// a booklet. If `None`, all sheets form a single signature.
+    pub signature: Option<NonZeroUsize>,
+    /// How far the innermost sheet of a signature sticks out beyond the
+    /// outermost one after folding. Pages on inner sheets are shifted towards
+    /// the spine by up to this amount to compensate.
+    pub creep: Abs,
+    /// Whether to add a margin around each spread with crop marks at the
+    /// corners of the pages and fold marks at the spine.
+    pub marks: bool,
+}
+
+/// The margin around each spread if marks are enabled, in points.
+const MARK_MARGIN: f64 = 18.0;
+
+/// The length of crop and fold marks, in points.
+const MARK_LENGTH: f64 = 12.0;
+
+/// The distance between a mark and the page it belongs to, in points.
+const MARK_OFFSET: f64 = 3.0;
+
+impl Imposition {
+    /// Arrange the pages of a document two-up on sheets, returning a document
+    /// with one page per side of a sheet.
+    ///
+    /// Booklets are padded with blank pages to a multiple of four pages per
+    /// signature. Each spread is as large as twice the largest page of the
+    /// document, with smaller pages centered in their half.
+    pub fn impose(&self, document: &PagedDocument) -> PagedDocument {
+        let cell = document
+            .pages
+            .iter()
+            .map(|page| page.frame.size())
+            .fold(Size::zero(), Size::max);
+
+        let sides = if self.booklet {
+            self.booklet_sides(document.pages.len())
+        } else {
+            (0..document.pages.len().div_ceil(2))
+                .map(|i| Side {
+                    pages: [Some(2 * i), Some(2 * i + 1)],
+                    shift: Abs::zero(),
+                })
+                .collect()
+        };
+
+        let pages: Vec<Page> = sides
+            .iter()
+            .enumerate()
+            .map(|(i, side)| self.spread(document, side, cell, i))
+            .collect();
+
+        PagedDocument {
+            introspector: Introspector::paged(&pages),
+            info: document.info.clone(),
+            pages,
+        }
+    }
+
+    /// Determine the pages on each side of the sheets of a booklet.
+    fn booklet_sides(&self, count: usize) -> Vec<Side> {
+        let padded = count.div_ceil(4).max(1) * 4;
+        let per_signature = self.signature.map_or(padded, |sheets| 4 * sheets.get());
+        let page = |i: usize| (i < count).then_some(i);
+
+        let mut sides = vec![];
+        let mut start = 0;
+        while start < padded {
+            // The last signature may hold fewer sheets.
+            let len = per_signature.min(padded - start);
+            let sheets = len / 4;
+            for sheet in 0..sheets {
+                let shift = if sheets > 1 {
+                    self.creep * (sheet as f64 / (sheets - 1) as f64)
+                } else {
+                    Abs::zero()
+                };
+                let (outer, inner) = (start + len - 1 - 2 * sheet, start + 2 * sheet);
+                sides.push(Side { pages: [page(outer), page(inner)], shift });
+                sides.push(Side { pages: [page(inner + 1), page(outer - 1)], shift });
+            }
+            start += len;
+        }
+
+        sides
+    }
+
+    /// Compose one side of a sheet.
+    fn spread(
+        &self,
+        document: &PagedDocument,
+        side: &Side,
+        cell: Size,
+        index: usize,
+    ) -> Page {
+        let margin = if self.marks { Abs::pt(MARK_MARGIN) } else { Abs::zero() };
+        let size = Size::new(2.0 * cell.x + 2.0 * margin, cell.y + 2.0 * margin);
+        let mut frame = Frame::hard(size);
+
+        for (half, page) in side.pages.iter().enumerate() {
+            let Some(page) = page.and_then(|i| document.pages.get(i)) else {
+                continue;
+            };
+
+            // Center the page in its half and shift it towards the spine.
+            let page_size = page.frame.size();
+            let shift = if half == 0 { side.shift } else { -side.shift };
+            let pos = Point::new(
+                margin + cell.x * half as f64 + (cell.x - page_size.x) / 2.0 + shift,
+                margin + (cell.y - page_size.y) / 2.0,
+            );
+
+            if let Some(fill) = page.fill_or_transparent() {
+                let shape = Geometry::Rect(page_size).filled(fill);
+                frame.push(pos, FrameItem::Shape(shape, Span::detached()));
+            }
+            frame.push_frame(pos, page.frame.clone());
+        }
+
+        if self.marks {
+            draw_marks(&mut frame, margin, cell);
+        }
+
+        Page {
+            frame,
+            fill: Smart::Auto,
+            numbering: None,
+            supplement: Content::empty(),
+            counter: Counter::new(CounterKey::Page),
+            number: 1 + index as u64,
+        }
+    }
+}
+
+/// The pages on one side of a sheet.
+struct Side {
+    /// The indices of the left and right page, if any.
+    pages: [Option<usize>; 2],
+    /// How far to shift the pages towards the spine.
+    shift: Abs,
+}
+
+/// Draw crop marks at the outer corners of a spread and fold marks at the
+/// spine.
+fn draw_marks(frame: &mut Frame, margin: Abs, cell: Size) {
+    let stroke = FixedStroke::from_pair(Color::BLACK, Abs::pt(0.25));
+    let mut line = |start: Point, delta: Point| {
+        let shape = Geometry::Line(delta).stroked(stroke.clone());
+        frame.push(start, FrameItem::Shape(shape, Span::detached()));
+    };
+
+    let (left, right) = (margin, margin + 2.0 * cell.x);
+    let (top, bottom) = (margin, margin + cell.y);
+    let gap = Abs::pt(MARK_OFFSET);
+    let len = Abs::pt(MARK_LENGTH).min(margin - gap);
+
+    for x in [left, right] {
+        let outward = if x == left { -1.0 } else { 1.0 };
+        for y in [top, bottom] {
+            let upward = if y == top { -1.0 } else { 1.0 };
+            line(Point::new(x + outward * gap, y), Point::with_x(outward * len));
+            line(Point::new(x, y + upward * gap), Point::with_y(upward * len));
+        }
+    }
+
+    let spine = margin + cell.x;
+    line(Point::new(spine, top - gap), Point::with_y(-len));
+    line(Point::new(spine, bottom + gap), Point::with_y(len));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_booklet_sides() {
+        let order = |count, signature: Option<usize>| {
+            let imposition = Imposition {
+                booklet: true,
+                signature: signature.and_then(NonZeroUsize::new),
+                creep: Abs::zero(),
+                marks: false,
+            };
+            imposition
+                .booklet_sides(count)
+                .into_iter()
+                .map(|side| side.pages)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            order(8, None),
+            [
+                [Some(7), Some(0)],
+                [Some(1), Some(6)],
+                [Some(5), Some(2)],
+                [Some(3), Some(4)],
+            ]
+        );
+        assert_eq!(
+            order(6, None),
+            [[None, Some(0)], [Some(1), None], [Some(5), Some(2)], [Some(3), Some(4)]]
+        );
+        assert_eq!(
+            order(8, Some(1)),
+            [
+                [Some(3), Some(0)],
+                [Some(1), Some(2)],
+                [Some(7), Some(4)],
+                [Some(5), Some(6)],
+            ]
+        );
+    }
+}
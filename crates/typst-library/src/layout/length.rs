@@ -7,9 +7,12 @@ use ecow::{eco_format, EcoString};
 use typst_syntax::Span;
 use typst_utils::Numeric;
 
-use crate::diag::{bail, HintedStrResult, SourceResult};
-use crate::foundations::{func, scope, ty, Context, Fold, Repr, Resolve, StyleChain};
-use crate::layout::{Abs, Em};
+use crate::diag::{bail, At, HintedStrResult, SourceResult};
+use crate::foundations::{
+    func, scope, ty, Cast, Context, Fold, Repr, Resolve, StyleChain,
+};
+use crate::layout::{Abs, AbsUnit, Em};
+use crate::text::TextElem;
 
 /// A size or distance, possibly expressed with contextual units.
 ///
@@ -137,6 +140,42 @@ impl Length {
         Ok(self.abs.to_inches())
     }
 
+    /// Converts this length to the given unit.
+    ///
+    /// This is useful when the unit is only known at runtime, for instance
+    /// because it is part of a template's configuration. Like the methods for
+    /// the individual units, converting to an absolute unit fails with an
+    /// error if this length has non-zero `em` units. Converting to `{"em"}`
+    /// instead expresses the whole length in multiples of the current font
+    /// size and thus requires [context].
+    ///
+    /// ```example
+    /// #let unit = "mm"
+    /// #(1in).to(unit) \
+    /// #context (1em + 5.5pt).to("em")
+    /// ```
+    #[func]
+    pub fn to(
+        &self,
+        span: Span,
+        context: Tracked<Context>,
+        /// The unit to convert to.
+        unit: LengthUnit,
+    ) -> SourceResult<f64> {
+        let (unit, name) = match unit {
+            LengthUnit::Pt => (AbsUnit::Pt, "pt"),
+            LengthUnit::Mm => (AbsUnit::Mm, "mm"),
+            LengthUnit::Cm => (AbsUnit::Cm, "cm"),
+            LengthUnit::In => (AbsUnit::In, "inches"),
+            LengthUnit::Em => {
+                let font_size = TextElem::size_in(context.styles().at(span)?);
+                return Ok(self.em.get() + Em::from_length(self.abs, font_size).get());
+            }
+        };
+        self.ensure_that_em_is_zero(span, name)?;
+        Ok(self.abs.to_unit(unit))
+    }
+
     /// Resolve this length to an absolute length.
     ///
     /// ```example
@@ -160,6 +199,21 @@ impl Length {
     }
 }
 
+/// A unit that a length can be converted to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum LengthUnit {
+    /// Points.
+    Pt,
+    /// Millimeters.
+    Mm,
+    /// Centimeters.
+    Cm,
+    /// Inches.
+    In,
+    /// Multiples of the current font size.
+    Em,
+}
+
 impl Debug for Length {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match (self.abs.is_zero(), self.em.is_zero()) {
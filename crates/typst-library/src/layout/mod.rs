@@ -14,6 +14,7 @@ mod fragment;
 mod frame;
 pub mod grid;
 mod hide;
+mod imposition;
 #[path = "layout.rs"]
 mod layout_;
 mod length;
@@ -32,6 +33,7 @@ mod size;
 mod spacing;
 mod stack;
 mod transform;
+mod watermark;
 
 pub use self::abs::*;
 pub use self::align::*;
@@ -47,6 +49,7 @@ pub use self::fragment::*;
 pub use self::frame::*;
 pub use self::grid::*;
 pub use self::hide::*;
+pub use self::imposition::*;
 pub use self::layout_::*;
 pub use self::length::*;
 pub use self::measure_::*;
@@ -63,6 +66,7 @@ pub use self::size::*;
 pub use self::spacing::*;
 pub use self::stack::*;
 pub use self::transform::*;
+pub use self::watermark::*;
 
 use crate::foundations::Scope;
 
@@ -95,6 +99,7 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<RotateElem>();
     global.define_elem::<SkewElem>();
     global.define_elem::<HideElem>();
+    global.define_elem::<WatermarkElem>();
     global.define_func::<measure>();
     global.define_func::<layout>();
     global.reset_category();
@@ -2,18 +2,19 @@ use std::num::NonZeroUsize;
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 
+use comemo::Track;
 use typst_utils::{singleton, NonZeroExt, Scalar};
 
-use crate::diag::{bail, SourceResult};
+use crate::diag::{bail, At, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, Args, AutoValue, Cast, Construct, Content, Dict, Fold, NativeElement,
-    Set, Smart, Value,
+    cast, elem, Args, AutoValue, Cast, Construct, Content, Context, Dict, Fold, Func,
+    IntoValue, NativeElement, Selector, Set, Smart, Value,
 };
-use crate::introspection::Introspector;
+use crate::introspection::{Counter, CounterKey, Introspector, Tag};
 use crate::layout::{
-    Abs, Alignment, FlushElem, Frame, HAlignment, Length, OuterVAlignment, Ratio, Rel,
-    Sides, SpecificAlignment,
+    Abs, Alignment, FlushElem, Frame, FrameItem, HAlignment, Length, OuterVAlignment,
+    Ratio, Rel, Sides, SpecificAlignment,
 };
 use crate::model::{DocumentInfo, Numbering};
 use crate::text::LocalName;
@@ -223,6 +224,36 @@ pub struct PageElem {
     #[ghost]
     pub numbering: Option<Numbering>,
 
+    /// The counter that numbers the pages.
+    ///
+    /// By default, pages are numbered by the page counter. To let the
+    /// numbering start at a different number, for instance for a chapter
+    /// that is exported on its own, update the page counter at the start of
+    /// the document with `{counter(page).update(37)}`.
+    ///
+    /// Some pages, like plates inserted between the regular pages, are
+    /// numbered in a sequence of their own. For these pages, set this to a
+    /// different counter, typically one with a string key. That counter is
+    /// then stepped once per page and shown by the page's numbering, while
+    /// the page counter pauses until the regular pages continue. Page
+    /// references, the outline, and page labels in exported PDFs follow the
+    /// counter of each page.
+    ///
+    /// ```example
+    /// #set page(height: 80pt, numbering: "1")
+    /// Regular page
+    ///
+    /// #page(
+    ///   numbering: "P-1",
+    ///   counter: counter("plates"),
+    /// )[Plate]
+    ///
+    /// Regular page again
+    /// ```
+    #[ghost]
+    #[default(Counter::new(CounterKey::Page))]
+    pub counter: Counter,
+
     /// A supplement for the pages.
     ///
     /// For page references, this is added before the page number.
@@ -286,6 +317,27 @@ pub struct PageElem {
     #[ghost]
     pub header_ascent: Rel<Length>,
 
+    /// The pages on which the header is left out.
+    ///
+    /// - A [selector]: Omits the header on each page that contains an element
+    ///   matching the selector, for instance the first page of each chapter.
+    /// - A function: Is called with the physical page number, starting from
+    ///   one, and must return whether to omit the header on that page.
+    /// - `{none}`: Shows the header on all pages.
+    ///
+    /// ```example
+    /// #set page(
+    ///   height: 100pt,
+    ///   header: [_Typst Academy_ #h(1fr) Notes],
+    ///   header-skip: heading.where(level: 1),
+    /// )
+    ///
+    /// = Introduction
+    /// #lorem(20)
+    /// ```
+    #[ghost]
+    pub header_skip: Option<PageFilter>,
+
     /// The page's footer. Fills the bottom margin of each page.
     ///
     /// - Content: Shows the content as the footer.
@@ -324,10 +376,29 @@ pub struct PageElem {
     #[ghost]
     pub footer_descent: Rel<Length>,
 
+    /// The pages on which the footer is left out.
+    ///
+    /// Accepts the same values as [`header-skip`]($page.header-skip). If the
+    /// page number is shown in the footer, it is left out as well.
+    ///
+    /// ```example
+    /// #set page(
+    ///   height: 80pt,
+    ///   numbering: "1",
+    ///   footer-skip: n => n == 1,
+    /// )
+    ///
+    /// Title page
+    /// #pagebreak()
+    /// Contents
+    /// ```
+    #[ghost]
+    pub footer_skip: Option<PageFilter>,
+
     /// Content in the page's background.
     ///
     /// This content will be placed behind the page's body. It can be
-    /// used to place a background image or a watermark.
+    /// used to place a background image or a [watermark].
     ///
     /// ```example
     /// #set page(background: rotate(24deg,
@@ -480,8 +551,11 @@ pub struct Page {
     pub numbering: Option<Numbering>,
     /// The page's supplement.
     pub supplement: Content,
-    /// The logical page number (controlled by `counter(page)` and may thus not
-    /// match the physical number).
+    /// The counter that numbers the page. This is the page counter, unless the
+    /// page is part of a separately numbered sequence.
+    pub counter: Counter,
+    /// The logical page number (controlled by the page's counter and may thus
+    /// not match the physical number).
     pub number: u64,
 }
 
@@ -703,6 +777,56 @@ impl Parity {
     }
 }
 
+/// Determines the pages on which a header or footer is left out.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub enum PageFilter {
+    /// Pages that contain an element matching the selector.
+    Selector(Selector),
+    /// Pages for whose physical number the function returns `true`.
+    Func(Func),
+}
+
+impl PageFilter {
+    /// Whether the filter applies to the page with the given physical number
+    /// and body frame.
+    pub fn matches(
+        &self,
+        engine: &mut Engine,
+        number: NonZeroUsize,
+        body: &Frame,
+    ) -> SourceResult<bool> {
+        Ok(match self {
+            Self::Selector(selector) => contains(body, selector),
+            Self::Func(func) => func
+                .call(engine, Context::none().track(), [number.get()])?
+                .cast::<bool>()
+                .at(func.span())?,
+        })
+    }
+}
+
+/// Whether the frame contains the start of an element matching the selector.
+fn contains(frame: &Frame, selector: &Selector) -> bool {
+    frame.items().any(|(_, item)| match item {
+        FrameItem::Tag(Tag::Start(elem)) => selector.matches(elem, None),
+        FrameItem::Group(group) => contains(&group.frame, selector),
+        _ => false,
+    })
+}
+
+cast! {
+    PageFilter,
+    self => match self {
+        Self::Selector(v) => v.into_value(),
+        Self::Func(v) => v.into_value(),
+    },
+    v: Func => match v.element() {
+        Some(elem) => Self::Selector(elem.select()),
+        None => Self::Func(v),
+    },
+    v: Selector => Self::Selector(v),
+}
+
 /// Specification of a paper.
 #[derive(Debug, Copy, Clone, Hash)]
 pub struct Paper {
@@ -4,7 +4,7 @@ use std::ops::{Add, Div, Mul, Neg};
 use ecow::EcoString;
 use typst_utils::{Numeric, Scalar};
 
-use crate::foundations::{repr, ty, Repr};
+use crate::foundations::{func, repr, scope, ty, Repr};
 
 /// A ratio of a whole.
 ///
@@ -35,9 +35,11 @@ use crate::foundations::{repr, ty, Repr};
 /// | [`float`]       | `{27% * 0.37037}`       | `{10%}`         |
 /// | [`fraction`]    | `{27% * 3fr}`           | `{0.81fr}`      |
 ///
+/// To get the plain number of a ratio, divide it by `{100%}`.
+///
 /// When ratios are displayed in the document, they are rounded to two
 /// significant digits for readability.
-#[ty(cast)]
+#[ty(scope, cast)]
 #[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Ratio(Scalar);
 
@@ -88,6 +90,23 @@ impl Ratio {
     }
 }
 
+#[scope]
+impl Ratio {
+    /// Creates a ratio from a number, where `{1.0}` means `{100%}`.
+    ///
+    /// ```example
+    /// #ratio(0.25) \
+    /// #(ratio(3 / 4) * 8cm)
+    /// ```
+    #[func(constructor)]
+    pub fn construct(
+        /// The number to convert.
+        value: f64,
+    ) -> Ratio {
+        Self::new(value)
+    }
+}
+
 impl Debug for Ratio {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(f, "{:?}%", self.get() * 100.0)
@@ -5,7 +5,7 @@ use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssi
 use ecow::{eco_format, EcoString};
 use typst_utils::Numeric;
 
-use crate::foundations::{cast, ty, Fold, Repr, Resolve, StyleChain};
+use crate::foundations::{cast, func, scope, ty, Fold, Repr, Resolve, StyleChain};
 use crate::layout::{Abs, Em, Length, Ratio};
 
 /// A length in relation to some known length.
@@ -66,7 +66,12 @@ use crate::layout::{Abs, Em, Length, Ratio};
 /// #(100% - 50pt).length \
 /// #(100% - 50pt).ratio
 /// ```
-#[ty(cast, name = "relative", title = "Relative Length")]
+///
+/// Plain lengths and ratios don't have these fields. To access them on any
+/// value that may be a length, ratio, or relative length (like an argument of
+/// your own layout function), first convert it with the
+/// [`relative`]($relative) constructor.
+#[ty(scope, cast, name = "relative", title = "Relative Length")]
 #[derive(Default, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Rel<T: Numeric = Length> {
     /// The relative part.
@@ -129,6 +134,40 @@ impl Rel<Length> {
     }
 }
 
+#[scope]
+impl Rel<Length> {
+    /// Creates a relative length from a length, ratio, or relative length, or
+    /// from its components.
+    ///
+    /// Components given by name replace the respective component of the
+    /// positional value.
+    ///
+    /// ```example
+    /// #let parts(size) = {
+    ///   let size = relative(size)
+    ///   (size.ratio, size.length)
+    /// }
+    ///
+    /// #parts(50%) \
+    /// #parts(2cm) \
+    /// #relative(ratio: 25%, length: 1em)
+    /// ```
+    #[func(constructor)]
+    pub fn construct(
+        /// The value to convert.
+        #[default]
+        value: Rel<Length>,
+        /// The length component.
+        #[named]
+        length: Option<Length>,
+        /// The ratio component.
+        #[named]
+        ratio: Option<Ratio>,
+    ) -> Rel<Length> {
+        Rel::new(ratio.unwrap_or(value.rel), length.unwrap_or(value.abs))
+    }
+}
+
 impl<T: Numeric + Debug> Debug for Rel<T> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match (self.rel.is_zero(), self.abs.is_zero()) {
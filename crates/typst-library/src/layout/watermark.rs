@@ -0,0 +1,96 @@
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{
+    elem, Content, Func, NativeElement, Packed, Show, ShowSet, Smart, StyleChain, Styles,
+};
+use crate::introspection::Locatable;
+use crate::layout::{Angle, BlockElem, Ratio};
+use crate::text::TextElem;
+use crate::visualize::Color;
+
+/// A watermark that spans the page diagonally.
+///
+/// The watermark is meant to be used as a page's [`background`]($page.background)
+/// or [`foreground`]($page.foreground). Its body, typically some text or an
+/// [image], is rotated and scaled so that it fills the area within the page
+/// margins. Text in a watermark is light gray by default.
+///
+/// # Example
+/// ```example
+/// #set page(background: watermark[DRAFT])
+///
+/// = Quarterly report
+/// #lorem(30)
+/// ```
+///
+/// # Restricting to certain pages
+/// To show the watermark only on some pages, you can pass a function to the
+/// [`pages`]($watermark.pages) parameter. Alternatively, since page properties
+/// apply per page run, you can change the page's background with a set rule
+/// where the watermarked pages start and end.
+#[elem(Locatable, Show, ShowSet)]
+pub struct WatermarkElem {
+    /// The angle by which the watermark is rotated.
+    ///
+    /// When set to `{auto}`, the watermark follows the diagonal from the
+    /// bottom-left to the top-right corner of its area.
+    ///
+    /// ```example
+    /// #set page(background: watermark(
+    ///   angle: 0deg,
+    ///   text(red.lighten(70%))[VOID],
+    /// ))
+    /// ```
+    pub angle: Smart<Angle>,
+
+    /// How much of the available area the watermark fills.
+    ///
+    /// The body is scaled up or down such that its rotated bounds fill this
+    /// ratio of the area in the dimension where they fit the tightest.
+    #[default(Ratio::new(0.8))]
+    pub size: Ratio,
+
+    /// Whether to keep the watermark within the page margins.
+    ///
+    /// If this is `{false}`, the watermark is fitted into the whole page
+    /// instead.
+    #[default(true)]
+    pub margins: bool,
+
+    /// Determines the pages on which the watermark is shown.
+    ///
+    /// If given a function, it is called with the physical page number,
+    /// starting from one, and must return a boolean. When set to `{none}`,
+    /// the watermark is shown on every page.
+    ///
+    /// ```example
+    /// #set page(height: 80pt, background: watermark(
+    ///   pages: n => n > 1,
+    ///   [DRAFT],
+    /// ))
+    ///
+    /// Final. #pagebreak()
+    /// Not yet.
+    /// ```
+    pub pages: Option<Func>,
+
+    /// The content of the watermark.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for Packed<WatermarkElem> {
+    fn show(&self, engine: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(BlockElem::single_layouter(self.clone(), engine.routines.layout_watermark)
+            .pack()
+            .spanned(self.span()))
+    }
+}
+
+impl ShowSet for Packed<WatermarkElem> {
+    fn show_set(&self, _: StyleChain) -> Styles {
+        let mut out = Styles::new();
+        out.set(TextElem::set_fill(Color::SILVER.into()));
+        out
+    }
+}
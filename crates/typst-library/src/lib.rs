@@ -34,7 +34,9 @@ use typst_syntax::{FileId, Source, Span};
 use typst_utils::{LazyHash, SmallBitSet};
 
 use crate::diag::FileResult;
-use crate::foundations::{Array, Binding, Bytes, Datetime, Dict, Module, Scope, Styles};
+use crate::foundations::{
+    Array, Binding, Bytes, Datetime, Dict, Module, Scope, Styles, Value,
+};
 use crate::layout::{Alignment, Dir};
 use crate::text::{Font, FontBook};
 use crate::visualize::Color;
@@ -152,6 +154,8 @@ pub struct Library {
     pub std: Binding,
     /// In-development features that were enabled.
     pub features: Features,
+    /// Limits on the resources a compilation may use.
+    pub limits: Limits,
 }
 
 impl Library {
@@ -159,6 +163,27 @@ impl Library {
     pub fn builder() -> LibraryBuilder {
         LibraryBuilder::default()
     }
+
+    /// Create a copy of the library in which `sys.inputs` holds the given
+    /// inputs.
+    ///
+    /// This is cheaper than building a new library and keeps all other
+    /// configuration intact.
+    pub fn with_inputs(&self, inputs: Dict) -> Self {
+        let mut global = self.global.clone();
+        if let Some(Ok(sys)) = global.scope_mut().get_mut("sys").map(Binding::write) {
+            *sys = Value::Module(foundations::sys::module(inputs));
+        }
+
+        Self {
+            std: Binding::detached(global.clone()),
+            global,
+            math: self.math.clone(),
+            styles: self.styles.clone(),
+            features: self.features.clone(),
+            limits: self.limits,
+        }
+    }
 }
 
 impl Default for Library {
@@ -175,6 +200,7 @@ impl Default for Library {
 pub struct LibraryBuilder {
     inputs: Option<Dict>,
     features: Features,
+    limits: Limits,
 }
 
 impl LibraryBuilder {
@@ -192,6 +218,12 @@ impl LibraryBuilder {
         self
     }
 
+    /// Configure limits on the resources a compilation may use.
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     /// Consumes the builder and returns a `Library`.
     pub fn build(self) -> Library {
         let math = math::module();
@@ -203,6 +235,7 @@ impl LibraryBuilder {
             styles: Styles::new(),
             std: Binding::detached(global),
             features: self.features,
+            limits: self.limits,
         }
     }
 }
@@ -237,6 +270,52 @@ pub enum Feature {
     Html,
 }
 
+/// Limits on the resources a compilation may use.
+///
+/// The defaults are suitable for trusted documents. Services that compile
+/// documents submitted by users should lower them.
+///
+/// Some resources are deliberately not covered:
+/// - There is no budget for the total number of evaluation steps. Evaluation
+///   is memoized, so a cached result does not repeat its steps and such a
+///   budget would depend on what earlier compilations left in the cache.
+/// - Memory and wall-clock time are not limited, as they cannot be enforced
+///   deterministically from within the compiler.
+///
+/// To bound these, run the compilation in a separate thread or process and
+/// stop it from the outside.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Limits {
+    /// The maximum nesting depth of function calls.
+    ///
+    /// Each call takes up space on the native stack, so raising this limit
+    /// far beyond the default may crash the compiler on platforms where the
+    /// stack cannot grow.
+    pub max_call_depth: usize,
+    /// The maximum number of iterations of a single `while` loop. This also
+    /// bounds the number of consecutive tail calls.
+    ///
+    /// `for` loops are not bounded by this limit. They iterate over an array,
+    /// dictionary, string, or bytes value that already exists and thus always
+    /// terminate.
+    pub max_while_iterations: usize,
+    /// The maximum nesting depth of module imports and includes, if any.
+    pub max_import_depth: Option<usize>,
+    /// The maximum number of pixels of a raster image, if any.
+    pub max_image_pixels: Option<u64>,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_call_depth: 80,
+            max_while_iterations: 10_000,
+            max_import_depth: None,
+            max_image_pixels: None,
+        }
+    }
+}
+
 /// A group of related standard library definitions.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -10,6 +10,8 @@ mod json_;
 mod read_;
 #[path = "toml.rs"]
 mod toml_;
+#[path = "xlsx.rs"]
+mod xlsx_;
 #[path = "xml.rs"]
 mod xml_;
 #[path = "yaml.rs"]
@@ -24,6 +26,7 @@ pub use self::csv_::*;
 pub use self::json_::*;
 pub use self::read_::*;
 pub use self::toml_::*;
+pub use self::xlsx_::*;
 pub use self::xml_::*;
 pub use self::yaml_::*;
 
@@ -42,6 +45,7 @@ pub(super) fn define(global: &mut Scope) {
     global.define_func::<yaml>();
     global.define_func::<cbor>();
     global.define_func::<xml>();
+    global.define_func::<xlsx>();
     global.reset_category();
 }
 
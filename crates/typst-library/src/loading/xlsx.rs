@@ -0,0 +1,574 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::{Cursor, Read};
+
+use ecow::{eco_format, EcoString};
+use typst_syntax::Spanned;
+
+use crate::diag::{
+    bail, At, HintedStrResult, LoadError, LoadedWithin, ReportPos, SourceResult,
+    StrResult,
+};
+use crate::engine::Engine;
+use crate::foundations::{cast, func, Array, Dict, IntoValue, Repr, Str, Value};
+use crate::loading::{DataSource, Load, RowType};
+
+/// Reads a worksheet from an Excel workbook.
+///
+/// The cells of the worksheet are read into a 2-dimensional array: Each row
+/// is represented as an array of cell values, and all rows are collected into
+/// a single array. Text cells become strings, numeric cells become integers
+/// or floats, boolean cells become booleans, and empty cells become `{none}`.
+/// Formulas are not evaluated; instead, the result that was last computed by
+/// the spreadsheet application is read. Dates are stored as numbers in XLSX
+/// files and are read as such.
+///
+/// By default, the first worksheet is read from cell `A1` up to the last
+/// used row and column.
+///
+/// # Example
+/// ```typ
+/// #let sales = xlsx("sales.xlsx", sheet: "2025", range: "A1:D13")
+///
+/// #table(
+///   columns: 4,
+///   ..sales.flatten().map(v => [#v]),
+/// )
+/// ```
+#[func(title = "XLSX")]
+pub fn xlsx(
+    engine: &mut Engine,
+    /// A [path]($syntax/#paths) to an XLSX file or raw XLSX bytes.
+    source: Spanned<DataSource>,
+    /// Which worksheet to read: Either its name or its zero-based index in
+    /// the workbook.
+    #[named]
+    sheet: Option<Spanned<Sheet>>,
+    /// Which cells to read, in A1 notation. Either a rectangular range like
+    /// `{"B2:D10"}` or a single cell like `{"C4"}`.
+    #[named]
+    range: Option<Spanned<Str>>,
+    /// How to represent the worksheet's rows.
+    ///
+    /// - If set to `array`, each row is represented as a plain array of
+    ///   values.
+    /// - If set to `dictionary`, each row is represented as a dictionary
+    ///   mapping from the values in the first row of the range to the row's
+    ///   values.
+    #[named]
+    #[default(RowType::Array)]
+    row_type: RowType,
+) -> SourceResult<Array> {
+    let range_span = range.as_ref().map(|range| range.span);
+    let range = range
+        .map(|Spanned { v, span }| CellRange::parse(&v).at(span))
+        .transpose()?;
+
+    let loaded = source.load(engine.world)?;
+    let mut workbook = Workbook::open(loaded.data.as_slice()).within(&loaded)?;
+    let path = match sheet {
+        Some(Spanned { v, span }) => workbook.sheet_path(&v).at(span)?,
+        None => workbook
+            .sheet_path(&Sheet::Index(0))
+            .map_err(format_xlsx_error)
+            .within(&loaded)?,
+    };
+
+    let cells = workbook.cells(&path).within(&loaded)?;
+    let Some(range) = range.or_else(|| CellRange::covering(&cells)) else {
+        return Ok(Array::new());
+    };
+    range.check_size().at(range_span.unwrap_or(source.span))?;
+
+    let mut rows = vec![vec![Value::None; range.cols()]; range.rows()];
+    for ((row, col), value) in cells {
+        if range.contains(row, col) {
+            rows[row - range.start.0][col - range.start.1] = value;
+        }
+    }
+
+    let mut array = Array::new();
+    let mut rows = rows.into_iter();
+    match row_type {
+        RowType::Array => {
+            array.extend(rows.map(|row| row.into_iter().collect::<Array>().into_value()))
+        }
+        RowType::Dict => {
+            let headers: Vec<Str> = rows
+                .next()
+                .unwrap_or_default()
+                .into_iter()
+                .enumerate()
+                .map(|(i, header)| match header {
+                    Value::Str(s) => s,
+                    Value::None => column_name(range.start.1 + i).into(),
+                    v => v.repr().into(),
+                })
+                .collect();
+            for row in rows {
+                let dict: Dict = headers.iter().cloned().zip(row).collect();
+                array.push(dict.into_value());
+            }
+        }
+    }
+
+    Ok(array)
+}
+
+/// A worksheet selected by name or index.
+pub enum Sheet {
+    /// The zero-based position of the sheet in the workbook.
+    Index(usize),
+    /// The name of the sheet.
+    Name(Str),
+}
+
+cast! {
+    Sheet,
+    self => match self {
+        Self::Index(v) => v.into_value(),
+        Self::Name(v) => v.into_value(),
+    },
+    v: usize => Self::Index(v),
+    v: Str => Self::Name(v),
+}
+
+/// An opened XLSX workbook.
+struct Workbook<'a> {
+    /// The ZIP archive that contains the workbook parts.
+    archive: zip::ZipArchive<Cursor<&'a [u8]>>,
+    /// The names of the sheets and the paths to their parts, in order.
+    sheets: Vec<(EcoString, EcoString)>,
+    /// The strings that cells can refer to by index.
+    shared: Vec<Str>,
+}
+
+impl<'a> Workbook<'a> {
+    /// Open the workbook and read its sheet list and shared strings.
+    fn open(data: &'a [u8]) -> Result<Self, LoadError> {
+        let mut archive =
+            zip::ZipArchive::new(Cursor::new(data)).map_err(format_xlsx_error)?;
+
+        let text = read_part(&mut archive, "xl/_rels/workbook.xml.rels")?
+            .ok_or_else(|| format_xlsx_error("workbook relationships are missing"))?;
+        let doc = parse_part(&text)?;
+        let targets: HashMap<&str, EcoString> = doc
+            .descendants()
+            .filter(|node| node.has_tag_name("Relationship"))
+            .filter_map(|node| {
+                let id = node.attribute("Id")?;
+                let target = node.attribute("Target")?;
+                Some((id, resolve_target(target)))
+            })
+            .collect();
+
+        let text = read_part(&mut archive, "xl/workbook.xml")?
+            .ok_or_else(|| format_xlsx_error("workbook is missing"))?;
+        let doc = parse_part(&text)?;
+        let sheets = doc
+            .descendants()
+            .filter(|node| node.has_tag_name("sheet"))
+            .filter_map(|node| {
+                let name = node.attribute("name")?;
+                let id = node.attributes().find(|attr| attr.name() == "id")?.value();
+                Some((name.into(), targets.get(id)?.clone()))
+            })
+            .collect();
+
+        let mut shared = vec![];
+        if let Some(text) = read_part(&mut archive, "xl/sharedStrings.xml")? {
+            let doc = parse_part(&text)?;
+            shared = doc
+                .descendants()
+                .filter(|node| node.has_tag_name("si"))
+                .map(inline_text)
+                .collect();
+        }
+
+        Ok(Self { archive, sheets, shared })
+    }
+
+    /// Find the path of the part that contains the given sheet.
+    fn sheet_path(&self, sheet: &Sheet) -> StrResult<EcoString> {
+        let found = match sheet {
+            Sheet::Index(i) => self.sheets.get(*i),
+            Sheet::Name(name) => {
+                self.sheets.iter().find(|(n, _)| n.as_str() == name.as_str())
+            }
+        };
+
+        match found {
+            Some((_, path)) => Ok(path.clone()),
+            None if self.sheets.is_empty() => bail!("workbook contains no sheets"),
+            None => {
+                let names = self.sheets.iter().map(|(n, _)| n.repr()).collect::<Vec<_>>();
+                bail!(
+                    "workbook contains no sheet {} (available sheets: {})",
+                    match sheet {
+                        Sheet::Index(i) => eco_format!("at index {i}"),
+                        Sheet::Name(name) => name.repr(),
+                    },
+                    names.join(", "),
+                )
+            }
+        }
+    }
+
+    /// Read the non-empty cells of a sheet, keyed by zero-based row and
+    /// column.
+    fn cells(&mut self, path: &str) -> Result<Vec<((usize, usize), Value)>, LoadError> {
+        let text = read_part(&mut self.archive, path)?.ok_or_else(|| {
+            format_xlsx_error(eco_format!("sheet part {path} is missing"))
+        })?;
+        let doc = parse_part(&text)?;
+
+        // Rows and cells may omit their position, in which case they directly
+        // follow the previous one.
+        let mut cells = vec![];
+        let out_of_bounds = || format_xlsx_error("cell lies outside of the worksheet");
+        let mut next_row = 0;
+        for row_node in doc.descendants().filter(|node| node.has_tag_name("row")) {
+            let row = row_node
+                .attribute("r")
+                .and_then(|r| r.parse::<usize>().ok())
+                .map_or(next_row, |r| r.saturating_sub(1));
+            if row >= MAX_ROWS {
+                return Err(out_of_bounds());
+            }
+            next_row = row + 1;
+
+            let mut next_col = 0;
+            for cell in row_node.children().filter(|node| node.has_tag_name("c")) {
+                let col = cell
+                    .attribute("r")
+                    .and_then(CellRange::parse_cell)
+                    .map_or(next_col, |(_, col)| col);
+                if col >= MAX_COLS {
+                    return Err(out_of_bounds());
+                }
+                next_col = col + 1;
+
+                let value = self.cell_value(cell)?;
+                if !value.is_none() {
+                    cells.push(((row, col), value));
+                }
+            }
+        }
+
+        Ok(cells)
+    }
+
+    /// Convert a cell into a value based on its type.
+    fn cell_value(&self, cell: roxmltree::Node) -> Result<Value, LoadError> {
+        let raw = cell
+            .children()
+            .find(|node| node.has_tag_name("v"))
+            .and_then(|node| node.text());
+
+        Ok(match (cell.attribute("t").unwrap_or("n"), raw) {
+            ("inlineStr", _) => cell
+                .children()
+                .find(|node| node.has_tag_name("is"))
+                .map(inline_text)
+                .into_value(),
+            (_, None) => Value::None,
+            ("s", Some(raw)) => raw
+                .trim()
+                .parse::<usize>()
+                .ok()
+                .and_then(|i| self.shared.get(i))
+                .ok_or_else(|| format_xlsx_error("invalid shared string index"))?
+                .clone()
+                .into_value(),
+            ("b", Some(raw)) => (raw.trim() == "1").into_value(),
+            ("str" | "e", Some(raw)) => raw.into_value(),
+            (_, Some(raw)) => {
+                let number = raw
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|_| format_xlsx_error("invalid number in cell"))?;
+                if number.fract() == 0.0 && number.abs() < 2f64.powi(53) {
+                    (number as i64).into_value()
+                } else {
+                    number.into_value()
+                }
+            }
+        })
+    }
+}
+
+/// The number of rows in an Excel worksheet.
+const MAX_ROWS: usize = 1 << 20;
+
+/// The number of columns in an Excel worksheet.
+const MAX_COLS: usize = 1 << 14;
+
+/// The maximum number of cells that are read at once.
+const MAX_CELLS: usize = 1 << 22;
+
+/// The maximum size of a decompressed part of the workbook package in bytes.
+const MAX_PART_SIZE: u64 = 1 << 28;
+
+/// A rectangular range of cells with inclusive, zero-based bounds as
+/// row-column pairs.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct CellRange {
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+impl CellRange {
+    /// Parse a range in A1 notation.
+    fn parse(range: &str) -> StrResult<Self> {
+        let invalid = || eco_format!("invalid cell range {}", range.repr());
+        let (start, end) = range.split_once(':').unwrap_or((range, range));
+        let start = Self::parse_cell(start).ok_or_else(invalid)?;
+        let end = Self::parse_cell(end).ok_or_else(invalid)?;
+        if start.0 > end.0 || start.1 > end.1 {
+            bail!("cell range {} is reversed", range.repr());
+        }
+        Ok(Self { start, end })
+    }
+
+    /// Parse a single cell reference like `B12` into a row-column pair.
+    fn parse_cell(cell: &str) -> Option<(usize, usize)> {
+        let cell = cell.trim();
+        let split = cell.find(|c: char| c.is_ascii_digit())?;
+        let (letters, digits) = cell.split_at(split);
+        if letters.is_empty() || letters.len() > 3 {
+            return None;
+        }
+
+        let mut col = 0;
+        for c in letters.chars() {
+            if !c.is_ascii_alphabetic() {
+                return None;
+            }
+            col = col * 26 + usize::from(c.to_ascii_uppercase() as u8 - b'A' + 1);
+        }
+
+        let row = digits.parse::<usize>().ok().filter(|&row| row > 0)?;
+        (row <= MAX_ROWS && col <= MAX_COLS).then(|| (row - 1, col - 1))
+    }
+
+    /// The range from `A1` to the last used row and column, if any cell is
+    /// used.
+    fn covering(cells: &[((usize, usize), Value)]) -> Option<Self> {
+        let end = cells
+            .iter()
+            .map(|&(pos, _)| pos)
+            .reduce(|(r, c), (row, col)| (r.max(row), c.max(col)))?;
+        Some(Self { start: (0, 0), end })
+    }
+
+    /// The number of rows in the range.
+    fn rows(self) -> usize {
+        self.end.0 - self.start.0 + 1
+    }
+
+    /// The number of columns in the range.
+    fn cols(self) -> usize {
+        self.end.1 - self.start.1 + 1
+    }
+
+    /// Ensure that the range is small enough to be read into an array.
+    fn check_size(self) -> HintedStrResult<()> {
+        match self.rows().checked_mul(self.cols()) {
+            Some(cells) if cells <= MAX_CELLS => Ok(()),
+            _ => bail!(
+                "cell range is too large";
+                hint: "at most {MAX_CELLS} cells can be read at once, try a smaller range"
+            ),
+        }
+    }
+
+    /// Whether the range contains the given cell.
+    fn contains(self, row: usize, col: usize) -> bool {
+        (self.start.0..=self.end.0).contains(&row)
+            && (self.start.1..=self.end.1).contains(&col)
+    }
+}
+
+/// The letters that identify a zero-based column in A1 notation.
+fn column_name(mut col: usize) -> EcoString {
+    let mut name = EcoString::new();
+    loop {
+        name.insert(0, char::from(b'A' + (col % 26) as u8));
+        if col < 26 {
+            break;
+        }
+        col = col / 26 - 1;
+    }
+    name
+}
+
+/// Read a part of the workbook package as text, if it exists.
+fn read_part(
+    archive: &mut zip::ZipArchive<Cursor<&[u8]>>,
+    path: &str,
+) -> Result<Option<String>, LoadError> {
+    let mut file = match archive.by_name(path) {
+        Ok(file) => file,
+        Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+        Err(err) => return Err(format_xlsx_error(err)),
+    };
+
+    read_limited(file, MAX_PART_SIZE).map(Some)
+}
+
+/// Read text from a reader, failing if it is longer than `limit` bytes.
+///
+/// This guards against small archives that decompress to huge parts.
+fn read_limited(reader: impl Read, limit: u64) -> Result<String, LoadError> {
+    let mut data = Vec::new();
+    reader
+        .take(limit + 1)
+        .read_to_end(&mut data)
+        .map_err(format_xlsx_error)?;
+    if data.len() as u64 > limit {
+        return Err(format_xlsx_error(eco_format!(
+            "workbook part is larger than {limit} bytes"
+        )));
+    }
+    String::from_utf8(data).map_err(format_xlsx_error)
+}
+
+/// Parse an XML part of the workbook package.
+fn parse_part(text: &str) -> Result<roxmltree::Document<'_>, LoadError> {
+    roxmltree::Document::parse(text).map_err(format_xlsx_error)
+}
+
+/// Resolve a relationship target relative to the workbook part.
+fn resolve_target(target: &str) -> EcoString {
+    match target.strip_prefix('/') {
+        Some(absolute) => absolute.into(),
+        None => eco_format!("xl/{target}"),
+    }
+}
+
+/// Concatenate the text runs of a rich text string, skipping phonetic hints.
+fn inline_text(node: roxmltree::Node) -> Str {
+    let mut text = EcoString::new();
+    for t in node.descendants().filter(|node| node.has_tag_name("t")) {
+        if !t.ancestors().any(|node| node.has_tag_name("rPh")) {
+            text.push_str(t.text().unwrap_or_default());
+        }
+    }
+    text.into()
+}
+
+/// Format the user-facing XLSX error message.
+fn format_xlsx_error(err: impl Display) -> LoadError {
+    LoadError::new(ReportPos::None, "failed to parse XLSX", err)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use zip::write::SimpleFileOptions;
+
+    use super::*;
+
+    fn workbook() -> Vec<u8> {
+        let parts = [
+            (
+                "xl/_rels/workbook.xml.rels",
+                r#"<Relationships>
+                    <Relationship Id="rId1" Target="worksheets/sheet1.xml"/>
+                    <Relationship Id="rId2" Target="/xl/worksheets/sheet2.xml"/>
+                </Relationships>"#,
+            ),
+            (
+                "xl/workbook.xml",
+                r#"<workbook xmlns:r="urn:relationships">
+                    <sheets>
+                        <sheet name="First" sheetId="1" r:id="rId1"/>
+                        <sheet name="Second" sheetId="2" r:id="rId2"/>
+                    </sheets>
+                </workbook>"#,
+            ),
+            (
+                "xl/sharedStrings.xml",
+                r#"<sst>
+                    <si><t>Name</t></si>
+                    <si><r><t>Ty</t></r><r><t>pst</t></r></si>
+                </sst>"#,
+            ),
+            (
+                "xl/worksheets/sheet1.xml",
+                r#"<worksheet><sheetData>
+                    <row r="1">
+                        <c r="A1" t="s"><v>0</v></c>
+                        <c r="C1" t="b"><v>1</v></c>
+                    </row>
+                    <row r="3">
+                        <c r="A3" t="s"><v>1</v></c>
+                        <c r="B3"><v>2.5</v></c>
+                        <c><v>7</v></c>
+                    </row>
+                </sheetData></worksheet>"#,
+            ),
+            (
+                "xl/worksheets/sheet2.xml",
+                r#"<worksheet><sheetData>
+                    <row r="2"><c r="B2" t="inlineStr"><is><t>Hi</t></is></c></row>
+                </sheetData></worksheet>"#,
+            ),
+        ];
+
+        let mut writer = zip::ZipWriter::new(Cursor::new(vec![]));
+        for (path, text) in parts {
+            writer.start_file(path, SimpleFileOptions::default()).unwrap();
+            writer.write_all(text.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_xlsx_cells() {
+        let data = workbook();
+        let mut workbook = Workbook::open(&data).unwrap();
+        let path = workbook.sheet_path(&Sheet::Index(0)).unwrap();
+        assert_eq!(path, "xl/worksheets/sheet1.xml");
+        assert_eq!(
+            workbook.cells(&path).unwrap(),
+            [
+                ((0, 0), Str::from("Name").into_value()),
+                ((0, 2), true.into_value()),
+                ((2, 0), Str::from("Typst").into_value()),
+                ((2, 1), 2.5.into_value()),
+                ((2, 2), 7_i64.into_value()),
+            ]
+        );
+
+        let path = workbook.sheet_path(&Sheet::Name("Second".into())).unwrap();
+        assert_eq!(
+            workbook.cells(&path).unwrap(),
+            [((1, 1), Str::from("Hi").into_value())]
+        );
+        assert!(workbook.sheet_path(&Sheet::Index(2)).is_err());
+    }
+
+    #[test]
+    fn test_xlsx_cell_range() {
+        let range = CellRange::parse("B2:AA10").unwrap();
+        assert_eq!(range, CellRange { start: (1, 1), end: (9, 26) });
+        assert_eq!(
+            CellRange::parse("c4").unwrap(),
+            CellRange { start: (3, 2), end: (3, 2) }
+        );
+        assert!(CellRange::parse("D4:A1").is_err());
+        assert!(CellRange::parse("A0").is_err());
+        assert!(CellRange::parse("A1:A1048577").is_err());
+        assert!(CellRange::parse("A1:XFD1048576").unwrap().check_size().is_err());
+        assert_eq!(column_name(0), "A");
+        assert_eq!(column_name(26), "AA");
+    }
+
+    #[test]
+    fn test_xlsx_read_limited() {
+        assert_eq!(read_limited("<sst/>".as_bytes(), 6).unwrap(), "<sst/>");
+        assert!(read_limited("<sst></sst>".as_bytes(), 6).is_err());
+    }
+}
@@ -6,8 +6,8 @@ use unicode_math_class::MathClass;
 use crate::diag::SourceResult;
 use crate::engine::Engine;
 use crate::foundations::{
-    elem, Content, NativeElement, Packed, Show, ShowSet, Smart, StyleChain, Styles,
-    Synthesize,
+    elem, Content, Element, NativeElement, Packed, Show, ShowSet, Smart, StyleChain,
+    Styles, Synthesize,
 };
 use crate::introspection::{Count, Counter, CounterUpdate, Locatable};
 use crate::layout::{
@@ -228,6 +228,10 @@ impl Refable for Packed<EquationElem> {
     fn numbering(&self) -> Option<&Numbering> {
         (**self).numbering(StyleChain::default()).as_ref()
     }
+
+    fn supplement_kind(&self) -> Option<Element> {
+        Some(EquationElem::elem())
+    }
 }
 
 impl Outlinable for Packed<EquationElem> {
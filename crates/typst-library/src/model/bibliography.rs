@@ -4,10 +4,11 @@ use std::ffi::OsStr;
 use std::fmt::{self, Debug, Formatter};
 use std::num::NonZeroUsize;
 use std::path::Path;
+use std::str::FromStr;
 use std::sync::{Arc, LazyLock};
 
 use comemo::{Track, Tracked};
-use ecow::{eco_format, EcoString, EcoVec};
+use ecow::{eco_format, eco_vec, EcoString, EcoVec};
 use hayagriva::archive::ArchivedStyle;
 use hayagriva::io::BibLaTeXError;
 use hayagriva::{
@@ -25,9 +26,9 @@ use crate::diag::{
 };
 use crate::engine::{Engine, Sink};
 use crate::foundations::{
-    elem, Bytes, CastInfo, Content, Derived, FromValue, IntoValue, Label, NativeElement,
-    OneOrMultiple, Packed, Reflect, Scope, Show, ShowSet, Smart, StyleChain, Styles,
-    Synthesize, Value,
+    cast, dict, elem, ops, repr, Bytes, CastInfo, Content, Context, Derived, FromValue,
+    Func, IntoValue, Label, NativeElement, OneOrMultiple, Packed, Reflect, Scope,
+    Selector, Show, ShowSet, Smart, StyleChain, Styles, Synthesize, Value,
 };
 use crate::introspection::{Introspector, Locatable, Location};
 use crate::layout::{
@@ -36,13 +37,13 @@ use crate::layout::{
 };
 use crate::loading::{format_yaml_error, DataSource, Load, LoadSource, Loaded};
 use crate::model::{
-    CitationForm, CiteGroup, Destination, FootnoteElem, HeadingElem, LinkElem, ParElem,
-    Url,
+    CitationForm, CiteGroup, Destination, FootnoteElem, HeadingElem, LinkElem,
+    NumberingPattern, ParElem, Url,
 };
 use crate::routines::Routines;
 use crate::text::{
-    FontStyle, Lang, LocalName, Region, Smallcaps, SubElem, SuperElem, TextElem,
-    WeightDelta,
+    localized_str, localized_str_in, FontStyle, Lang, LocalName, Region, Smallcaps,
+    SpaceElem, SubElem, SuperElem, TextElem, WeightDelta,
 };
 use crate::World;
 
@@ -55,7 +56,10 @@ use crate::World;
 ///   file format designed for use with Typst. Visit its
 ///   [documentation](https://github.com/typst/hayagriva/blob/main/docs/file-format.md)
 ///   for more details.
-/// - A BibLaTeX `.bib` file.
+/// - A BibLaTeX `.bib` file. Besides the classic BibTeX entry types and
+///   fields, BibLaTeX-specific ones like `@online` and `@software` entries
+///   and the `date`, `urldate`, `doi`, and `eprint` fields are understood.
+///   URLs and DOIs are displayed as links.
 ///
 /// As soon as you add a bibliography somewhere in your document, you can start
 /// citing things with reference syntax (`[@key]`) or explicit calls to the
@@ -78,6 +82,12 @@ use crate::World;
 /// | Economics       | `{"harvard-cite-them-right"}`                          |
 /// | Physics         | `{"american-physics-society"}`                         |
 ///
+/// # Multiple bibliographies
+/// A document can contain multiple bibliographies, for example one at the end
+/// of each chapter. Each bibliography then lists the works cited since the
+/// previous one and is numbered independently. Citations after the last
+/// bibliography belong to that bibliography, too.
+///
 /// # Example
 /// ```example
 /// This was already noted by
@@ -125,6 +135,33 @@ pub struct BibliographyElem {
     #[default(false)]
     pub full: bool,
 
+    /// The order of the works in the bibliography.
+    ///
+    /// - `{auto}` uses the order prescribed by the
+    ///   [bibliography style]($bibliography.style).
+    /// - `{"citation"}` lists the works in the order in which they are first
+    ///   cited.
+    /// - `{"author"}` sorts the works by author, then by year, then by title.
+    /// - A function receives each work as a dictionary with the keys `key`,
+    ///   `title`, `author`, and `year` and returns a value to sort by. Since
+    ///   it only reorders the finished list, it is best used with styles that
+    ///   don't number their references.
+    ///
+    /// Numeric styles number the works in the resulting order, so citation
+    /// order is useful with them.
+    ///
+    /// ```example
+    /// #set text(lang: "en")
+    /// @netwok @arrgh
+    ///
+    /// #bibliography(
+    ///   "works.bib",
+    ///   style: "apa",
+    ///   sorting: work => -work.year,
+    /// )
+    /// ```
+    pub sorting: Smart<BibliographySorting>,
+
     /// The bibliography style.
     ///
     /// This can be:
@@ -144,6 +181,23 @@ pub struct BibliographyElem {
     })]
     pub style: Derived<CslSource, CslStyle>,
 
+    /// Whether to note after each reference on which pages the work is cited.
+    ///
+    /// The page numbers are displayed with the numbering of the respective
+    /// page and link to the first citation on that page. Citations with
+    /// [`form`]($cite.form) set to `{none}` are not listed.
+    ///
+    /// ```example
+    /// #set page(numbering: "1")
+    /// See @arrgh and @netwok.
+    /// #pagebreak()
+    /// Also @arrgh.
+    ///
+    /// #bibliography("works.bib", backrefs: true)
+    /// ```
+    #[default(false)]
+    pub backrefs: bool,
+
     /// The language setting where the bibliography is.
     #[internal]
     #[synthesized]
@@ -156,19 +210,34 @@ pub struct BibliographyElem {
 }
 
 impl BibliographyElem {
-    /// Find the document's bibliography.
-    pub fn find(introspector: Tracked<Introspector>) -> StrResult<Packed<Self>> {
-        let query = introspector.query(&Self::elem().select());
-        let mut iter = query.iter();
-        let Some(elem) = iter.next() else {
+    /// Find the document's bibliographies, each with the citation groups that
+    /// belong to it.
+    ///
+    /// A bibliography collects the citations made since the previous one.
+    /// Citations after the last bibliography belong to it as well.
+    pub fn sections(
+        introspector: Tracked<Introspector>,
+    ) -> StrResult<Vec<(Packed<Self>, EcoVec<Content>)>> {
+        let selector =
+            Selector::Or(eco_vec![Self::elem().select(), CiteGroup::elem().select()]);
+
+        let mut sections = vec![];
+        let mut groups = EcoVec::new();
+        for elem in introspector.query(&selector) {
+            match elem.to_packed::<Self>() {
+                Some(bibliography) => {
+                    sections.push((bibliography.clone(), std::mem::take(&mut groups)))
+                }
+                None => groups.push(elem),
+            }
+        }
+
+        let Some((_, last)) = sections.last_mut() else {
             bail!("the document does not contain a bibliography");
         };
 
-        if iter.next().is_some() {
-            bail!("multiple bibliographies are not yet supported");
-        }
-
-        Ok(elem.to_packed::<Self>().unwrap().clone())
+        last.extend(groups);
+        Ok(sections)
     }
 
     /// Whether the bibliography contains the given key.
@@ -180,6 +249,22 @@ impl BibliographyElem {
             .any(|elem| elem.to_packed::<Self>().unwrap().sources.derived.has(key))
     }
 
+    /// Suggests keys from the document's bibliographies that are similar to
+    /// the given, non-existent one.
+    pub fn suggest(engine: &Engine, key: Label) -> Option<EcoString> {
+        let mut similar = vec![];
+        for elem in engine.introspector.query(&Self::elem().select()).iter() {
+            let this = elem.to_packed::<Self>().unwrap();
+            for other in this.sources.derived.similar(key) {
+                if !similar.contains(&other) {
+                    similar.push(other);
+                }
+            }
+        }
+        similar.truncate(3);
+        suggestion(&similar)
+    }
+
     /// Find all bibliography keys.
     pub fn keys(introspector: Tracked<Introspector>) -> Vec<(Label, Option<EcoString>)> {
         let mut vec = vec![];
@@ -224,8 +309,16 @@ impl Show for Packed<BibliographyElem> {
         }
 
         let works = Works::generate(engine).at(span)?;
-        let references = works
-            .references
+        let Some(listing) = works.references.get(&self.location().unwrap()) else {
+            bail!(
+                span, "cannot format bibliography in isolation";
+                hint: "check whether this bibliography is measured \
+                       without being inserted into the document"
+            );
+        };
+
+        let references = listing
+            .entries
             .as_ref()
             .ok_or_else(|| match self.style(styles).source {
                 CslSource::Named(style) => eco_format!(
@@ -238,17 +331,56 @@ impl Show for Packed<BibliographyElem> {
             })
             .at(span)?;
 
-        if references.iter().any(|(prefix, _)| prefix.is_some()) {
+        let mut references: Vec<_> = references.iter().collect();
+        if let Smart::Custom(BibliographySorting::Func(func)) = self.sorting(styles) {
+            let mut keyed = vec![];
+            for reference in references {
+                let Some(entry) = self.sources.derived.get(reference.0) else { continue };
+                let work = work_dict(reference.0, entry);
+                let key =
+                    func.call(engine, Context::new(None, Some(styles)).track(), [work])?;
+                keyed.push((key, reference));
+            }
+
+            let mut error = None;
+            keyed.sort_by(|(a, _), (b, _)| {
+                ops::compare(a, b).unwrap_or_else(|err| {
+                    error.get_or_insert(err);
+                    std::cmp::Ordering::Equal
+                })
+            });
+            if let Some(err) = error {
+                bail!(span, "failed to sort bibliography: {err}");
+            }
+
+            references = keyed.into_iter().map(|(_, reference)| reference).collect();
+        }
+
+        let mut references: Vec<_> = references
+            .into_iter()
+            .map(|(key, prefix, reference)| (*key, prefix, reference.clone()))
+            .collect();
+
+        if self.backrefs(styles) {
+            for (key, _, reference) in &mut references {
+                let Some(locations) = listing.citations.get(key) else { continue };
+                if let Some(pages) = cited_pages(engine, styles, locations)? {
+                    *reference += SpaceElem::shared().clone() + pages;
+                }
+            }
+        }
+
+        if references.iter().any(|(_, prefix, _)| prefix.is_some()) {
             let row_gutter = ParElem::spacing_in(styles);
 
             let mut cells = vec![];
-            for (prefix, reference) in references {
+            for (_, prefix, reference) in references {
                 cells.push(GridChild::Item(GridItem::Cell(
                     Packed::new(GridCell::new(prefix.clone().unwrap_or_default()))
                         .spanned(span),
                 )));
                 cells.push(GridChild::Item(GridItem::Cell(
-                    Packed::new(GridCell::new(reference.clone())).spanned(span),
+                    Packed::new(GridCell::new(reference)).spanned(span),
                 )));
             }
             seq.push(
@@ -260,9 +392,8 @@ impl Show for Packed<BibliographyElem> {
                     .spanned(span),
             );
         } else {
-            for (_, reference) in references {
-                let realized = reference.clone();
-                let block = if works.hanging_indent {
+            for (_, _, realized) in references {
+                let block = if listing.hanging_indent {
                     let body = HElem::new((-INDENT).into()).pack() + realized;
                     let inset = Sides::default()
                         .with(TextElem::dir_in(styles).start(), Some(INDENT.into()));
@@ -281,6 +412,55 @@ impl Show for Packed<BibliographyElem> {
     }
 }
 
+/// Displays the pages on which a work is cited, like "Cited on pages 3, 7."
+///
+/// Each page is listed once and links to the first citation on it.
+fn cited_pages(
+    engine: &mut Engine,
+    styles: StyleChain,
+    locations: &[Location],
+) -> SourceResult<Option<Content>> {
+    let mut seen = vec![];
+    let mut pages = vec![];
+    for &loc in locations {
+        let page = engine.introspector.page(loc);
+        if seen.contains(&page) {
+            continue;
+        }
+
+        seen.push(page);
+        let numbering = engine
+            .introspector
+            .page_numbering(loc)
+            .cloned()
+            .unwrap_or_else(|| NumberingPattern::from_str("1").unwrap().into());
+        let number = engine
+            .introspector
+            .page_counter(loc)
+            .display_at_loc(engine, loc, styles, &numbering)?;
+        pages.push(number.linked(Destination::Location(loc)));
+    }
+
+    if pages.is_empty() {
+        return Ok(None);
+    }
+
+    let key = if pages.len() == 1 { "cited-on-page" } else { "cited-on-page-plural" };
+    let term = localized_str_in(styles, key).unwrap_or_else(|| {
+        localized_str(TextElem::lang_in(styles), TextElem::region_in(styles), key).into()
+    });
+
+    let mut seq = vec![TextElem::packed(term), TextElem::packed("\u{a0}")];
+    for (i, page) in pages.into_iter().enumerate() {
+        if i > 0 {
+            seq.push(TextElem::packed(", "));
+        }
+        seq.push(page);
+    }
+    seq.push(TextElem::packed("."));
+    Ok(Some(Content::sequence(seq)))
+}
+
 impl ShowSet for Packed<BibliographyElem> {
     fn show_set(&self, _: StyleChain) -> Styles {
         const INDENT: Em = Em::new(1.0);
@@ -295,6 +475,48 @@ impl LocalName for Packed<BibliographyElem> {
     const KEY: &'static str = "bibliography";
 }
 
+/// How to sort the works in a bibliography.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub enum BibliographySorting {
+    /// In the order of their first citation.
+    Citation,
+    /// By author, then by year, then by title.
+    Author,
+    /// By the value a function returns for each work.
+    Func(Func),
+}
+
+cast! {
+    BibliographySorting,
+    self => match self {
+        Self::Citation => "citation".into_value(),
+        Self::Author => "author".into_value(),
+        Self::Func(func) => func.into_value(),
+    },
+    /// In the order of their first citation.
+    "citation" => Self::Citation,
+    /// By author, then by year, then by title.
+    "author" => Self::Author,
+    v: Func => Self::Func(v),
+}
+
+/// The fields of a work that a sorting function receives.
+fn work_dict(key: Label, entry: &hayagriva::Entry) -> Value {
+    let authors = entry
+        .authors()
+        .unwrap_or_default()
+        .iter()
+        .map(|person| EcoString::from(person.name.as_str()))
+        .collect::<Vec<_>>();
+    dict! {
+        "key" => EcoString::from(key.resolve().as_str()),
+        "title" => entry.title().map(|title| eco_format!("{}", title.value)),
+        "author" => authors,
+        "year" => entry.date().map(|date| i64::from(date.year)),
+    }
+    .into_value()
+}
+
 /// A loaded bibliography.
 #[derive(Clone, PartialEq, Hash)]
 pub struct Bibliography(Arc<ManuallyHash<IndexMap<Label, hayagriva::Entry>>>);
@@ -358,6 +580,54 @@ impl Bibliography {
     fn iter(&self) -> impl Iterator<Item = (Label, &hayagriva::Entry)> {
         self.0.iter().map(|(&k, v)| (k, v))
     }
+
+    /// Finds up to three keys that are similar to the given, non-existent one,
+    /// closest first.
+    fn similar(&self, key: Label) -> Vec<Label> {
+        let key = key.resolve();
+        let key = key.as_str();
+
+        // Allow one edit for every three characters, but at least one.
+        let max = (key.chars().count() / 3).max(1);
+        let mut candidates: Vec<_> = self
+            .0
+            .keys()
+            .filter_map(|&other| {
+                let distance = edit_distance(key, other.resolve().as_str());
+                (distance <= max).then_some((distance, other))
+            })
+            .collect();
+
+        candidates.sort_by_key(|&(distance, _)| distance);
+        candidates.into_iter().take(3).map(|(_, other)| other).collect()
+    }
+}
+
+/// Formats a hint that suggests the given keys instead of a non-existent one.
+fn suggestion(similar: &[Label]) -> Option<EcoString> {
+    if similar.is_empty() {
+        return None;
+    }
+
+    let keys: Vec<_> =
+        similar.iter().map(|key| eco_format!("`{}`", key.resolve())).collect();
+    Some(eco_format!("did you mean {}?", repr::separated_list(&keys, "or")))
+}
+
+/// The Levenshtein distance between two strings, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
 }
 
 impl Debug for Bibliography {
@@ -505,6 +775,34 @@ impl CslStyle {
     pub fn get(&self) -> &citationberg::IndependentStyle {
         self.0.as_ref()
     }
+
+    /// This style with its bibliography sorted by author, year, and title, or
+    /// in citation order if `by_author` is false.
+    #[comemo::memoize]
+    fn resorted(&self, by_author: bool) -> CslStyle {
+        use citationberg::taxonomy::{
+            DateVariable, NameVariable, StandardVariable, Variable,
+        };
+        use citationberg::{Sort, SortDirection, SortKey};
+
+        let key = |variable| SortKey::Variable {
+            variable,
+            sort_direction: SortDirection::Ascending,
+        };
+
+        let mut style = self.get().clone();
+        if let Some(bibliography) = &mut style.bibliography {
+            bibliography.sort = by_author.then(|| Sort {
+                keys: vec![
+                    key(Variable::Name(NameVariable::Author)),
+                    key(Variable::Date(DateVariable::Issued)),
+                    key(Variable::Standard(StandardVariable::Title)),
+                ],
+            });
+        }
+
+        Self(Arc::new(ManuallyHash::new(style, typst_utils::hash128(&(self, by_author)))))
+    }
 }
 
 /// Source for a CSL style.
@@ -567,11 +865,21 @@ impl IntoValue for CslSource {
 pub(super) struct Works {
     /// Maps from the location of a citation group to its rendered content.
     pub citations: HashMap<Location, SourceResult<Content>>,
-    /// Lists all references in the bibliography, with optional prefix, or
-    /// `None` if the citation style can't be used for bibliographies.
-    pub references: Option<Vec<(Option<Content>, Content)>>,
+    /// Maps from the location of a bibliography to its references.
+    pub references: HashMap<Location, Listing>,
+}
+
+/// The references of one bibliography.
+pub(super) struct Listing {
+    /// Lists all references in the bibliography, with their key and optional
+    /// prefix, or `None` if the citation style can't be used for
+    /// bibliographies.
+    pub entries: Option<Vec<(Label, Option<Content>, Content)>>,
     /// Whether the bibliography should have hanging indent.
     pub hanging_indent: bool,
+    /// The locations of the visible citations of each work, in document
+    /// order.
+    pub citations: HashMap<Label, EcoVec<Location>>,
 }
 
 impl Works {
@@ -587,9 +895,22 @@ impl Works {
         world: Tracked<dyn World + '_>,
         introspector: Tracked<Introspector>,
     ) -> StrResult<Arc<Works>> {
-        let mut generator = Generator::new(routines, world, introspector)?;
-        let rendered = generator.drive();
-        let works = generator.display(&rendered)?;
+        let mut works = Works {
+            citations: HashMap::new(),
+            references: HashMap::new(),
+        };
+
+        // Each bibliography is generated on its own, so that the citations of
+        // one section don't affect the numbering or disambiguation of another.
+        for (bibliography, groups) in BibliographyElem::sections(introspector)? {
+            let location = bibliography.location().unwrap();
+            let mut generator = Generator::new(routines, world, bibliography, groups);
+            let rendered = generator.drive();
+            let (citations, listing) = generator.display(&rendered)?;
+            works.citations.extend(citations);
+            works.references.insert(location, listing);
+        }
+
         Ok(Arc::new(works))
     }
 }
@@ -600,9 +921,9 @@ struct Generator<'a> {
     routines: &'a Routines,
     /// The world that is used to evaluate mathematical material in citations.
     world: Tracked<'a, dyn World + 'a>,
-    /// The document's bibliography.
+    /// The bibliography to generate.
     bibliography: Packed<BibliographyElem>,
-    /// The document's citation groups.
+    /// The citation groups that belong to the bibliography.
     groups: EcoVec<Content>,
     /// Details about each group that are accumulated while driving hayagriva's
     /// bibliography driver and needed when processing hayagriva's output.
@@ -631,6 +952,8 @@ struct CiteInfo {
     key: Label,
     /// The citation's supplement.
     supplement: Option<Content>,
+    /// The content shown before and after the cited work.
+    affixes: (Option<Content>, Option<Content>),
     /// Whether this citation was hidden.
     hidden: bool,
 }
@@ -640,19 +963,18 @@ impl<'a> Generator<'a> {
     fn new(
         routines: &'a Routines,
         world: Tracked<'a, dyn World + 'a>,
-        introspector: Tracked<Introspector>,
-    ) -> StrResult<Self> {
-        let bibliography = BibliographyElem::find(introspector)?;
-        let groups = introspector.query(&CiteGroup::elem().select());
+        bibliography: Packed<BibliographyElem>,
+        groups: EcoVec<Content>,
+    ) -> Self {
         let infos = Vec::with_capacity(groups.len());
-        Ok(Self {
+        Self {
             routines,
             world,
             bibliography,
             groups,
             infos,
             failures: HashMap::new(),
-        })
+        }
     }
 
     /// Drives hayagriva's citation driver.
@@ -662,6 +984,15 @@ impl<'a> Generator<'a> {
 
         let database = &self.bibliography.sources.derived;
         let bibliography_style = &self.bibliography.style(StyleChain::default()).derived;
+        let bibliography_style = &match self.bibliography.sorting(StyleChain::default()) {
+            Smart::Custom(BibliographySorting::Citation) => {
+                bibliography_style.resorted(false)
+            }
+            Smart::Custom(BibliographySorting::Author) => {
+                bibliography_style.resorted(true)
+            }
+            _ => bibliography_style.clone(),
+        };
 
         // Process all citation groups.
         let mut driver = BibliographyDriver::new();
@@ -681,11 +1012,15 @@ impl<'a> Generator<'a> {
             // Create infos and items for each child in the group.
             for child in children {
                 let Some(entry) = database.get(child.key) else {
-                    errors.push(error!(
+                    let mut error = error!(
                         child.span(),
                         "key `{}` does not exist in the bibliography",
                         child.key.resolve()
-                    ));
+                    );
+                    if let Some(hint) = suggestion(&database.similar(child.key)) {
+                        error.hint(hint);
+                    }
+                    errors.push(error);
                     continue;
                 };
 
@@ -711,7 +1046,14 @@ impl<'a> Generator<'a> {
                 };
 
                 normal &= special_form.is_none();
-                subinfos.push(CiteInfo { key: child.key, supplement, hidden });
+                let affixes = (
+                    child.prefix(StyleChain::default()),
+                    child.suffix(StyleChain::default()),
+                );
+                subinfos.push(CiteInfo { key: child.key, supplement, affixes, hidden });
+                // Hayagriva's `CitationItem` has no per-item affixes, so they
+                // are attached by the `ElemRenderer` instead and CSL never
+                // sees them.
                 items.push(CitationItem::new(entry, locator, None, hidden, special_form));
             }
 
@@ -772,12 +1114,24 @@ impl<'a> Generator<'a> {
     }
 
     /// Displays hayagriva's output as content for the citations and references.
-    fn display(&mut self, rendered: &hayagriva::Rendered) -> StrResult<Works> {
+    #[allow(clippy::type_complexity)]
+    fn display(
+        &mut self,
+        rendered: &hayagriva::Rendered,
+    ) -> StrResult<(HashMap<Location, SourceResult<Content>>, Listing)> {
         let citations = self.display_citations(rendered)?;
-        let references = self.display_references(rendered)?;
+        let entries = self.display_references(rendered)?;
         let hanging_indent =
             rendered.bibliography.as_ref().is_some_and(|b| b.hanging_indent);
-        Ok(Works { citations, references, hanging_indent })
+
+        let mut locations: HashMap<Label, EcoVec<Location>> = HashMap::new();
+        for info in &self.infos {
+            for subinfo in info.subinfos.iter().filter(|sub| !sub.hidden) {
+                locations.entry(subinfo.key).or_default().push(info.location);
+            }
+        }
+
+        Ok((citations, Listing { entries, hanging_indent, citations: locations }))
     }
 
     /// Display the citation groups.
@@ -798,6 +1152,12 @@ impl<'a> Generator<'a> {
         let mut output = std::mem::take(&mut self.failures);
         for (info, citation) in self.infos.iter().zip(&rendered.citations) {
             let supplement = |i: usize| info.subinfos.get(i)?.supplement.clone();
+            let affixes = |i: usize| {
+                info.subinfos
+                    .get(i)
+                    .map(|sub| sub.affixes.clone())
+                    .unwrap_or_default()
+            };
             let link = |i: usize| {
                 links.get(info.subinfos.get(i)?.key.resolve().as_str()).copied()
             };
@@ -807,6 +1167,7 @@ impl<'a> Generator<'a> {
                 world: self.world,
                 span: info.span,
                 supplement: &supplement,
+                affixes: &affixes,
                 link: &link,
             };
 
@@ -837,7 +1198,7 @@ impl<'a> Generator<'a> {
     fn display_references(
         &self,
         rendered: &hayagriva::Rendered,
-    ) -> StrResult<Option<Vec<(Option<Content>, Content)>>> {
+    ) -> StrResult<Option<Vec<(Label, Option<Content>, Content)>>> {
         let Some(rendered) = &rendered.bibliography else { return Ok(None) };
 
         // Determine for each citation key where it first occurred, so that we
@@ -860,6 +1221,7 @@ impl<'a> Generator<'a> {
                 world: self.world,
                 span: self.bibliography.span(),
                 supplement: &|_| None,
+                affixes: &|_| (None, None),
                 link: &|_| None,
             };
 
@@ -891,7 +1253,9 @@ impl<'a> Generator<'a> {
             // we can link to the bibliography entry.
             prefix.as_mut().unwrap_or(&mut reference).set_location(backlink);
 
-            output.push((prefix, reference));
+            // Keys are non-empty, as ensured when loading the bibliography.
+            let key = Label::new(PicoStr::intern(item.key.as_str())).unwrap();
+            output.push((key, prefix, reference));
         }
 
         Ok(Some(output))
@@ -908,6 +1272,8 @@ struct ElemRenderer<'a> {
     span: Span,
     /// Resolves the supplement of i-th citation in the request.
     supplement: &'a dyn Fn(usize) -> Option<Content>,
+    /// Resolves the content before and after the i-th citation in the request.
+    affixes: &'a dyn Fn(usize) -> (Option<Content>, Option<Content>),
     /// Resolves where the i-th citation in the request should link to.
     link: &'a dyn Fn(usize) -> Option<Location>,
 }
@@ -1014,6 +1380,16 @@ impl ElemRenderer<'_> {
                 let dest = Destination::Location(location);
                 content = content.linked(dest);
             }
+
+            // The affixes are added outside of the link, so that only the
+            // cited work itself links to the bibliography.
+            let (before, after) = (self.affixes)(i);
+            if let Some(before) = before {
+                content = before + SpaceElem::shared().clone() + content;
+            }
+            if let Some(after) = after {
+                content = content + SpaceElem::shared().clone() + after;
+            }
         }
 
         Ok(content)
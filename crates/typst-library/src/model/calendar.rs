@@ -0,0 +1,375 @@
+use std::num::NonZeroUsize;
+
+use ecow::eco_format;
+use smallvec::smallvec;
+
+use crate::diag::{bail, At, HintedStrResult, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    cast, dict, elem, Cast, Content, Context, Datetime, Dict, Duration, Func, IntoValue,
+    NativeElement, Packed, Show, Smart, StyleChain,
+};
+use crate::layout::{Alignment, Celled, Sizing, TrackSizings};
+use crate::model::{TableCell, TableChild, TableElem, TableHeader, TableItem};
+use crate::text::{Lang, Region, TextElem};
+
+/// A calendar of a month.
+///
+/// The calendar is laid out as a [table] with one column per weekday and one
+/// row per week. The week starts on the day that is customary for the current
+/// [language]($text.lang) and [region]($text.region), unless configured
+/// otherwise. Since the result is a table, its appearance can be customized
+/// with set and show rules on `table` within the calendar.
+///
+/// # Example
+/// ```example
+/// #set text(lang: "de")
+/// #show calendar: set table(stroke: none)
+///
+/// #calendar(
+///   datetime(year: 2025, month: 3, day: 1),
+///   day: date => if date.weekday() == 7 {
+///     text(red, str(date.day()))
+///   } else {
+///     str(date.day())
+///   },
+/// )
+/// ```
+#[elem(Show)]
+pub struct CalendarElem {
+    /// A date within the month to show.
+    #[required]
+    pub month: Datetime,
+
+    /// The day on which the week starts.
+    ///
+    /// If set to `{auto}`, the week starts on the day that is customary in
+    /// the current region, or if no region is set, for the current language.
+    /// For instance, it starts on Sunday in the United States and on Monday
+    /// in most of Europe.
+    pub week_start: Smart<Weekday>,
+
+    /// How to display a day of the month.
+    ///
+    /// If set to `{none}`, the day's number is displayed. A function receives
+    /// the day's date and returns the content to display. This can be used to
+    /// mark holidays or list appointments.
+    pub day: Option<Func>,
+
+    /// The labels of the weekdays, starting with Monday.
+    ///
+    /// If set to `{auto}`, abbreviations of the weekdays in the current
+    /// language are used. Languages for which no abbreviations are known use
+    /// the English ones.
+    pub labels: Smart<Vec<Content>>,
+}
+
+impl Show for Packed<CalendarElem> {
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let span = self.span();
+        let (Some(year), Some(month)) = (self.month.year(), self.month.month()) else {
+            bail!(span, "calendar month must be a date");
+        };
+
+        let lang = TextElem::lang_in(styles);
+        let labels = match self.labels(styles) {
+            Smart::Custom(labels) if labels.len() == 7 => labels,
+            Smart::Custom(labels) => {
+                bail!(span, "expected 7 weekday labels, found {}", labels.len())
+            }
+            Smart::Auto => weekday_labels(lang).map(TextElem::packed).to_vec(),
+        };
+
+        let start = self
+            .week_start(styles)
+            .unwrap_or_else(|| Weekday::customary(lang, TextElem::region_in(styles)));
+        let offset = start.number() - 1;
+
+        let header = (0..7)
+            .map(|i| {
+                let label = labels[(offset + i) % 7].clone();
+                TableItem::Cell(Packed::new(TableCell::new(label)).spanned(span))
+            })
+            .collect();
+
+        let mut cells =
+            vec![TableChild::Header(Packed::new(TableHeader::new(header)).spanned(span))];
+
+        let day = self.day(styles);
+        for d in 1..=31 {
+            let Some(date) = Datetime::from_ymd(year, month, d) else { break };
+            let body = match &day {
+                Some(func) => func
+                    .call(engine, Context::new(None, Some(styles)).track(), [date])?
+                    .display(),
+                None => TextElem::packed(eco_format!("{d}")),
+            };
+
+            let mut cell = TableCell::new(body);
+            if d == 1 {
+                let weekday = date.weekday().unwrap_or(1) as usize;
+                cell.push_x(Smart::Custom((weekday + 7 - 1 - offset) % 7));
+            }
+            cells
+                .push(TableChild::Item(TableItem::Cell(Packed::new(cell).spanned(span))));
+        }
+
+        Ok(TableElem::new(cells)
+            .with_columns(TrackSizings(smallvec![Sizing::Auto; 7]))
+            .with_align(Celled::Value(Smart::Custom(Alignment::CENTER)))
+            .pack()
+            .spanned(span))
+    }
+}
+
+/// A timetable of events during a week.
+///
+/// The timetable is laid out as a [table] with a column for the times and
+/// one column per day. Each event spans the rows of the time slots it covers.
+/// Overlapping events on the same day are not supported.
+///
+/// # Example
+/// ```example
+/// #timetable(
+///   start: datetime(hour: 8, minute: 0, second: 0),
+///   end: datetime(hour: 12, minute: 0, second: 0),
+///   (
+///     day: "monday",
+///     start: datetime(hour: 8, minute: 0, second: 0),
+///     end: datetime(hour: 10, minute: 0, second: 0),
+///     body: [Algebra],
+///   ),
+///   (
+///     day: "wednesday",
+///     start: datetime(hour: 9, minute: 0, second: 0),
+///     end: datetime(hour: 10, minute: 0, second: 0),
+///     body: [Physics],
+///   ),
+/// )
+/// ```
+#[elem(Show)]
+pub struct TimetableElem {
+    /// The days to show, in order.
+    #[default(vec![
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+    ])]
+    pub days: Vec<Weekday>,
+
+    /// The time at which the first slot starts.
+    #[default(Datetime::from_hms(8, 0, 0).unwrap())]
+    pub start: Datetime,
+
+    /// The time at which the last slot ends.
+    #[default(Datetime::from_hms(18, 0, 0).unwrap())]
+    pub end: Datetime,
+
+    /// The length of a time slot, which corresponds to one row.
+    #[default(Duration::from(time::Duration::hours(1)))]
+    pub step: Duration,
+
+    /// The events.
+    ///
+    /// Each event is a dictionary with a `start` and an `end` [datetime], a
+    /// `body`, and a `day`. The day is a weekday like `{"monday"}`. It can be
+    /// omitted if `start` has a date, in which case the day is its weekday.
+    /// Events on days that are not shown are left out, and events that are
+    /// partially outside of the time range are cut off.
+    #[variadic]
+    pub events: Vec<TimetableEvent>,
+}
+
+impl Show for Packed<TimetableElem> {
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let span = self.span();
+        let days = self.days(styles);
+        let step = self.step(styles).minutes().round() as i64;
+        if step <= 0 {
+            bail!(span, "step must be at least one minute");
+        }
+
+        let lang = TextElem::lang_in(styles);
+        let first = minutes(&self.start(styles)).at(span)?;
+        let last = minutes(&self.end(styles)).at(span)?;
+        let rows = ((last - first + step - 1) / step).max(0) as usize;
+
+        let cell = |body: Content, x: usize, y: usize| {
+            let mut cell = TableCell::new(body);
+            cell.push_x(Smart::Custom(x));
+            cell.push_y(Smart::Custom(y));
+            cell
+        };
+
+        let header = std::iter::once(Content::empty())
+            .chain(
+                days.iter()
+                    .map(|day| TextElem::packed(weekday_labels(lang)[day.number() - 1])),
+            )
+            .enumerate()
+            .map(|(x, label)| {
+                TableItem::Cell(Packed::new(cell(label, x, 0)).spanned(span))
+            })
+            .collect();
+
+        let mut cells =
+            vec![TableChild::Header(Packed::new(TableHeader::new(header)).spanned(span))];
+
+        for row in 0..rows {
+            let time = first + row as i64 * step;
+            let label =
+                TextElem::packed(eco_format!("{:02}:{:02}", time / 60, time % 60));
+            let item =
+                TableItem::Cell(Packed::new(cell(label, 0, row + 1)).spanned(span));
+            cells.push(TableChild::Item(item));
+        }
+
+        for event in self.events.iter() {
+            let Some(x) = days.iter().position(|&day| day == event.day) else {
+                continue;
+            };
+
+            let start = (event.start - first).max(0) / step;
+            let end = ((event.end - first + step - 1) / step).min(rows as i64);
+            if end <= start {
+                continue;
+            }
+
+            let mut cell = cell(event.body.clone(), x + 1, start as usize + 1);
+            cell.push_rowspan(NonZeroUsize::new((end - start) as usize).unwrap());
+            cells
+                .push(TableChild::Item(TableItem::Cell(Packed::new(cell).spanned(span))));
+        }
+
+        Ok(TableElem::new(cells)
+            .with_columns(TrackSizings(smallvec![Sizing::Auto; days.len() + 1]))
+            .pack()
+            .spanned(span))
+    }
+}
+
+/// An event in a timetable.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct TimetableEvent {
+    /// The day of the event.
+    day: Weekday,
+    /// The start in minutes since midnight.
+    start: i64,
+    /// The end in minutes since midnight.
+    end: i64,
+    /// What to display.
+    body: Content,
+}
+
+cast! {
+    TimetableEvent,
+    self => {
+        let time = |m: i64| Datetime::from_hms((m / 60) as u8, (m % 60) as u8, 0);
+        dict! {
+            "day" => self.day,
+            "start" => time(self.start),
+            "end" => time(self.end),
+            "body" => self.body,
+        }.into_value()
+    },
+    mut dict: Dict => {
+        let start: Datetime = dict.take("start")?.cast()?;
+        let end: Datetime = dict.take("end")?.cast()?;
+        let day = match dict.take("day") {
+            Ok(day) => day.cast()?,
+            Err(_) => match start.weekday() {
+                Some(number) => Weekday::from_number(number),
+                None => bail!("event must have a day or a start date"),
+            },
+        };
+        let body = dict.take("body")?.cast()?;
+        dict.finish(&["day", "start", "end", "body"])?;
+        Self { day, start: minutes(&start)?, end: minutes(&end)?, body }
+    },
+}
+
+/// The minutes since midnight of a datetime with a time.
+fn minutes(datetime: &Datetime) -> HintedStrResult<i64> {
+    match (datetime.hour(), datetime.minute()) {
+        (Some(hour), Some(minute)) => Ok(i64::from(hour) * 60 + i64::from(minute)),
+        _ => bail!("datetime must have a time"),
+    }
+}
+
+/// A day of the week.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum Weekday {
+    /// The first day of the ISO week.
+    Monday,
+    /// The second day of the ISO week.
+    Tuesday,
+    /// The third day of the ISO week.
+    Wednesday,
+    /// The fourth day of the ISO week.
+    Thursday,
+    /// The fifth day of the ISO week.
+    Friday,
+    /// The sixth day of the ISO week.
+    Saturday,
+    /// The seventh day of the ISO week.
+    Sunday,
+}
+
+impl Weekday {
+    /// The weekday with the given number, counting from Monday as one.
+    fn from_number(number: u8) -> Self {
+        match number {
+            1 => Self::Monday,
+            2 => Self::Tuesday,
+            3 => Self::Wednesday,
+            4 => Self::Thursday,
+            5 => Self::Friday,
+            6 => Self::Saturday,
+            _ => Self::Sunday,
+        }
+    }
+
+    /// The number of the weekday, counting from Monday as one.
+    fn number(self) -> usize {
+        self as usize + 1
+    }
+
+    /// The day on which the week customarily starts in a region, or if no
+    /// region is given, for a language.
+    fn customary(lang: Lang, region: Option<Region>) -> Self {
+        if let Some(region) = region {
+            return match region.as_str() {
+                "AG" | "AS" | "BD" | "BR" | "BS" | "BT" | "BW" | "BZ" | "CA" | "CN"
+                | "CO" | "DM" | "DO" | "ET" | "GT" | "GU" | "HK" | "HN" | "ID" | "IL"
+                | "IN" | "JM" | "JP" | "KE" | "KH" | "KR" | "LA" | "MH" | "MM" | "MO"
+                | "MT" | "MX" | "MZ" | "NI" | "NP" | "PA" | "PE" | "PH" | "PK" | "PR"
+                | "PT" | "PY" | "SA" | "SG" | "SV" | "TH" | "TT" | "TW" | "UM" | "US"
+                | "VE" | "VI" | "WS" | "YE" | "ZA" | "ZW" => Self::Sunday,
+                "AE" | "AF" | "BH" | "DJ" | "DZ" | "EG" | "IQ" | "IR" | "JO" | "KW"
+                | "LY" | "OM" | "QA" | "SD" | "SY" => Self::Saturday,
+                _ => Self::Monday,
+            };
+        }
+
+        match lang.as_str() {
+            "en" | "he" | "ja" | "ko" | "pt" | "th" | "zh" => Self::Sunday,
+            "ar" | "fa" => Self::Saturday,
+            _ => Self::Monday,
+        }
+    }
+}
+
+/// Abbreviations of the weekdays in a language, starting with Monday.
+fn weekday_labels(lang: Lang) -> [&'static str; 7] {
+    match lang.as_str() {
+        "de" => ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+        "es" => ["lu", "ma", "mi", "ju", "vi", "sá", "do"],
+        "fr" => ["lu", "ma", "me", "je", "ve", "sa", "di"],
+        "it" => ["lu", "ma", "me", "gi", "ve", "sa", "do"],
+        "nl" => ["ma", "di", "wo", "do", "vr", "za", "zo"],
+        "pt" => ["seg", "ter", "qua", "qui", "sex", "sáb", "dom"],
+        _ => ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"],
+    }
+}
@@ -39,6 +39,36 @@ use crate::text::{Lang, Region, TextElem};
 /// >>> #bibliography("works.bib")
 /// ```
 ///
+/// # Textual and parenthetical citations
+/// The [`form`]($cite.form) of a citation determines whether it is
+/// parenthetical, like "(Doe, 2020)", or part of the text, like
+/// "Doe (2020)". Coming from LaTeX's `natbib` package, the commands map to
+/// Typst as follows:
+///
+/// | `natbib`              | Typst                                        |
+/// |-----------------------|----------------------------------------------|
+/// | `\citep{key}`         | `[@key]`                                     |
+/// | `\citet{key}`         | `{cite(<key>, form: "prose")}`               |
+/// | `\citep[p.~7]{key}`   | `[@key[p.~7]]`                               |
+/// | `\citep[see][]{key}`  | `{cite(<key>, prefix: [see])}`               |
+/// | `\citeauthor{key}`    | `{cite(<key>, form: "author")}`              |
+/// | `\citeyear{key}`      | `{cite(<key>, form: "year")}`                |
+/// | `\citeyearpar{key}`   | `[(#cite(<key>, form: "year"))]`             |
+///
+/// How each form looks is ultimately up to the citation style. In numeric
+/// styles, for instance, a prose citation shows the author followed by the
+/// number.
+///
+/// ```example
+/// #set text(lang: "en")
+/// As #cite(<arrgh>, form: "prose")
+/// shows, pirates are dangerous.
+/// This was noted before
+/// @netwok[p.~7].
+///
+/// #bibliography("works.bib", style: "apa")
+/// ```
+///
 /// # Syntax
 /// This function indirectly has dedicated syntax. [References]($ref) can be
 /// used to cite works from the bibliography. The label then corresponds to the
@@ -70,6 +100,30 @@ pub struct CiteElem {
     /// ```
     pub supplement: Option<Content>,
 
+    /// Content to show before the cited work, such as "see" or "cf.".
+    ///
+    /// Together with the [`suffix`]($cite.suffix), this allows to annotate
+    /// the individual works in a group of adjacent citations. The prefix is
+    /// separated from the work by a space.
+    ///
+    /// The affixes are added by Typst around the rendered entry rather than
+    /// by the citation style. Hence, the style does not punctuate them and
+    /// an affix may disappear when a numeric style collapses several
+    /// citations into a range like "[1–3]". If you need affixes, prefer
+    /// citing the affected works individually.
+    ///
+    /// ```example
+    /// #cite(<netwok>, prefix: [see], supplement: [pp.~14--18])
+    /// #cite(<arrgh>, prefix: [cf.])
+    ///
+    /// #bibliography("works.bib")
+    /// ```
+    pub prefix: Option<Content>,
+
+    /// Content to show after the cited work and its supplement, separated
+    /// from it by a space.
+    pub suffix: Option<Content>,
+
     /// The kind of citation to produce. Different forms are useful in different
     /// scenarios: A normal citation is useful as a source at the end of a
     /// sentence, while a "prose" citation is more suitable for inclusion in the
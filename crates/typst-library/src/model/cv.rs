@@ -0,0 +1,398 @@
+use std::cmp::Reverse;
+
+use ecow::eco_format;
+
+use crate::diag::{bail, At, HintedStrResult, SourceResult, StrResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    cast, dict, elem, scope, Content, Context, Datetime, Dict, DisplayPattern, Func,
+    IntoValue, NativeElement, Packed, Show, Smart, Str, StyleChain, Styles, Value,
+};
+use crate::layout::{BlockBody, BlockElem, Fr, HElem};
+use crate::model::{EmphElem, ParbreakElem, StrongElem};
+use crate::text::{LinebreakElem, TextElem};
+
+/// A curriculum vitae, résumé, or any other list of dated entries.
+///
+/// A CV consists of [entries]($cv.entry), each with a title, an organization,
+/// a location, a date range, tags, and a description. Entries can be written
+/// directly or loaded from a data file, since dictionaries with the same keys
+/// are accepted as entries. How the entries look is determined by a
+/// [theme]($cv.theme).
+///
+/// # Example
+/// ```example
+/// #cv(
+///   sort: true,
+///   cv.entry(
+///     [Research Assistant],
+///     organization: [University of Example],
+///     start: "2019-10",
+///     end: "2021-03",
+///   )[Worked on typesetting algorithms.],
+///   (
+///     title: "Software Engineer",
+///     organization: "Example Corp.",
+///     location: "Berlin",
+///     start: "2021-04",
+///     end: none,
+///   ),
+/// )
+/// ```
+///
+/// # Loading entries from a file
+/// When the entries are stored in a file, they can be loaded and spread into
+/// the `cv` function. With a YAML file like
+///
+/// ```yaml
+/// - title: Software Engineer
+///   organization: Example Corp.
+///   start: 2021-04
+///   end: present
+///   tags: [engineering]
+/// ```
+///
+/// the CV can be produced with `{cv(..yaml("cv.yaml"))}`. In data files, the
+/// string `{"present"}` can be used to mark an ongoing entry.
+#[elem(scope, Show)]
+pub struct CvElem {
+    /// How to display the entries.
+    ///
+    /// - `{"classic"}`: The title and organization in the first line with the
+    ///   dates at the right, then the location, and then the description.
+    /// - `{"compact"}`: The title, organization, and dates in a single line,
+    ///   without the description.
+    /// - A function: The function receives a dictionary with the entry's
+    ///   `title`, `organization`, `location`, `tags`, and `body`, as well as
+    ///   its formatted `dates`, and returns the content to display.
+    ///
+    /// ```example
+    /// #set cv(theme: entry => [
+    ///   #entry.dates: *#entry.title*
+    ///   #entry.tags.map(tag => box(
+    ///     fill: luma(230),
+    ///     inset: 2pt,
+    ///     text(0.8em, tag),
+    ///   )).join(h(2pt))
+    /// ])
+    ///
+    /// #cv(
+    ///   (title: "Typesetter", start: 2020, tags: ("print",)),
+    ///   (title: "Editor", start: 2022, tags: ("print", "web")),
+    /// )
+    /// ```
+    #[default(CvTheme::Classic)]
+    pub theme: CvTheme,
+
+    /// The [format]($datetime.display) in which dates with a month or day are
+    /// displayed. Dates that consist of only a year are always displayed as
+    /// the year.
+    #[default("[month repr:short] [year]".into())]
+    pub date_format: Str,
+
+    /// What to display as the end of an ongoing entry.
+    #[default(TextElem::packed("present"))]
+    pub ongoing: Content,
+
+    /// If given, only entries with at least one of these tags are shown.
+    ///
+    /// This allows tailoring a CV to an application from a single list of
+    /// entries.
+    ///
+    /// ```example
+    /// #cv(
+    ///   tags: ("teaching",),
+    ///   (title: "Tutor", tags: ("teaching",)),
+    ///   (title: "Developer", tags: ("engineering",)),
+    /// )
+    /// ```
+    pub tags: Option<Vec<Str>>,
+
+    /// Whether to sort the entries by their start date, with the most recent
+    /// entry first. Entries without a start date come last.
+    #[default(false)]
+    pub sort: bool,
+
+    /// The CV's entries.
+    #[variadic]
+    pub children: Vec<Packed<CvEntry>>,
+}
+
+#[scope]
+impl CvElem {
+    #[elem]
+    type CvEntry;
+}
+
+impl Show for Packed<CvElem> {
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let filter = self.tags(styles);
+        let mut entries: Vec<&Packed<CvEntry>> = self
+            .children
+            .iter()
+            .filter(|entry| {
+                filter.as_ref().is_none_or(|filter| {
+                    entry.tags(styles).iter().any(|tag| filter.contains(tag))
+                })
+            })
+            .collect();
+
+        if self.sort(styles) {
+            entries.sort_by_key(|entry| Reverse(entry.start(styles).map(|d| d.key())));
+        }
+
+        // Pass the CV's settings on to its entries.
+        let mut map = Styles::new();
+        map.set(CvElem::set_theme(self.theme(styles)));
+        map.set(CvElem::set_date_format(self.date_format(styles)));
+        map.set(CvElem::set_ongoing(self.ongoing(styles)));
+
+        Ok(Content::sequence(entries.into_iter().map(|entry| entry.clone().pack()))
+            .styled_with_map(map))
+    }
+}
+
+/// An entry in a CV.
+#[elem(name = "entry", title = "CV Entry", Show)]
+pub struct CvEntry {
+    /// The title of the entry, for instance a role or a degree.
+    #[required]
+    pub title: Content,
+
+    /// The organization, for instance a company or a university.
+    pub organization: Option<Content>,
+
+    /// Where the entry took place.
+    pub location: Option<Content>,
+
+    /// When the entry started.
+    ///
+    /// This can be a [datetime], a year as an integer, or a string in the
+    /// form `{"2021"}`, `{"2021-04"}`, or `{"2021-04-01"}`.
+    pub start: Option<CvDate>,
+
+    /// When the entry ended. Accepts the same values as `start`.
+    ///
+    /// If set to `{auto}`, the entry is a single point in time, like an award.
+    /// If set to `{none}`, the entry is ongoing.
+    pub end: Smart<Option<CvDate>>,
+
+    /// Tags for the entry, which can be used to [filter]($cv.tags) entries.
+    pub tags: Vec<Str>,
+
+    /// A description of the entry.
+    #[positional]
+    pub body: Option<Content>,
+}
+
+impl Packed<CvEntry> {
+    /// Format the entry's date range.
+    fn dates(&self, styles: StyleChain) -> SourceResult<Option<Content>> {
+        let Some(start) = self.start(styles) else { return Ok(None) };
+        let format = CvElem::date_format_in(styles);
+        let display = |date: &CvDate| -> SourceResult<Content> {
+            Ok(TextElem::packed(date.display(&format).at(self.span())?))
+        };
+
+        let dash = TextElem::packed(" – ");
+        Ok(Some(match self.end(styles) {
+            Smart::Auto => display(&start)?,
+            Smart::Custom(Some(end)) if end == start => display(&start)?,
+            Smart::Custom(Some(end)) => display(&start)? + dash + display(&end)?,
+            Smart::Custom(None) => display(&start)? + dash + CvElem::ongoing_in(styles),
+        }))
+    }
+}
+
+impl Show for Packed<CvEntry> {
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let span = self.span();
+        let dates = self.dates(styles)?;
+        let organization = self.organization(styles);
+        let location = self.location(styles);
+        let body = self.body(styles);
+
+        let heading = || {
+            let mut seq = vec![StrongElem::new(self.title.clone()).pack()];
+            if let Some(organization) = &organization {
+                seq.push(TextElem::packed(", "));
+                seq.push(organization.clone());
+            }
+            if let Some(dates) = &dates {
+                seq.push(HElem::new(Fr::one().into()).pack());
+                seq.push(dates.clone());
+            }
+            seq
+        };
+
+        let realized = match CvElem::theme_in(styles) {
+            CvTheme::Classic => {
+                let mut seq = heading();
+                if let Some(location) = location {
+                    seq.push(LinebreakElem::shared().clone());
+                    seq.push(EmphElem::new(location).pack());
+                }
+                if let Some(body) = body {
+                    seq.push(ParbreakElem::shared().clone());
+                    seq.push(body);
+                }
+                Content::sequence(seq)
+            }
+            CvTheme::Compact => Content::sequence(heading()),
+            CvTheme::Func(func) => {
+                let entry = dict! {
+                    "title" => self.title.clone(),
+                    "organization" => organization,
+                    "location" => location,
+                    "dates" => dates,
+                    "tags" => self.tags(styles),
+                    "body" => body,
+                };
+                func.call(engine, Context::new(None, Some(styles)).track(), [entry])?
+                    .display()
+            }
+        };
+
+        Ok(BlockElem::new()
+            .with_body(Some(BlockBody::Content(realized)))
+            .pack()
+            .spanned(span))
+    }
+}
+
+cast! {
+    CvEntry,
+    v: Content => v.unpack::<Self>().unwrap_or_else(Self::new),
+    v: Dict => Self::from_dict(v)?,
+}
+
+impl CvEntry {
+    /// Create an entry from a dictionary, as loaded from a data file.
+    fn from_dict(mut dict: Dict) -> HintedStrResult<Self> {
+        let mut entry = Self::new(dict.take("title")?.cast()?);
+        if let Ok(organization) = dict.take("organization") {
+            entry.push_organization(organization.cast()?);
+        }
+        if let Ok(location) = dict.take("location") {
+            entry.push_location(location.cast()?);
+        }
+        if let Ok(start) = dict.take("start") {
+            entry.push_start(start.cast()?);
+        }
+        if let Ok(end) = dict.take("end") {
+            entry.push_end(match end {
+                Value::Str(s) if s.as_str() == "present" => Smart::Custom(None),
+                end => end.cast()?,
+            });
+        }
+        if let Ok(tags) = dict.take("tags") {
+            entry.push_tags(tags.cast()?);
+        }
+        if let Ok(body) = dict.take("body") {
+            entry.push_body(body.cast()?);
+        }
+        dict.finish(&[
+            "title",
+            "organization",
+            "location",
+            "start",
+            "end",
+            "tags",
+            "body",
+        ])?;
+        Ok(entry)
+    }
+}
+
+/// How to display the entries of a CV.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub enum CvTheme {
+    /// Title, organization, and dates, followed by location and description.
+    Classic,
+    /// Title, organization, and dates in a single line.
+    Compact,
+    /// A custom function that displays an entry.
+    Func(Func),
+}
+
+cast! {
+    CvTheme,
+    self => match self {
+        Self::Classic => "classic".into_value(),
+        Self::Compact => "compact".into_value(),
+        Self::Func(func) => func.into_value(),
+    },
+    /// Title, organization, and dates, followed by location and description.
+    "classic" => Self::Classic,
+    /// Title, organization, and dates in a single line.
+    "compact" => Self::Compact,
+    v: Func => Self::Func(v),
+}
+
+/// A date in a CV, which may consist of only a year.
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub enum CvDate {
+    /// Just a year.
+    Year(i32),
+    /// A full date.
+    Date(Datetime),
+}
+
+impl CvDate {
+    /// Parse a date from a string like `"2021"`, `"2021-04"`, or
+    /// `"2021-04-01"`.
+    fn parse(text: &str) -> StrResult<Self> {
+        let invalid = || eco_format!("invalid date `{text}`");
+        let parts = text
+            .split('-')
+            .map(|part| part.trim().parse::<i32>().map_err(|_| invalid()))
+            .collect::<StrResult<Vec<_>>>()?;
+        match parts.as_slice() {
+            &[year] => Ok(Self::Year(year)),
+            &[year, month] => Self::ymd(year, month, 1).ok_or_else(invalid),
+            &[year, month, day] => Self::ymd(year, month, day).ok_or_else(invalid),
+            _ => bail!("invalid date `{text}`"),
+        }
+    }
+
+    /// Create a full date, if it is valid.
+    fn ymd(year: i32, month: i32, day: i32) -> Option<Self> {
+        let month = u8::try_from(month).ok()?;
+        let day = u8::try_from(day).ok()?;
+        Datetime::from_ymd(year, month, day).map(Self::Date)
+    }
+
+    /// A key by which dates can be sorted.
+    fn key(&self) -> (i32, u8, u8) {
+        match self {
+            Self::Year(year) => (*year, 0, 0),
+            Self::Date(date) => (
+                date.year().unwrap_or_default(),
+                date.month().unwrap_or_default(),
+                date.day().unwrap_or_default(),
+            ),
+        }
+    }
+
+    /// Display the date in the given [format]($datetime.display).
+    fn display(&self, format: &Str) -> HintedStrResult<Str> {
+        Ok(match self {
+            Self::Year(year) => eco_format!("{year}").into(),
+            Self::Date(date) => {
+                let pattern: DisplayPattern = Value::Str(format.clone()).cast()?;
+                date.display(Smart::Custom(pattern))?.into()
+            }
+        })
+    }
+}
+
+cast! {
+    CvDate,
+    self => match self {
+        Self::Year(year) => year.into_value(),
+        Self::Date(date) => date.into_value(),
+    },
+    v: i32 => Self::Year(v),
+    v: Datetime => Self::Date(v),
+    v: Str => Self::parse(&v)?,
+}
@@ -0,0 +1,161 @@
+use std::num::NonZeroUsize;
+use std::str::FromStr;
+
+use comemo::Tracked;
+use typst_utils::NonZeroExt;
+
+use crate::diag::{HintedStrResult, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    elem, func, scope, Content, Context, NativeElement, Packed, Show, StyleChain,
+};
+use crate::introspection::{Count, Counter, CounterUpdate, Locatable};
+use crate::layout::{BlockBody, BlockElem, Em, HElem};
+use crate::model::{Numbering, NumberingPattern};
+
+/// A numbered question in an exam or exercise sheet.
+///
+/// Questions can be worth a number of points. The total number of points of
+/// all questions in the document is available through the
+/// [`question.total`]($question.total) function.
+///
+/// By default, a question only displays its number and body. To also display
+/// its points, use a [show rule]($styling/#show-rules) and access the
+/// `points` field.
+///
+/// # Example
+/// ```example
+/// #show question: it => {
+///   it
+///   if it.points != none {
+///     place(top + right)[(#it.points P.)]
+///   }
+/// }
+///
+/// #question(points: 2)[
+///   What is the capital of France?
+/// ]
+/// #question(points: 3)[
+///   Name three prime numbers.
+/// ]
+///
+/// Total: #context question.total() points
+/// ```
+#[elem(scope, Locatable, Count, Show)]
+pub struct QuestionElem {
+    /// How many points the question is worth.
+    pub points: Option<f64>,
+
+    /// How to [number]($numbering) the question. Set this to `{none}` to
+    /// disable numbering.
+    ///
+    /// ```example
+    /// #set question(numbering: "a)")
+    /// #question[First]
+    /// #question[Second]
+    /// ```
+    #[default(Some(NumberingPattern::from_str("1.").unwrap().into()))]
+    #[borrowed]
+    pub numbering: Option<Numbering>,
+
+    /// The question's body.
+    #[required]
+    pub body: Content,
+}
+
+#[scope]
+impl QuestionElem {
+    /// Computes the total number of points of all questions in the document.
+    ///
+    /// Questions without points don't contribute to the total.
+    #[func(contextual)]
+    pub fn total(engine: &mut Engine, context: Tracked<Context>) -> HintedStrResult<f64> {
+        context.introspect()?;
+        Ok(engine
+            .introspector
+            .query(&Self::elem().select())
+            .iter()
+            .filter_map(|elem| elem.to_packed::<QuestionElem>())
+            .filter_map(|elem| elem.points(StyleChain::default()))
+            .sum())
+    }
+}
+
+impl Show for Packed<QuestionElem> {
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let span = self.span();
+        let mut realized = self.body.clone();
+        if let Some(numbering) = self.numbering(styles) {
+            let location = self.location().unwrap();
+            let number = Counter::of(QuestionElem::elem())
+                .display_at_loc(engine, location, styles, numbering)?
+                .spanned(span);
+            let spacing = HElem::new(Em::new(0.5).into()).with_weak(true).pack();
+            realized = number + spacing + realized;
+        }
+
+        Ok(BlockElem::new()
+            .with_body(Some(BlockBody::Content(realized)))
+            .pack()
+            .spanned(span))
+    }
+}
+
+impl Count for Packed<QuestionElem> {
+    fn update(&self) -> Option<CounterUpdate> {
+        self.numbering(StyleChain::default())
+            .is_some()
+            .then(|| CounterUpdate::Step(NonZeroUsize::ONE))
+    }
+}
+
+/// The solution to a question, which is only shown when enabled.
+///
+/// This allows compiling both the student and the solution version of an exam
+/// from the same source. Typically, the visibility of all solutions is set
+/// once at the top of the document based on an
+/// [input]($category/foundations/sys) given on the command line, like
+/// `typst compile --input solutions=true exam.typ`.
+///
+/// # Example
+/// ```example
+/// #set solution(visible: sys.inputs.at("solutions", default: "true") == "true")
+///
+/// #question[What is $2 + 2$?]
+/// #solution[$2 + 2 = 4$]
+/// ```
+#[elem(Show)]
+pub struct SolutionElem {
+    /// Whether the solution is shown.
+    #[default(false)]
+    pub visible: bool,
+
+    /// What to show instead of the solution while it is hidden, for example
+    /// some space to write down the answer.
+    ///
+    /// ```example
+    /// #set solution(placeholder: block(
+    ///   width: 100%,
+    ///   height: 1cm,
+    ///   stroke: (bottom: 0.5pt + gray),
+    /// ))
+    ///
+    /// #question[Explain why the sky is blue.]
+    /// #solution[Rayleigh scattering.]
+    /// ```
+    pub placeholder: Option<Content>,
+
+    /// The solution's body.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for Packed<SolutionElem> {
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        Ok(if self.visible(styles) {
+            self.body.clone()
+        } else {
+            self.placeholder(styles).unwrap_or_default()
+        })
+    }
+}
@@ -22,7 +22,7 @@ use crate::layout::{
 use crate::model::{
     Numbering, NumberingPattern, Outlinable, ParbreakElem, Refable, Supplement,
 };
-use crate::text::{Lang, Region, TextElem};
+use crate::text::{localized_str_in, Lang, Region, TextElem};
 use crate::visualize::ImageElem;
 
 /// A figure with an optional caption.
@@ -274,12 +274,9 @@ impl Synthesize for Packed<FigureElem> {
             Smart::Auto => {
                 // Default to the local name for the kind, if available.
                 let name = match &kind {
-                    FigureKind::Elem(func) => func
-                        .local_name(
-                            TextElem::lang_in(styles),
-                            TextElem::region_in(styles),
-                        )
-                        .map(TextElem::packed),
+                    FigureKind::Elem(func) => {
+                        func.local_name(styles).map(TextElem::packed)
+                    }
                     FigureKind::Name(_) => None,
                 };
 
@@ -430,6 +427,14 @@ impl Refable for Packed<FigureElem> {
     fn numbering(&self) -> Option<&Numbering> {
         (**self).numbering(StyleChain::default()).as_ref()
     }
+
+    fn supplement_kind(&self) -> Option<Element> {
+        // After synthesis, the kind should always be custom.
+        match (**self).kind(StyleChain::default()) {
+            Smart::Custom(FigureKind::Elem(func)) => Some(func),
+            _ => None,
+        }
+    }
 }
 
 impl Outlinable for Packed<FigureElem> {
@@ -451,7 +456,11 @@ impl Outlinable for Packed<FigureElem> {
     fn body(&self) -> Content {
         self.caption(StyleChain::default())
             .as_ref()
-            .map(|caption| caption.body.clone())
+            .map(|caption| {
+                caption
+                    .short(StyleChain::default())
+                    .unwrap_or_else(|| caption.body.clone())
+            })
             .unwrap_or_default()
     }
 }
@@ -538,6 +547,23 @@ pub struct FigureCaption {
     #[required]
     pub body: Content,
 
+    /// A shorter version of the caption to show in the [outline] instead of
+    /// the full body, like the optional argument of LaTeX's `\caption`.
+    ///
+    /// ```example
+    /// #outline(target: figure)
+    ///
+    /// #figure(
+    ///   rect[Hello],
+    ///   caption: figure.caption(
+    ///     short: [A rectangle],
+    ///     [A rectangle with a greeting, drawn to show how long captions
+    ///      can be kept out of the outline],
+    ///   ),
+    /// )
+    /// ```
+    pub short: Option<Content>,
+
     /// The figure's supplement.
     #[synthesized]
     pub kind: FigureKind,
@@ -574,10 +600,15 @@ impl FigureCaption {
 
     fn get_separator(&self, styles: StyleChain) -> Content {
         self.separator(styles).unwrap_or_else(|| {
-            TextElem::packed(Self::local_separator(
-                TextElem::lang_in(styles),
-                TextElem::region_in(styles),
-            ))
+            TextElem::packed(
+                localized_str_in(styles, "caption-separator").unwrap_or_else(|| {
+                    Self::local_separator(
+                        TextElem::lang_in(styles),
+                        TextElem::region_in(styles),
+                    )
+                    .into()
+                }),
+            )
         })
     }
 }
@@ -6,8 +6,8 @@ use typst_utils::{Get, NonZeroExt};
 use crate::diag::{warning, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    elem, Content, NativeElement, Packed, Resolve, Show, ShowSet, Smart, StyleChain,
-    Styles, Synthesize, TargetElem,
+    elem, Content, Element, NativeElement, Packed, Resolve, Show, ShowSet, Smart,
+    StyleChain, Styles, Synthesize, TargetElem,
 };
 use crate::html::{attr, tag, HtmlElem};
 use crate::introspection::{
@@ -44,6 +44,32 @@ use crate::text::{FontWeight, LocalName, SpaceElem, TextElem, TextSize};
 /// To start, ...
 /// ```
 ///
+/// # Parts and appendices
+/// The heading counter is hierarchical: Stepping a heading resets the counts
+/// of all deeper levels. If you use top-level headings for the parts of a
+/// book, chapter numbers thus restart in each part. A numbering function can
+/// decide which of the counts to display.
+///
+/// Since the numbering is an ordinary settable property, you can also switch
+/// it mid-document. To start appendices, reset the counter and apply a new
+/// pattern. Headings and references to them use the pattern in effect at the
+/// heading's position.
+///
+/// ```example
+/// #set heading(numbering: "1.1")
+///
+/// = Results <results>
+/// == Discussion
+///
+/// #counter(heading).update(0)
+/// #set heading(numbering: "A.1")
+///
+/// = Raw Data <data>
+/// == Measurements
+///
+/// See @results and @data.
+/// ```
+///
 /// # Syntax
 /// Headings have dedicated syntax: They can be created by starting a line with
 /// one or multiple equals signs, followed by a space. The number of equals
@@ -171,15 +197,39 @@ pub struct HeadingElem {
     /// The indent all but the first line of a heading should have.
     ///
     /// The default value of `{auto}` indicates that the subsequent heading
-    /// lines will be indented based on the width of the numbering.
+    /// lines will be indented based on the width of the numbering, so that
+    /// they align with the start of the heading's text. To align them with
+    /// the numbering instead, set this to `{0pt}`.
+    ///
+    /// Like all heading properties, this can be configured per level with a
+    /// show-set rule.
     ///
     /// ```example
     /// #set heading(numbering: "1.")
-    /// #heading[A very, very, very, very, very, very long heading]
+    /// #show heading.where(level: 2): set heading(hanging-indent: 0pt)
+    ///
+    /// = A very, very, very, very, very long heading
+    /// == A very, very, very, very, very long subheading
     /// ```
     #[default(Smart::Auto)]
     pub hanging_indent: Smart<Length>,
 
+    /// The space between the heading's numbering and its text.
+    ///
+    /// When exporting to HTML, the numbering and text are always separated by
+    /// a regular space.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// #show heading.where(level: 1): set heading(numbering-gap: 1em)
+    ///
+    /// = Introduction
+    /// == Motivation
+    /// ```
+    #[resolve]
+    #[default(Em::new(0.3).into())]
+    pub numbering_gap: Length,
+
     /// The heading's title.
     #[required]
     pub body: Content,
@@ -220,8 +270,6 @@ impl Show for Packed<HeadingElem> {
     fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
         let html = TargetElem::target_in(styles).is_html();
 
-        const SPACING_TO_NUMBERING: Em = Em::new(0.3);
-
         let span = self.span();
         let mut realized = self.body.clone();
 
@@ -232,6 +280,7 @@ impl Show for Packed<HeadingElem> {
         };
 
         if let Some(numbering) = (**self).numbering(styles).as_ref() {
+            let gap = self.numbering_gap(styles);
             let location = self.location().unwrap();
             let numbering = Counter::of(HeadingElem::elem())
                 .display_at_loc(engine, location, styles, numbering)?
@@ -252,13 +301,13 @@ impl Show for Packed<HeadingElem> {
                 )?
                 .size();
 
-                indent = size.x + SPACING_TO_NUMBERING.resolve(styles);
+                indent = size.x + gap;
             }
 
             let spacing = if html {
                 SpaceElem::shared().clone()
             } else {
-                HElem::new(SPACING_TO_NUMBERING.into()).with_weak(true).pack()
+                HElem::new(gap.into()).with_weak(true).pack()
             };
 
             realized = numbering + spacing + realized;
@@ -353,6 +402,10 @@ impl Refable for Packed<HeadingElem> {
     fn numbering(&self) -> Option<&Numbering> {
         (**self).numbering(StyleChain::default()).as_ref()
     }
+
+    fn supplement_kind(&self) -> Option<Element> {
+        Some(HeadingElem::elem())
+    }
 }
 
 impl Outlinable for Packed<HeadingElem> {
@@ -0,0 +1,250 @@
+use std::str::FromStr;
+
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{
+    elem, Cast, Content, NativeElement, Packed, Show, Smart, StyleChain, Styles,
+};
+use crate::introspection::{Counter, Locatable, Location};
+use crate::layout::{
+    Abs, Alignment, BlockBody, BlockElem, Fr, HAlignment, HElem, Margin, PageElem, Paper,
+    PlaceElem, Sides, VAlignment, VElem,
+};
+use crate::model::{NumberingPattern, StrongElem};
+use crate::text::{TextElem, TextSize};
+use crate::visualize::LineElem;
+
+/// A formal letter.
+///
+/// The letter sets up the page and places the sender, recipient, and
+/// information block at the positions prescribed by a letter standard, so
+/// that the recipient's address shows through the window of a matching
+/// envelope. Pages after the first one repeat the sender in their header,
+/// along with the page number.
+///
+/// The letter should be applied to the whole document with an
+/// [everything show rule]($styling/#show-rules).
+///
+/// # Example
+/// ```example
+/// #show: letter.with(
+///   sender: [Jane Doe · Main Street 1 · 12345 Town],
+///   recipient: [
+///     Example Corp. \
+///     Attn. John Smith \
+///     Market Square 2 \
+///     54321 City
+///   ],
+///   info: [
+///     Your reference: AB-123 \
+///     Date: March 3, 2025
+///   ],
+///   subject: [Termination of contract],
+/// )
+///
+/// Dear Mr. Smith,
+///
+/// #lorem(30)
+/// ```
+#[elem(Locatable, Show)]
+pub struct LetterElem {
+    /// The standard that determines the page layout and the position of the
+    /// address window.
+    #[default(LetterStandard::Din5008B)]
+    pub standard: LetterStandard,
+
+    /// The sender, in a single line.
+    ///
+    /// It is shown above the recipient within the address window and in the
+    /// header of all but the first page.
+    pub sender: Option<Content>,
+
+    /// The recipient's address.
+    pub recipient: Option<Content>,
+
+    /// An information block that is shown to the right of the address
+    /// window, for instance with the date and reference numbers.
+    pub info: Option<Content>,
+
+    /// The subject line, which is shown in bold above the body.
+    pub subject: Option<Content>,
+
+    /// Whether to show marks at the left page edge that indicate where to
+    /// fold the letter, and where to punch it for filing.
+    #[default(true)]
+    pub fold_marks: bool,
+
+    /// The letter's contents.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for Packed<LetterElem> {
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let geometry = self.standard(styles).geometry();
+        let margin = geometry.margin;
+
+        // Places content at an absolute position on the first page.
+        let at = |x: Abs, y: Abs, width: Abs, body: Content| {
+            let block = BlockElem::new()
+                .with_width(Smart::Custom(width.into()))
+                .with_body(Some(BlockBody::Content(body)))
+                .pack();
+            PlaceElem::new(block)
+                .with_alignment(Smart::Custom(Alignment::Both(
+                    HAlignment::Left,
+                    VAlignment::Top,
+                )))
+                .with_dx((x - margin.left).into())
+                .with_dy((y - margin.top).into())
+                .pack()
+        };
+
+        let window = geometry.window;
+        let mut seq = vec![];
+        let sender = self.sender(styles);
+        if let (Some(sender), true) = (&sender, geometry.zone > Abs::zero()) {
+            let small = TextElem::set_size(TextSize(Abs::pt(7.0).into()));
+            let y = window.y + geometry.zone - Abs::mm(5.0);
+            seq.push(at(window.x, y, window.width, sender.clone().styled(small)));
+        }
+
+        if let Some(recipient) = self.recipient(styles) {
+            let y = window.y + geometry.zone;
+            seq.push(at(window.x, y, window.width, recipient));
+        }
+
+        if let Some(info) = self.info(styles) {
+            let (x, y) = geometry.info;
+            seq.push(at(x, y, geometry.paper.width() - margin.right - x, info));
+        }
+
+        if self.fold_marks(styles) {
+            let x = Abs::mm(5.0);
+            let marks = geometry.folds.iter().map(|&y| (y, Abs::mm(5.0)));
+            let punch = geometry.punch.map(|y| (y, Abs::mm(7.0)));
+            for (y, length) in marks.chain(punch) {
+                let line = LineElem::new().with_length(length.into()).pack();
+                seq.push(at(x, y, length, line));
+            }
+        }
+
+        seq.push(VElem::new((geometry.body - margin.top).into()).pack());
+        if let Some(subject) = self.subject(styles) {
+            seq.push(StrongElem::new(subject).pack());
+            seq.push(VElem::new(Abs::mm(8.46).into()).pack());
+        }
+        seq.push(self.body.clone());
+
+        let header = LetterHeaderElem::new(self.location().unwrap(), sender).pack();
+        let mut page = Styles::new();
+        page.set(PageElem::set_width(Smart::Custom(geometry.paper.width().into())));
+        page.set(PageElem::set_height(Smart::Custom(geometry.paper.height().into())));
+        page.set(PageElem::set_margin(Margin {
+            sides: margin.map(|side| Some(Smart::Custom(side.into()))),
+            two_sided: None,
+        }));
+        page.set(PageElem::set_header(Smart::Custom(Some(header))));
+
+        Ok(Content::sequence(seq).styled_with_map(page))
+    }
+}
+
+/// A standard for the layout of a letter.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum LetterStandard {
+    /// DIN 5008 form A on A4 paper, with a high address window.
+    #[string("din-5008-a")]
+    Din5008A,
+    /// DIN 5008 form B on A4 paper, with a low address window.
+    #[string("din-5008-b")]
+    Din5008B,
+    /// US letter paper for a No. 10 window envelope.
+    #[string("us-10")]
+    Us10,
+}
+
+/// The positions of the parts of a letter on the first page, measured from
+/// the top left corner of the page.
+struct LetterGeometry {
+    paper: Paper,
+    margin: Sides<Abs>,
+    window: Window,
+    /// The height of the zone at the top of the address window that is
+    /// reserved for the sender.
+    zone: Abs,
+    info: (Abs, Abs),
+    /// Where the subject or body starts.
+    body: Abs,
+    folds: [Abs; 2],
+    punch: Option<Abs>,
+}
+
+/// The address window of an envelope.
+struct Window {
+    x: Abs,
+    y: Abs,
+    width: Abs,
+}
+
+impl LetterStandard {
+    /// The positions of the parts of a letter in this standard.
+    fn geometry(self) -> LetterGeometry {
+        let mm = Abs::mm;
+        let inch = Abs::inches;
+        match self {
+            Self::Din5008A | Self::Din5008B => {
+                let b = self == Self::Din5008B;
+                let top = if b { mm(45.0) } else { mm(27.0) };
+                LetterGeometry {
+                    paper: Paper::A4,
+                    margin: Sides::new(mm(25.0), mm(20.0), mm(20.0), mm(20.0)),
+                    window: Window { x: mm(20.0), y: top, width: mm(85.0) },
+                    zone: if b { mm(17.7) } else { mm(12.7) },
+                    info: (mm(125.0), top + mm(5.0)),
+                    body: top + mm(53.46),
+                    folds: if b { [mm(105.0), mm(210.0)] } else { [mm(87.0), mm(192.0)] },
+                    punch: Some(mm(148.5)),
+                }
+            }
+            Self::Us10 => LetterGeometry {
+                paper: Paper::US_LETTER,
+                margin: Sides::new(inch(1.0), inch(0.75), inch(1.0), inch(1.0)),
+                window: Window { x: inch(0.875), y: inch(2.0), width: inch(4.0) },
+                zone: Abs::zero(),
+                info: (inch(4.75), inch(0.75)),
+                body: inch(3.5),
+                folds: [inch(11.0 / 3.0), inch(22.0 / 3.0)],
+                punch: None,
+            },
+        }
+    }
+}
+
+/// The header of a letter, which is only shown on the pages after the first
+/// one.
+#[elem(Locatable, Show)]
+struct LetterHeaderElem {
+    /// The location of the letter.
+    #[required]
+    letter: Location,
+
+    /// The sender.
+    #[required]
+    sender: Option<Content>,
+}
+
+impl Show for Packed<LetterHeaderElem> {
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let location = self.location().unwrap();
+        if engine.introspector.page(location) <= engine.introspector.page(self.letter) {
+            return Ok(Content::empty());
+        }
+
+        let numbering = NumberingPattern::from_str("1").unwrap().into();
+        let number = Counter::of(PageElem::elem())
+            .display_at_loc(engine, location, styles, &numbering)?;
+        let fill = HElem::new(Fr::one().into()).pack();
+        Ok(self.sender.clone().unwrap_or_default() + fill + number)
+    }
+}
@@ -1,14 +1,18 @@
 //! Structuring elements that define the document model.
 
 mod bibliography;
+mod calendar;
 mod cite;
+mod cv;
 mod document;
 mod emph;
 #[path = "enum.rs"]
 mod enum_;
+mod exam;
 mod figure;
 mod footnote;
 mod heading;
+mod letter;
 mod link;
 mod list;
 #[path = "numbering.rs"]
@@ -17,18 +21,23 @@ mod outline;
 mod par;
 mod quote;
 mod reference;
+mod slide;
 mod strong;
 mod table;
 mod terms;
 
 pub use self::bibliography::*;
+pub use self::calendar::*;
 pub use self::cite::*;
+pub use self::cv::*;
 pub use self::document::*;
 pub use self::emph::*;
 pub use self::enum_::*;
+pub use self::exam::*;
 pub use self::figure::*;
 pub use self::footnote::*;
 pub use self::heading::*;
+pub use self::letter::*;
 pub use self::link::*;
 pub use self::list::*;
 pub use self::numbering_::*;
@@ -36,6 +45,7 @@ pub use self::outline::*;
 pub use self::par::*;
 pub use self::quote::*;
 pub use self::reference::*;
+pub use self::slide::*;
 pub use self::strong::*;
 pub use self::table::*;
 pub use self::terms::*;
@@ -63,6 +73,15 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<TermsElem>();
     global.define_elem::<EmphElem>();
     global.define_elem::<StrongElem>();
+    global.define_elem::<QuestionElem>();
+    global.define_elem::<SolutionElem>();
+    global.define_elem::<SlideElem>();
+    global.define_elem::<UncoverElem>();
+    global.define_elem::<OnlyElem>();
+    global.define_elem::<LetterElem>();
+    global.define_elem::<CvElem>();
+    global.define_elem::<CalendarElem>();
+    global.define_elem::<TimetableElem>();
     global.define_func::<numbering>();
     global.reset_category();
 }
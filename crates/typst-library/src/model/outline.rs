@@ -13,12 +13,10 @@ use crate::foundations::{
     LocatableSelector, NativeElement, Packed, Resolve, Show, ShowSet, Smart, StyleChain,
     Styles,
 };
-use crate::introspection::{
-    Counter, CounterKey, Introspector, Locatable, Location, Locator, LocatorLink,
-};
+use crate::introspection::{Introspector, Locatable, Location, Locator, LocatorLink};
 use crate::layout::{
-    Abs, Axes, BlockBody, BlockElem, BoxElem, Dir, Em, Fr, HElem, Length, Region, Rel,
-    RepeatElem, Sides,
+    Abs, Axes, BlockBody, BlockElem, BoxElem, Dir, Em, Fr, HAlignment, HElem, Length,
+    Region, Rel, RepeatElem, Sides,
 };
 use crate::math::EquationElem;
 use crate::model::{Destination, HeadingElem, NumberingPattern, ParElem, Refable};
@@ -205,6 +203,25 @@ pub struct OutlineElem {
     /// ```
     pub depth: Option<NonZeroUsize>,
 
+    /// A function that decides which elements to include in the outline.
+    ///
+    /// The function receives the [entry]($outline.entry) that would be
+    /// generated for an element, with its [`level`]($outline.entry.level) and
+    /// [`element`]($outline.entry.element), and returns whether to include it.
+    /// It only sees elements that pass the [`depth`]($outline.depth) limit.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// #outline(filter: it => (
+    ///   it.level == 1 or it.element.numbering != none
+    /// ))
+    ///
+    /// = Introduction
+    /// == Background
+    /// #heading(level: 2, numbering: none)[Aside]
+    /// ```
+    pub filter: Option<Func>,
+
     /// How to indent the outline's entries.
     ///
     /// - `{auto}`: Indents the numbering/prefix of a nested entry with the
@@ -270,6 +287,7 @@ impl Show for Packed<OutlineElem> {
 
         let elems = engine.introspector.query(&self.target(styles).0);
         let depth = self.depth(styles).unwrap_or(NonZeroUsize::MAX);
+        let filter = self.filter(styles);
 
         // Build the outline entries.
         for elem in elems {
@@ -278,10 +296,23 @@ impl Show for Packed<OutlineElem> {
             };
 
             let level = outlinable.level();
-            if outlinable.outlined() && level <= depth {
-                let entry = OutlineEntry::new(level, elem);
-                seq.push(entry.pack().spanned(span));
+            if !outlinable.outlined() || level > depth {
+                continue;
+            }
+
+            let entry = OutlineEntry::new(level, elem.clone()).pack().spanned(span);
+            if let Some(filter) = &filter {
+                let context = Context::new(elem.location(), Some(styles));
+                let include = filter
+                    .call(engine, context.track(), [entry.clone()])?
+                    .cast::<bool>()
+                    .at(span)?;
+                if !include {
+                    continue;
+                }
             }
+
+            seq.push(entry);
         }
 
         Ok(Content::sequence(seq))
@@ -395,6 +426,17 @@ pub struct OutlineEntry {
     ///
     /// = A New Beginning
     /// ```
+    ///
+    /// To use a different fill for each level, combine a show rule that
+    /// selects the level with a set rule:
+    ///
+    /// ```example
+    /// #show outline.entry.where(level: 1): set outline.entry(fill: none)
+    /// #outline()
+    ///
+    /// = Chapter
+    /// == Section
+    /// ```
     #[borrowed]
     #[default(Some(
         RepeatElem::new(TextElem::packed("."))
@@ -403,6 +445,35 @@ pub struct OutlineEntry {
     ))]
     pub fill: Option<Content>,
 
+    /// The space between the fill and the page number.
+    ///
+    /// If set to `{auto}`, a regular word space is used.
+    ///
+    /// ```example
+    /// #set outline.entry(page-gap: 0pt)
+    /// #outline()
+    ///
+    /// = A Tight Fit
+    /// ```
+    pub page_gap: Smart<Length>,
+
+    /// The width reserved for the page number at the end of each entry.
+    ///
+    /// If set to a length, page numbers are aligned to the end of a column of
+    /// that width, so that the fills of all entries end at the same position,
+    /// regardless of how many digits the page numbers have. If set to
+    /// `{none}`, the fill extends right up to each page number.
+    ///
+    /// ```example
+    /// #set outline.entry(page-width: 2em)
+    /// #outline()
+    ///
+    /// = Introduction
+    /// #counter(page).update(99)
+    /// = Conclusion
+    /// ```
+    pub page_width: Option<Length>,
+
     /// Lets outline entries access the outline they are part of. This is a bit
     /// of a hack and should be superseded by a proper ancestry mechanism.
     #[ghost]
@@ -605,7 +676,12 @@ impl OutlineEntry {
                     .pack()
                     .spanned(span),
             );
-            seq.push(SpaceElem::shared().clone());
+            match self.page_gap(styles) {
+                Smart::Auto => seq.push(SpaceElem::shared().clone()),
+                Smart::Custom(gap) => {
+                    seq.push(HElem::new(gap.into()).pack().spanned(span))
+                }
+            }
         } else {
             seq.push(HElem::new(Fr::one().into()).pack().spanned(span));
         }
@@ -613,7 +689,17 @@ impl OutlineEntry {
         // Add the page number. The word joiner in front ensures that the page
         // number doesn't stand alone in its line.
         seq.push(TextElem::packed("\u{2060}"));
-        seq.push(self.page(engine, context, span)?);
+        let page = self.page(engine, context, span)?;
+        match self.page_width(styles) {
+            Some(width) => seq.push(
+                BoxElem::new()
+                    .with_body(Some(page.aligned(HAlignment::End.into())))
+                    .with_width(width.into())
+                    .pack()
+                    .spanned(span),
+            ),
+            None => seq.push(page),
+        }
 
         Ok(Content::sequence(seq))
     }
@@ -643,7 +729,10 @@ impl OutlineEntry {
             .page_numbering(loc)
             .cloned()
             .unwrap_or_else(|| NumberingPattern::from_str("1").unwrap().into());
-        Counter::new(CounterKey::Page).display_at_loc(engine, loc, styles, &numbering)
+        engine
+            .introspector
+            .page_counter(loc)
+            .display_at_loc(engine, loc, styles, &numbering)
     }
 }
 
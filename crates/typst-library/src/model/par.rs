@@ -1,14 +1,17 @@
+use std::num::NonZeroUsize;
+
 use typst_utils::singleton;
 
 use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, dict, elem, scope, Args, Cast, Construct, Content, Dict, NativeElement, Packed,
-    Smart, Unlabellable, Value,
+    cast, dict, elem, scope, Args, Cast, Construct, Content, Dict, Element, IntoValue,
+    NativeElement, Packed, Smart, StyleChain, Synthesize, Unlabellable, Value,
 };
-use crate::introspection::{Count, CounterUpdate, Locatable};
-use crate::layout::{Em, HAlignment, Length, OuterHAlignment};
-use crate::model::Numbering;
+use crate::introspection::{Count, Counter, CounterUpdate, Locatable};
+use crate::layout::{Em, HAlignment, Length, OuterHAlignment, Ratio};
+use crate::model::{Numbering, Refable, Supplement};
+use crate::text::{LocalName, TextElem};
 
 /// A logical subdivison of textual content.
 ///
@@ -74,6 +77,24 @@ use crate::model::Numbering;
 /// word. This is, for example, what [non-`tight`]($list.tight) lists do to
 /// force their items to become paragraphs.
 ///
+/// # Numbered paragraphs
+/// Some documents, like contracts or statutes, number their paragraphs so that
+/// they can be cited precisely. When a paragraph [`numbering`]($par.numbering)
+/// is set, each paragraph is prefixed with its number. A paragraph that is
+/// created explicitly with the `par` function can also be [labelled]($label)
+/// and then [referenced]($ref) like a heading or figure. The paragraph counter
+/// is available as `{counter(par)}`.
+///
+/// ```example
+/// #set par(numbering: "§1", hanging-indent: 1.5em)
+///
+/// #par[The parties agree on the terms
+/// below.] <terms>
+///
+/// Any dispute arising from @terms is
+/// settled amicably.
+/// ```
+///
 /// # Example
 /// ```example
 /// #set par(
@@ -93,7 +114,9 @@ use crate::model::Numbering;
 /// let $a$ be the smallest of the
 /// three integers. Then, we ...
 /// ```
-#[elem(scope, title = "Paragraph")]
+// Paragraphs are locatable even without numbering because they serve as
+// anchors that keep a preview's scroll position across recompilations.
+#[elem(scope, title = "Paragraph", Locatable, Synthesize, Count, LocalName, Refable)]
 pub struct ParElem {
     /// The spacing between lines.
     ///
@@ -138,6 +161,38 @@ pub struct ParElem {
     #[default(false)]
     pub justify: bool,
 
+    /// Whether to also justify the last line of a justified paragraph.
+    ///
+    /// - `{false}`: The last line is not justified. This is the default.
+    /// - `{true}`: The last line is justified like all others, which can be
+    ///   useful for short, centered blocks of text like epigraphs.
+    /// - A [ratio]: The last line is only justified if it already fills at
+    ///   least this ratio of the available width. This way, a last line that
+    ///   is nearly full doesn't stick out.
+    ///
+    /// The same applies to lines that end with a [line break]($linebreak).
+    /// This has no effect unless [`justify`]($par.justify) is enabled.
+    ///
+    /// ```example
+    /// #set par(justify: true, justify-last: 80%)
+    /// #lorem(12)
+    ///
+    /// #lorem(14)
+    /// ```
+    #[default(JustifyLast::Never)]
+    pub justify_last: JustifyLast,
+
+    /// How to align the last line of a paragraph if it isn't justified.
+    ///
+    /// When set to `{auto}`, the last line follows the current
+    /// [alignment]($align.alignment) like all other lines.
+    ///
+    /// ```example
+    /// #set par(justify: true, last-line: center)
+    /// #lorem(16)
+    /// ```
+    pub last_line: Smart<HAlignment>,
+
     /// How to determine line breaks.
     ///
     /// When this property is set to `{auto}`, its default value, optimized line
@@ -161,6 +216,24 @@ pub struct ParElem {
     /// ```
     pub linebreaks: Smart<Linebreaks>,
 
+    /// Below which width text is hyphenated even if it isn't justified.
+    ///
+    /// When set to a length, text with [automatic hyphenation]($text.hyphenate)
+    /// is also hyphenated in paragraphs and containers narrower than this
+    /// width, like table cells. If a word still doesn't fit, a warning is
+    /// emitted. When set to `{none}`, automatic hyphenation only depends on
+    /// justification.
+    ///
+    /// ```example
+    /// #set par(hyphenate-below: 15em)
+    /// #table(
+    ///   columns: (50pt, 1fr),
+    ///   [Extraordinarily long words], [Fine],
+    /// )
+    /// ```
+    #[resolve]
+    pub hyphenate_below: Option<Length>,
+
     /// The indent the first line of a paragraph should have.
     ///
     /// By default, only the first line of a consecutive paragraph will be
@@ -216,6 +289,39 @@ pub struct ParElem {
     #[resolve]
     pub hanging_indent: Length,
 
+    /// How to [number]($numbering) paragraphs.
+    ///
+    /// When set, the number is displayed at the start of each paragraph's
+    /// first line and paragraphs can be referenced. To display the number
+    /// differently, you can write a `par` show rule and use
+    /// `{counter(par)}` to access it.
+    ///
+    /// ```example
+    /// #set par(numbering: "1.")
+    ///
+    /// #lorem(8)
+    ///
+    /// #lorem(10)
+    /// ```
+    pub numbering: Option<Numbering>,
+
+    /// A supplement for the paragraph.
+    ///
+    /// For references to paragraphs, this is added before the referenced
+    /// number.
+    ///
+    /// If a function is specified, it is passed the referenced paragraph and
+    /// should return content.
+    ///
+    /// ```example
+    /// #set par(numbering: "1", supplement: [Para.])
+    ///
+    /// #par[#lorem(6)] <intro>
+    ///
+    /// As stated in @intro, ...
+    /// ```
+    pub supplement: Smart<Option<Supplement>>,
+
     /// The contents of the paragraph.
     #[required]
     pub body: Content,
@@ -227,6 +333,65 @@ impl ParElem {
     type ParLine;
 }
 
+impl Synthesize for Packed<ParElem> {
+    fn synthesize(
+        &mut self,
+        engine: &mut Engine,
+        styles: StyleChain,
+    ) -> SourceResult<()> {
+        // Only numbered paragraphs can be referenced, so there is no need to
+        // resolve the supplement of all others.
+        if (**self).numbering(styles).is_none() {
+            return Ok(());
+        }
+
+        let supplement = match self.as_ref().supplement(styles) {
+            Smart::Auto => TextElem::packed(Self::local_name_in(styles)),
+            Smart::Custom(None) => Content::empty(),
+            Smart::Custom(Some(supplement)) => {
+                supplement.resolve(engine, styles, [self.clone().pack()])?
+            }
+        };
+
+        self.push_supplement(Smart::Custom(Some(Supplement::Content(supplement))));
+        Ok(())
+    }
+}
+
+impl Count for Packed<ParElem> {
+    fn update(&self) -> Option<CounterUpdate> {
+        self.numbering()
+            .is_some()
+            .then(|| CounterUpdate::Step(NonZeroUsize::ONE))
+    }
+}
+
+impl LocalName for Packed<ParElem> {
+    const KEY: &'static str = "paragraph";
+}
+
+impl Refable for Packed<ParElem> {
+    fn supplement(&self) -> Content {
+        // After synthesis, this should always be custom content.
+        match (**self).supplement(StyleChain::default()) {
+            Smart::Custom(Some(Supplement::Content(content))) => content,
+            _ => Content::empty(),
+        }
+    }
+
+    fn counter(&self) -> Counter {
+        Counter::of(ParElem::elem())
+    }
+
+    fn numbering(&self) -> Option<&Numbering> {
+        (**self).numbering(StyleChain::default()).as_ref()
+    }
+
+    fn supplement_kind(&self) -> Option<Element> {
+        Some(ParElem::elem())
+    }
+}
+
 /// How to determine line breaks in a paragraph.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
 pub enum Linebreaks {
@@ -239,6 +404,29 @@ pub enum Linebreaks {
     Optimized,
 }
 
+/// Whether to justify the last line of a justified paragraph.
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub enum JustifyLast {
+    /// Never justify the last line.
+    Never,
+    /// Always justify the last line.
+    Always,
+    /// Justify the last line if it fills at least the given ratio of the
+    /// available width.
+    Close(Ratio),
+}
+
+cast! {
+    JustifyLast,
+    self => match self {
+        Self::Never => false.into_value(),
+        Self::Always => true.into_value(),
+        Self::Close(ratio) => ratio.into_value(),
+    },
+    v: bool => if v { Self::Always } else { Self::Never },
+    v: Ratio => Self::Close(v),
+}
+
 /// Configuration for first line indent.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Hash)]
 pub struct FirstLineIndent {
@@ -1,18 +1,18 @@
 use comemo::Track;
-use ecow::eco_format;
+use ecow::{eco_format, eco_vec, EcoString};
 
-use crate::diag::{bail, At, Hint, SourceResult};
+use crate::diag::{bail, error, At, Hint, HintedStrResult, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, Cast, Content, Context, Func, IntoValue, Label, NativeElement, Packed,
-    Repr, Show, Smart, StyleChain, Synthesize,
+    cast, dict, elem, Cast, Content, Context, Dict, Element, Func, IntoValue, Label,
+    NativeElement, Packed, Repr, Selector, Show, Smart, StyleChain, Synthesize, Value,
 };
-use crate::introspection::{Counter, CounterKey, Locatable};
+use crate::introspection::{Counter, CounterState, Locatable, Location};
 use crate::math::EquationElem;
 use crate::model::{
     BibliographyElem, CiteElem, Destination, Figurable, FootnoteElem, Numbering,
 };
-use crate::text::TextElem;
+use crate::text::{SpaceElem, TextElem};
 
 /// A reference to a label or bibliography.
 ///
@@ -189,6 +189,73 @@ pub struct RefElem {
     #[default(RefForm::Normal)]
     pub form: RefForm,
 
+    /// The last target of a range of references.
+    ///
+    /// The reference then shows the numbers of both targets, separated by an
+    /// en dash, with the plural of the supplement if it is known for the
+    /// current [language]($text.lang). This only applies to `{"normal"}`
+    /// references to elements in the document.
+    ///
+    /// ```example
+    /// #set math.equation(numbering: "(1)")
+    ///
+    /// $ a $ <a>
+    /// $ b $ <b>
+    /// $ c $ <c>
+    ///
+    /// See #ref(<a>, to: <c>).
+    /// ```
+    pub to: Option<Label>,
+
+    /// Whether to collapse adjacent references.
+    ///
+    /// When enabled, `{"normal"}` references to elements of the same kind
+    /// that are only separated by spaces are shown as a single reference with
+    /// the plural of the supplement, if it is known for the current
+    /// [language]($text.lang). Runs of at least three consecutively numbered
+    /// elements are shown as a range. References with a custom supplement,
+    /// like `[@intro[Part]]`, are not collapsed.
+    ///
+    /// ```example
+    /// #set math.equation(numbering: "(1)")
+    /// #set ref(collapse: true)
+    ///
+    /// $ a $ <a>
+    /// $ b $ <b>
+    /// $ c $ <c>
+    /// $ d $ <d>
+    ///
+    /// See @a @b @c and @a @d.
+    /// ```
+    #[default(false)]
+    pub collapse: bool,
+
+    /// Reference targets from other documents of the same project.
+    ///
+    /// This allows you to compile a single chapter of a large book on its own
+    /// while keeping references to other chapters intact. The dictionary maps
+    /// label names to targets with the keys `supplement`, `numbers`, and
+    /// `page`, where `numbers` and `page` are the already formatted numbers of
+    /// the target and of its page.
+    /// A reference to a label that is not defined in the document is then
+    /// resolved from this dictionary instead.
+    ///
+    /// The CLI writes such a dictionary for all labelled, numbered elements
+    /// in a document when you pass `--save-state book.json` to
+    /// `typst compile`. To iterate on a single chapter, compile the whole
+    /// book once with this flag and then load the result in the chapter:
+    ///
+    /// ```typ
+    /// #set ref(external: json("book.json").labels)
+    /// ```
+    ///
+    /// As the targets are not part of the document, references to them are
+    /// not linked. Only labels and page numbers are carried over this way:
+    /// Counters still start from zero in the chapter, and citations are not
+    /// resolved from the book's bibliography.
+    #[borrowed]
+    pub external: Dict,
+
     /// A synthesized citation.
     #[synthesized]
     pub citation: Option<Packed<CiteElem>>,
@@ -229,6 +296,12 @@ impl Show for Packed<RefElem> {
 
         let form = self.form(styles);
         if form == RefForm::Page {
+            if elem.is_err() {
+                if let Some(content) = self.show_external(engine, styles, form)? {
+                    return Ok(content);
+                }
+            }
+
             let elem = elem.at(span)?;
             let elem = elem.clone();
 
@@ -242,12 +315,13 @@ impl Show for Packed<RefElem> {
                 ))
                 .at(span)?;
             let supplement = engine.introspector.page_supplement(loc);
+            let counter = engine.introspector.page_counter(loc);
 
             return show_reference(
                 self,
                 engine,
                 styles,
-                Counter::new(CounterKey::Page),
+                counter,
                 numbering.clone(),
                 supplement,
                 elem,
@@ -270,26 +344,148 @@ impl Show for Packed<RefElem> {
             return Ok(to_citation(self, engine, styles)?.pack().spanned(span));
         }
 
-        let elem = elem.at(span)?;
+        if elem.is_err() {
+            if let Some(content) = self.show_external(engine, styles, form)? {
+                return Ok(content);
+            }
+        }
+
+        let elem = match elem {
+            Ok(elem) => elem,
+            Err(message) => {
+                let mut error = error!(span, "{message}");
+                if let Some(hint) = BibliographyElem::suggest(engine, self.target) {
+                    error.hint(hint);
+                }
+                return Err(eco_vec![error]);
+            }
+        };
 
         if let Some(footnote) = elem.to_packed::<FootnoteElem>() {
             return Ok(footnote.into_ref(self.target).pack().spanned(span));
         }
 
-        let elem = elem.clone();
-        let refable = elem
-            .with::<dyn Refable>()
-            .ok_or_else(|| {
-                if elem.can::<dyn Figurable>() {
-                    eco_format!(
-                        "cannot reference {} directly, try putting it into a figure",
-                        elem.func().name()
-                    )
-                } else {
-                    eco_format!("cannot reference {}", elem.func().name())
-                }
-            })
-            .at(span)?;
+        let target = Target::new(elem.clone()).at(span)?;
+
+        if let Some(to) = self.to(styles) {
+            let last = engine.introspector.query_label(to).at(span)?.clone();
+            let last = Target::new(last).at(span)?;
+            return show_multiple(self, engine, styles, &[(&target, &last)]);
+        }
+
+        if self.collapse(styles) && self.supplement(styles).is_auto() {
+            return Ok(CollapsibleRef::new(self.clone()).pack().spanned(span));
+        }
+
+        show_reference(
+            self,
+            engine,
+            styles,
+            target.counter,
+            target.numbering,
+            target.supplement,
+            target.elem,
+        )
+    }
+}
+
+/// A reference that may be collapsed with adjacent ones.
+///
+/// This is produced by the built-in show rule of references when
+/// [`collapse`]($ref.collapse) is enabled and grouped into a [`RefGroup`]
+/// during realization.
+#[elem]
+pub struct CollapsibleRef {
+    /// The reference.
+    #[required]
+    pub reference: Packed<RefElem>,
+}
+
+/// A group of adjacent references.
+///
+/// This is automatically created from adjacent collapsible references during
+/// show rule application.
+#[elem(Show)]
+pub struct RefGroup {
+    /// The references.
+    #[required]
+    pub children: Vec<Packed<RefElem>>,
+}
+
+impl Show for Packed<RefGroup> {
+    #[typst_macros::time(name = "ref", span = self.span())]
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let mut targets = vec![];
+        for child in &self.children {
+            let elem = engine.introspector.query_label(child.target).at(child.span())?;
+            targets.push(Target::new(elem.clone()).at(child.span())?);
+        }
+
+        // Collapse runs of references to elements of the same kind and keep
+        // the others apart.
+        let mut seq = vec![];
+        let mut i = 0;
+        while i < targets.len() {
+            let first = &targets[i];
+            let len = 1 + targets[i + 1..]
+                .iter()
+                .take_while(|target| target.same_kind(first))
+                .count();
+
+            if !seq.is_empty() {
+                seq.push(SpaceElem::shared().clone());
+            }
+
+            let reference = &self.children[i];
+            seq.push(if len == 1 {
+                show_reference(
+                    reference,
+                    engine,
+                    styles,
+                    first.counter.clone(),
+                    first.numbering.clone(),
+                    first.supplement.clone(),
+                    first.elem.clone(),
+                )?
+            } else {
+                let ranges = consecutive_ranges(engine, &targets[i..i + len])?;
+                show_multiple(reference, engine, styles, &ranges)?
+            });
+
+            i += len;
+        }
+
+        Ok(Content::sequence(seq))
+    }
+}
+
+/// A referenceable element in the document.
+struct Target {
+    /// The element itself.
+    elem: Content,
+    /// The element's counter.
+    counter: Counter,
+    /// How the element is numbered.
+    numbering: Numbering,
+    /// The element's supplement.
+    supplement: Content,
+    /// The element whose localized name is the default supplement.
+    kind: Option<Element>,
+}
+
+impl Target {
+    /// Resolve the target from an element, which must be referenceable.
+    fn new(elem: Content) -> HintedStrResult<Self> {
+        let refable = elem.with::<dyn Refable>().ok_or_else(|| {
+            if elem.can::<dyn Figurable>() {
+                eco_format!(
+                    "cannot reference {} directly, try putting it into a figure",
+                    elem.func().name()
+                )
+            } else {
+                eco_format!("cannot reference {}", elem.func().name())
+            }
+        })?;
 
         let numbering = refable
             .numbering()
@@ -304,21 +500,203 @@ impl Show for Packed<RefElem> {
                 } else {
                     elem.func().name()
                 }
-            ))
-            .at(span)?;
-
-        show_reference(
-            self,
-            engine,
-            styles,
-            refable.counter(),
-            numbering.clone(),
-            refable.supplement(),
+            ))?
+            .clone();
+
+        Ok(Self {
+            counter: refable.counter(),
+            supplement: refable.supplement(),
+            kind: refable.supplement_kind(),
+            numbering,
             elem,
-        )
+        })
+    }
+
+    /// Whether references to this and the other target can be collapsed.
+    fn same_kind(&self, other: &Self) -> bool {
+        self.counter == other.counter
+            && self.numbering == other.numbering
+            && self.supplement == other.supplement
+    }
+
+    /// The plural of the supplement, if the supplement is the localized name
+    /// of the target's kind and the plural is known.
+    fn plural_supplement(&self, styles: StyleChain) -> Option<Content> {
+        let kind = self.kind?;
+        if kind.local_name(styles)? != self.supplement.plain_text() {
+            return None;
+        }
+        kind.local_plural(styles).map(TextElem::packed)
+    }
+
+    /// Display the target's numbers, linked to the element.
+    fn display(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let loc = self.elem.location().unwrap();
+        self.counter
+            .display_at_loc(engine, loc, styles, &self.numbering.trimmed())
+            .map(|numbers| numbers.linked(Destination::Location(loc)))
+    }
+}
+
+/// Split targets of the same kind into ranges of consecutively numbered
+/// elements. Only runs of at least three elements form a range, while shorter
+/// runs are split into ranges of single targets.
+fn consecutive_ranges<'a>(
+    engine: &mut Engine,
+    targets: &'a [Target],
+) -> SourceResult<Vec<(&'a Target, &'a Target)>> {
+    let mut states = vec![];
+    for target in targets {
+        states.push(target.counter.at_loc(engine, target.elem.location().unwrap())?);
+    }
+
+    // Whether the number `next` directly follows `prev` on the same level.
+    let follows = |prev: &CounterState, next: &CounterState| match (
+        prev.0.split_last(),
+        next.0.split_last(),
+    ) {
+        (Some((a, outer_a)), Some((b, outer_b))) => outer_a == outer_b && a + 1 == *b,
+        _ => false,
+    };
+
+    let mut ranges = vec![];
+    let mut start = 0;
+    while start < targets.len() {
+        let mut end = start;
+        while end + 1 < targets.len() && follows(&states[end], &states[end + 1]) {
+            end += 1;
+        }
+
+        if end - start >= 2 {
+            ranges.push((&targets[start], &targets[end]));
+        } else {
+            ranges.extend(targets[start..=end].iter().map(|target| (target, target)));
+        }
+
+        start = end + 1;
+    }
+
+    Ok(ranges)
+}
+
+impl Packed<RefElem> {
+    /// Show a reference to a target from another document, if the target is
+    /// listed in the `external` dictionary.
+    fn show_external(
+        &self,
+        engine: &mut Engine,
+        styles: StyleChain,
+        form: RefForm,
+    ) -> SourceResult<Option<Content>> {
+        let Ok(target) = self.external(styles).get(&self.target.resolve()) else {
+            return Ok(None);
+        };
+
+        let target = target.clone().cast::<ExternalTarget>().at(self.span())?;
+        let (supplement, numbers) = match form {
+            RefForm::Normal => (target.supplement, target.numbers),
+            RefForm::Page => (
+                self.location()
+                    .map(|loc| engine.introspector.page_supplement(loc))
+                    .unwrap_or_default(),
+                target.page,
+            ),
+        };
+
+        let supplement = match self.supplement(styles).as_ref() {
+            Smart::Auto => supplement,
+            Smart::Custom(None) => Content::empty(),
+            Smart::Custom(Some(supplement)) => {
+                supplement.resolve(engine, styles, [Value::None])?
+            }
+        };
+
+        let mut content = numbers;
+        if !supplement.is_empty() {
+            content = supplement + TextElem::packed("\u{a0}") + content;
+        }
+
+        Ok(Some(content))
     }
 }
 
+/// Collect the labelled, numbered elements of a laid-out document as
+/// reference targets for the [`external`](RefElem::external) field of
+/// references in another document.
+///
+/// Only the supplement, the formatted number, and the formatted page number
+/// of each target are collected. The states of other counters and of the
+/// bibliography are not carried over.
+pub fn external_targets(engine: &mut Engine) -> SourceResult<Dict> {
+    let introspector = engine.introspector;
+    let mut targets = Dict::new();
+    for elem in introspector.query(&Selector::can::<dyn Refable>()) {
+        let (Some(label), Some(loc)) = (elem.label(), elem.location()) else {
+            continue;
+        };
+
+        let Some(refable) = elem.with::<dyn Refable>() else { continue };
+        let Some(numbering) = refable.numbering() else { continue };
+
+        let numbers = refable.counter().at_loc(engine, loc)?;
+        let numbers = format_plain(engine, loc, numbering, &numbers)?;
+
+        // Pages without numbering show their logical number, like with the
+        // default numbering.
+        let pages = introspector.page_counter(loc).at_loc(engine, loc)?;
+        let page = match introspector.page_numbering(loc) {
+            Some(numbering) => format_plain(engine, loc, numbering, &pages)?,
+            None => eco_format!("{}", pages.first()),
+        };
+
+        let target = dict! {
+            "supplement" => refable.supplement().plain_text(),
+            "numbers" => numbers,
+            "page" => page,
+        };
+        targets.insert(label.resolve().as_str().into(), target.into_value());
+    }
+
+    Ok(targets)
+}
+
+/// Format a counter state with a numbering as plain text.
+fn format_plain(
+    engine: &mut Engine,
+    loc: Location,
+    numbering: &Numbering,
+    state: &CounterState,
+) -> SourceResult<EcoString> {
+    let context = Context::new(Some(loc), None);
+    Ok(numbering
+        .clone()
+        .trimmed()
+        .apply(engine, context.track(), &state.0)?
+        .display()
+        .plain_text())
+}
+
+/// A reference target from another document.
+struct ExternalTarget {
+    /// The supplement of the target.
+    supplement: Content,
+    /// The formatted number of the target.
+    numbers: Content,
+    /// The formatted page number of the target.
+    page: Content,
+}
+
+cast! {
+    ExternalTarget,
+    mut dict: Dict => {
+        let supplement = dict.take("supplement").ok().map(Value::cast).transpose()?;
+        let numbers = dict.take("numbers")?.cast()?;
+        let page = dict.take("page")?.display();
+        dict.finish(&["supplement", "numbers", "page"])?;
+        Self { supplement: supplement.unwrap_or_default(), numbers, page }
+    },
+}
+
 /// Show a reference.
 fn show_reference(
     reference: &Packed<RefElem>,
@@ -346,6 +724,46 @@ fn show_reference(
     Ok(content.linked(Destination::Location(loc)))
 }
 
+/// Show a reference to multiple elements of the same kind, like "Figures 1,
+/// 3–5". Each range is given by its first and last target, which are the same
+/// for a single element.
+fn show_multiple(
+    reference: &Packed<RefElem>,
+    engine: &mut Engine,
+    styles: StyleChain,
+    ranges: &[(&Target, &Target)],
+) -> SourceResult<Content> {
+    let first = ranges[0].0;
+    let supplement = match reference.supplement(styles).as_ref() {
+        Smart::Auto => first
+            .plural_supplement(styles)
+            .unwrap_or_else(|| first.supplement.clone()),
+        Smart::Custom(None) => Content::empty(),
+        Smart::Custom(Some(supplement)) => {
+            supplement.resolve(engine, styles, [first.elem.clone()])?
+        }
+    };
+
+    let mut seq = vec![];
+    for (i, &(start, end)) in ranges.iter().enumerate() {
+        if i > 0 {
+            seq.push(TextElem::packed(", "));
+        }
+        seq.push(start.display(engine, styles)?);
+        if !std::ptr::eq(start, end) {
+            seq.push(TextElem::packed("–"));
+            seq.push(end.display(engine, styles)?);
+        }
+    }
+
+    let mut content = Content::sequence(seq);
+    if !supplement.is_empty() {
+        content = supplement + TextElem::packed("\u{a0}") + content;
+    }
+
+    Ok(content)
+}
+
 /// Turn a reference into a citation.
 fn to_citation(
     reference: &Packed<RefElem>,
@@ -423,4 +841,10 @@ pub trait Refable {
 
     /// Returns the numbering of this element.
     fn numbering(&self) -> Option<&Numbering>;
+
+    /// The element whose localized name is the default supplement, if any.
+    ///
+    /// References to multiple elements use the plural of this name unless the
+    /// supplement was customized.
+    fn supplement_kind(&self) -> Option<Element>;
 }
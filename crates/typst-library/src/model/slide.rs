@@ -0,0 +1,324 @@
+use std::num::NonZeroUsize;
+
+use ecow::{eco_format, EcoString};
+use typst_utils::NonZeroExt;
+
+use crate::diag::{bail, SourceResult, StrResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    cast, elem, Cast, Content, IntoValue, NativeElement, Packed, Selector, Show, Smart,
+    Str, StyleChain,
+};
+use crate::introspection::Locatable;
+use crate::layout::{BlockElem, HideElem, PagebreakElem, Rel};
+use crate::visualize::LineElem;
+
+/// A slide in a presentation.
+///
+/// Each slide starts on a new page. Parts of a slide can be revealed
+/// incrementally with the [`uncover`] and [`only`] functions. A slide with
+/// multiple such steps results in one page per step, which presentation
+/// software then shows one after another. Pair slides with a presentation
+/// [paper size]($page.paper) to give them a fixed aspect ratio.
+///
+/// Although the body of a slide is laid out once per step, the elements within
+/// it exist only once in the document: [Counters]($counter) are stepped once,
+/// labels stay unique, and a [query] finds each element on the first step where
+/// it is shown.
+///
+/// # Example
+/// ```example
+/// #set page(paper: "presentation-16-9", height: auto)
+///
+/// #slide[
+///   = Agenda
+///   - Introduction
+///   #uncover("2-")[- Results]
+///   #only(3)[- Questions]
+/// ]
+/// ```
+#[elem(Locatable, Show)]
+pub struct SlideElem {
+    /// The number of steps of the slide.
+    ///
+    /// If set to `{auto}`, this is the highest step mentioned by an [`uncover`]
+    /// or [`only`] within the slide's body. Steps used within
+    /// [context expressions]($context) or show rules can't be detected in this
+    /// way and require setting the number of steps explicitly.
+    pub steps: Smart<NonZeroUsize>,
+
+    /// How presentation software should transition to this slide.
+    ///
+    /// Typst does not interpret this itself. It can be retrieved with a
+    /// [query] to configure a presentation tool, for instance with
+    /// `typst query slides.typ slide --field transition`.
+    pub transition: Option<SlideTransition>,
+
+    /// Speaker notes for the slide.
+    ///
+    /// Notes are not shown on the slide itself. They can either be shown in a
+    /// separate notes version of the presentation (see
+    /// [`show-notes`]($slide.show-notes)) or be extracted with a [query], for
+    /// instance with `typst query slides.typ slide --field notes`.
+    pub notes: Option<Content>,
+
+    /// Whether to show the speaker notes below the slides.
+    ///
+    /// When enabled, each slide is shown only once, as it looks during its last
+    /// step, followed by its notes. Content that an [`only`] removes during the
+    /// last step is thus not shown. This is typically enabled based on an
+    /// [input]($category/foundations/sys) to compile a notes version of the
+    /// presentation from the same source, e.g. with
+    /// `typst compile --input notes=true slides.typ notes.pdf`.
+    ///
+    /// ```example
+    /// #set page(height: auto)
+    /// #set slide(show-notes: true)
+    ///
+    /// #slide(notes: [Greet the audience.])[
+    ///   = Welcome
+    ///   #uncover(2)[Let's get started.]
+    /// ]
+    /// ```
+    #[default(false)]
+    pub show_notes: bool,
+
+    /// The slide's contents.
+    #[required]
+    pub body: Content,
+
+    /// The step that is currently shown.
+    #[internal]
+    #[ghost]
+    pub step: Option<NonZeroUsize>,
+}
+
+impl Packed<SlideElem> {
+    /// The number of steps of the slide, detecting it from the body if it is
+    /// not set explicitly.
+    pub fn resolve_steps(&self, styles: StyleChain) -> NonZeroUsize {
+        let steps = match self.steps(styles) {
+            Smart::Custom(steps) => return steps,
+            Smart::Auto => {
+                let selector = Selector::Or(
+                    [UncoverElem::elem().select(), OnlyElem::elem().select()].into(),
+                );
+                self.body
+                    .query(selector)
+                    .iter()
+                    .filter_map(|elem| {
+                        if let Some(uncover) = elem.to_packed::<UncoverElem>() {
+                            Some(uncover.steps.last())
+                        } else {
+                            elem.to_packed::<OnlyElem>().map(|only| only.steps.last())
+                        }
+                    })
+                    .fold(1, usize::max)
+            }
+        };
+        NonZeroUsize::new(steps).unwrap_or(NonZeroUsize::ONE)
+    }
+}
+
+impl Show for Packed<SlideElem> {
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let mut seq = vec![
+            PagebreakElem::shared_weak().clone(),
+            BlockElem::multi_layouter(self.clone(), engine.routines.layout_slide)
+                .pack()
+                .spanned(self.span()),
+        ];
+        if self.show_notes(styles) {
+            if let Some(notes) = self.notes(styles) {
+                seq.push(LineElem::new().with_length(Rel::one()).pack());
+                seq.push(notes);
+            }
+        }
+        seq.push(PagebreakElem::shared_weak().clone());
+        Ok(Content::sequence(seq))
+    }
+}
+
+/// A transition between slides, as known from PDF viewers.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum SlideTransition {
+    /// The old slide dissolves into the new one.
+    Dissolve,
+    /// The new slide fades in.
+    Fade,
+    /// A line sweeps across the screen, revealing the new slide.
+    Wipe,
+    /// The new slide pushes the old one off the screen.
+    Push,
+    /// The new slide slides over the old one.
+    Cover,
+    /// Two lines sweep across the screen, revealing the new slide.
+    Split,
+    /// Multiple lines sweep across the screen, revealing the new slide.
+    Blinds,
+}
+
+/// Shows content only during certain steps of a slide, but reserves its space
+/// during all steps.
+///
+/// Outside of a [slide], the content is always shown.
+///
+/// ```example
+/// #set page(height: auto)
+/// #slide[
+///   Dear audience,
+///   #uncover(2)[welcome!]
+/// ]
+/// ```
+#[elem(Show)]
+pub struct UncoverElem {
+    /// The steps during which the content is shown.
+    ///
+    /// This can be a single step, an array of steps, or a string with ranges
+    /// of steps like `{"1-3"}` or `{"2, 4-"}`, where an open range includes
+    /// all following steps.
+    #[required]
+    pub steps: Steps,
+
+    /// The content to show.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for Packed<UncoverElem> {
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        Ok(match SlideElem::step_in(styles) {
+            Some(step) if !self.steps.contains(step) => {
+                HideElem::new(self.body.clone()).pack().spanned(self.span())
+            }
+            _ => self.body.clone(),
+        })
+    }
+}
+
+/// Shows content only during certain steps of a slide and removes it
+/// otherwise.
+///
+/// In contrast to [`uncover`], the content does not take up space during the
+/// other steps. Outside of a [slide], the content is always shown.
+///
+/// ```example
+/// #set page(height: auto)
+/// #slide[
+///   The answer is
+///   #only(1)[_secret_]#only("2-")[*42*].
+/// ]
+/// ```
+#[elem(Show)]
+pub struct OnlyElem {
+    /// The steps during which the content is shown. See the
+    /// [`steps`]($uncover.steps) parameter of `uncover` for details.
+    #[required]
+    pub steps: Steps,
+
+    /// The content to show.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for Packed<OnlyElem> {
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        Ok(match SlideElem::step_in(styles) {
+            Some(step) if !self.steps.contains(step) => Content::empty(),
+            _ => self.body.clone(),
+        })
+    }
+}
+
+/// A set of steps of a slide, as ranges with inclusive bounds.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Steps(Vec<(usize, Option<usize>)>);
+
+impl Steps {
+    /// Parse steps from a string like `"1, 3-4, 6-"`.
+    fn parse(text: &str) -> StrResult<Self> {
+        let number = |part: &str| -> StrResult<usize> {
+            match part.trim().parse::<NonZeroUsize>() {
+                Ok(number) => Ok(number.get()),
+                Err(_) => bail!("invalid step `{}`", part.trim()),
+            }
+        };
+
+        let mut ranges = vec![];
+        for part in text.split(',') {
+            let range = match part.split_once('-') {
+                Some((start, end)) if end.trim().is_empty() => (number(start)?, None),
+                Some((start, end)) => (number(start)?, Some(number(end)?)),
+                None => (number(part)?, Some(number(part)?)),
+            };
+            if range.1.is_some_and(|end| end < range.0) {
+                bail!("step range `{}` is reversed", part.trim());
+            }
+            ranges.push(range);
+        }
+
+        Ok(Self(ranges))
+    }
+
+    /// Whether the given step is included.
+    fn contains(&self, step: NonZeroUsize) -> bool {
+        let step = step.get();
+        self.0
+            .iter()
+            .any(|&(start, end)| start <= step && end.is_none_or(|end| step <= end))
+    }
+
+    /// The highest step that is explicitly mentioned.
+    fn last(&self) -> usize {
+        self.0
+            .iter()
+            .map(|&(start, end)| end.unwrap_or(start))
+            .max()
+            .unwrap_or(1)
+    }
+
+    /// Format the steps in the syntax accepted by [`Steps::parse`].
+    fn format(&self) -> EcoString {
+        let mut out = EcoString::new();
+        for (i, &(start, end)) in self.0.iter().enumerate() {
+            if i > 0 {
+                out.push_str(", ");
+            }
+            out.push_str(&match end {
+                Some(end) if end == start => eco_format!("{start}"),
+                Some(end) => eco_format!("{start}-{end}"),
+                None => eco_format!("{start}-"),
+            });
+        }
+        out
+    }
+}
+
+cast! {
+    Steps,
+    self => self.format().into_value(),
+    v: NonZeroUsize => Self(vec![(v.get(), Some(v.get()))]),
+    v: Vec<NonZeroUsize> => Self(v.into_iter().map(|v| (v.get(), Some(v.get()))).collect()),
+    v: Str => Self::parse(&v)?,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_parse() {
+        let steps = Steps::parse("1, 3-4, 6-").unwrap();
+        let contains = |step| steps.contains(NonZeroUsize::new(step).unwrap());
+        assert!(contains(1));
+        assert!(!contains(2));
+        assert!(contains(4));
+        assert!(!contains(5));
+        assert!(contains(100));
+        assert_eq!(steps.last(), 6);
+        assert_eq!(steps.format(), "1, 3-4, 6-");
+        assert!(Steps::parse("0").is_err());
+        assert!(Steps::parse("3-1").is_err());
+        assert!(Steps::parse("a-").is_err());
+    }
+}
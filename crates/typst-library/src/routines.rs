@@ -16,13 +16,14 @@ use crate::introspection::{Introspector, Locator, SplitLocator};
 use crate::layout::{
     Abs, BoxElem, ColumnsElem, Fragment, Frame, GridElem, InlineItem, MoveElem, PadElem,
     PagedDocument, Region, Regions, Rel, RepeatElem, RotateElem, ScaleElem, Size,
-    SkewElem, StackElem,
+    SkewElem, StackElem, WatermarkElem,
 };
 use crate::math::EquationElem;
-use crate::model::{DocumentInfo, EnumElem, ListElem, TableElem};
+use crate::model::{DocumentInfo, EnumElem, ListElem, SlideElem, TableElem};
 use crate::visualize::{
-    CircleElem, CurveElem, EllipseElem, ImageElem, LineElem, PathElem, PolygonElem,
-    RectElem, SquareElem,
+    BarcodeElem, BoardElem, ChartElem, CircleElem, CurveElem, EllipseElem, FretboardElem,
+    GanttElem, ImageElem, LineElem, PathElem, PolygonElem, QrCodeElem, RectElem,
+    SquareElem, TextPathElem,
 };
 use crate::World;
 
@@ -203,6 +204,15 @@ routines! {
         region: Region,
     ) -> SourceResult<Frame>
 
+    /// Lays out a [`WatermarkElem`].
+    fn layout_watermark(
+        elem: &Packed<WatermarkElem>,
+        engine: &mut Engine,
+        locator: Locator,
+        styles: StyleChain,
+        region: Region,
+    ) -> SourceResult<Frame>
+
     /// Lays out a [`PadElem`].
     fn layout_pad(
         elem: &Packed<PadElem>,
@@ -212,6 +222,15 @@ routines! {
         regions: Regions,
     ) -> SourceResult<Fragment>
 
+    /// Lays out a [`SlideElem`].
+    fn layout_slide(
+        elem: &Packed<SlideElem>,
+        engine: &mut Engine,
+        locator: Locator,
+        styles: StyleChain,
+        regions: Regions,
+    ) -> SourceResult<Fragment>
+
     /// Lays out a [`LineElem`].
     fn layout_line(
         elem: &Packed<LineElem>,
@@ -293,6 +312,69 @@ routines! {
         region: Region,
     ) -> SourceResult<Frame>
 
+    /// Lays out a [`QrCodeElem`].
+    fn layout_qrcode(
+        elem: &Packed<QrCodeElem>,
+        engine: &mut Engine,
+        locator: Locator,
+        styles: StyleChain,
+        region: Region,
+    ) -> SourceResult<Frame>
+
+    /// Lays out a [`BarcodeElem`].
+    fn layout_barcode(
+        elem: &Packed<BarcodeElem>,
+        engine: &mut Engine,
+        locator: Locator,
+        styles: StyleChain,
+        region: Region,
+    ) -> SourceResult<Frame>
+
+    /// Lays out a [`ChartElem`].
+    fn layout_chart(
+        elem: &Packed<ChartElem>,
+        engine: &mut Engine,
+        locator: Locator,
+        styles: StyleChain,
+        region: Region,
+    ) -> SourceResult<Frame>
+
+    /// Lays out a [`FretboardElem`].
+    fn layout_fretboard(
+        elem: &Packed<FretboardElem>,
+        engine: &mut Engine,
+        locator: Locator,
+        styles: StyleChain,
+        region: Region,
+    ) -> SourceResult<Frame>
+
+    /// Lays out a [`BoardElem`].
+    fn layout_board(
+        elem: &Packed<BoardElem>,
+        engine: &mut Engine,
+        locator: Locator,
+        styles: StyleChain,
+        region: Region,
+    ) -> SourceResult<Frame>
+
+    /// Lays out a [`GanttElem`].
+    fn layout_gantt(
+        elem: &Packed<GanttElem>,
+        engine: &mut Engine,
+        locator: Locator,
+        styles: StyleChain,
+        region: Region,
+    ) -> SourceResult<Frame>
+
+    /// Lays out a [`TextPathElem`].
+    fn layout_text_path(
+        elem: &Packed<TextPathElem>,
+        engine: &mut Engine,
+        locator: Locator,
+        styles: StyleChain,
+        region: Region,
+    ) -> SourceResult<Frame>
+
     /// Lays out an [`EquationElem`] in a paragraph.
     fn layout_equation_inline(
         elem: &Packed<EquationElem>,
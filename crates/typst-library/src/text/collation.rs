@@ -0,0 +1,272 @@
+use std::cmp::Ordering;
+
+use ecow::EcoString;
+use unicode_normalization::char::decompose_canonical;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::text::Lang;
+
+/// Compares strings according to the alphabetical order of a language.
+///
+/// This implements a simplified variant of the Unicode Collation Algorithm
+/// with three levels: Base letters are compared first, then accents, and then
+/// case. Languages that treat some letters or digraphs as separate letters of
+/// their alphabet (like "å" in Swedish or "ch" in Czech) are tailored
+/// accordingly. All other languages use the default order, in which accented
+/// letters sort together with their base letter.
+#[derive(Debug, Copy, Clone)]
+pub struct Collator {
+    lang: Lang,
+    tailoring: &'static [Tailoring],
+}
+
+/// A letter or digraph that sorts relative to a base letter: The sequence,
+/// the anchor letter, and the offset from the anchor.
+type Tailoring = (&'static str, char, i32);
+
+/// The secondary weight of a letter without accents.
+const BASE: u32 = 1;
+
+impl Collator {
+    /// Create a collator for a language.
+    pub fn new(lang: Lang) -> Self {
+        Self { lang, tailoring: tailoring(lang) }
+    }
+
+    /// Compare two strings.
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        if a == b {
+            return Ordering::Equal;
+        }
+
+        self.key(a).cmp(&self.key(b))
+    }
+
+    /// Compute the sort key of a string.
+    ///
+    /// Comparing the keys of two strings yields the same order as
+    /// [`compare`](Self::compare). When sorting many strings, computing the
+    /// keys once up front is faster than comparing the strings directly.
+    pub fn key(&self, text: &str) -> SortKey {
+        let turkic = matches!(self.lang.as_str(), "tr" | "az");
+        let mut chars = vec![];
+        for c in text.nfc() {
+            match c {
+                'I' if turkic => chars.push(('ı', true)),
+                'İ' if turkic => chars.push(('i', true)),
+                _ => {
+                    let upper = !c.is_lowercase() && c.is_uppercase();
+                    chars.extend(c.to_lowercase().map(|l| (l, upper)));
+                }
+            }
+        }
+
+        let mut key = SortKey { text: text.into(), ..Default::default() };
+        let mut i = 0;
+        while i < chars.len() {
+            // Find the longest tailored sequence starting here.
+            let tailored = self
+                .tailoring
+                .iter()
+                .enumerate()
+                .filter(|(_, (seq, _, _))| {
+                    let mut rest = chars[i..].iter().map(|&(c, _)| c);
+                    seq.chars().all(|c| rest.next() == Some(c))
+                })
+                .max_by_key(|(_, (seq, _, _))| seq.chars().count());
+
+            if let Some((index, &(seq, anchor, offset))) = tailored {
+                let weight = (anchor as u32 * 256).saturating_add_signed(offset);
+                key.push(weight, BASE + 1 + index as u32, chars[i].1);
+                i += seq.chars().count();
+                continue;
+            }
+
+            let (c, upper) = chars[i];
+            match c {
+                'ß' => {
+                    key.push('s' as u32 * 256, BASE + 1, upper);
+                    key.push('s' as u32 * 256, BASE, upper);
+                }
+                'æ' | 'œ' => {
+                    let first = if c == 'æ' { 'a' } else { 'o' };
+                    key.push(first as u32 * 256, BASE + 1, upper);
+                    key.push('e' as u32 * 256, BASE, upper);
+                }
+                'ø' => key.letter('o', &[0x338], upper),
+                'đ' => key.letter('d', &[0x335], upper),
+                'ł' => key.letter('l', &[0x337], upper),
+                _ => {
+                    let mut base = None;
+                    let mut marks = vec![];
+                    decompose_canonical(c, |d| match base {
+                        None => base = Some(d),
+                        Some(_) => marks.push(d as u32),
+                    });
+                    key.letter(base.unwrap_or(c), &marks, upper);
+                }
+            }
+            i += 1;
+        }
+
+        key
+    }
+}
+
+/// The sort key of a string, with one sequence of weights per level.
+///
+/// Strings with equal weights on all levels are ordered by their code points.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct SortKey {
+    primary: Vec<u32>,
+    secondary: Vec<u32>,
+    tertiary: Vec<bool>,
+    text: EcoString,
+}
+
+impl SortKey {
+    /// The string the key was computed for.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Add a collation element.
+    fn push(&mut self, primary: u32, secondary: u32, upper: bool) {
+        self.primary.push(primary);
+        self.secondary.push(secondary);
+        self.tertiary.push(upper);
+    }
+
+    /// Add a letter with combining marks.
+    fn letter(&mut self, base: char, marks: &[u32], upper: bool) {
+        self.push(base as u32 * 256, BASE, upper);
+        self.secondary.extend_from_slice(marks);
+    }
+}
+
+/// The letters that a language sorts differently from the default order.
+fn tailoring(lang: Lang) -> &'static [Tailoring] {
+    match lang.as_str() {
+        "cs" => {
+            &[("č", 'c', 1), ("ch", 'h', 1), ("ř", 'r', 1), ("š", 's', 1), ("ž", 'z', 1)]
+        }
+        "da" => &[
+            ("æ", 'z', 1),
+            ("ä", 'z', 1),
+            ("ø", 'z', 2),
+            ("ö", 'z', 2),
+            ("å", 'z', 3),
+            ("aa", 'z', 3),
+        ],
+        "es" => &[("ñ", 'n', 1)],
+        "fi" | "sv" => {
+            &[("å", 'z', 1), ("ä", 'z', 2), ("æ", 'z', 2), ("ö", 'z', 3), ("ø", 'z', 3)]
+        }
+        "hr" | "bs" => &[
+            ("č", 'c', 1),
+            ("ć", 'c', 2),
+            ("dž", 'd', 1),
+            ("đ", 'd', 2),
+            ("lj", 'l', 1),
+            ("nj", 'n', 1),
+            ("š", 's', 1),
+            ("ž", 'z', 1),
+        ],
+        "hu" => &[
+            ("cs", 'c', 1),
+            ("dz", 'd', 1),
+            ("dzs", 'd', 2),
+            ("gy", 'g', 1),
+            ("ly", 'l', 1),
+            ("ny", 'n', 1),
+            ("ö", 'o', 1),
+            ("ő", 'o', 1),
+            ("sz", 's', 1),
+            ("ty", 't', 1),
+            ("ü", 'u', 1),
+            ("ű", 'u', 1),
+            ("zs", 'z', 1),
+        ],
+        "nb" | "nn" | "no" => {
+            &[("æ", 'z', 1), ("ä", 'z', 1), ("ø", 'z', 2), ("ö", 'z', 2), ("å", 'z', 3)]
+        }
+        "pl" => &[
+            ("ą", 'a', 1),
+            ("ć", 'c', 1),
+            ("ę", 'e', 1),
+            ("ł", 'l', 1),
+            ("ń", 'n', 1),
+            ("ó", 'o', 1),
+            ("ś", 's', 1),
+            ("ź", 'z', 1),
+            ("ż", 'z', 2),
+        ],
+        "sk" => &[
+            ("ä", 'a', 1),
+            ("č", 'c', 1),
+            ("ch", 'h', 1),
+            ("ô", 'o', 1),
+            ("ř", 'r', 1),
+            ("š", 's', 1),
+            ("ž", 'z', 1),
+        ],
+        "tr" | "az" => &[
+            ("ç", 'c', 1),
+            ("ğ", 'g', 1),
+            ("ı", 'i', -1),
+            ("ö", 'o', 1),
+            ("ş", 's', 1),
+            ("ü", 'u', 1),
+        ],
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn sorted(lang: &str, words: &[&'static str]) -> Vec<&'static str> {
+        let collator = Collator::new(Lang::from_str(lang).unwrap());
+        let mut words = words.to_vec();
+        words.sort_by(|a, b| collator.compare(a, b));
+        words
+    }
+
+    #[test]
+    fn test_collation_default() {
+        assert_eq!(
+            sorted(
+                "de",
+                &["Zebra", "Äpfel", "apfel", "Apfel", "Bär", "Straße", "Strasse"]
+            ),
+            ["apfel", "Apfel", "Äpfel", "Bär", "Strasse", "Straße", "Zebra"],
+        );
+        assert_eq!(
+            sorted("en", &["résumé", "resume", "rest"]),
+            ["rest", "resume", "résumé"]
+        );
+    }
+
+    #[test]
+    fn test_collation_tailored() {
+        assert_eq!(
+            sorted("sv", &["öl", "zon", "åsna", "ära"]),
+            ["zon", "åsna", "ära", "öl"]
+        );
+        assert_eq!(
+            sorted("cs", &["chléb", "hrad", "cukr", "ihla"]),
+            ["cukr", "hrad", "chléb", "ihla"]
+        );
+        assert_eq!(
+            sorted("hu", &["dzsungel", "dzéta", "dob"]),
+            ["dob", "dzéta", "dzsungel"]
+        );
+        assert_eq!(
+            sorted("tr", &["ip", "ırmak", "Istanbul", "hat"]),
+            ["hat", "ırmak", "Istanbul", "ip"]
+        );
+    }
+}
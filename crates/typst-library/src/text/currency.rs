@@ -0,0 +1,417 @@
+use std::str::FromStr;
+
+use comemo::Tracked;
+use ecow::{eco_format, EcoString};
+
+use crate::diag::{bail, HintedStrResult, StrResult};
+use crate::foundations::{
+    cast, dict, func, repr, scope, Array, Cast, Context, Decimal, Dict, IntoValue,
+    Rounding, Smart, Str, Value,
+};
+use crate::layout::Ratio;
+use crate::text::{Lang, Region, TextElem};
+
+/// Formats an amount of money in a currency.
+///
+/// The amount is rounded to the usual number of fractional digits of the
+/// currency and formatted with the digit grouping, decimal separator, and
+/// symbol placement of the current [language]($text.lang) and
+/// [region]($text.region). Since the result depends on the language, this
+/// function requires [context].
+///
+/// To avoid rounding errors, amounts should be given as [decimals]($decimal)
+/// rather than as floats. For the same reason, floats are not accepted.
+///
+/// ```example
+/// #let price = decimal("1234.5")
+/// #context currency(price, "EUR") \
+/// #set text(lang: "de")
+/// #context currency(price, "EUR") \
+/// #set text(lang: "fr")
+/// #context currency(price, "EUR") \
+/// #set text(lang: "de", region: "ch")
+/// #context currency(price, "CHF")
+/// ```
+#[func(scope, contextual)]
+pub fn currency(
+    context: Tracked<Context>,
+    /// The amount of money.
+    amount: Amount,
+    /// The [ISO 4217](https://en.wikipedia.org/wiki/ISO_4217) code of the
+    /// currency, like `{"EUR"}` or `{"USD"}`.
+    code: CurrencyCode,
+    /// Whether to display the currency's symbol or its code.
+    ///
+    /// Currencies without a well-known symbol are always displayed with their
+    /// code.
+    ///
+    /// ```example
+    /// #context currency(25, "USD", display: "code")
+    /// ```
+    #[named]
+    #[default(CurrencyDisplay::Symbol)]
+    display: CurrencyDisplay,
+    /// The number of fractional digits to display. If set to `{auto}`, the
+    /// usual number for the currency is used, which is two for most
+    /// currencies.
+    #[named]
+    #[default]
+    places: Smart<u32>,
+    /// How to round the amount to the number of fractional digits.
+    #[named]
+    #[default]
+    rounding: Rounding,
+) -> HintedStrResult<Str> {
+    let styles = context.styles()?;
+    let places = places.unwrap_or(code.places());
+    let amount = amount.0.round_with(places, rounding);
+    let format =
+        NumberFormat::new(TextElem::lang_in(styles), TextElem::region_in(styles));
+    let symbol = match display {
+        CurrencyDisplay::Symbol => code.symbol(),
+        CurrencyDisplay::Code => code.as_str(),
+    };
+    Ok(format.currency(amount, places, symbol).into())
+}
+
+#[scope]
+impl currency {
+    /// Computes the value-added tax (or any other percentage) of a net amount
+    /// and rounds it to the given number of fractional digits.
+    ///
+    /// ```example
+    /// #currency.vat(decimal("49.99"), 19%)
+    /// ```
+    #[func]
+    pub fn vat(
+        /// The net amount.
+        net: Amount,
+        /// The tax rate.
+        rate: TaxRate,
+        /// The number of fractional digits to round to.
+        #[named]
+        #[default(2)]
+        places: u32,
+        /// How to round the tax.
+        #[named]
+        #[default]
+        rounding: Rounding,
+    ) -> StrResult<Decimal> {
+        Ok(mul(net.0, rate.0)?.round_with(places, rounding))
+    }
+
+    /// Computes the totals of a list of invoice items.
+    ///
+    /// Each item is a dictionary with a `price` and optionally a `quantity`
+    /// (defaults to `{1}`) and a `vat` rate that overrides the default rate.
+    /// The tax is computed once per rate on the sum of the items with that
+    /// rate and then rounded, as is common on invoices.
+    ///
+    /// Returns a dictionary with the `net` sum, the total `vat`, the `gross`
+    /// sum, and the tax per rate as `rates`, an array of dictionaries with the
+    /// `rate`, the `net` sum, and the `vat` for that rate.
+    ///
+    /// ```example
+    /// #let items = (
+    ///   (price: decimal("12.50"), quantity: 3),
+    ///   (price: decimal("4.20"), vat: 7%),
+    /// )
+    /// #let totals = currency.total(items, vat: 19%)
+    /// #context [
+    ///   Net: #currency(totals.net, "EUR") \
+    ///   VAT: #currency(totals.vat, "EUR") \
+    ///   *Total: #currency(totals.gross, "EUR")*
+    /// ]
+    /// ```
+    #[func]
+    pub fn total(
+        /// The invoice items.
+        items: Vec<InvoiceItem>,
+        /// The default tax rate of the items.
+        #[named]
+        #[default(TaxRate(Decimal::ZERO))]
+        vat: TaxRate,
+        /// The number of fractional digits to round the tax to.
+        #[named]
+        #[default(2)]
+        places: u32,
+        /// How to round the tax.
+        #[named]
+        #[default]
+        rounding: Rounding,
+    ) -> StrResult<Dict> {
+        // The net sums per tax rate, in order of appearance.
+        let mut rates: Vec<(Decimal, Decimal)> = vec![];
+        for item in items {
+            let rate = item.vat.map_or(vat.0, |rate| rate.0);
+            let sum = mul(item.price, item.quantity)?;
+            match rates.iter_mut().find(|(r, _)| *r == rate) {
+                Some((_, net)) => *net = add(*net, sum)?,
+                None => rates.push((rate, sum)),
+            }
+        }
+
+        let (mut net, mut tax) = (Decimal::ZERO, Decimal::ZERO);
+        let mut breakdown = Array::new();
+        for (rate, sum) in rates {
+            let vat = mul(sum, rate)?.round_with(places, rounding);
+            net = add(net, sum)?;
+            tax = add(tax, vat)?;
+            let rate = Ratio::new(f64::try_from(rate).unwrap_or_default());
+            breakdown
+                .push(dict! { "rate" => rate, "net" => sum, "vat" => vat }.into_value());
+        }
+
+        Ok(dict! {
+            "net" => net,
+            "vat" => tax,
+            "gross" => add(net, tax)?,
+            "rates" => breakdown,
+        })
+    }
+}
+
+/// Add two decimals, failing on overflow.
+fn add(a: Decimal, b: Decimal) -> StrResult<Decimal> {
+    match a.checked_add(b) {
+        Some(sum) => Ok(sum),
+        None => bail!("value is too large"),
+    }
+}
+
+/// Multiply two decimals, failing on overflow.
+fn mul(a: Decimal, b: Decimal) -> StrResult<Decimal> {
+    match a.checked_mul(b) {
+        Some(product) => Ok(product),
+        None => bail!("value is too large"),
+    }
+}
+
+/// An amount of money.
+pub struct Amount(Decimal);
+
+cast! {
+    Amount,
+    v: Decimal => Self(v),
+    v: i64 => Self(v.into()),
+}
+
+/// A tax rate as a fraction.
+pub struct TaxRate(Decimal);
+
+cast! {
+    TaxRate,
+    self => self.0.into_value(),
+    v: Ratio => {
+        // Go through the shortest representation of the float, so that `19%`
+        // becomes exactly `0.19`.
+        let text = eco_format!("{}", v.get());
+        match Decimal::from_str(&text) {
+            Ok(rate) => Self(rate),
+            Err(_) => bail!("tax rate is not representable as a decimal"),
+        }
+    },
+    v: Decimal => Self(v),
+}
+
+/// An item on an invoice.
+pub struct InvoiceItem {
+    price: Decimal,
+    quantity: Decimal,
+    vat: Option<TaxRate>,
+}
+
+cast! {
+    InvoiceItem,
+    mut dict: Dict => {
+        let price = dict.take("price")?.cast::<Amount>()?.0;
+        let quantity = match dict.take("quantity") {
+            Ok(quantity) => quantity.cast::<Amount>()?.0,
+            Err(_) => Decimal::ONE,
+        };
+        let vat = dict.take("vat").ok().map(Value::cast).transpose()?;
+        dict.finish(&["price", "quantity", "vat"])?;
+        Self { price, quantity, vat }
+    },
+}
+
+/// How to display a currency.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum CurrencyDisplay {
+    /// The currency's symbol, like `€`.
+    Symbol,
+    /// The currency's ISO 4217 code, like `EUR`.
+    Code,
+}
+
+/// An ISO 4217 currency code.
+pub struct CurrencyCode(EcoString);
+
+impl CurrencyCode {
+    /// The code as a string.
+    fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The currency's symbol, or its code if it has no well-known symbol.
+    fn symbol(&self) -> &str {
+        match self.as_str() {
+            "EUR" => "€",
+            "USD" => "$",
+            "GBP" => "£",
+            "JPY" | "CNY" => "¥",
+            "INR" => "₹",
+            "KRW" => "₩",
+            "RUB" => "₽",
+            "UAH" => "₴",
+            "TRY" => "₺",
+            "ILS" => "₪",
+            "NGN" => "₦",
+            "VND" => "₫",
+            "PHP" => "₱",
+            "BRL" => "R$",
+            "PLN" => "zł",
+            "CZK" => "Kč",
+            "SEK" | "NOK" | "DKK" | "ISK" => "kr",
+            code => code,
+        }
+    }
+
+    /// The usual number of fractional digits of the currency.
+    fn places(&self) -> u32 {
+        match self.as_str() {
+            "BIF" | "CLP" | "ISK" | "JPY" | "KRW" | "PYG" | "UGX" | "VND" | "XAF"
+            | "XOF" => 0,
+            "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+            _ => 2,
+        }
+    }
+}
+
+cast! {
+    CurrencyCode,
+    v: Str => {
+        if v.len() != 3 || !v.chars().all(|c| c.is_ascii_uppercase()) {
+            bail!("currency code must consist of three uppercase letters");
+        }
+        Self(v.into())
+    },
+}
+
+/// How a language formats numbers and amounts of money.
+struct NumberFormat {
+    /// Separates the integer from the fractional digits.
+    decimal: &'static str,
+    /// Separates groups of three digits.
+    group: &'static str,
+    /// Whether the currency symbol comes after the number.
+    symbol_after: bool,
+    /// What separates the currency symbol from the number.
+    space: &'static str,
+}
+
+impl NumberFormat {
+    /// The format for a language and region.
+    fn new(lang: Lang, region: Option<Region>) -> Self {
+        const NBSP: &str = "\u{a0}";
+        const NNBSP: &str = "\u{202f}";
+        let before =
+            |decimal, group, space| Self { decimal, group, symbol_after: false, space };
+        let after =
+            |decimal, group| Self { decimal, group, symbol_after: true, space: NBSP };
+
+        if region.is_some_and(|region| matches!(region.as_str(), "CH" | "LI")) {
+            return before(".", "’", NBSP);
+        }
+
+        match lang.as_str() {
+            "de" if region.is_some_and(|region| region.as_str() == "AT") => {
+                before(",", NBSP, NBSP)
+            }
+            "nl" => before(",", ".", NBSP),
+            "de" | "es" | "it" | "pt" | "da" | "el" | "id" | "ro" | "hr" | "sl"
+            | "sr" | "tr" | "is" => after(",", "."),
+            "fr" => after(",", NNBSP),
+            "cs" | "sk" | "pl" | "ru" | "uk" | "bg" | "hu" | "fi" | "sv" | "nb"
+            | "nn" | "no" | "et" | "lt" | "lv" => after(",", NBSP),
+            _ => before(".", ",", ""),
+        }
+    }
+
+    /// Format an amount of money with the given number of fractional digits.
+    fn currency(&self, amount: Decimal, places: u32, symbol: &str) -> EcoString {
+        let number = self.number(amount.abs(), places);
+
+        // Symbols made of letters, like currency codes, are always separated
+        // from the number.
+        let space = if symbol.chars().any(char::is_alphabetic) && self.space.is_empty() {
+            "\u{a0}"
+        } else {
+            self.space
+        };
+
+        let mut out = EcoString::new();
+        if amount.is_negative() && !amount.is_zero() {
+            out.push_str(repr::MINUS_SIGN);
+        }
+        if self.symbol_after {
+            out.push_str(&number);
+            out.push_str(space);
+            out.push_str(symbol);
+        } else {
+            out.push_str(symbol);
+            out.push_str(space);
+            out.push_str(&number);
+        }
+        out
+    }
+
+    /// Format a non-negative number with the given number of fractional
+    /// digits.
+    fn number(&self, value: Decimal, places: u32) -> EcoString {
+        let text = eco_format!("{value}");
+        let (int, fract) = text.split_once('.').unwrap_or((&text, ""));
+
+        let mut out = EcoString::new();
+        for (i, c) in int.chars().enumerate() {
+            if i > 0 && (int.len() - i) % 3 == 0 {
+                out.push_str(self.group);
+            }
+            out.push(c);
+        }
+
+        if places > 0 {
+            out.push_str(self.decimal);
+            out.push_str(fract);
+            for _ in fract.len()..places as usize {
+                out.push('0');
+            }
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(lang: &str, region: Option<&str>, amount: &str, code: &str) -> EcoString {
+        let lang = Lang::from_str(lang).unwrap();
+        let region = region.map(|region| Region::from_str(region).unwrap());
+        let code = CurrencyCode(code.into());
+        let amount = Decimal::from_str(amount).unwrap();
+        NumberFormat::new(lang, region).currency(amount, code.places(), code.symbol())
+    }
+
+    #[test]
+    fn test_currency_format() {
+        assert_eq!(format("en", None, "1234567.5", "USD"), "$1,234,567.50");
+        assert_eq!(format("en", None, "-12", "CHF"), "−CHF\u{a0}12.00");
+        assert_eq!(format("de", None, "1234.5", "EUR"), "1.234,50\u{a0}€");
+        assert_eq!(format("fr", None, "1234.5", "EUR"), "1\u{202f}234,50\u{a0}€");
+        assert_eq!(format("de", Some("CH"), "1234.5", "CHF"), "CHF\u{a0}1’234.50");
+        assert_eq!(format("ja", None, "1234", "JPY"), "¥1,234");
+        assert_eq!(format("en", None, "0.5", "KWD"), "KWD\u{a0}0.500");
+    }
+}
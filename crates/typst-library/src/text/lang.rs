@@ -1,10 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::str::FromStr;
 
 use ecow::{eco_format, EcoString};
 
-use crate::diag::Hint;
-use crate::foundations::{cast, StyleChain};
+use crate::diag::{bail, Hint};
+use crate::foundations::{cast, Dict, Fold, IntoValue, StyleChain};
 use crate::layout::Dir;
 use crate::text::TextElem;
 
@@ -26,17 +26,21 @@ const TRANSLATIONS: &[(&str, &str)] = &[
     translation!("es"),
     translation!("et"),
     translation!("eu"),
+    translation!("fa"),
     translation!("fi"),
     translation!("fr"),
     translation!("gl"),
     translation!("he"),
+    translation!("hi"),
     translation!("hr"),
     translation!("hu"),
     translation!("id"),
     translation!("is"),
     translation!("it"),
     translation!("ja"),
+    translation!("ko"),
     translation!("la"),
+    translation!("lt"),
     translation!("lv"),
     translation!("nb"),
     translation!("nl"),
@@ -46,6 +50,7 @@ const TRANSLATIONS: &[(&str, &str)] = &[
     translation!("pt-PT"),
     translation!("ro"),
     translation!("ru"),
+    translation!("sk"),
     translation!("sl"),
     translation!("sq"),
     translation!("sr"),
@@ -83,15 +88,19 @@ impl Lang {
     pub const GERMAN: Self = Self(*b"de ", 2);
     pub const GREEK: Self = Self(*b"el ", 2);
     pub const HEBREW: Self = Self(*b"he ", 2);
+    pub const HINDI: Self = Self(*b"hi ", 2);
     pub const HUNGARIAN: Self = Self(*b"hu ", 2);
     pub const ICELANDIC: Self = Self(*b"is ", 2);
     pub const INDONESIAN: Self = Self(*b"id ", 2);
     pub const ITALIAN: Self = Self(*b"it ", 2);
     pub const JAPANESE: Self = Self(*b"ja ", 2);
+    pub const KOREAN: Self = Self(*b"ko ", 2);
     pub const LATIN: Self = Self(*b"la ", 2);
     pub const LATVIAN: Self = Self(*b"lv ", 2);
+    pub const LITHUANIAN: Self = Self(*b"lt ", 2);
     pub const LOWER_SORBIAN: Self = Self(*b"dsb", 3);
     pub const NYNORSK: Self = Self(*b"nn ", 2);
+    pub const PERSIAN: Self = Self(*b"fa ", 2);
     pub const POLISH: Self = Self(*b"pl ", 2);
     pub const PORTUGUESE: Self = Self(*b"pt ", 2);
     pub const ROMANIAN: Self = Self(*b"ro ", 2);
@@ -245,13 +254,121 @@ pub trait LocalName {
         localized_str(lang, region, Self::KEY)
     }
 
-    /// Gets the local name from the style chain.
-    fn local_name_in(styles: StyleChain) -> &'static str
+    /// Gets the local name from the style chain, taking overrides from
+    /// [`text.translations`](TextElem::translations) into account.
+    fn local_name_in(styles: StyleChain) -> EcoString
     where
         Self: Sized,
     {
-        Self::local_name(TextElem::lang_in(styles), TextElem::region_in(styles))
+        localized_str_in(styles, Self::KEY).unwrap_or_else(|| {
+            Self::local_name(TextElem::lang_in(styles), TextElem::region_in(styles))
+                .into()
+        })
     }
+
+    /// Gets the plural of the local name from the style chain, if it is known
+    /// for the language.
+    fn local_plural_in(styles: StyleChain) -> Option<EcoString>
+    where
+        Self: Sized,
+    {
+        localized_plural_in(styles, Self::KEY)
+    }
+}
+
+/// Retrieves an overridden localized string for the language and region in
+/// the style chain, if any.
+pub fn localized_str_in(styles: StyleChain, key: &str) -> Option<EcoString> {
+    TextElem::translations_in(styles)
+        .get(TextElem::lang_in(styles), TextElem::region_in(styles), key)
+        .cloned()
+}
+
+/// Retrieves the plural of the localized term with the given key, like
+/// "Figures" for `figure`, for the language and region in the style chain.
+///
+/// Returns `None` if the plural isn't known in the language. Unlike for other
+/// terms, this does not fall back to English.
+pub fn localized_plural_in(styles: StyleChain, key: &str) -> Option<EcoString> {
+    let lang = TextElem::lang_in(styles);
+    let region = TextElem::region_in(styles);
+    let plural = eco_format!("{key}-plural");
+    localized_str_in(styles, &plural).or_else(|| {
+        let find = |region| {
+            let bundle = parse_language_bundle(lang, region).ok()?;
+            bundle.get(plural.as_str()).map(|&term| term.into())
+        };
+        region.and_then(|region| find(Some(region))).or_else(|| find(None))
+    })
+}
+
+/// Overrides for the localized terms that Typst uses, per language.
+///
+/// Terms for a language and region take precedence over terms for just the
+/// language.
+#[derive(Debug, Default, Clone, PartialEq, Hash)]
+pub struct Translations(BTreeMap<EcoString, BTreeMap<EcoString, EcoString>>);
+
+impl Translations {
+    /// The terms that can be overridden in addition to the local names of
+    /// elements.
+    const EXTRA_KEYS: &[&str] = &["caption-separator"];
+
+    /// The overridden term for the given language, region, and key.
+    pub fn get(
+        &self,
+        lang: Lang,
+        region: Option<Region>,
+        key: &str,
+    ) -> Option<&EcoString> {
+        let find = |region| self.0.get(&lang_str(lang, region))?.get(key);
+        region.and_then(|region| find(Some(region))).or_else(|| find(None))
+    }
+}
+
+impl Fold for Translations {
+    fn fold(self, outer: Self) -> Self {
+        let mut merged = outer.0;
+        for (lang, terms) in self.0 {
+            merged.entry(lang).or_default().extend(terms);
+        }
+        Self(merged)
+    }
+}
+
+cast! {
+    Translations,
+    self => self
+        .0
+        .into_iter()
+        .map(|(lang, terms)| {
+            let terms = terms.into_iter().map(|(k, v)| (k.into(), v.into_value()));
+            (lang.into(), terms.collect::<Dict>().into_value())
+        })
+        .collect::<Dict>()
+        .into_value(),
+    dict: Dict => {
+        let english = parse_language_bundle(Lang::ENGLISH, None).unwrap();
+        let mut translations = BTreeMap::new();
+        for (code, terms) in dict {
+            let (lang, region) = match code.split_once('-') {
+                Some((lang, region)) => (lang, Some(Region::from_str(region)?)),
+                None => (code.as_str(), None),
+            };
+            let lang = Lang::from_str(lang)?;
+            let mut map = BTreeMap::new();
+            for (key, term) in terms.cast::<Dict>()? {
+                if !english.contains_key(key.as_str())
+                    && !Translations::EXTRA_KEYS.contains(&key.as_str())
+                {
+                    bail!("unknown term `{key}`");
+                }
+                map.insert(key.into(), term.cast::<EcoString>()?);
+            }
+            translations.insert(lang_str(lang, region), map);
+        }
+        Self(translations)
+    },
 }
 
 /// Retrieves the localized string for a given language and region.
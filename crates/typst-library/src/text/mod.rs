@@ -1,6 +1,8 @@
 //! Text handling.
 
 mod case;
+mod collation;
+mod currency;
 mod deco;
 mod font;
 mod item;
@@ -14,8 +16,12 @@ mod shift;
 mod smallcaps_;
 mod smartquote;
 mod space;
+#[path = "truncate.rs"]
+mod truncate_;
 
 pub use self::case::*;
+pub use self::collation::*;
+pub use self::currency::*;
 pub use self::deco::*;
 pub use self::font::*;
 pub use self::item::*;
@@ -27,6 +33,7 @@ pub use self::shift::*;
 pub use self::smallcaps_::*;
 pub use self::smartquote::*;
 pub use self::space::*;
+pub use self::truncate_::*;
 
 use std::fmt::{self, Debug, Formatter};
 use std::hash::Hash;
@@ -72,6 +79,8 @@ pub(super) fn define(global: &mut Scope) {
     global.define_func::<lower>();
     global.define_func::<upper>();
     global.define_func::<lorem>();
+    global.define_func::<currency>();
+    global.define_func::<truncate>();
     global.reset_category();
 }
 
@@ -424,10 +433,60 @@ pub struct TextElem {
 
     /// An [ISO 3166-1 alpha-2 region code.](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)
     ///
-    /// This lets the text processing pipeline make more informed choices.
+    /// This lets the text processing pipeline make more informed choices. The
+    /// region refines the conventions of the [language]($text.lang) where
+    /// they differ between countries:
+    ///
+    /// - [Smart quotes]($smartquote) use the quotes of the region, for
+    ///   instance single quotes as the outer quotes in British English.
+    /// - Terms like the supplements of figures use the region's translation,
+    ///   if there is one.
+    /// - The week labels of [Gantt charts]($gantt) are formatted in the
+    ///   region's order of day and month. Other dates, like those shown with
+    ///   [`datetime.display`]($datetime.display), are not affected by the
+    ///   region.
+    ///
+    /// ```example
+    /// "Hello," she said.
+    ///
+    /// #set text(region: "GB")
+    /// "Hello," she said.
+    /// ```
     #[ghost]
     pub region: Option<Region>,
 
+    /// Overrides for the terms that Typst generates in the current language,
+    /// like the supplements of figures and the title of the outline.
+    ///
+    /// This is a dictionary that maps language codes, optionally with a
+    /// region like `{"de-AT"}`, to dictionaries of terms. The available terms
+    /// are `figure`, `table`, `equation`, `bibliography`, `heading`,
+    /// `outline`, `raw`, and `page` for the names of these elements as well as
+    /// `caption-separator` for the separator between a caption's number and
+    /// body. Terms that are not overridden keep their built-in translation.
+    /// Subsequent set rules add to the overrides instead of replacing them.
+    ///
+    /// Overrides also work for languages without built-in translations, which
+    /// otherwise fall back to English terms. To reuse such a language pack
+    /// across documents, you can keep it in a data file and load it with a
+    /// set rule like `{set text(translations: toml("translations.toml"))}`,
+    /// where each table of the file holds the terms for one language.
+    ///
+    /// ```example
+    /// #set text(translations: (
+    ///   en: (figure: "Fig.", outline: "Table of Contents"),
+    ///   de: (figure: "Abb.", caption-separator: ". "),
+    /// ))
+    ///
+    /// #figure(rect(), caption: [A box])
+    ///
+    /// #set text(lang: "de")
+    /// #figure(rect(), caption: [Eine Box])
+    /// ```
+    #[fold]
+    #[ghost]
+    pub translations: Translations,
+
     /// The OpenType writing script.
     ///
     /// The combination of `{lang}` and `{script}` determine how font features,
@@ -488,7 +547,9 @@ pub struct TextElem {
     pub dir: TextDir,
 
     /// Whether to hyphenate text to improve line breaking. When `{auto}`, text
-    /// will be hyphenated if and only if justification is enabled.
+    /// will be hyphenated if justification is enabled or if it is laid out in
+    /// a container narrower than the paragraph's
+    /// [`hyphenate-below`]($par.hyphenate-below) width.
     ///
     /// Setting the [text language]($text.lang) ensures that the correct
     /// hyphenation patterns are used.
@@ -618,6 +679,9 @@ pub struct TextElem {
     /// these ligatures by turning off the OpenType `liga` and `clig` font
     /// features.
     ///
+    /// Ligatures don't affect the underlying text: When copying text from an
+    /// exported PDF, a ligature yields the characters it was formed from.
+    ///
     /// ```example
     /// #set text(size: 20pt)
     /// A fine ligature.
@@ -641,6 +705,16 @@ pub struct TextElem {
     #[ghost]
     pub historical_ligatures: bool,
 
+    /// Whether contextual alternates are active.
+    ///
+    /// Some fonts substitute glyphs depending on their neighbours, for
+    /// instance to connect the letters of a script font or to turn `->` into
+    /// an arrow in programming fonts. Setting this to `{false}` disables these
+    /// substitutions by turning off the OpenType `calt` font feature.
+    #[default(true)]
+    #[ghost]
+    pub contextual_alternates: bool,
+
     /// Which kind of numbers / figures to select. When set to `{auto}`, the
     /// default numbers for the font are used.
     ///
@@ -727,6 +801,12 @@ pub struct TextElem {
     #[ghost]
     pub span_offset: usize,
 
+    /// Whether the text's spaces can be adjusted for justification.
+    #[internal]
+    #[default(true)]
+    #[ghost]
+    pub justifiable: bool,
+
     /// A delta to apply on the font weight.
     #[internal]
     #[fold]
@@ -1264,6 +1344,10 @@ pub fn features(styles: StyleChain) -> Vec<Feature> {
         feat(b"hlig", 1);
     }
 
+    if !TextElem::contextual_alternates_in(styles) {
+        feat(b"calt", 0);
+    }
+
     match TextElem::number_type_in(styles) {
         Smart::Auto => {}
         Smart::Custom(NumberType::Lining) => feat(b"lnum", 1),
@@ -3,7 +3,7 @@ use std::ops::Range;
 use std::sync::{Arc, LazyLock};
 
 use comemo::Tracked;
-use ecow::{EcoString, EcoVec};
+use ecow::{eco_format, EcoString, EcoVec};
 use syntect::highlighting::{self as synt};
 use syntect::parsing::{ParseSyntaxError, SyntaxDefinition, SyntaxSet, SyntaxSetBuilder};
 use typst_syntax::{split_newlines, LinkedNode, Span, Spanned};
@@ -16,15 +16,17 @@ use crate::diag::{
 };
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, Bytes, Content, Derived, NativeElement, OneOrMultiple, Packed,
-    PlainText, Show, ShowSet, Smart, StyleChain, Styles, Synthesize, TargetElem,
+    cast, elem, scope, Bytes, Cast, Content, Derived, NativeElement, OneOrMultiple,
+    Packed, PlainText, Show, ShowSet, Smart, StyleChain, Styles, Synthesize, TargetElem,
 };
 use crate::html::{tag, HtmlElem};
-use crate::layout::{BlockBody, BlockElem, Em, HAlignment};
+use crate::layout::{BlockBody, BlockElem, Em, HAlignment, Length, Rel, Sides};
 use crate::loading::{DataSource, Load};
 use crate::model::{Figurable, ParElem};
-use crate::text::{FontFamily, FontList, LinebreakElem, LocalName, TextElem, TextSize};
-use crate::visualize::Color;
+use crate::text::{
+    FontFamily, FontList, HighlightElem, LinebreakElem, LocalName, TextElem, TextSize,
+};
+use crate::visualize::{Color, Paint};
 use crate::World;
 
 /// Raw text with optional syntax highlighting.
@@ -161,6 +163,31 @@ pub struct RawElem {
     #[borrowed]
     pub lang: Option<EcoString>,
 
+    /// Whether to display the raw text as a diff.
+    ///
+    /// In a diff, lines starting with `+` are added and lines starting with
+    /// `-` are removed. These lines are highlighted with a faded version of
+    /// the color that the [theme]($raw.theme) uses for inserted and deleted
+    /// text, respectively. The rest of each line is syntax-highlighted
+    /// in the raw text's [language]($raw.lang) as usual, so that the changes
+    /// to a piece of code can be shown with its highlighting.
+    ///
+    /// Whether a line was added or removed is available as the
+    /// [`change`]($raw.line.change) field of its [line]($raw.line).
+    ///
+    /// ````example
+    /// #set raw(diff: true)
+    ///
+    /// ```rust
+    ///  fn main() {
+    /// -    println!("Hello");
+    /// +    println!("Hello, world!");
+    ///  }
+    /// ```
+    /// ````
+    #[default(false)]
+    pub diff: bool,
+
     /// The horizontal alignment that each line in a raw block should have.
     /// This option is ignored if this is not a raw block (if specified
     /// `block: false` or single backticks were used in markup mode).
@@ -268,6 +295,67 @@ pub struct RawElem {
     #[default(2)]
     pub tab_size: usize,
 
+    /// The font family of the raw text.
+    ///
+    /// Unlike setting the font with `{show raw: set text(font: ..)}`, this
+    /// setting can be scoped like any other set rule.
+    ///
+    /// ````example
+    /// #set raw(font: "New Computer Modern Mono")
+    /// Print with `println!`.
+    /// ````
+    #[default(FontList(vec![FontFamily::new("DejaVu Sans Mono")]))]
+    pub font: FontList,
+
+    /// The size of the raw text.
+    ///
+    /// When given in `em`, the size is relative to the surrounding text. The
+    /// default makes up for monospace fonts typically appearing larger than
+    /// proportional fonts at the same size.
+    ///
+    /// ````example
+    /// #set raw(size: 1em)
+    /// Print with `println!`.
+    /// ````
+    #[default(Em::new(0.8).into())]
+    pub size: Length,
+
+    /// How to fill the background of the raw text.
+    ///
+    /// For inline raw text, the fill is applied like a
+    /// [highlight]($highlight), so that the raw text can still break across
+    /// lines. For raw blocks, the fill is applied to the block.
+    ///
+    /// ````example
+    /// #set raw(fill: luma(235), inset: (x: 3pt))
+    /// Print with `println!`.
+    /// ````
+    pub fill: Option<Paint>,
+
+    /// How much to pad the raw text.
+    ///
+    /// For raw blocks, this is applied to the block. For inline raw text,
+    /// the horizontal inset extends the [fill]($raw.fill) beyond the text
+    /// without taking up space, like the [`extent`]($highlight.extent) of a
+    /// highlight. The vertical inset and relative insets are ignored there.
+    #[fold]
+    pub inset: Sides<Option<Rel<Length>>>,
+
+    /// Whether the spaces in inline raw text are stretched and shrunk when its
+    /// paragraph is [justified]($par.justify).
+    ///
+    /// Disable this to keep code in justified prose aligned with the
+    /// monospace grid.
+    ///
+    /// ````example
+    /// #set par(justify: true)
+    /// #set raw(justify: false)
+    /// Call `f(a, b, c)` to compute the
+    /// result from its three arguments.
+    /// ````
+    #[default(true)]
+    pub justify: bool,
+
     /// The stylized lines of raw text.
     ///
     /// Made accessible for the [`raw.line` element]($raw.line).
@@ -314,9 +402,44 @@ impl Synthesize for Packed<RawElem> {
 impl Packed<RawElem> {
     #[comemo::memoize]
     fn highlight(&self, styles: StyleChain) -> Vec<Packed<RawLine>> {
-        let elem = self.as_ref();
-        let lines = preprocess(&elem.text, styles, self.span());
+        let mut lines = preprocess(&self.text, styles, self.span());
+        if !self.diff(styles) {
+            return self.highlight_lines(lines, &[], styles);
+        }
+
+        // Highlight the lines without their change markers and then add the
+        // markers back. The spans of the highlighted text are shifted past the
+        // markers.
+        let markers = split_diff(&mut lines);
+        let offsets = markers.iter().map(|(marker, _)| marker.len()).collect::<Vec<_>>();
+        let mut seq = self.highlight_lines(lines, &offsets, styles);
+        for (line, (marker, change)) in seq.iter_mut().zip(markers) {
+            if marker.is_empty() {
+                continue;
+            }
+
+            let span = line.span();
+            let text = eco_format!("{marker}{}", line.text);
+            let body = TextElem::packed(marker).spanned(span) + line.body.clone();
+            *line = Packed::new(
+                RawLine::new(line.number, line.count, text, body).with_change(change),
+            )
+            .spanned(span);
+        }
+
+        seq
+    }
 
+    /// Highlight the given lines, where the text of each line starts at the
+    /// given offset into its span (or at zero if there is no offset).
+    fn highlight_lines(
+        &self,
+        lines: EcoVec<(EcoString, Span)>,
+        offsets: &[usize],
+        styles: StyleChain,
+    ) -> Vec<Packed<RawLine>> {
+        let offset = |i: usize| offsets.get(i).copied().unwrap_or(0);
+        let elem = self.as_ref();
         let count = lines.len() as i64;
         let lang = elem
             .lang(styles)
@@ -326,14 +449,13 @@ impl Packed<RawElem> {
             .or(Some("txt".into()));
 
         let non_highlighted_result = |lines: EcoVec<(EcoString, Span)>| {
-            lines.into_iter().enumerate().map(|(i, (line, line_span))| {
-                Packed::new(RawLine::new(
-                    i as i64 + 1,
-                    count,
-                    line.clone(),
-                    TextElem::packed(line).spanned(line_span),
-                ))
-                .spanned(line_span)
+            lines.into_iter().enumerate().map(move |(i, (line, line_span))| {
+                let mut body = TextElem::packed(line.clone()).spanned(line_span);
+                if offset(i) > 0 {
+                    body = body.styled(TextElem::set_span_offset(offset(i)));
+                }
+                Packed::new(RawLine::new(i as i64 + 1, count, line, body))
+                    .spanned(line_span)
             })
         };
 
@@ -364,9 +486,10 @@ impl Packed<RawElem> {
                     // Find span and start of line.
                     // Note: Dedent is already applied to the text
                     let span = lines.get(i).map_or_else(Span::detached, |l| l.1);
-                    let span_offset = text[..range.start]
-                        .rfind('\n')
-                        .map_or(0, |i| range.start - (i + 1));
+                    let span_offset = offset(i)
+                        + text[..range.start]
+                            .rfind('\n')
+                            .map_or(0, |i| range.start - (i + 1));
                     styled(&text[range], foreground, style, span, span_offset)
                 },
                 &mut |i, range, line| {
@@ -397,7 +520,7 @@ impl Packed<RawElem> {
             let mut highlighter = syntect::easy::HighlightLines::new(syntax, theme);
             for (i, (line, line_span)) in lines.into_iter().enumerate() {
                 let mut line_content = vec![];
-                let mut span_offset = 0;
+                let mut span_offset = offset(i);
                 for (style, piece) in highlighter
                     .highlight_line(line.as_str(), syntax_set)
                     .into_iter()
@@ -458,11 +581,25 @@ impl Show for Packed<RawElem> {
             .spanned(self.span()));
         }
 
+        let fill = self.fill(styles);
+        let inset = self.inset(styles);
         if self.block(styles) {
             // Align the text before inserting it into the block.
             realized = realized.aligned(self.align(styles).into());
             realized = BlockElem::new()
                 .with_body(Some(BlockBody::Content(realized)))
+                .with_fill(fill)
+                .with_inset(inset)
+                .pack()
+                .spanned(self.span());
+        } else if fill.is_some() {
+            // A highlight instead of a box, so that the text can still break
+            // across lines.
+            let extent =
+                inset.left.or(inset.right).map_or(Length::zero(), |inset| inset.abs);
+            realized = HighlightElem::new(realized)
+                .with_fill(fill)
+                .with_extent(extent)
                 .pack()
                 .spanned(self.span());
         }
@@ -477,11 +614,13 @@ impl ShowSet for Packed<RawElem> {
         out.set(TextElem::set_overhang(false));
         out.set(TextElem::set_lang(Lang::ENGLISH));
         out.set(TextElem::set_hyphenate(Smart::Custom(false)));
-        out.set(TextElem::set_size(TextSize(Em::new(0.8).into())));
-        out.set(TextElem::set_font(FontList(vec![FontFamily::new("DejaVu Sans Mono")])));
+        out.set(TextElem::set_size(TextSize(self.size(styles))));
+        out.set(TextElem::set_font(self.font(styles)));
         out.set(TextElem::set_cjk_latin_spacing(Smart::Custom(None)));
         if self.block(styles) {
             out.set(ParElem::set_justify(false));
+        } else if !self.justify(styles) {
+            out.set(TextElem::set_justifiable(false));
         }
         out
     }
@@ -653,15 +792,47 @@ pub struct RawLine {
     /// The highlighted raw text.
     #[required]
     pub body: Content,
+
+    /// Whether the line was added or removed, if the raw text is displayed as
+    /// a [diff]($raw.diff).
+    pub change: Option<RawChange>,
 }
 
 impl Show for Packed<RawLine> {
     #[typst_macros::time(name = "raw.line", span = self.span())]
-    fn show(&self, _: &mut Engine, _styles: StyleChain) -> SourceResult<Content> {
-        Ok(self.body.clone())
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let scope = match self.change(styles) {
+            Some(RawChange::Added) => "markup.inserted.diff",
+            Some(RawChange::Removed) => "markup.deleted.diff",
+            None => return Ok(self.body.clone()),
+        };
+
+        // Take the color from the theme's style for diffs, faded so that the
+        // highlighted text stays readable on light and dark backgrounds.
+        let theme: &synt::Theme = match RawElem::theme_in(styles) {
+            Smart::Custom(Some(theme)) => theme.derived.get(),
+            _ => &RAW_THEME,
+        };
+        let scope = syntect::parsing::Scope::new(scope).unwrap();
+        let style = synt::Highlighter::new(theme).style_for_stack(&[scope]);
+        let fill = to_typst(style.foreground).with_alpha(0.15);
+
+        Ok(HighlightElem::new(self.body.clone())
+            .with_fill(Some(fill.into()))
+            .pack()
+            .spanned(self.span()))
     }
 }
 
+/// How a line of a diff was changed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum RawChange {
+    /// The line was added.
+    Added,
+    /// The line was removed.
+    Removed,
+}
+
 impl PlainText for Packed<RawLine> {
     fn plain_text(&self, text: &mut EcoString) {
         text.push_str(&self.text);
@@ -776,6 +947,31 @@ impl<'a> ThemedHighlighter<'a> {
     }
 }
 
+/// Split the change markers off the lines of a diff.
+///
+/// Returns each line's marker and change. Lines that don't start with a
+/// marker or a space, like the range headers of a unified diff, are left
+/// untouched.
+fn split_diff(
+    lines: &mut EcoVec<(EcoString, Span)>,
+) -> Vec<(EcoString, Option<RawChange>)> {
+    lines
+        .make_mut()
+        .iter_mut()
+        .map(|(line, _)| {
+            let change = match line.chars().next() {
+                Some('+') => Some(RawChange::Added),
+                Some('-') => Some(RawChange::Removed),
+                Some(' ') => None,
+                _ => return (EcoString::new(), None),
+            };
+            let marker = line[..1].into();
+            *line = line[1..].into();
+            (marker, change)
+        })
+        .collect()
+}
+
 fn preprocess(
     text: &RawContent,
     styles: StyleChain,
@@ -1,13 +1,16 @@
 use ecow::EcoString;
+use ttf_parser::gsub::SubstitutionSubtable;
+use ttf_parser::{GlyphId, Tag};
 
 use crate::diag::SourceResult;
 use crate::engine::Engine;
 use crate::foundations::{
-    elem, Content, NativeElement, Packed, SequenceElem, Show, StyleChain, TargetElem,
+    elem, Content, NativeElement, Packed, Regex, SequenceElem, Show, StyleChain,
+    TargetElem,
 };
 use crate::html::{tag, HtmlElem};
 use crate::layout::{Em, Length};
-use crate::text::{variant, SpaceElem, TextElem, TextSize};
+use crate::text::{variant, Font, FontFeatures, SpaceElem, TextElem, TextSize};
 use crate::World;
 
 /// Renders text in subscript.
@@ -20,11 +23,13 @@ use crate::World;
 /// ```
 #[elem(title = "Subscript", Show)]
 pub struct SubElem {
-    /// Whether to prefer the dedicated subscript characters of the font.
+    /// Whether to prefer the dedicated subscript glyphs of the font.
     ///
     /// If this is enabled, Typst first tries to transform the text to subscript
-    /// codepoints. If that fails, it falls back to rendering lowered and shrunk
-    /// normal letters.
+    /// codepoints. If that fails, it tries the font's OpenType `subs` feature,
+    /// which is used if it provides subscript glyphs for all of the text. If
+    /// that fails, too, it falls back to rendering lowered and shrunk normal
+    /// letters.
     ///
     /// ```example
     /// N#sub(typographic: true)[1]
@@ -34,14 +39,18 @@ pub struct SubElem {
     pub typographic: bool,
 
     /// The baseline shift for synthetic subscripts. Does not apply if
-    /// `typographic` is true and the font has subscript codepoints for the
-    /// given `body`.
+    /// `typographic` is true and the font has subscript glyphs or codepoints
+    /// for the given `body`.
+    ///
+    /// ```example
+    /// H#sub(typographic: false, baseline: 0.3em)[2]O
+    /// ```
     #[default(Em::new(0.2).into())]
     pub baseline: Length,
 
     /// The font size for synthetic subscripts. Does not apply if
-    /// `typographic` is true and the font has subscript codepoints for the
-    /// given `body`.
+    /// `typographic` is true and the font has subscript glyphs or codepoints
+    /// for the given `body`.
     #[default(TextSize(Em::new(0.6).into()))]
     pub size: TextSize,
 
@@ -63,12 +72,10 @@ impl Show for Packed<SubElem> {
         }
 
         if self.typographic(styles) {
-            if let Some(text) = convert_script(&body, true) {
-                if is_shapable(engine, &text, styles) {
-                    return Ok(TextElem::packed(text));
-                }
+            if let Some(content) = typographic_script(engine, &body, styles, true) {
+                return Ok(content);
             }
-        };
+        }
 
         Ok(body
             .styled(TextElem::set_baseline(self.baseline(styles)))
@@ -86,11 +93,13 @@ impl Show for Packed<SubElem> {
 /// ```
 #[elem(title = "Superscript", Show)]
 pub struct SuperElem {
-    /// Whether to prefer the dedicated superscript characters of the font.
+    /// Whether to prefer the dedicated superscript glyphs of the font.
     ///
     /// If this is enabled, Typst first tries to transform the text to
-    /// superscript codepoints. If that fails, it falls back to rendering
-    /// raised and shrunk normal letters.
+    /// superscript codepoints. If that fails, it tries the font's OpenType
+    /// `sups` feature, which is used if it provides superscript glyphs for all
+    /// of the text. If that fails, too, it falls back to rendering raised and
+    /// shrunk normal letters.
     ///
     /// ```example
     /// N#super(typographic: true)[1]
@@ -100,14 +109,18 @@ pub struct SuperElem {
     pub typographic: bool,
 
     /// The baseline shift for synthetic superscripts. Does not apply if
-    /// `typographic` is true and the font has superscript codepoints for the
-    /// given `body`.
+    /// `typographic` is true and the font has superscript glyphs or codepoints
+    /// for the given `body`.
+    ///
+    /// ```example
+    /// 2#super(typographic: false, baseline: -0.6em, size: 0.5em)[nd]
+    /// ```
     #[default(Em::new(-0.5).into())]
     pub baseline: Length,
 
     /// The font size for synthetic superscripts. Does not apply if
-    /// `typographic` is true and the font has superscript codepoints for the
-    /// given `body`.
+    /// `typographic` is true and the font has superscript glyphs or codepoints
+    /// for the given `body`.
     #[default(TextSize(Em::new(0.6).into()))]
     pub size: TextSize,
 
@@ -129,12 +142,10 @@ impl Show for Packed<SuperElem> {
         }
 
         if self.typographic(styles) {
-            if let Some(text) = convert_script(&body, false) {
-                if is_shapable(engine, &text, styles) {
-                    return Ok(TextElem::packed(text));
-                }
+            if let Some(content) = typographic_script(engine, &body, styles, false) {
+                return Ok(content);
             }
-        };
+        }
 
         Ok(body
             .styled(TextElem::set_baseline(self.baseline(styles)))
@@ -142,22 +153,44 @@ impl Show for Packed<SuperElem> {
     }
 }
 
-/// Find and transform the text contained in `content` to the given script kind
+/// Tries to display `body` with the dedicated script glyphs of the font.
+///
+/// Prefers the Unicode subscript or superscript codepoints and falls back to
+/// the font's OpenType `subs` or `sups` feature for text that has no such
+/// codepoints, like most letters.
+fn typographic_script(
+    engine: &Engine,
+    body: &Content,
+    styles: StyleChain,
+    sub: bool,
+) -> Option<Content> {
+    let map = if sub { to_subscript_codepoint } else { to_superscript_codepoint };
+    if let Some(text) = convert_script(body, map) {
+        if is_shapable(engine, &text, styles) {
+            return Some(TextElem::packed(text));
+        }
+    }
+
+    let text = convert_script(body, Some)?;
+    let tag = Tag::from_bytes(if sub { b"subs" } else { b"sups" });
+    has_feature_glyphs(engine, &text, styles, tag).then(|| {
+        let features = FontFeatures(vec![(tag, 1)]);
+        body.clone().styled(TextElem::set_features(features))
+    })
+}
+
+/// Find and transform the text contained in `content` with the given mapping
 /// if and only if it only consists of `Text`, `Space`, and `Empty` leaves.
-fn convert_script(content: &Content, sub: bool) -> Option<EcoString> {
+fn convert_script(content: &Content, map: fn(char) -> Option<char>) -> Option<EcoString> {
     if content.is::<SpaceElem>() {
         Some(' '.into())
     } else if let Some(elem) = content.to_packed::<TextElem>() {
-        if sub {
-            elem.text.chars().map(to_subscript_codepoint).collect()
-        } else {
-            elem.text.chars().map(to_superscript_codepoint).collect()
-        }
+        elem.text.chars().map(map).collect()
     } else if let Some(sequence) = content.to_packed::<SequenceElem>() {
         sequence
             .children
             .iter()
-            .map(|item| convert_script(item, sub))
+            .map(|item| convert_script(item, map))
             .collect()
     } else {
         None
@@ -167,22 +200,56 @@ fn convert_script(content: &Content, sub: bool) -> Option<EcoString> {
 /// Checks whether the first retrievable family contains all code points of the
 /// given string.
 fn is_shapable(engine: &Engine, text: &str, styles: StyleChain) -> bool {
-    let world = engine.world;
-    for family in TextElem::font_in(styles) {
-        if let Some(font) = world
-            .book()
-            .select(family.as_str(), variant(styles))
-            .and_then(|id| world.font(id))
-        {
-            let covers = family.covers();
-            return text.chars().all(|c| {
-                covers.is_none_or(|cov| cov.is_match(c.encode_utf8(&mut [0; 4])))
-                    && font.ttf().glyph_index(c).is_some()
-            });
-        }
-    }
+    let Some((font, covers)) = first_font(engine, styles) else { return false };
+    text.chars().all(|c| {
+        covers.is_none_or(|cov| cov.is_match(c.encode_utf8(&mut [0; 4])))
+            && font.ttf().glyph_index(c).is_some()
+    })
+}
+
+/// Checks whether the given OpenType feature of the first retrievable family
+/// substitutes all non-space characters of the given string.
+fn has_feature_glyphs(engine: &Engine, text: &str, styles: StyleChain, tag: Tag) -> bool {
+    let Some((font, covers)) = first_font(engine, styles) else { return false };
+    let ttf = font.ttf();
+    let Some(gsub) = ttf.tables().gsub else { return false };
+    let Some(feature) = gsub.features.index(tag).and_then(|i| gsub.features.get(i))
+    else {
+        return false;
+    };
+
+    let substitutes = |glyph: GlyphId| {
+        feature.lookup_indices.into_iter().any(|index| {
+            gsub.lookups.get(index).is_some_and(|lookup| {
+                lookup.subtables.into_iter::<SubstitutionSubtable>().any(|subtable| {
+                    match subtable {
+                        SubstitutionSubtable::Single(single) => {
+                            single.coverage().contains(glyph)
+                        }
+                        _ => false,
+                    }
+                })
+            })
+        })
+    };
 
-    false
+    text.chars().filter(|c| !c.is_whitespace()).all(|c| {
+        covers.is_none_or(|cov| cov.is_match(c.encode_utf8(&mut [0; 4])))
+            && ttf.glyph_index(c).is_some_and(substitutes)
+    })
+}
+
+/// Retrieves the first available font of the font families, together with the
+/// family's coverage restriction.
+fn first_font<'a>(
+    engine: &Engine,
+    styles: StyleChain<'a>,
+) -> Option<(Font, Option<&'a Regex>)> {
+    let world = engine.world;
+    TextElem::font_in(styles).into_iter().find_map(|family| {
+        let id = world.book().select(family.as_str(), variant(styles))?;
+        Some((world.font(id)?, family.covers()))
+    })
 }
 
 /// Convert a character to its corresponding Unicode superscript.
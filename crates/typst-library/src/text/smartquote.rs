@@ -207,11 +207,12 @@ impl<'s> SmartQuotes<'s> {
     /// The language should be specified as an all-lowercase ISO 639-1 code, the
     /// region as an all-uppercase ISO 3166-alpha2 code.
     ///
-    /// Currently, the supported languages are: English, Czech, Danish, German,
-    /// Swiss / Liechtensteinian German, Estonian, Icelandic, Italian, Latin,
-    /// Lithuanian, Latvian, Slovak, Slovenian, Spanish, Bosnian, Finnish,
-    /// Swedish, French, Swiss French, Hungarian, Polish, Romanian, Japanese,
-    /// Traditional Chinese, Russian, Norwegian, Hebrew and Croatian.
+    /// Currently, the supported languages are: English, British English, Czech,
+    /// Danish, German, Swiss / Liechtensteinian German, Estonian, Icelandic,
+    /// Italian, Latin, Lithuanian, Latvian, Slovak, Slovenian, Spanish, Bosnian,
+    /// Finnish, Swedish, French, Swiss French, European Portuguese, Hungarian,
+    /// Polish, Romanian, Japanese, Traditional Chinese, Russian, Norwegian,
+    /// Hebrew and Croatian.
     ///
     /// For unknown languages, the English quotes are used as fallback.
     pub fn get(
@@ -234,6 +235,7 @@ impl<'s> SmartQuotes<'s> {
                 false => ("‹\u{202F}", "\u{202F}›", "«\u{202F}", "\u{202F}»"),
                 true => default,
             },
+            "en" if matches!(region, Some("GB")) && !alternative => ("“", "”", "‘", "’"),
             "cs" | "da" | "de" | "sk" | "sl" if alternative => ("›", "‹", "»", "«"),
             "cs" | "de" | "et" | "is" | "lt" | "lv" | "sk" | "sl" => low_high,
             "da" => ("‘", "’", "“", "”"),
@@ -245,6 +247,7 @@ impl<'s> SmartQuotes<'s> {
             "la" if alternative => ("“", "”", "«\u{202F}", "\u{202F}»"),
             "it" | "la" => ("“", "”", "«", "»"),
             "es" if matches!(region, Some("ES") | None) => ("“", "”", "«", "»"),
+            "pt" if matches!(region, Some("PT")) => ("“", "”", "«", "»"),
             "hu" | "pl" | "ro" => ("’", "’", "„", "”"),
             "no" | "nb" | "nn" if alternative => low_high,
             "no" | "nb" | "nn" => ("’", "’", "«", "»"),
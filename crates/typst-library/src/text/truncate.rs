@@ -0,0 +1,92 @@
+use comemo::Tracked;
+use typst_syntax::Span;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::diag::{At, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{func, Content, Context, Resolve, StyleChain};
+use crate::introspection::{Location, Locator, LocatorLink};
+use crate::layout::{Abs, Axes, Length, Region};
+use crate::text::TextElem;
+
+/// Shortens text so that it fits into a width, ending it with an ellipsis.
+///
+/// This is useful for table cells, running headers, and labels that must not
+/// wrap. The text is measured in the current style, so this function requires
+/// [context]. Text that already fits is returned unchanged. Otherwise, its
+/// plain text is cut at the last grapheme that leaves room for the ellipsis,
+/// so any formatting within it is lost.
+///
+/// Combined with [`layout`], the text can be fit into the available space.
+///
+/// ```example
+/// #context truncate(60pt)[A rather long caption]
+///
+/// #block(width: 80pt, layout(size => truncate(
+///   size.width,
+///   ellipsis: [ ...],
+///   [Chapter 3: Typesetting Mathematics],
+/// )))
+/// ```
+#[func(contextual)]
+pub fn truncate(
+    engine: &mut Engine,
+    context: Tracked<Context>,
+    span: Span,
+    /// The maximum width of the text.
+    width: Length,
+    /// The content to end shortened text with.
+    #[named]
+    #[default(TextElem::packed("…"))]
+    ellipsis: Content,
+    /// The text to shorten.
+    body: Content,
+) -> SourceResult<Content> {
+    let styles = context.styles().at(span)?;
+    let here = context.location().at(span)?;
+    let max = width.resolve(styles);
+
+    if max.fits(measure_width(engine, here, styles, &body)?) {
+        return Ok(body);
+    }
+
+    // Find the longest prefix that fits together with the ellipsis. Since the
+    // width grows with the number of graphemes, we can search for it.
+    let text = body.plain_text();
+    let bounds: Vec<usize> = text.grapheme_indices(true).map(|(i, _)| i).collect();
+    let shortened = |n: usize| {
+        let prefix = bounds.get(n).map_or(text.as_str(), |&end| &text[..end]);
+        match prefix.trim_end() {
+            "" => ellipsis.clone(),
+            prefix => TextElem::packed(prefix) + ellipsis.clone(),
+        }
+    };
+
+    let (mut lo, mut hi) = (0, bounds.len());
+    while lo < hi {
+        let mid = (lo + hi).div_ceil(2);
+        if max.fits(measure_width(engine, here, styles, &shortened(mid))?) {
+            lo = mid;
+        } else {
+            hi = mid - 1;
+        }
+    }
+
+    Ok(shortened(lo))
+}
+
+/// Measures the width of content laid out on a single line.
+fn measure_width(
+    engine: &mut Engine,
+    here: Location,
+    styles: StyleChain,
+    content: &Content,
+) -> SourceResult<Abs> {
+    // Like `measure`, we lay out in measurement mode so that
+    // introspection-driven features within the content continue to work.
+    let link = LocatorLink::measure(here);
+    let locator = Locator::link(&link);
+    let pod = Region::new(Axes::splat(Abs::inf()), Axes::splat(false));
+    let frame = (engine.routines.layout_frame)(engine, content, locator, styles, pod)?;
+    Ok(frame.width())
+}
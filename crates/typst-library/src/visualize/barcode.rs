@@ -0,0 +1,240 @@
+use crate::diag::{bail, SourceResult, StrResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    elem, Cast, Content, NativeElement, Packed, Show, Str, StyleChain,
+};
+use crate::layout::{Abs, BlockElem, Length};
+use crate::visualize::{Color, Paint};
+
+/// A one-dimensional barcode.
+///
+/// The code is drawn with vector shapes, so it stays sharp at any size and
+/// zoom level. The data is not printed below the bars, but can be added with
+/// a regular text element.
+///
+/// # Example
+/// ```example
+/// #barcode("Typst 0.13")
+/// #barcode("400638133393", kind: "ean13")
+/// ```
+#[elem(title = "Barcode", Show)]
+pub struct BarcodeElem {
+    /// The data to encode.
+    ///
+    /// For Code 128, this may contain printable ASCII characters. For EAN-13,
+    /// it must consist of twelve digits, or of thirteen digits including the
+    /// check digit.
+    #[required]
+    pub data: Str,
+
+    /// The barcode symbology to use.
+    #[default(BarcodeKind::Code128)]
+    pub kind: BarcodeKind,
+
+    /// The width of the narrowest bar.
+    #[resolve]
+    #[default(Abs::pt(1.0).into())]
+    pub module: Length,
+
+    /// The height of the bars.
+    #[resolve]
+    #[default(Abs::cm(1.5).into())]
+    pub height: Length,
+
+    /// How to fill the bars.
+    #[default(Color::BLACK.into())]
+    pub fill: Paint,
+
+    /// Whether to reserve the blank margins at both ends of the code that
+    /// scanners need to detect it.
+    #[default(true)]
+    pub quiet_zone: bool,
+}
+
+impl Show for Packed<BarcodeElem> {
+    fn show(&self, engine: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(BlockElem::single_layouter(self.clone(), engine.routines.layout_barcode)
+            .pack()
+            .spanned(self.span()))
+    }
+}
+
+/// A barcode symbology.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum BarcodeKind {
+    /// Code 128 with code set B, which encodes printable ASCII characters.
+    #[string("code128")]
+    Code128,
+    /// EAN-13, which encodes thirteen digits and is used for retail products
+    /// and books.
+    #[string("ean13")]
+    Ean13,
+}
+
+impl BarcodeKind {
+    /// Encode the data as a sequence of modules, where `true` is a bar and
+    /// `false` is a space.
+    pub fn encode(self, data: &str) -> StrResult<Vec<bool>> {
+        match self {
+            Self::Code128 => encode_code128(data),
+            Self::Ean13 => encode_ean13(data),
+        }
+    }
+
+    /// The minimum number of blank modules before and after the code.
+    pub fn quiet_zone(self) -> (usize, usize) {
+        match self {
+            Self::Code128 => (10, 10),
+            Self::Ean13 => (11, 7),
+        }
+    }
+}
+
+/// Encode data as Code 128 using code set B.
+fn encode_code128(data: &str) -> StrResult<Vec<bool>> {
+    if data.is_empty() {
+        bail!("barcode data must not be empty");
+    }
+
+    const START_B: usize = 104;
+    const STOP: usize = 106;
+
+    let mut values = vec![START_B];
+    for c in data.chars() {
+        if !(' '..='~').contains(&c) {
+            bail!("Code 128 cannot encode the character {c:?}");
+        }
+        values.push(c as usize - 32);
+    }
+
+    let checksum = values
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| i.max(1) * value)
+        .sum::<usize>()
+        % 103;
+    values.push(checksum);
+    values.push(STOP);
+
+    let mut modules = vec![];
+    for value in values {
+        push_widths(&mut modules, CODE128_PATTERNS[value], true);
+    }
+    Ok(modules)
+}
+
+/// Encode data as EAN-13, computing the check digit if it is missing.
+fn encode_ean13(data: &str) -> StrResult<Vec<bool>> {
+    if !data.bytes().all(|b| b.is_ascii_digit()) || !matches!(data.len(), 12 | 13) {
+        bail!("EAN-13 data must consist of 12 or 13 digits");
+    }
+
+    let mut digits: Vec<usize> = data.bytes().map(|b| usize::from(b - b'0')).collect();
+    let check = ean13_check_digit(&digits[..12]);
+    match digits.get(12) {
+        Some(&given) if given != check => {
+            bail!("EAN-13 check digit is invalid (expected {check}, found {given})")
+        }
+        Some(_) => {}
+        None => digits.push(check),
+    }
+
+    let mut modules = vec![];
+    push_widths(&mut modules, "111", true);
+    let parity = EAN_PARITY[digits[0]].as_bytes();
+    for (i, &digit) in digits[1..7].iter().enumerate() {
+        if parity[i] == b'L' {
+            push_widths(&mut modules, EAN_PATTERNS[digit], false);
+        } else {
+            let reversed: String = EAN_PATTERNS[digit].chars().rev().collect();
+            push_widths(&mut modules, &reversed, false);
+        }
+    }
+    push_widths(&mut modules, "11111", false);
+    for &digit in &digits[7..] {
+        push_widths(&mut modules, EAN_PATTERNS[digit], true);
+    }
+    push_widths(&mut modules, "111", true);
+    Ok(modules)
+}
+
+/// Compute the EAN-13 check digit for the first twelve digits.
+fn ean13_check_digit(digits: &[usize]) -> usize {
+    let sum: usize = digits
+        .iter()
+        .enumerate()
+        .map(|(i, &digit)| if i % 2 == 0 { digit } else { 3 * digit })
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+/// Append alternating bars and spaces with the given widths in modules.
+fn push_widths(modules: &mut Vec<bool>, widths: &str, bar: bool) {
+    for (i, width) in widths.bytes().enumerate() {
+        let dark = (i % 2 == 0) == bar;
+        modules.extend(std::iter::repeat_n(dark, usize::from(width - b'0')));
+    }
+}
+
+/// The bar and space widths of the Code 128 symbols, including the start
+/// symbols (103 to 105) and the stop symbol (106).
+const CODE128_PATTERNS: [&str; 107] = [
+    "212222", "222122", "222221", "121223", "121322", "131222", "122213", "122312",
+    "132212", "221213", "221312", "231212", "112232", "122132", "122231", "113222",
+    "123122", "123221", "223211", "221132", "221231", "213212", "223112", "312131",
+    "311222", "321122", "321221", "312212", "322112", "322211", "212123", "212321",
+    "232121", "111323", "131123", "131321", "112313", "132113", "132311", "211313",
+    "231113", "231311", "112133", "112331", "132131", "113123", "113321", "133121",
+    "313121", "211331", "231131", "213113", "213311", "213131", "311123", "311321",
+    "331121", "312113", "312311", "332111", "314111", "221411", "431111", "111224",
+    "111422", "121124", "121421", "141122", "141221", "112214", "112412", "122114",
+    "122411", "142112", "142211", "241211", "221114", "413111", "241112", "134111",
+    "111242", "121142", "121241", "114212", "124112", "124211", "411212", "421112",
+    "421211", "212141", "214121", "412121", "111143", "111341", "131141", "114113",
+    "114311", "411113", "411311", "113141", "114131", "311141", "411131", "211412",
+    "211214", "211232", "2331112",
+];
+
+/// The space and bar widths of the EAN digits in the left-hand odd parity
+/// set. The right-hand set has the same widths starting with a bar, and the
+/// even parity set has them reversed.
+const EAN_PATTERNS: [&str; 10] =
+    ["3211", "2221", "2122", "1411", "1132", "1231", "1114", "1312", "1213", "3112"];
+
+/// The parities of the left-hand digits, determined by the first digit.
+const EAN_PARITY: [&str; 10] = [
+    "LLLLLL", "LLGLGG", "LLGGLG", "LLGGGL", "LGLLGG", "LGGLLG", "LGGGLG", "LGLGLG",
+    "LGLGGL", "LGGLGL",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code128_patterns() {
+        for (i, pattern) in CODE128_PATTERNS.iter().enumerate() {
+            let width: u32 = pattern.bytes().map(|b| u32::from(b - b'0')).sum();
+            assert_eq!(width, if i == 106 { 13 } else { 11 }, "symbol {i}");
+        }
+    }
+
+    #[test]
+    fn test_code128_encode() {
+        let modules = encode_code128("Hi").unwrap();
+        assert_eq!(modules.len(), 11 * 4 + 13);
+        assert!(modules[0] && modules[modules.len() - 1]);
+        assert!(encode_code128("").is_err());
+        assert!(encode_code128("ä").is_err());
+    }
+
+    #[test]
+    fn test_ean13() {
+        let digits = [4, 0, 0, 6, 3, 8, 1, 3, 3, 3, 9, 3];
+        assert_eq!(ean13_check_digit(&digits), 1);
+        assert_eq!(encode_ean13("400638133393").unwrap().len(), 95);
+        assert_eq!(encode_ean13("4006381333931"), encode_ean13("400638133393"));
+        assert!(encode_ean13("4006381333932").is_err());
+        assert!(encode_ean13("40063813339").is_err());
+    }
+}
@@ -0,0 +1,205 @@
+use crate::diag::{bail, SourceResult, StrResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    cast, elem, Array, Cast, Content, IntoValue, NativeElement, Packed, Show, Str,
+    StyleChain, Value,
+};
+use crate::layout::{Abs, BlockElem, Length};
+use crate::visualize::{Color, Paint};
+
+/// A diagram of a board game position.
+///
+/// Chess positions can be given in
+/// [Forsyth–Edwards Notation](https://en.wikipedia.org/wiki/Forsyth–Edwards_Notation)
+/// (FEN). Chess pieces are drawn with the chess symbols of the current font,
+/// so a font that contains them is needed. Go and checkers positions are
+/// given as arrays of rows and drawn with vector shapes.
+///
+/// # Example
+/// ```example
+/// #board(
+///   "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R",
+///   size: 4cm,
+///   coordinates: true,
+/// )
+/// ```
+#[elem(Show)]
+pub struct BoardElem {
+    /// The position on the board.
+    ///
+    /// This is either a FEN string or an array of rows from top to bottom,
+    /// where each row is an array with one item per square. Each item is
+    /// `{none}` for an empty square or a one-letter string for a piece:
+    ///
+    /// - Chess: `K`, `Q`, `R`, `B`, `N`, and `P` for white's king, queen,
+    ///   rook, bishop, knight, and pawn, and the same letters in lowercase for
+    ///   black's pieces.
+    /// - Go: `b` and `w` for black and white stones.
+    /// - Checkers: `b` and `w` for black and white men, and `B` and `W` for
+    ///   black and white kings.
+    ///
+    /// Of a FEN string, only the piece placement is used. The other fields,
+    /// like the side to move, can be included, but are ignored.
+    ///
+    /// ```example
+    /// #board(kind: "go", size: 3cm, (
+    ///   (none, "b", "w", none, none),
+    ///   ("b", none, "b", "w", none),
+    ///   (none, "b", "w", none, none),
+    ///   (none, none, none, none, none),
+    ///   (none, none, none, none, none),
+    /// ))
+    /// ```
+    #[required]
+    pub position: BoardPosition,
+
+    /// Which game the position belongs to.
+    #[default(BoardKind::Chess)]
+    pub kind: BoardKind,
+
+    /// The width of the board.
+    #[resolve]
+    #[default(Abs::cm(5.0).into())]
+    pub size: Length,
+
+    /// Whether to show the board from the other side, for instance to show a
+    /// chess position from black's perspective.
+    #[default(false)]
+    pub flipped: bool,
+
+    /// Whether to label the files and ranks of a chess or checkers board.
+    #[default(false)]
+    pub coordinates: bool,
+
+    /// How to fill the light squares of a chess or checkers board and the
+    /// background of a go board.
+    #[default(Color::from_u8(0xF0, 0xD9, 0xB5, 0xFF).into())]
+    pub light: Paint,
+
+    /// How to fill the dark squares of a chess or checkers board.
+    #[default(Color::from_u8(0xB5, 0x88, 0x63, 0xFF).into())]
+    pub dark: Paint,
+}
+
+impl Show for Packed<BoardElem> {
+    fn show(&self, engine: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(BlockElem::single_layouter(self.clone(), engine.routines.layout_board)
+            .pack()
+            .spanned(self.span()))
+    }
+}
+
+/// A board game.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum BoardKind {
+    /// Chess, with pieces on the squares.
+    Chess,
+    /// Go, with stones on the intersections of the lines.
+    Go,
+    /// Checkers or draughts, with men and kings on the squares.
+    Checkers,
+}
+
+impl BoardKind {
+    /// The letters that denote pieces in this game.
+    pub fn pieces(self) -> &'static str {
+        match self {
+            Self::Chess => "KQRBNPkqrbnp",
+            Self::Go => "bw",
+            Self::Checkers => "bwBW",
+        }
+    }
+}
+
+/// The pieces on a rectangular board.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct BoardPosition {
+    /// The number of squares per row.
+    width: usize,
+    /// The squares in row-major order, from the top left.
+    squares: Vec<Option<char>>,
+}
+
+impl BoardPosition {
+    /// The number of squares per row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.squares.len() / self.width
+    }
+
+    /// The piece at the given column and row, counted from the top left.
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        self.squares[y * self.width + x]
+    }
+
+    /// Parse the piece placement of a FEN string.
+    fn from_fen(fen: &str) -> StrResult<Self> {
+        let placement = fen.split_whitespace().next().unwrap_or_default();
+        let mut rows = vec![];
+        for rank in placement.split('/') {
+            let mut row = vec![];
+            for c in rank.chars() {
+                match c.to_digit(10) {
+                    Some(n) => row.extend(std::iter::repeat_n(None, n as usize)),
+                    None if BoardKind::Chess.pieces().contains(c) => row.push(Some(c)),
+                    None => bail!("invalid piece `{c}` in FEN"),
+                }
+            }
+            rows.push(row);
+        }
+        Self::from_rows(rows)
+    }
+
+    /// Create a position from rows of squares, ensuring that all rows have
+    /// the same length.
+    fn from_rows(rows: Vec<Vec<Option<char>>>) -> StrResult<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if width == 0 {
+            bail!("board must not be empty");
+        }
+
+        let mut squares = Vec::with_capacity(width * rows.len());
+        for (i, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                bail!("row {} has {} squares, expected {width}", i + 1, row.len());
+            }
+            squares.extend(row);
+        }
+
+        Ok(Self { width, squares })
+    }
+}
+
+cast! {
+    BoardPosition,
+    self => self
+        .squares
+        .chunks(self.width)
+        .map(|row| row.iter().map(|&square| square.into_value()).collect::<Array>())
+        .map(Value::Array)
+        .collect::<Array>()
+        .into_value(),
+    v: Str => Self::from_fen(&v)?,
+    v: Vec<Vec<Option<char>>> => Self::from_rows(v)?,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_board_from_fen() {
+        let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        let board = BoardPosition::from_fen(start).unwrap();
+        assert_eq!((board.width(), board.height()), (8, 8));
+        assert_eq!(board.get(4, 0), Some('k'));
+        assert_eq!(board.get(3, 4), None);
+        assert_eq!(board.get(3, 7), Some('Q'));
+        assert!(BoardPosition::from_fen("8/7").is_err());
+        assert!(BoardPosition::from_fen("8/x7").is_err());
+    }
+}
@@ -0,0 +1,175 @@
+use typst_utils::Scalar;
+
+use crate::diag::{bail, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    array, cast, dict, elem, Array, Cast, Content, Dict, IntoValue, NativeElement,
+    Packed, Show, StyleChain, Value,
+};
+use crate::layout::{Abs, BlockElem, Length};
+use crate::visualize::{Color, Paint};
+
+/// A chart that visualizes numeric data.
+///
+/// Charts are drawn with vector shapes. Their axis labels, tick labels, and
+/// legend are regular text and thus use the document's current font settings.
+///
+/// Each positional argument is a data series. A series is either an array of
+/// data points or a dictionary with the keys `data`, `label`, and `fill`. A
+/// data point is either a number (its position in the series then determines
+/// its x coordinate) or an array of an x and a y coordinate.
+///
+/// # Example
+/// ```example
+/// #chart(
+///   kind: "line",
+///   x-label: [Month],
+///   labels: ([Jan], [Feb], [Mar], [Apr]),
+///   (label: [Sales], data: (3, 5, 4, 7)),
+///   (label: [Costs], data: (2, 2.5, 3, 3.5)),
+/// )
+///
+/// #chart(
+///   kind: "pie",
+///   labels: ([Rent], [Food], [Other]),
+///   (45, 30, 25),
+/// )
+/// ```
+#[elem(title = "Chart", Show)]
+pub struct ChartElem {
+    /// The kind of chart.
+    ///
+    /// ```example
+    /// #chart(
+    ///   kind: "bar",
+    ///   labels: ([2023], [2024], [2025]),
+    ///   (label: [North], data: (4, 6, 5)),
+    ///   (label: [South], data: (3, 4, 7)),
+    /// )
+    /// ```
+    #[default(ChartKind::Line)]
+    pub kind: ChartKind,
+
+    /// The width of the whole chart, including its labels and legend.
+    #[resolve]
+    #[default(Abs::cm(8.0).into())]
+    pub width: Length,
+
+    /// The height of the whole chart, including its labels and legend.
+    #[resolve]
+    #[default(Abs::cm(5.0).into())]
+    pub height: Length,
+
+    /// Names for the data points.
+    ///
+    /// For bar charts, these label the categories along the x-axis. For line
+    /// and scatter charts, they replace the numeric labels of the x-axis. Each
+    /// label is placed at the x coordinate of the corresponding point of the
+    /// first series. For pie charts, they label the slices in the legend.
+    pub labels: Vec<Content>,
+
+    /// A title for the x-axis.
+    pub x_label: Option<Content>,
+
+    /// A title for the y-axis.
+    pub y_label: Option<Content>,
+
+    /// Whether to show a legend with the labels of the series or, for pie
+    /// charts, of the slices.
+    #[default(true)]
+    pub legend: bool,
+
+    /// The colors of series and slices that don't specify a fill, used in
+    /// turn.
+    #[default(vec![
+        Color::BLUE.into(),
+        Color::ORANGE.into(),
+        Color::GREEN.into(),
+        Color::RED.into(),
+        Color::PURPLE.into(),
+        Color::TEAL.into(),
+    ])]
+    pub palette: Vec<Paint>,
+
+    /// The data series.
+    #[variadic]
+    pub series: Vec<ChartSeries>,
+}
+
+impl Show for Packed<ChartElem> {
+    fn show(&self, engine: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(BlockElem::single_layouter(self.clone(), engine.routines.layout_chart)
+            .pack()
+            .spanned(self.span()))
+    }
+}
+
+/// The kind of a chart.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum ChartKind {
+    /// Connects the data points of each series with lines.
+    Line,
+    /// Draws a group of bars for each data point, one per series.
+    Bar,
+    /// Marks each data point with a dot.
+    Scatter,
+    /// Divides a circle into slices proportional to the values of the first
+    /// series.
+    Pie,
+}
+
+/// A data series in a chart.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct ChartSeries {
+    /// The data points.
+    pub data: Vec<ChartPoint>,
+    /// The name of the series in the legend.
+    pub label: Option<Content>,
+    /// The color of the series, overriding the palette.
+    pub fill: Option<Paint>,
+}
+
+cast! {
+    ChartSeries,
+    self => dict! {
+        "data" => self.data,
+        "label" => self.label,
+        "fill" => self.fill,
+    }.into_value(),
+    data: Vec<ChartPoint> => Self { data, label: None, fill: None },
+    mut dict: Dict => {
+        let data = dict.take("data")?.cast()?;
+        let label = dict.take("label").ok().map(Value::cast).transpose()?;
+        let fill = dict.take("fill").ok().map(Value::cast).transpose()?;
+        dict.finish(&["data", "label", "fill"])?;
+        Self { data, label, fill }
+    },
+}
+
+/// A data point in a chart.
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub struct ChartPoint {
+    /// The x coordinate, if it was given explicitly.
+    pub x: Option<Scalar>,
+    /// The y coordinate.
+    pub y: Scalar,
+}
+
+cast! {
+    ChartPoint,
+    self => match self.x {
+        Some(x) => array![x.get(), self.y.get()].into_value(),
+        None => self.y.get().into_value(),
+    },
+    y: f64 => Self { x: None, y: Scalar::new(y) },
+    array: Array => {
+        let mut iter = array.into_iter();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some(x), Some(y), None) => Self {
+                x: Some(Scalar::new(x.cast()?)),
+                y: Scalar::new(y.cast()?),
+            },
+            _ => bail!("point array must contain exactly two entries"),
+        }
+    },
+}
@@ -0,0 +1,273 @@
+use ecow::EcoString;
+use time::macros::format_description;
+
+use crate::diag::{bail, HintedStrResult, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    cast, dict, elem, Cast, Content, Datetime, Dict, IntoValue, NativeElement, Packed,
+    Show, Smart, StyleChain, Value,
+};
+use crate::layout::{BlockElem, Em, Length, Ratio, Rel};
+use crate::text::Region;
+use crate::visualize::{Color, Paint};
+
+/// A Gantt chart that shows tasks as bars along a time axis.
+///
+/// Each positional argument is a task, given as a dictionary with the keys
+/// `label`, `start`, `end`, and `fill`. The start and end are
+/// [datetimes]($datetime) with a date. If the end is a plain date, the task
+/// lasts until the end of that day. A task without an end is a milestone and
+/// is drawn as a diamond, so a chart of milestones only makes for a timeline.
+///
+/// The time axis spans from the earliest start to the latest end of all
+/// tasks. Its labels are regular text and thus use the document's current
+/// font settings.
+///
+/// # Example
+/// ```example
+/// #gantt(
+///   today: datetime(year: 2025, month: 3, day: 12),
+///   (
+///     label: [Research],
+///     start: datetime(year: 2025, month: 3, day: 3),
+///     end: datetime(year: 2025, month: 3, day: 9),
+///   ),
+///   (
+///     label: [Prototype],
+///     start: datetime(year: 2025, month: 3, day: 10),
+///     end: datetime(year: 2025, month: 3, day: 23),
+///   ),
+///   (
+///     label: [Review],
+///     start: datetime(year: 2025, month: 3, day: 24),
+///   ),
+/// )
+/// ```
+#[elem(title = "Gantt Chart", Show)]
+pub struct GanttElem {
+    /// The width of the whole chart, including the task labels.
+    #[resolve]
+    #[default(Rel::from(Ratio::one()))]
+    pub width: Rel<Length>,
+
+    /// The height of each task's row.
+    #[resolve]
+    #[default(Em::new(1.8).into())]
+    pub row_height: Length,
+
+    /// The interval between the labels of the time axis.
+    ///
+    /// If set to `{auto}`, the unit is chosen based on the time span of the
+    /// chart.
+    pub unit: Smart<GanttUnit>,
+
+    /// A date at which to draw a marker across the chart, for instance
+    /// `{datetime.today()}`.
+    pub today: Option<Datetime>,
+
+    /// How to fill the bars and milestones of tasks that don't specify a
+    /// fill.
+    #[default(Color::BLUE.into())]
+    pub fill: Paint,
+
+    /// The tasks.
+    #[variadic]
+    pub tasks: Vec<GanttTask>,
+}
+
+impl Show for Packed<GanttElem> {
+    fn show(&self, engine: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(BlockElem::single_layouter(self.clone(), engine.routines.layout_gantt)
+            .pack()
+            .spanned(self.span()))
+    }
+}
+
+/// The interval between the labels of a Gantt chart's time axis.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum GanttUnit {
+    /// Labels every day with its number.
+    Day,
+    /// Labels every Monday with its month and day. The day comes first unless
+    /// the [text region]($text.region) is unset or the United States.
+    Week,
+    /// Labels the first day of every month with its month and year.
+    Month,
+}
+
+impl GanttUnit {
+    /// The unit that suits a time span of the given number of days.
+    pub fn for_span(days: f64) -> Self {
+        if days <= 31.0 {
+            Self::Day
+        } else if days <= 182.0 {
+            Self::Week
+        } else {
+            Self::Month
+        }
+    }
+
+    /// The labeled days between two day numbers, with their labels formatted
+    /// for the given region.
+    pub fn ticks(
+        self,
+        first: f64,
+        last: f64,
+        region: Option<Region>,
+    ) -> Vec<(f64, EcoString)> {
+        let day_first = region.is_some_and(|region| region.as_str() != "US");
+        let format = match self {
+            Self::Day => format_description!("[day padding:none]"),
+            Self::Week if day_first => {
+                format_description!("[day padding:none] [month repr:short]")
+            }
+            Self::Week => format_description!("[month repr:short] [day padding:none]"),
+            Self::Month => format_description!("[month repr:short] [year]"),
+        };
+
+        // Find the first labeled day, which starts a week or month.
+        let Ok(mut date) = time::Date::from_julian_day(first.ceil() as i32) else {
+            return vec![];
+        };
+        match self {
+            Self::Day => {}
+            Self::Week => {
+                while date.weekday() != time::Weekday::Monday {
+                    let Some(next) = date.next_day() else { return vec![] };
+                    date = next;
+                }
+            }
+            Self::Month if date.day() == 1 => {}
+            Self::Month => {
+                let Some(next) = next_month(date) else { return vec![] };
+                date = next;
+            }
+        }
+
+        let mut ticks = vec![];
+        while f64::from(date.to_julian_day()) <= last {
+            if let Ok(label) = date.format(format) {
+                ticks.push((f64::from(date.to_julian_day()), label.into()));
+            }
+            let next = match self {
+                Self::Day => date.next_day(),
+                Self::Week => date.checked_add(time::Duration::WEEK),
+                Self::Month => next_month(date),
+            };
+            let Some(next) = next else { break };
+            date = next;
+        }
+        ticks
+    }
+}
+
+/// The first day of the month after the given date.
+fn next_month(date: time::Date) -> Option<time::Date> {
+    let (year, month) = match date.month() {
+        time::Month::December => (date.year() + 1, time::Month::January),
+        month => (date.year(), month.next()),
+    };
+    time::Date::from_calendar_date(year, month, 1).ok()
+}
+
+/// A task in a Gantt chart.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct GanttTask {
+    /// The name of the task, shown next to its row.
+    pub label: Content,
+    /// When the task starts.
+    pub start: Datetime,
+    /// When the task ends, or `None` for a milestone.
+    pub end: Option<Datetime>,
+    /// The color of the task, overriding the chart's fill.
+    pub fill: Option<Paint>,
+}
+
+impl GanttTask {
+    /// The start and end of the task as day numbers, where the end of a
+    /// milestone equals its start.
+    pub fn days(&self) -> (f64, f64) {
+        let start = self.start.julian_day().unwrap_or_default();
+        let end = match &self.end {
+            Some(Datetime::Date(date)) => f64::from(date.to_julian_day() + 1),
+            Some(end) => end.julian_day().unwrap_or(start),
+            None => start,
+        };
+        (start, end)
+    }
+
+    /// Ensure that the dates have a date and are in order.
+    fn validate(&self) -> HintedStrResult<()> {
+        if [Some(&self.start), self.end.as_ref()]
+            .into_iter()
+            .flatten()
+            .any(|datetime| datetime.julian_day().is_none())
+        {
+            bail!("task dates must include a date");
+        }
+
+        let (start, end) = self.days();
+        if end < start {
+            bail!("task must not end before it starts");
+        }
+
+        Ok(())
+    }
+}
+
+cast! {
+    GanttTask,
+    self => dict! {
+        "label" => self.label,
+        "start" => self.start,
+        "end" => self.end,
+        "fill" => self.fill,
+    }.into_value(),
+    mut dict: Dict => {
+        let label = dict.take("label")?.cast()?;
+        let start = dict.take("start")?.cast()?;
+        let end = dict.take("end").ok().map(Value::cast).transpose()?;
+        let fill = dict.take("fill").ok().map(Value::cast).transpose()?;
+        dict.finish(&["label", "start", "end", "fill"])?;
+        let task = Self { label, start, end, fill };
+        task.validate()?;
+        task
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gantt_ticks() {
+        let day = |d| Datetime::from_ymd(2025, 3, d).unwrap().julian_day().unwrap();
+        let labels = |region: Option<&str>| {
+            let region = region.map(|region| region.parse().unwrap());
+            GanttUnit::Week
+                .ticks(day(1), day(20), region)
+                .into_iter()
+                .map(|(_, label)| label)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(labels(None), ["Mar 3", "Mar 10", "Mar 17"]);
+        assert_eq!(labels(Some("US")), ["Mar 3", "Mar 10", "Mar 17"]);
+        assert_eq!(labels(Some("GB")), ["3 Mar", "10 Mar", "17 Mar"]);
+        assert_eq!(GanttUnit::Month.ticks(day(2), day(31), None).len(), 0);
+        assert_eq!(GanttUnit::Day.ticks(day(1), day(3), None).len(), 3);
+
+        let months = |first, last| {
+            let first = Datetime::from_ymd(2024, 11, first).unwrap();
+            GanttUnit::Month
+                .ticks(first.julian_day().unwrap(), last, None)
+                .into_iter()
+                .map(|(_, label)| label)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            months(1, day(1)),
+            ["Nov 2024", "Dec 2024", "Jan 2025", "Feb 2025", "Mar 2025"]
+        );
+        assert_eq!(months(2, day(1)), ["Dec 2024", "Jan 2025", "Feb 2025", "Mar 2025"]);
+    }
+}
@@ -6,25 +6,30 @@ mod svg;
 pub use self::raster::{
     ExchangeFormat, PixelEncoding, PixelFormat, RasterFormat, RasterImage,
 };
-pub use self::svg::SvgImage;
+pub use self::svg::{SvgImage, SvgOptions};
 
+use std::ffi::OsStr;
 use std::fmt::{self, Debug, Formatter};
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 
-use ecow::EcoString;
+use base64::Engine as _;
+use comemo::Tracked;
+use ecow::{eco_format, EcoString};
 use typst_syntax::{Span, Spanned};
-use typst_utils::LazyHash;
+use typst_utils::{LazyHash, Scalar};
 
-use crate::diag::{SourceResult, StrResult};
+use crate::diag::{bail, At, HintedStrResult, LoadedWithin, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, func, scope, Bytes, Cast, Content, Derived, NativeElement, Packed, Show,
-    Smart, StyleChain,
+    cast, dict, elem, func, scope, Bytes, Cast, Content, Derived, Dict, IntoValue,
+    NativeElement, Packed, Show, Smart, StyleChain, Value,
 };
-use crate::layout::{BlockElem, Length, Rel, Sizing};
+use crate::layout::{BlockElem, Length, Ratio, Rel, Sizing};
 use crate::loading::{DataSource, Load, LoadSource, Loaded, Readable};
 use crate::model::Figurable;
 use crate::text::LocalName;
+use crate::World;
 
 /// A raster or vector graphic.
 ///
@@ -34,6 +39,11 @@ use crate::text::LocalName;
 /// integrate themselves into adjacent paragraphs. To force an image to become
 /// inline, put it into a [`box`].
 ///
+/// SVG images are exported to PDF as native vector graphics, with their
+/// paths, gradients, and clipping intact, so they stay sharp at any zoom
+/// level. Only filter effects are rasterized, see the
+/// [`raster-fallback`]($image.raster-fallback) setting.
+///
 /// # Example
 /// ```example
 /// #figure(
@@ -49,6 +59,11 @@ pub struct ImageElem {
     /// A [path]($syntax/#paths) to an image file or raw bytes making up an
     /// image in one of the supported [formats]($image.format).
     ///
+    /// Instead of a path, the image can also be given as a
+    /// [data URI](https://developer.mozilla.org/en-US/docs/Web/URI/Reference/Schemes/data)
+    /// like `{"data:image/png;base64,iVBORw0..."}`, which is decoded without
+    /// touching the file system.
+    ///
     /// Bytes can be used to specify raw pixel data in a row-major,
     /// left-to-right, top-to-bottom format.
     ///
@@ -64,11 +79,13 @@ pub struct ImageElem {
     /// ```
     #[required]
     #[parse(
+        // Errors are only reported during layout, where it is known whether
+        // the image should be replaced by a placeholder.
         let source = args.expect::<Spanned<DataSource>>("source")?;
-        let loaded = source.load(engine.world)?;
+        let loaded = load_source(&source, engine.world);
         Derived::new(source.v, loaded)
     )]
-    pub source: Derived<DataSource, Loaded>,
+    pub source: Derived<DataSource, SourceResult<Loaded>>,
 
     /// The image's format.
     ///
@@ -139,6 +156,50 @@ pub struct ImageElem {
     #[default(ImageFit::Cover)]
     pub fit: ImageFit,
 
+    /// A part of the image to show instead of the whole image.
+    ///
+    /// The part is given as a dictionary with the keys `x`, `y`, `width`, and
+    /// `height`, each relative to the image's size. The position defaults to
+    /// the top left corner and the size defaults to the rest of the image.
+    /// The cropped part is then treated like the whole image, so it is sized
+    /// and [fitted]($image.fit) on its own.
+    ///
+    /// ```example
+    /// #image("tiger.jpg", width: 50%)
+    /// #image(
+    ///   "tiger.jpg",
+    ///   width: 50%,
+    ///   crop: (x: 25%, y: 10%, width: 50%),
+    /// )
+    /// ```
+    pub crop: Option<ImageCrop>,
+
+    /// The resolution in dots per inch that determines the natural size of a
+    /// raster image when neither its width nor its height is set.
+    ///
+    /// When set to `{auto}`, the resolution stored in the image's metadata is
+    /// used, so that a 300 DPI scan is laid out smaller than a 72 DPI
+    /// screenshot with the same number of pixels. Images without such
+    /// metadata and SVGs are laid out at 72 DPI.
+    ///
+    /// ```example
+    /// #image("tiger.jpg", dpi: 300)
+    /// #image("tiger.jpg", dpi: 600)
+    /// ```
+    pub dpi: Smart<f64>,
+
+    /// Which frame of an animated GIF, PNG, or WebP image to show, starting
+    /// at one.
+    ///
+    /// Animations are not played in the document, so by default, the first
+    /// frame is shown. Images that aren't animated only have a single frame.
+    ///
+    /// ```typ
+    /// #image("recording.gif", frame: 12)
+    /// ```
+    #[default(NonZeroUsize::ONE)]
+    pub frame: NonZeroUsize,
+
     /// A hint to viewers how they should scale the image.
     ///
     /// When set to `{auto}`, the default is left up to the viewer. For PNG
@@ -162,6 +223,41 @@ pub struct ImageElem {
     })]
     #[borrowed]
     pub icc: Smart<Derived<DataSource, Bytes>>,
+
+    /// The resolution in dots per inch at which to rasterize the filter
+    /// effects of SVGs.
+    ///
+    /// Filter effects like blurs and drop shadows can't be represented as
+    /// vector graphics in PDF, so they are rasterized during PDF export while
+    /// the rest of the SVG stays a vector graphic. When set to `{none}`, the
+    /// exporter's default resolution is used. PNG export renders the whole
+    /// image at the export resolution and SVG export keeps filters as they
+    /// are, so this setting has no effect there.
+    ///
+    /// At the given resolution, the whole SVG may have at most 67 million
+    /// pixels, or fewer if the number of image pixels is limited in the
+    /// compiler's configuration.
+    pub raster_fallback: Option<f64>,
+
+    /// Whether to show a placeholder instead of failing when the image can't
+    /// be loaded or decoded.
+    ///
+    /// The placeholder is a clearly marked box that shows the image's path
+    /// and the error, and a warning is emitted in place of the error. This is
+    /// useful for drafts and previews where not all images are available yet.
+    /// The command line interface can enable this for the whole document with
+    /// the `--image-placeholders` flag.
+    ///
+    /// Errors about missing files are, like decoding errors, only reported
+    /// once the image is laid out. Hence, a set rule suffices to get a
+    /// placeholder for missing files, too.
+    ///
+    /// ```example
+    /// #set image(placeholder: true)
+    /// #image("missing.png", width: 4cm, height: 2cm)
+    /// ```
+    #[default(false)]
+    pub placeholder: bool,
 }
 
 #[scope]
@@ -196,7 +292,7 @@ impl ImageElem {
         let bytes = data.v.into_bytes();
         let loaded =
             Loaded::new(Spanned::new(LoadSource::Bytes, data.span), bytes.clone());
-        let source = Derived::new(DataSource::Bytes(bytes), loaded);
+        let source = Derived::new(DataSource::Bytes(bytes), Ok(loaded));
         let mut elem = ImageElem::new(source);
         if let Some(format) = format {
             elem.push_format(format);
@@ -218,6 +314,67 @@ impl ImageElem {
         }
         Ok(elem.pack().spanned(span))
     }
+
+    /// Reads the dimensions and format of an image without placing it.
+    ///
+    /// Returns a dictionary with the following keys:
+    /// - `width` ([float]): The width of the image. For raster images, this is
+    ///   the number of pixels. For SVGs, it is the width in the SVG's own
+    ///   units.
+    /// - `height` ([float]): The height of the image, in the same unit as the
+    ///   width.
+    /// - `format` ([str]): The image's [format]($image.format), e.g. `{"png"}`
+    ///   or `{"svg"}`.
+    /// - `vector` ([bool]): Whether the image is a vector graphic.
+    /// - `dpi` ([float] or `{none}`): The image's pixel density in pixels per
+    ///   inch, if known.
+    ///
+    /// This is useful for templates that lay out images differently depending
+    /// on their aspect ratio.
+    ///
+    /// ```example
+    /// #let info = image.info("tiger.jpg")
+    /// #let wide = info.width > info.height
+    /// #image("tiger.jpg", width: if wide { 100% } else { 50% })
+    /// ```
+    #[func(title = "Image Info")]
+    pub fn info(
+        engine: &mut Engine,
+        /// A [path]($syntax/#paths) to an image file or raw bytes making up an
+        /// image.
+        source: Spanned<DataSource>,
+        /// The image's format. Detected automatically by default.
+        #[named]
+        #[default]
+        format: Smart<ImageFormat>,
+    ) -> SourceResult<Dict> {
+        let span = source.span;
+        let loaded = load_source(&source, engine.world)?;
+        let format = match format {
+            Smart::Custom(v) => v,
+            Smart::Auto => ImageFormat::determine(&source.v, &loaded.data).at(span)?,
+        };
+
+        let kind = match format {
+            ImageFormat::Raster(format) => {
+                RasterImage::new(loaded.data.clone(), format, Smart::Auto)
+                    .at(span)?
+                    .into()
+            }
+            ImageFormat::Vector(VectorFormat::Svg) => {
+                SvgImage::new(loaded.data.clone()).within(&loaded)?.into()
+            }
+        };
+
+        let image = Image::plain(kind);
+        Ok(dict! {
+            "width" => image.width(),
+            "height" => image.height(),
+            "format" => image.format(),
+            "vector" => matches!(image.kind(), ImageKind::Svg(_)),
+            "dpi" => image.dpi(),
+        })
+    }
 }
 
 impl Show for Packed<ImageElem> {
@@ -236,6 +393,57 @@ impl LocalName for Packed<ImageElem> {
 
 impl Figurable for Packed<ImageElem> {}
 
+/// Load the data of an image, decoding data URIs instead of reading a file.
+fn load_source(
+    source: &Spanned<DataSource>,
+    world: Tracked<dyn World + '_>,
+) -> SourceResult<Loaded> {
+    let Some((meta, payload)) = split_data_uri(&source.v) else {
+        return source.load(world);
+    };
+
+    let data = decode_data_uri(meta, payload).at(source.span)?;
+    Ok(Loaded::new(Spanned::new(LoadSource::Bytes, source.span), data))
+}
+
+/// Split a data URI into the part before the comma, which holds the media
+/// type and parameters, and the payload.
+fn split_data_uri(source: &DataSource) -> Option<(&str, &str)> {
+    let DataSource::Path(path) = source else { return None };
+    path.strip_prefix("data:")?.split_once(',')
+}
+
+/// Decode the payload of a data URI, which is either base64 or
+/// percent-encoded.
+fn decode_data_uri(meta: &str, payload: &str) -> StrResult<Bytes> {
+    if meta.ends_with(";base64") {
+        let payload: String =
+            payload.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+        return base64::engine::general_purpose::STANDARD
+            .decode(payload)
+            .map(Bytes::new)
+            .map_err(|err| eco_format!("failed to decode data URI ({err})"));
+    }
+
+    let mut data = Vec::with_capacity(payload.len());
+    let mut rest = payload.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let decoded = std::str::from_utf8(tail.get(..2).unwrap_or_default())
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .ok_or("data URI contains an invalid percent-encoding")?;
+            data.push(decoded);
+            rest = &tail[2..];
+        } else {
+            data.push(byte);
+            rest = tail;
+        }
+    }
+
+    Ok(Bytes::new(data))
+}
+
 /// How an image should adjust itself to a given area,
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
 pub enum ImageFit {
@@ -253,6 +461,61 @@ pub enum ImageFit {
     Stretch,
 }
 
+/// A rectangular part of an image, relative to the image's size.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ImageCrop {
+    /// The horizontal offset of the part from the image's left edge.
+    pub x: Ratio,
+    /// The vertical offset of the part from the image's top edge.
+    pub y: Ratio,
+    /// The width of the part.
+    pub width: Ratio,
+    /// The height of the part.
+    pub height: Ratio,
+}
+
+impl ImageCrop {
+    /// Ensure that the part is non-empty and lies within the image.
+    fn validate(&self) -> HintedStrResult<()> {
+        // Tolerate rounding errors, as in `7.57% + 92.43%`, which is slightly
+        // more than one when computed with floats.
+        const EPSILON: f64 = 1e-9;
+        let within = |offset: Ratio, extent: Ratio| {
+            offset.get() >= 0.0
+                && extent.get() > 0.0
+                && offset.get() + extent.get() <= 1.0 + EPSILON
+        };
+        if !within(self.x, self.width) || !within(self.y, self.height) {
+            bail!(
+                "crop must lie within the image";
+                hint: "offsets and sizes are relative to the image's size"
+            );
+        }
+        Ok(())
+    }
+}
+
+cast! {
+    ImageCrop,
+    self => dict! {
+        "x" => self.x,
+        "y" => self.y,
+        "width" => self.width,
+        "height" => self.height,
+    }.into_value(),
+    mut dict: Dict => {
+        let mut take = |key| dict.take(key).ok().map(Value::cast::<Ratio>).transpose();
+        let x = take("x")?.unwrap_or_default();
+        let y = take("y")?.unwrap_or_default();
+        let width = take("width")?.unwrap_or(Ratio::one() - x);
+        let height = take("height")?.unwrap_or(Ratio::one() - y);
+        dict.finish(&["x", "y", "width", "height"])?;
+        let crop = Self { x, y, width, height };
+        crop.validate()?;
+        crop
+    },
+}
+
 /// A loaded raster or vector image.
 ///
 /// Values of this type are cheap to clone and hash.
@@ -268,6 +531,8 @@ struct Repr {
     alt: Option<EcoString>,
     /// The scaling algorithm to use.
     scaling: Smart<ImageScaling>,
+    /// The resolution at which to rasterize an SVG's filter effects.
+    raster_fallback: Option<Scalar>,
 }
 
 impl Image {
@@ -284,7 +549,7 @@ impl Image {
         alt: Option<EcoString>,
         scaling: Smart<ImageScaling>,
     ) -> Self {
-        Self::new_impl(kind.into(), alt, scaling)
+        Self::new_impl(kind.into(), alt, scaling, None)
     }
 
     /// Create an image with optional properties set to the default.
@@ -299,8 +564,21 @@ impl Image {
         kind: ImageKind,
         alt: Option<EcoString>,
         scaling: Smart<ImageScaling>,
+        raster_fallback: Option<Scalar>,
     ) -> Image {
-        Self(Arc::new(LazyHash::new(Repr { kind, alt, scaling })))
+        Self(Arc::new(LazyHash::new(Repr { kind, alt, scaling, raster_fallback })))
+    }
+
+    /// Set the resolution in dots per inch at which exporters rasterize the
+    /// filter effects of an SVG image if they can't represent them as vector
+    /// graphics.
+    pub fn with_raster_fallback(self, dpi: Option<f64>) -> Self {
+        Self::new_impl(
+            self.0.kind.clone(),
+            self.0.alt.clone(),
+            self.0.scaling,
+            dpi.map(Scalar::new),
+        )
     }
 
     /// The format of the image.
@@ -345,6 +623,12 @@ impl Image {
         self.0.scaling
     }
 
+    /// The resolution in dots per inch at which to rasterize the filter
+    /// effects of an SVG image, if set.
+    pub fn raster_fallback(&self) -> Option<f64> {
+        self.0.raster_fallback.map(Scalar::get)
+    }
+
     /// The decoded image.
     pub fn kind(&self) -> &ImageKind {
         &self.0.kind
@@ -394,6 +678,48 @@ pub enum ImageFormat {
 }
 
 impl ImageFormat {
+    /// Determine the format of an image from the extension of its path or,
+    /// failing that, from its data.
+    pub fn determine(source: &DataSource, data: &Bytes) -> HintedStrResult<Self> {
+        // PDFs are a common source for letterheads and stationery, but they
+        // can't be embedded as images yet.
+        if data.starts_with(b"%PDF-") {
+            bail!(
+                "PDF documents are not supported as images";
+                hint: "convert the page to SVG or PNG to use it as an image or page background"
+            );
+        }
+
+        if let Some((meta, _)) = split_data_uri(source) {
+            let media_type = meta.split(';').next().unwrap_or_default();
+            match media_type.to_lowercase().as_str() {
+                "image/png" => return Ok(ExchangeFormat::Png.into()),
+                "image/jpeg" | "image/jpg" => return Ok(ExchangeFormat::Jpg.into()),
+                "image/gif" => return Ok(ExchangeFormat::Gif.into()),
+                "image/svg+xml" => return Ok(VectorFormat::Svg.into()),
+                "image/webp" => return Ok(ExchangeFormat::Webp.into()),
+                _ => {}
+            }
+        } else if let DataSource::Path(path) = source {
+            let ext = std::path::Path::new(path.as_str())
+                .extension()
+                .and_then(OsStr::to_str)
+                .unwrap_or_default()
+                .to_lowercase();
+
+            match ext.as_str() {
+                "png" => return Ok(ExchangeFormat::Png.into()),
+                "jpg" | "jpeg" => return Ok(ExchangeFormat::Jpg.into()),
+                "gif" => return Ok(ExchangeFormat::Gif.into()),
+                "svg" | "svgz" => return Ok(VectorFormat::Svg.into()),
+                "webp" => return Ok(ExchangeFormat::Webp.into()),
+                _ => {}
+            }
+        }
+
+        Ok(Self::detect(data).ok_or("unknown image format")?)
+    }
+
     /// Try to detect the format of an image from data.
     pub fn detect(data: &[u8]) -> Option<Self> {
         if let Some(format) = ExchangeFormat::detect(data) {
@@ -1,7 +1,8 @@
 use std::cmp::Ordering;
 use std::hash::{Hash, Hasher};
 use std::io;
-use std::sync::Arc;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, OnceLock};
 
 use crate::diag::{bail, StrResult};
 use crate::foundations::{cast, dict, Bytes, Cast, Dict, Smart, Value};
@@ -11,10 +12,11 @@ use image::codecs::jpeg::JpegDecoder;
 use image::codecs::png::PngDecoder;
 use image::codecs::webp::WebPDecoder;
 use image::{
-    guess_format, DynamicImage, ImageBuffer, ImageDecoder, ImageResult, Limits, Pixel,
+    guess_format, AnimationDecoder, DynamicImage, Frames, ImageBuffer, ImageDecoder,
+    ImageResult, Limits, Pixel,
 };
 
-/// A decoded raster image.
+/// A raster image whose pixels are decoded on demand.
 #[derive(Clone, Hash)]
 pub struct RasterImage(Arc<Repr>);
 
@@ -22,20 +24,53 @@ pub struct RasterImage(Arc<Repr>);
 struct Repr {
     data: Bytes,
     format: RasterFormat,
-    dynamic: Arc<DynamicImage>,
+    frame: NonZeroUsize,
+    size: (u32, u32),
+    dynamic: OnceLock<StrResult<Arc<DynamicImage>>>,
     exif_rotation: Option<u32>,
     icc: Option<Bytes>,
     dpi: Option<f64>,
 }
 
 impl RasterImage {
-    /// Decode a raster image.
+    /// Create a raster image, reading only its header.
+    ///
+    /// The pixels are decoded the first time they are
+    /// [accessed](Self::dynamic), which layout never needs to do.
     pub fn new(
         data: Bytes,
         format: impl Into<RasterFormat>,
         icc: Smart<Bytes>,
     ) -> StrResult<Self> {
-        Self::new_impl(data, format.into(), icc)
+        Self::new_impl(data, format.into(), icc, NonZeroUsize::ONE)
+    }
+
+    /// Create a raster image showing one frame of an animation.
+    ///
+    /// Unlike with [`new`](Self::new), the frame is decoded right away, along
+    /// with all frames before it that it may build upon.
+    pub fn with_frame(
+        data: Bytes,
+        format: impl Into<RasterFormat>,
+        icc: Smart<Bytes>,
+        frame: NonZeroUsize,
+    ) -> StrResult<Self> {
+        Self::new_impl(data, format.into(), icc, frame)
+    }
+
+    /// Create a raster image from raw pixel data, for instance from a
+    /// programmatically generated plot.
+    ///
+    /// The pixels are given in row-major order from the top left, with the
+    /// channels of each pixel as determined by the encoding.
+    pub fn from_pixels(
+        width: u32,
+        height: u32,
+        encoding: PixelEncoding,
+        pixels: &[u8],
+    ) -> StrResult<Self> {
+        let format = PixelFormat::new(encoding, width, height);
+        Self::plain(Bytes::new(pixels.to_vec()), format)
     }
 
     /// Create a raster image with optional properties set to the default.
@@ -44,58 +79,55 @@ impl RasterImage {
     }
 
     /// The internal, non-generic implementation.
+    ///
+    /// This is memoized on the contents of `data` rather than on where it was
+    /// loaded from, so an edited image file is read afresh as soon as the
+    /// world returns its new bytes.
     #[comemo::memoize]
     #[typst_macros::time(name = "load raster image")]
     fn new_impl(
         data: Bytes,
         format: RasterFormat,
         icc: Smart<Bytes>,
+        frame: NonZeroUsize,
     ) -> StrResult<RasterImage> {
         let mut exif_rot = None;
 
-        let (dynamic, icc, dpi) = match format {
+        let (size, icc, dpi) = match format {
             RasterFormat::Exchange(format) => {
-                fn decode<T: ImageDecoder>(
-                    decoder: ImageResult<T>,
-                    icc: Smart<Bytes>,
-                ) -> ImageResult<(image::DynamicImage, Option<Bytes>)> {
-                    let mut decoder = decoder?;
-                    let icc = icc.custom().or_else(|| {
-                        decoder
-                            .icc_profile()
-                            .ok()
-                            .flatten()
-                            .filter(|icc| !icc.is_empty())
-                            .map(Bytes::new)
-                    });
-                    decoder.set_limits(Limits::default())?;
-                    let dynamic = image::DynamicImage::from_decoder(decoder)?;
-                    Ok((dynamic, icc))
-                }
+                let mut decoder = decoder(&data, format).map_err(format_image_error)?;
 
-                let cursor = io::Cursor::new(&data);
-                let (mut dynamic, icc) = match format {
-                    ExchangeFormat::Jpg => decode(JpegDecoder::new(cursor), icc),
-                    ExchangeFormat::Png => decode(PngDecoder::new(cursor), icc),
-                    ExchangeFormat::Gif => decode(GifDecoder::new(cursor), icc),
-                    ExchangeFormat::Webp => decode(WebPDecoder::new(cursor), icc),
-                }
-                .map_err(format_image_error)?;
+                // Check the dimensions from the header against the limits
+                // right away, so that decoding on demand can't exhaust memory.
+                decoder.set_limits(Limits::default()).map_err(format_image_error)?;
+
+                let icc = icc.custom().or_else(|| {
+                    decoder
+                        .icc_profile()
+                        .ok()
+                        .flatten()
+                        .filter(|icc| !icc.is_empty())
+                        .map(Bytes::new)
+                });
 
                 let exif = exif::Reader::new()
                     .read_from_container(&mut std::io::Cursor::new(&data))
                     .ok();
 
-                // Apply rotation from EXIF metadata.
+                // Rotations by 90 degrees from EXIF metadata swap the sides.
+                let (width, height) = decoder.dimensions();
+                let mut size = (width, height);
                 if let Some(rotation) = exif.as_ref().and_then(exif_rotation) {
-                    apply_rotation(&mut dynamic, rotation);
+                    if (5..=8).contains(&rotation) {
+                        size = (height, width);
+                    }
                     exif_rot = Some(rotation);
                 }
 
                 // Extract pixel density.
                 let dpi = determine_dpi(&data, exif.as_ref());
 
-                (dynamic, icc, dpi)
+                (size, icc, dpi)
             }
 
             RasterFormat::Pixel(format) => {
@@ -122,30 +154,28 @@ impl RasterImage {
                     bail!("pixel dimensions and pixel data do not match");
                 }
 
-                fn to<P: Pixel<Subpixel = u8>>(
-                    data: &Bytes,
-                    format: PixelFormat,
-                ) -> ImageBuffer<P, Vec<u8>> {
-                    ImageBuffer::from_raw(format.width, format.height, data.to_vec())
-                        .unwrap()
-                }
-
-                let dynamic = match format.encoding {
-                    PixelEncoding::Rgb8 => to::<image::Rgb<u8>>(&data, format).into(),
-                    PixelEncoding::Rgba8 => to::<image::Rgba<u8>>(&data, format).into(),
-                    PixelEncoding::Luma8 => to::<image::Luma<u8>>(&data, format).into(),
-                    PixelEncoding::Lumaa8 => to::<image::LumaA<u8>>(&data, format).into(),
-                };
-
-                (dynamic, icc.custom(), None)
+                ((format.width, format.height), icc.custom(), None)
             }
         };
 
+        // Later frames of an animation can only be decoded together with the
+        // frames before them, so we do it here to report missing frames.
+        let dynamic = if frame.get() > 1 {
+            let RasterFormat::Exchange(format) = format else {
+                bail!("raw pixel data has only one frame");
+            };
+            OnceLock::from(Ok(Arc::new(decode_frame(&data, format, frame)?)))
+        } else {
+            OnceLock::new()
+        };
+
         Ok(Self(Arc::new(Repr {
             data,
             format,
+            frame,
+            size,
+            dynamic,
             exif_rotation: exif_rot,
-            dynamic: Arc::new(dynamic),
             icc,
             dpi,
         })))
@@ -161,17 +191,26 @@ impl RasterImage {
         self.0.format
     }
 
+    /// The frame of the animation that the image shows, starting at one.
+    pub fn frame(&self) -> NonZeroUsize {
+        self.0.frame
+    }
+
     /// The image's pixel width.
     pub fn width(&self) -> u32 {
-        self.dynamic().width()
+        self.0.size.0
     }
 
     /// The image's pixel height.
     pub fn height(&self) -> u32 {
-        self.dynamic().height()
+        self.0.size.1
     }
 
-    /// TODO.
+    /// The orientation from the image's EXIF metadata, if any.
+    ///
+    /// The decoded [dynamic image](Self::dynamic) is already upright, but the
+    /// raw [data](Self::data) is not, so exporters that embed the data
+    /// directly must apply the orientation themselves.
     pub fn exif_rotation(&self) -> Option<u32> {
         self.0.exif_rotation
     }
@@ -183,9 +222,24 @@ impl RasterImage {
         self.0.dpi
     }
 
-    /// Access the underlying dynamic image.
+    /// Access the underlying dynamic image, decoding it on first access.
+    ///
+    /// Fails if the pixel data turns out to be corrupt beyond the header.
+    pub fn decoded(&self) -> StrResult<&Arc<DynamicImage>> {
+        self.0
+            .dynamic
+            .get_or_init(|| self.0.decode().map(Arc::new).map_err(format_image_error))
+            .as_ref()
+            .map_err(Clone::clone)
+    }
+
+    /// Access the underlying dynamic image, decoding it on first access.
+    ///
+    /// # Panics
+    /// Panics if the image cannot be [decoded](Self::decoded). Exporters should
+    /// check this and report an error before accessing the pixels.
     pub fn dynamic(&self) -> &Arc<DynamicImage> {
-        &self.0.dynamic
+        self.decoded().expect("image pixels should be decodable")
     }
 
     /// Access the ICC profile, if any.
@@ -194,11 +248,93 @@ impl RasterImage {
     }
 }
 
+impl Repr {
+    /// Decode the pixels of the image.
+    #[typst_macros::time(name = "decode raster image")]
+    fn decode(&self) -> ImageResult<DynamicImage> {
+        match self.format {
+            RasterFormat::Exchange(format) => {
+                let mut decoder = decoder(&self.data, format)?;
+                decoder.set_limits(Limits::default())?;
+                let mut dynamic = DynamicImage::from_decoder(decoder)?;
+
+                // Apply rotation from EXIF metadata.
+                if let Some(rotation) = self.exif_rotation {
+                    apply_rotation(&mut dynamic, rotation);
+                }
+
+                Ok(dynamic)
+            }
+
+            RasterFormat::Pixel(format) => {
+                fn to<P: Pixel<Subpixel = u8>>(
+                    data: &Bytes,
+                    format: PixelFormat,
+                ) -> ImageBuffer<P, Vec<u8>> {
+                    ImageBuffer::from_raw(format.width, format.height, data.to_vec())
+                        .unwrap()
+                }
+
+                let data = &self.data;
+                Ok(match format.encoding {
+                    PixelEncoding::Rgb8 => to::<image::Rgb<u8>>(data, format).into(),
+                    PixelEncoding::Rgba8 => to::<image::Rgba<u8>>(data, format).into(),
+                    PixelEncoding::Luma8 => to::<image::Luma<u8>>(data, format).into(),
+                    PixelEncoding::Lumaa8 => to::<image::LumaA<u8>>(data, format).into(),
+                })
+            }
+        }
+    }
+}
+
+/// Create a decoder for image data in an exchange format, which reads the
+/// header right away.
+fn decoder(
+    data: &Bytes,
+    format: ExchangeFormat,
+) -> ImageResult<Box<dyn ImageDecoder + '_>> {
+    let cursor = io::Cursor::new(data);
+    Ok(match format {
+        ExchangeFormat::Jpg => Box::new(JpegDecoder::new(cursor)?),
+        ExchangeFormat::Png => Box::new(PngDecoder::new(cursor)?),
+        ExchangeFormat::Gif => Box::new(GifDecoder::new(cursor)?),
+        ExchangeFormat::Webp => Box::new(WebPDecoder::new(cursor)?),
+    })
+}
+
+/// Decode one frame of an animated image.
+fn decode_frame(
+    data: &Bytes,
+    format: ExchangeFormat,
+    frame: NonZeroUsize,
+) -> StrResult<DynamicImage> {
+    let cursor = io::Cursor::new(data);
+    let mut frames: Frames = match format {
+        ExchangeFormat::Gif => GifDecoder::new(cursor).map(AnimationDecoder::into_frames),
+        ExchangeFormat::Png => PngDecoder::new(cursor)
+            .and_then(PngDecoder::apng)
+            .map(AnimationDecoder::into_frames),
+        ExchangeFormat::Webp => {
+            WebPDecoder::new(cursor).map(AnimationDecoder::into_frames)
+        }
+        ExchangeFormat::Jpg => bail!("JPEG images have only one frame"),
+    }
+    .map_err(format_image_error)?;
+
+    match frames.nth(frame.get() - 1) {
+        Some(decoded) => Ok(DynamicImage::ImageRgba8(
+            decoded.map_err(format_image_error)?.into_buffer(),
+        )),
+        None => bail!("image has no frame {frame}"),
+    }
+}
+
 impl Hash for Repr {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // The image is fully defined by data, format, and ICC profile.
+        // The image is fully defined by data, format, frame, and ICC profile.
         self.data.hash(state);
         self.format.hash(state);
+        self.frame.hash(state);
         self.icc.hash(state);
     }
 }
@@ -242,7 +378,7 @@ pub enum ExchangeFormat {
     /// Lossy raster format suitable for photos.
     Jpg,
     /// Raster format that is typically used for short animated clips. Typst can
-    /// load GIFs, but they will become static.
+    /// load GIFs, but they will become static: Only the first frame is shown.
     Gif,
     /// Raster format that supports both lossy and lossless compression.
     Webp,
@@ -291,6 +427,28 @@ pub struct PixelFormat {
     height: u32,
 }
 
+impl PixelFormat {
+    /// Create a pixel format from a channel encoding and dimensions.
+    pub fn new(encoding: PixelEncoding, width: u32, height: u32) -> Self {
+        Self { encoding, width, height }
+    }
+
+    /// The channel encoding.
+    pub fn encoding(self) -> PixelEncoding {
+        self.encoding
+    }
+
+    /// The pixel width.
+    pub fn width(self) -> u32 {
+        self.width
+    }
+
+    /// The pixel height.
+    pub fn height(self) -> u32 {
+        self.height
+    }
+}
+
 /// Determines the channel encoding of raw pixel data.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
 pub enum PixelEncoding {
@@ -346,12 +504,12 @@ fn apply_rotation(image: &mut DynamicImage, rotation: u32) {
             ops::flip_horizontal_in_place(image);
             *image = image.rotate270();
         }
-        6 => *image = image.rotate270(),
+        6 => *image = image.rotate90(),
         7 => {
             ops::flip_horizontal_in_place(image);
             *image = image.rotate90();
         }
-        8 => *image = image.rotate90(),
+        8 => *image = image.rotate270(),
         _ => {}
     }
 }
@@ -467,4 +625,84 @@ mod tests {
         test("images/tiger.jpg", ExchangeFormat::Jpg, 72.0);
         test("images/graph.png", ExchangeFormat::Png, 144.0);
     }
+
+    #[test]
+    fn test_image_exif_rotation() {
+        #[track_caller]
+        fn test(rotation: u32, expected: [[u8; 2]; 3]) {
+            // A 3×2 image whose pixels are numbered in reading order.
+            let pixels =
+                image::GrayImage::from_fn(3, 2, |x, y| image::Luma([(y * 3 + x) as u8]));
+            let mut dynamic = DynamicImage::from(pixels);
+            apply_rotation(&mut dynamic, rotation);
+            let gray = dynamic.to_luma8();
+            assert_eq!((gray.width(), gray.height()), (2, 3));
+            for (y, row) in expected.iter().enumerate() {
+                for (x, &value) in row.iter().enumerate() {
+                    assert_eq!(gray.get_pixel(x as u32, y as u32).0, [value]);
+                }
+            }
+        }
+
+        test(5, [[0, 3], [1, 4], [2, 5]]);
+        test(6, [[3, 0], [4, 1], [5, 2]]);
+        test(7, [[5, 2], [4, 1], [3, 0]]);
+        test(8, [[2, 5], [1, 4], [0, 3]]);
+    }
+
+    #[test]
+    fn test_image_corrupt_pixels() {
+        // The header is intact, so loading succeeds, but decoding fails.
+        let data = typst_dev_assets::get("images/graph.png").unwrap();
+        let bytes = Bytes::new(data[..data.len() / 2].to_vec());
+        let image = RasterImage::plain(bytes, ExchangeFormat::Png).unwrap();
+        assert!(image.decoded().is_err());
+    }
+
+    #[test]
+    fn test_image_header_limits() {
+        // A GIF whose logical screen claims a huge size is rejected when
+        // loading, before any pixels are decoded.
+        let pixels = image::RgbaImage::new(1, 1);
+        let mut data = vec![];
+        pixels
+            .write_to(&mut io::Cursor::new(&mut data), image::ImageFormat::Gif)
+            .unwrap();
+        data[6..10].copy_from_slice(&[0xff; 4]);
+        let bytes = Bytes::new(data);
+        let err = RasterImage::plain(bytes, ExchangeFormat::Gif).err().unwrap();
+        assert_eq!(err, "file is too large");
+    }
+
+    #[test]
+    fn test_image_from_pixels() {
+        let pixels = [255, 0, 0, 255, 0, 0, 255, 128];
+        let image =
+            RasterImage::from_pixels(2, 1, PixelEncoding::Rgba8, &pixels).unwrap();
+        assert_eq!((image.width(), image.height()), (2, 1));
+        assert_eq!(image.dynamic().to_rgba8().get_pixel(1, 0).0, [0, 0, 255, 128]);
+        assert!(RasterImage::from_pixels(2, 2, PixelEncoding::Rgba8, &pixels).is_err());
+        assert!(RasterImage::from_pixels(0, 1, PixelEncoding::Luma8, &[]).is_err());
+    }
+
+    #[test]
+    fn test_image_gif_and_webp() {
+        #[track_caller]
+        fn test(format: ExchangeFormat) {
+            let pixels =
+                image::RgbaImage::from_pixel(3, 2, image::Rgba([255, 0, 0, 255]));
+            let mut data = vec![];
+            pixels
+                .write_to(&mut io::Cursor::new(&mut data), format.into())
+                .unwrap();
+            let bytes = Bytes::new(data);
+            assert_eq!(ExchangeFormat::detect(&bytes), Some(format));
+            let image = RasterImage::plain(bytes, format).unwrap();
+            assert_eq!(image.format(), RasterFormat::Exchange(format));
+            assert_eq!((image.width(), image.height()), (3, 2));
+        }
+
+        test(ExchangeFormat::Gif);
+        test(ExchangeFormat::Webp);
+    }
 }
@@ -4,6 +4,7 @@ use std::sync::{Arc, Mutex};
 
 use comemo::Tracked;
 use siphasher::sip128::{Hasher128, SipHasher13};
+use typst_syntax::FileId;
 
 use crate::diag::{format_xml_like_error, LoadError, LoadResult, ReportPos};
 use crate::foundations::Bytes;
@@ -11,6 +12,7 @@ use crate::layout::Axes;
 use crate::text::{
     Font, FontBook, FontFlags, FontStretch, FontStyle, FontVariant, FontWeight,
 };
+use crate::visualize::{ExchangeFormat, ImageFormat, RasterFormat, VectorFormat};
 use crate::World;
 
 /// A decoded SVG.
@@ -21,8 +23,9 @@ pub struct SvgImage(Arc<Repr>);
 struct Repr {
     data: Bytes,
     size: Axes<f64>,
-    font_hash: u128,
+    resources_hash: u128,
     tree: usvg::Tree,
+    unsupported: Vec<&'static str>,
 }
 
 impl SvgImage {
@@ -32,36 +35,53 @@ impl SvgImage {
     pub fn new(data: Bytes) -> LoadResult<SvgImage> {
         let tree =
             usvg::Tree::from_data(&data, &base_options()).map_err(format_usvg_error)?;
-        Ok(Self(Arc::new(Repr { data, size: tree_size(&tree), font_hash: 0, tree })))
+        Ok(Self::from_tree(data, tree, 0))
     }
 
-    /// Decode an SVG image with access to fonts.
+    /// Decode an SVG image with access to fonts and files.
     #[comemo::memoize]
     #[typst_macros::time(name = "load svg")]
-    pub fn with_fonts(
+    pub fn with_options(
         data: Bytes,
         world: Tracked<dyn World + '_>,
-        families: &[&str],
+        options: &SvgOptions,
     ) -> LoadResult<SvgImage> {
         let book = world.book();
-        let resolver = Mutex::new(FontResolver::new(world, book, families));
+        let fonts = Mutex::new(FontResolver::new(world, book, options.families));
+        let files = Mutex::new(FileResolver::new(world, options.base));
         let tree = usvg::Tree::from_data(
             &data,
             &usvg::Options {
                 font_resolver: usvg::FontResolver {
                     select_font: Box::new(|font, db| {
-                        resolver.lock().unwrap().select_font(font, db)
+                        fonts.lock().unwrap().select_font(font, db)
                     }),
                     select_fallback: Box::new(|c, exclude_fonts, db| {
-                        resolver.lock().unwrap().select_fallback(c, exclude_fonts, db)
+                        fonts.lock().unwrap().select_fallback(c, exclude_fonts, db)
+                    }),
+                },
+                image_href_resolver: usvg::ImageHrefResolver {
+                    resolve_data: usvg::ImageHrefResolver::default_data_resolver(),
+                    resolve_string: Box::new(|href, options| {
+                        files.lock().unwrap().resolve(href, options)
                     }),
                 },
                 ..base_options()
             },
         )
         .map_err(format_usvg_error)?;
-        let font_hash = resolver.into_inner().unwrap().finish();
-        Ok(Self(Arc::new(Repr { data, size: tree_size(&tree), font_hash, tree })))
+        let resources_hash = typst_utils::hash128(&(
+            fonts.into_inner().unwrap().finish(),
+            files.into_inner().unwrap().finish(),
+        ));
+        Ok(Self::from_tree(data, tree, resources_hash))
+    }
+
+    /// Create an image from a decoded tree.
+    fn from_tree(data: Bytes, tree: usvg::Tree, resources_hash: u128) -> Self {
+        let size = tree_size(&tree);
+        let unsupported = unsupported_elements(&data);
+        Self(Arc::new(Repr { data, size, resources_hash, tree, unsupported }))
     }
 
     /// The raw image data.
@@ -83,20 +103,45 @@ impl SvgImage {
     pub fn tree(&self) -> &usvg::Tree {
         &self.0.tree
     }
+
+    /// The names of the elements in the SVG that are ignored when decoding
+    /// it, like scripts and animations.
+    pub fn unsupported_elements(&self) -> &[&'static str] {
+        &self.0.unsupported
+    }
+
+    /// Whether the SVG uses filter effects, which not all export formats can
+    /// represent as vector graphics.
+    pub fn has_filters(&self) -> bool {
+        !self.0.tree.filters().is_empty()
+    }
 }
 
 impl Hash for Repr {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // An SVG might contain fonts, which must be incorporated into the hash.
-        // We can't hash a usvg tree directly, but the raw SVG data + a hash of
-        // all used fonts gives us something similar.
+        // An SVG might contain fonts and images from other files, which must
+        // be incorporated into the hash. We can't hash a usvg tree directly,
+        // but the raw SVG data + a hash of all used fonts and files gives us
+        // something similar.
         self.data.hash(state);
-        self.font_hash.hash(state);
+        self.resources_hash.hash(state);
     }
 }
 
-/// The base conversion options, to be extended with font-related options
-/// because those can change across the document.
+/// Options for decoding an SVG with access to fonts and files.
+#[derive(Debug, Copy, Clone, Hash)]
+pub struct SvgOptions<'a> {
+    /// The file that image references in the SVG are relative to, typically
+    /// the SVG file itself. If `None`, only images embedded as data URLs are
+    /// supported.
+    pub base: Option<FileId>,
+    /// The font families to try for text in the SVG whose own font families
+    /// aren't available.
+    pub families: &'a [&'a str],
+}
+
+/// The base conversion options, to be extended with font- and file-related
+/// options because those can change across the document.
 fn base_options() -> usvg::Options<'static> {
     usvg::Options {
         // Disable usvg's default to "Times New Roman".
@@ -107,7 +152,8 @@ fn base_options() -> usvg::Options<'static> {
         // the width and height. Changing the DPI only trips up
         // the logic in `resvg`.
 
-        // Override usvg's resource loading defaults.
+        // Override usvg's resource loading defaults. Images referenced by
+        // path are loaded through the world by `FileResolver` instead.
         resources_dir: None,
         image_href_resolver: usvg::ImageHrefResolver {
             resolve_data: usvg::ImageHrefResolver::default_data_resolver(),
@@ -123,6 +169,51 @@ fn tree_size(tree: &usvg::Tree) -> Axes<f64> {
     Axes::new(tree.size().width() as f64, tree.size().height() as f64)
 }
 
+/// Elements that usvg skips when decoding an SVG.
+const UNSUPPORTED_ELEMENTS: &[&str] = &[
+    "altGlyph",
+    "animate",
+    "animateColor",
+    "animateMotion",
+    "animateTransform",
+    "cursor",
+    "discard",
+    "font",
+    "font-face",
+    "foreignObject",
+    "glyph",
+    "hkern",
+    "missing-glyph",
+    "script",
+    "set",
+    "vkern",
+];
+
+/// Find the unsupported elements in an SVG, in order of their first
+/// occurrence.
+///
+/// As usvg drops these elements from its tree, this searches the raw data for
+/// their opening tags instead of parsing it a second time. Not perfect
+/// because the SVG could also be compressed or use a namespace prefix, but
+/// that's an edge case.
+fn unsupported_elements(data: &[u8]) -> Vec<&'static str> {
+    let mut found = UNSUPPORTED_ELEMENTS
+        .iter()
+        .filter_map(|&name| {
+            let tag = [b"<", name.as_bytes()].concat();
+            let pos = memchr::memmem::find_iter(data, &tag).find(|&i| {
+                matches!(
+                    data.get(i + tag.len()),
+                    Some(b' ' | b'\t' | b'\n' | b'\r' | b'/' | b'>')
+                )
+            })?;
+            Some((pos, name))
+        })
+        .collect::<Vec<_>>();
+    found.sort_by_key(|&(pos, _)| pos);
+    found.into_iter().map(|(_, name)| name).collect()
+}
+
 /// Format the user-facing SVG decoding error message.
 fn format_usvg_error(error: usvg::Error) -> LoadError {
     let error = match error {
@@ -135,6 +226,58 @@ fn format_usvg_error(error: usvg::Error) -> LoadError {
     LoadError::new(ReportPos::None, "failed to parse SVG", error)
 }
 
+/// Loads images that an SVG references by path through the world.
+struct FileResolver<'a> {
+    /// The world we use to load files.
+    world: Tracked<'a, dyn World + 'a>,
+    /// The file that references are relative to.
+    base: Option<FileId>,
+    /// Accumulates a hash of all loaded files.
+    hasher: SipHasher13,
+}
+
+impl<'a> FileResolver<'a> {
+    /// Create a new file resolver.
+    fn new(world: Tracked<'a, dyn World + 'a>, base: Option<FileId>) -> Self {
+        Self { world, base, hasher: SipHasher13::new() }
+    }
+
+    /// Returns a hash of all loaded files.
+    fn finish(self) -> u128 {
+        self.hasher.finish128().as_u128()
+    }
+
+    /// Load the image that an `href` refers to.
+    fn resolve(
+        &mut self,
+        href: &str,
+        options: &usvg::Options,
+    ) -> Option<usvg::ImageKind> {
+        // Web references are not supported, like everywhere else in Typst.
+        if href.contains("://") {
+            return None;
+        }
+
+        let data = self.world.file(self.base?.join(href)).ok()?;
+        href.hash(&mut self.hasher);
+        data.hash(&mut self.hasher);
+
+        let raw = || Arc::new(data.to_vec());
+        Some(match ImageFormat::detect(&data)? {
+            ImageFormat::Raster(RasterFormat::Exchange(format)) => match format {
+                ExchangeFormat::Png => usvg::ImageKind::PNG(raw()),
+                ExchangeFormat::Jpg => usvg::ImageKind::JPEG(raw()),
+                ExchangeFormat::Gif => usvg::ImageKind::GIF(raw()),
+                ExchangeFormat::Webp => usvg::ImageKind::WEBP(raw()),
+            },
+            ImageFormat::Raster(RasterFormat::Pixel(_)) => return None,
+            ImageFormat::Vector(VectorFormat::Svg) => {
+                usvg::ImageKind::SVG(usvg::Tree::from_data(&data, options).ok()?)
+            }
+        })
+    }
+}
+
 /// Provides Typst's fonts to usvg.
 struct FontResolver<'a> {
     /// Typst's font book.
@@ -192,7 +335,11 @@ impl FontResolver<'_> {
             .iter()
             .filter_map(|family| match family {
                 usvg::FontFamily::Named(named) => Some(named.as_str()),
-                // We don't support generic families at the moment.
+                // Map generic families to the fonts Typst uses for them by
+                // default. Other generic families fall back to the fonts
+                // active at the image.
+                usvg::FontFamily::Serif => Some("libertinus serif"),
+                usvg::FontFamily::Monospace => Some("dejavu sans mono"),
                 _ => None,
             })
             .chain(self.families.iter().copied())
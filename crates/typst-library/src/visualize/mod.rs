@@ -1,30 +1,44 @@
 //! Drawing and visualization.
 
+mod barcode;
+mod board;
+mod chart;
 mod color;
 mod curve;
+mod gantt;
 mod gradient;
 mod image;
 mod line;
+mod music;
 mod paint;
 mod path;
 mod polygon;
+mod qrcode;
 mod shape;
 mod stroke;
+mod text_path;
 mod tiling;
 
+pub use self::barcode::*;
+pub use self::board::*;
+pub use self::chart::*;
 pub use self::color::*;
 pub use self::curve::*;
+pub use self::gantt::*;
 pub use self::gradient::*;
 pub use self::image::*;
 pub use self::line::*;
+pub use self::music::*;
 pub use self::paint::*;
 pub use self::path::*;
 pub use self::polygon::*;
+pub use self::qrcode::*;
 pub use self::shape::*;
 pub use self::stroke::*;
+pub use self::text_path::*;
 pub use self::tiling::*;
 
-use crate::foundations::{Element, Scope, Type};
+use crate::foundations::{Deprecation, Element, Scope, Type};
 
 /// Hook up all visualize definitions.
 pub(super) fn define(global: &mut Scope) {
@@ -41,11 +55,23 @@ pub(super) fn define(global: &mut Scope) {
     global.define_elem::<CircleElem>();
     global.define_elem::<PolygonElem>();
     global.define_elem::<CurveElem>();
+    global.define_elem::<TextPathElem>();
+    global.define_elem::<QrCodeElem>();
+    global.define_elem::<BarcodeElem>();
+    global.define_elem::<ChartElem>();
+    global.define_elem::<ChordElem>();
+    global.define_elem::<FretboardElem>();
+    global.define_elem::<BoardElem>();
+    global.define_elem::<GanttElem>();
     global
         .define("path", Element::of::<PathElem>())
-        .deprecated("the `path` function is deprecated, use `curve` instead");
+        .deprecated(Deprecation::new(
+            "the `path` function is deprecated, use `curve` instead",
+        ));
     global
         .define("pattern", Type::of::<Tiling>())
-        .deprecated("the name `pattern` is deprecated, use `tiling` instead");
+        .deprecated(Deprecation::new(
+            "the name `pattern` is deprecated, use `tiling` instead",
+        ));
     global.reset_category();
 }
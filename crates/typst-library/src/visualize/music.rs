@@ -0,0 +1,237 @@
+use std::num::NonZeroUsize;
+
+use ecow::EcoString;
+use typst_utils::NonZeroExt;
+
+use crate::diag::{bail, SourceResult, StrResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    cast, elem, Content, IntoValue, NativeElement, Packed, Show, Smart, Str, StyleChain,
+};
+use crate::layout::{
+    Alignment, BlockElem, BoxElem, Em, HAlignment, Length, PlaceElem, Rel, Sizing,
+    VAlignment,
+};
+use crate::text::TextElem;
+use crate::visualize::{Color, Paint};
+
+/// A chord symbol above a piece of lyrics.
+///
+/// The chord's name is placed above the start of the lyrics, as is common in
+/// songbooks and lead sheets. The name does not take up space, so the
+/// [leading]($par.leading) should be increased to make room for it. The
+/// characters `b` and `#` after a note name or before a number are displayed
+/// as flat and sharp signs.
+///
+/// # Example
+/// ```example
+/// #set par(leading: 1.4em)
+/// #show chord: set text(weight: "bold")
+///
+/// #chord("G")[A]mazing #chord("G7")[grace], how
+/// #chord("C")[sweet] the #chord("Bb")[sound]
+/// ```
+///
+/// # Sheet music
+/// Chord symbols and [chord diagrams]($fretboard) are the only kinds of music
+/// notation that Typst can typeset. Notes on staves are not supported. To
+/// include sheet music in a document, engrave it with a dedicated program like
+/// LilyPond or MuseScore, export it as an SVG file, and include it with the
+/// [`image`] function.
+#[elem(Show)]
+pub struct ChordElem {
+    /// The chord's name, like `{"Am7"}` or `{"F#/C#"}`.
+    #[required]
+    pub name: Str,
+
+    /// How far the baseline of the chord name is raised above the baseline of
+    /// the lyrics.
+    #[default(Em::new(1.1).into())]
+    pub raise: Length,
+
+    /// The lyrics the chord is played on.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for Packed<ChordElem> {
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let span = self.span();
+        let name = TextElem::packed(format_chord(&self.name)).spanned(span);
+        let placed = PlaceElem::new(name)
+            .with_alignment(Smart::Custom(Alignment::Both(
+                HAlignment::Left,
+                VAlignment::Bottom,
+            )))
+            .with_dy((-self.raise(styles)).into())
+            .pack();
+
+        // Anchor the name at the start of the lyrics without taking up space.
+        let anchor = BoxElem::new()
+            .with_width(Sizing::Rel(Rel::zero()))
+            .with_body(Some(placed))
+            .pack();
+
+        Ok(anchor + self.body.clone())
+    }
+}
+
+/// Replace `b` and `#` with flat and sharp signs where they denote
+/// accidentals.
+fn format_chord(name: &str) -> EcoString {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = EcoString::new();
+    for (i, &c) in chars.iter().enumerate() {
+        let after_note = i > 0 && matches!(chars[i - 1], 'A'..='G');
+        let before_number = chars.get(i + 1).is_some_and(char::is_ascii_digit);
+        match c {
+            'b' if after_note || before_number => out.push('♭'),
+            '#' if after_note || before_number => out.push('♯'),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// A chord diagram for fretted instruments like guitar or ukulele.
+///
+/// The diagram shows the strings as vertical lines and the frets as
+/// horizontal lines. Fretted strings are marked with a dot, open strings with
+/// a circle, and muted strings with a cross above the diagram.
+///
+/// # Example
+/// ```example
+/// #stack(
+///   dir: ltr,
+///   spacing: 1em,
+///   fretboard("x32010", name: [C]),
+///   fretboard("320003", name: [G]),
+///   fretboard("x-5-7-7-6-5", name: [D], base: 5),
+/// )
+/// ```
+#[elem(Show)]
+pub struct FretboardElem {
+    /// The fret of each string, from the lowest string to the highest.
+    ///
+    /// This is either a string or an array. In a string, each character is
+    /// the fret of one string, with `x` for a muted string and `0` for an
+    /// open string. For frets from 10 upwards, the frets must be separated by
+    /// dashes, like `{"x-10-12-12-11-10"}`. In an array, each item is a fret
+    /// number or `{none}` for a muted string.
+    #[required]
+    pub frets: Fingering,
+
+    /// The chord's name, which is shown above the diagram.
+    pub name: Option<Content>,
+
+    /// The lowest fret shown in the diagram.
+    ///
+    /// If this is larger than one, the fret number is shown next to the
+    /// diagram. Otherwise, the nut is drawn as a thick line.
+    #[default(NonZeroUsize::ONE)]
+    pub base: NonZeroUsize,
+
+    /// How many frets to show. If set to `{auto}`, at least four frets are
+    /// shown, and more if needed for the fingering. At most 36 frets can be
+    /// shown.
+    pub rows: Smart<NonZeroUsize>,
+
+    /// The distance between two adjacent strings and two adjacent frets.
+    #[resolve]
+    #[default(Em::new(0.6).into())]
+    pub spacing: Length,
+
+    /// How to fill the lines and markers.
+    #[default(Color::BLACK.into())]
+    pub fill: Paint,
+}
+
+impl Show for Packed<FretboardElem> {
+    fn show(&self, engine: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(BlockElem::single_layouter(self.clone(), engine.routines.layout_fretboard)
+            .pack()
+            .spanned(self.span()))
+    }
+}
+
+/// The frets of the strings of a fretted instrument.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Fingering(Vec<Option<usize>>);
+
+impl Fingering {
+    /// The fret of each string, with `None` for muted strings.
+    pub fn strings(&self) -> &[Option<usize>] {
+        &self.0
+    }
+
+    /// The highest fret that is played.
+    pub fn highest(&self) -> usize {
+        self.0.iter().flatten().copied().max().unwrap_or(0)
+    }
+
+    /// Parse a fingering from a string like `"x32010"` or `"x-10-12-12"`.
+    fn parse(text: &str) -> StrResult<Self> {
+        let fret = |part: &str| -> StrResult<Option<usize>> {
+            match part {
+                "x" | "X" => Ok(None),
+                _ => match part.parse() {
+                    Ok(fret) => Ok(Some(fret)),
+                    Err(_) => bail!("invalid fret `{part}`"),
+                },
+            }
+        };
+
+        let frets = if text.contains('-') {
+            text.split('-').map(|part| fret(part.trim())).collect()
+        } else {
+            text.chars()
+                .filter(|c| !c.is_whitespace())
+                .map(|c| fret(c.encode_utf8(&mut [0; 4])))
+                .collect()
+        };
+
+        Self::new(frets?)
+    }
+
+    /// Create a fingering, ensuring that there is at least one string.
+    fn new(frets: Vec<Option<usize>>) -> StrResult<Self> {
+        if frets.is_empty() {
+            bail!("fingering must have at least one string");
+        }
+        Ok(Self(frets))
+    }
+}
+
+cast! {
+    Fingering,
+    self => self.0.into_value(),
+    v: Str => Self::parse(&v)?,
+    v: Vec<Option<usize>> => Self::new(v)?,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_chord() {
+        assert_eq!(format_chord("Bb"), "B♭");
+        assert_eq!(format_chord("F#m7b5"), "F♯m7♭5");
+        assert_eq!(format_chord("C7#9/Eb"), "C7♯9/E♭");
+        assert_eq!(format_chord("Cdim"), "Cdim");
+    }
+
+    #[test]
+    fn test_fingering_parse() {
+        let fingering = Fingering::parse("x32010").unwrap();
+        assert_eq!(
+            fingering.strings(),
+            [None, Some(3), Some(2), Some(0), Some(1), Some(0)]
+        );
+        assert_eq!(fingering.highest(), 3);
+        let fingering = Fingering::parse("x-10-12-12-11-10").unwrap();
+        assert_eq!(fingering.highest(), 12);
+        assert!(Fingering::parse("x3q").is_err());
+        assert!(Fingering::parse("").is_err());
+    }
+}
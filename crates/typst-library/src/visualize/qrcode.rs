@@ -0,0 +1,630 @@
+use crate::diag::{bail, SourceResult, StrResult};
+use crate::engine::Engine;
+use crate::foundations::{elem, Cast, Content, NativeElement, Packed, Show, StyleChain};
+use crate::layout::{Abs, BlockElem, Length};
+use crate::loading::Readable;
+use crate::visualize::{Color, Paint};
+
+/// A QR code.
+///
+/// The code is drawn with vector shapes, so it stays sharp at any size and
+/// zoom level. Data is always encoded in byte mode, so any string (including
+/// URLs and non-ASCII text) can be encoded.
+///
+/// # Example
+/// ```example
+/// #qrcode("https://typst.app", size: 3cm)
+/// ```
+#[elem(name = "qrcode", title = "QR Code", Show)]
+pub struct QrCodeElem {
+    /// The data to encode, either as a string or as raw bytes.
+    #[required]
+    pub data: Readable,
+
+    /// How much of the code may be damaged or obscured while it remains
+    /// readable.
+    ///
+    /// Higher levels make the code more robust, but require more modules for
+    /// the same data.
+    ///
+    /// ```example
+    /// #stack(
+    ///   dir: ltr,
+    ///   spacing: 1em,
+    ///   qrcode("Hello", ec-level: "low"),
+    ///   qrcode("Hello", ec-level: "high"),
+    /// )
+    /// ```
+    #[default(QrErrorCorrection::Medium)]
+    pub ec_level: QrErrorCorrection,
+
+    /// The width and height of the code, including its quiet zone.
+    #[resolve]
+    #[default(Abs::cm(2.5).into())]
+    pub size: Length,
+
+    /// How to fill the code's dark modules.
+    #[default(Color::BLACK.into())]
+    pub fill: Paint,
+
+    /// Whether to reserve the blank margin of four modules around the code
+    /// that scanners need to detect it.
+    #[default(true)]
+    pub quiet_zone: bool,
+}
+
+impl Show for Packed<QrCodeElem> {
+    fn show(&self, engine: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(BlockElem::single_layouter(self.clone(), engine.routines.layout_qrcode)
+            .pack()
+            .spanned(self.span()))
+    }
+}
+
+/// The error correction level of a QR code.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum QrErrorCorrection {
+    /// Recovers about 7% of the data.
+    Low,
+    /// Recovers about 15% of the data.
+    Medium,
+    /// Recovers about 25% of the data.
+    Quartile,
+    /// Recovers about 30% of the data.
+    High,
+}
+
+impl QrErrorCorrection {
+    /// The index of this level in the capacity tables.
+    fn index(self) -> usize {
+        match self {
+            Self::Low => 0,
+            Self::Medium => 1,
+            Self::Quartile => 2,
+            Self::High => 3,
+        }
+    }
+
+    /// The two bits identifying this level in the format information.
+    fn format_bits(self) -> u32 {
+        match self {
+            Self::Low => 1,
+            Self::Medium => 0,
+            Self::Quartile => 3,
+            Self::High => 2,
+        }
+    }
+}
+
+/// An encoded QR code symbol.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct QrCode {
+    /// The number of modules along each side.
+    size: usize,
+    /// Whether each module is dark, in row-major order.
+    modules: Vec<bool>,
+    /// Whether each module belongs to a function pattern and is thus exempt
+    /// from masking. Only needed during construction.
+    function: Vec<bool>,
+}
+
+impl QrCode {
+    /// Encode data as a QR code of the smallest version that fits it at the
+    /// given error correction level.
+    pub fn encode(data: &[u8], ecl: QrErrorCorrection) -> StrResult<Self> {
+        let Some(version) = (1..=40).find(|&version| {
+            let count_bits = if version <= 9 { 8 } else { 16 };
+            4 + count_bits + data.len() * 8 <= num_data_codewords(version, ecl) * 8
+        }) else {
+            bail!("data is too long for a QR code");
+        };
+
+        let mut code = Self {
+            size: version * 4 + 17,
+            modules: vec![false; (version * 4 + 17).pow(2)],
+            function: vec![false; (version * 4 + 17).pow(2)],
+        };
+
+        code.draw_function_patterns(version, ecl);
+        let data = encode_data(data, version, ecl);
+        let codewords = add_ecc_and_interleave(&data, version, ecl);
+        code.draw_codewords(&codewords);
+
+        // Pick the mask that results in the lowest penalty.
+        let mask = (0..8)
+            .min_by_key(|&mask| {
+                code.apply_mask(mask);
+                code.draw_format_bits(ecl, mask);
+                let penalty = code.penalty();
+                code.apply_mask(mask);
+                penalty
+            })
+            .unwrap();
+
+        code.apply_mask(mask);
+        code.draw_format_bits(ecl, mask);
+        code.function = vec![];
+        Ok(code)
+    }
+
+    /// The number of modules along each side, excluding the quiet zone.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Whether the module at the given column and row is dark.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.size + x]
+    }
+
+    /// Set a module that belongs to a function pattern.
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        self.modules[y * self.size + x] = dark;
+        self.function[y * self.size + x] = true;
+    }
+
+    /// Draw the finder, timing, and alignment patterns as well as the
+    /// format and version information.
+    fn draw_function_patterns(&mut self, version: usize, ecl: QrErrorCorrection) {
+        let size = self.size;
+        for i in 0..size {
+            self.set_function(6, i, i % 2 == 0);
+            self.set_function(i, 6, i % 2 == 0);
+        }
+
+        self.draw_finder(3, 3);
+        self.draw_finder(size - 4, 3);
+        self.draw_finder(3, size - 4);
+
+        let positions = alignment_positions(version);
+        let n = positions.len();
+        for (i, &x) in positions.iter().enumerate() {
+            for (j, &y) in positions.iter().enumerate() {
+                // Skip the three corners occupied by finder patterns.
+                let corner = (i == 0 && j == 0)
+                    || (i == 0 && j == n - 1)
+                    || (i == n - 1 && j == 0);
+                if !corner {
+                    self.draw_alignment(x, y);
+                }
+            }
+        }
+
+        // Reserve the format area with dummy bits.
+        self.draw_format_bits(ecl, 0);
+        self.draw_version(version);
+    }
+
+    /// Draw a finder pattern including its separator centered at the given
+    /// module.
+    fn draw_finder(&mut self, x: usize, y: usize) {
+        for dy in -4..=4_isize {
+            for dx in -4..=4_isize {
+                let xx = x as isize + dx;
+                let yy = y as isize + dy;
+                if (0..self.size as isize).contains(&xx)
+                    && (0..self.size as isize).contains(&yy)
+                {
+                    let dist = dx.abs().max(dy.abs());
+                    self.set_function(xx as usize, yy as usize, dist != 2 && dist != 4);
+                }
+            }
+        }
+    }
+
+    /// Draw an alignment pattern centered at the given module.
+    fn draw_alignment(&mut self, x: usize, y: usize) {
+        for dy in -2..=2_isize {
+            for dx in -2..=2_isize {
+                let dark = dx.abs().max(dy.abs()) != 1;
+                self.set_function(
+                    (x as isize + dx) as usize,
+                    (y as isize + dy) as usize,
+                    dark,
+                );
+            }
+        }
+    }
+
+    /// Draw both copies of the format information.
+    fn draw_format_bits(&mut self, ecl: QrErrorCorrection, mask: u32) {
+        let data = (ecl.format_bits() << 3) | mask;
+        let mut rem = data;
+        for _ in 0..10 {
+            rem = (rem << 1) ^ ((rem >> 9) * 0x537);
+        }
+        let bits = ((data << 10) | rem) ^ 0x5412;
+        let bit = |i: usize| (bits >> i) & 1 != 0;
+
+        // The copy around the top-left finder pattern.
+        for i in 0..=5 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+
+        // The copy split between the other two finder patterns.
+        let size = self.size;
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+        self.set_function(8, size - 8, true);
+    }
+
+    /// Draw both copies of the version information (version 7 and up).
+    fn draw_version(&mut self, version: usize) {
+        if version < 7 {
+            return;
+        }
+
+        let mut rem = version as u32;
+        for _ in 0..12 {
+            rem = (rem << 1) ^ ((rem >> 11) * 0x1F25);
+        }
+        let bits = ((version as u32) << 12) | rem;
+
+        for i in 0..18 {
+            let dark = (bits >> i) & 1 != 0;
+            let a = self.size - 11 + i % 3;
+            let b = i / 3;
+            self.set_function(a, b, dark);
+            self.set_function(b, a, dark);
+        }
+    }
+
+    /// Place the codewords in the zig-zag pattern, skipping function modules.
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let size = self.size as isize;
+        let total = codewords.len() * 8;
+        let mut i = 0;
+        let mut right = size - 1;
+        while right >= 1 {
+            if right == 6 {
+                right = 5;
+            }
+            for vert in 0..size {
+                for j in 0..2 {
+                    let x = (right - j) as usize;
+                    let upward = (right + 1) & 2 == 0;
+                    let y = if upward { size - 1 - vert } else { vert } as usize;
+                    let idx = y * self.size + x;
+                    if !self.function[idx] && i < total {
+                        let byte = codewords[i / 8];
+                        self.modules[idx] = (byte >> (7 - i % 8)) & 1 != 0;
+                        i += 1;
+                    }
+                }
+            }
+            right -= 2;
+        }
+    }
+
+    /// Flip all non-function modules selected by the mask. Applying the same
+    /// mask twice undoes it.
+    fn apply_mask(&mut self, mask: u32) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                let idx = y * self.size + x;
+                self.modules[idx] ^= invert && !self.function[idx];
+            }
+        }
+    }
+
+    /// Compute the penalty score that is used to select the best mask.
+    fn penalty(&self) -> usize {
+        let size = self.size;
+        let mut penalty = 0;
+
+        // Long runs of the same color and finder-like patterns, both in rows
+        // and columns.
+        const FINDER: [bool; 11] =
+            [true, false, true, true, true, false, true, false, false, false, false];
+        for transposed in [false, true] {
+            let get = |a: usize, b: usize| {
+                if transposed {
+                    self.get(b, a)
+                } else {
+                    self.get(a, b)
+                }
+            };
+
+            for b in 0..size {
+                let mut run = 1;
+                for a in 1..size {
+                    if get(a, b) == get(a - 1, b) {
+                        run += 1;
+                        if run == 5 {
+                            penalty += 3;
+                        } else if run > 5 {
+                            penalty += 1;
+                        }
+                    } else {
+                        run = 1;
+                    }
+                }
+
+                for a in 0..size.saturating_sub(10) {
+                    let matches = |reversed: bool| {
+                        (0..11).all(|k| {
+                            let expected = FINDER[if reversed { 10 - k } else { k }];
+                            get(a + k, b) == expected
+                        })
+                    };
+                    if matches(false) || matches(true) {
+                        penalty += 40;
+                    }
+                }
+            }
+        }
+
+        // Blocks of 2x2 modules with the same color.
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let color = self.get(x, y);
+                if color == self.get(x + 1, y)
+                    && color == self.get(x, y + 1)
+                    && color == self.get(x + 1, y + 1)
+                {
+                    penalty += 3;
+                }
+            }
+        }
+
+        // Imbalance of dark and light modules.
+        let dark = self.modules.iter().filter(|&&dark| dark).count();
+        let percent = dark * 100 / self.modules.len();
+        penalty += percent.abs_diff(50) / 5 * 10;
+
+        penalty
+    }
+}
+
+/// Assemble the data codewords: Mode indicator, character count, data, and
+/// padding up to the version's capacity.
+fn encode_data(data: &[u8], version: usize, ecl: QrErrorCorrection) -> Vec<u8> {
+    let capacity = num_data_codewords(version, ecl) * 8;
+    let mut bits = BitBuffer::default();
+    bits.push(0b0100, 4);
+    bits.push(data.len() as u32, if version <= 9 { 8 } else { 16 });
+    for &byte in data {
+        bits.push(byte.into(), 8);
+    }
+
+    // Terminator and padding to a byte boundary.
+    bits.push(0, (capacity - bits.len).min(4));
+    bits.push(0, (8 - bits.len % 8) % 8);
+
+    // Alternating pad bytes.
+    let mut bytes = bits.bytes;
+    for pad in [0xEC, 0x11].into_iter().cycle() {
+        if bytes.len() * 8 >= capacity {
+            break;
+        }
+        bytes.push(pad);
+    }
+
+    bytes
+}
+
+/// Split the data into blocks, append error correction codewords to each,
+/// and interleave the results.
+fn add_ecc_and_interleave(
+    data: &[u8],
+    version: usize,
+    ecl: QrErrorCorrection,
+) -> Vec<u8> {
+    let num_blocks = NUM_ERROR_CORRECTION_BLOCKS[ecl.index()][version] as usize;
+    let ecc_len = ECC_CODEWORDS_PER_BLOCK[ecl.index()][version] as usize;
+    let raw = num_raw_data_modules(version) / 8;
+    let num_short = num_blocks - raw % num_blocks;
+    let short_len = raw / num_blocks;
+    let divisor = reed_solomon_divisor(ecc_len);
+
+    let mut blocks = Vec::with_capacity(num_blocks);
+    let mut k = 0;
+    for i in 0..num_blocks {
+        let len = short_len - ecc_len + usize::from(i >= num_short);
+        let chunk = &data[k..k + len];
+        k += len;
+
+        let mut block = chunk.to_vec();
+        if i < num_short {
+            // Placeholder so that all blocks have the same length.
+            block.push(0);
+        }
+        block.extend(reed_solomon_remainder(chunk, &divisor));
+        blocks.push(block);
+    }
+
+    let mut result = Vec::with_capacity(raw);
+    for i in 0..blocks[0].len() {
+        for (j, block) in blocks.iter().enumerate() {
+            // Skip the placeholders of short blocks.
+            if i != short_len - ecc_len || j >= num_short {
+                result.push(block[i]);
+            }
+        }
+    }
+
+    result
+}
+
+/// The number of data codewords for a version and error correction level.
+fn num_data_codewords(version: usize, ecl: QrErrorCorrection) -> usize {
+    num_raw_data_modules(version) / 8
+        - ECC_CODEWORDS_PER_BLOCK[ecl.index()][version] as usize
+            * NUM_ERROR_CORRECTION_BLOCKS[ecl.index()][version] as usize
+}
+
+/// The number of modules available for data and error correction, i.e. all
+/// modules minus function patterns.
+fn num_raw_data_modules(version: usize) -> usize {
+    let mut result = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let num_align = version / 7 + 2;
+        result -= (25 * num_align - 10) * num_align - 55;
+        if version >= 7 {
+            result -= 36;
+        }
+    }
+    result
+}
+
+/// The center coordinates of the alignment patterns along each axis.
+fn alignment_positions(version: usize) -> Vec<usize> {
+    if version == 1 {
+        return vec![];
+    }
+
+    let num_align = version / 7 + 2;
+    let step = (version * 8 + num_align * 3 + 5) / (num_align * 4 - 4) * 2;
+    let mut result = vec![6];
+    let mut pos = version * 4 + 17 - 7;
+    for _ in 0..num_align - 1 {
+        result.insert(1, pos);
+        pos -= step;
+    }
+    result
+}
+
+/// Compute the generator polynomial for Reed-Solomon codes of the given
+/// degree, excluding the leading term.
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0; degree];
+    result[degree - 1] = 1;
+    let mut root = 1;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = gf_mul(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
+            }
+        }
+        root = gf_mul(root, 0x02);
+    }
+    result
+}
+
+/// Compute the Reed-Solomon error correction codewords for the data.
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0; divisor.len()];
+    for &byte in data {
+        let factor = byte ^ result.remove(0);
+        result.push(0);
+        for (x, &y) in result.iter_mut().zip(divisor) {
+            *x ^= gf_mul(y, factor);
+        }
+    }
+    result
+}
+
+/// Multiply two elements of GF(2^8) modulo the QR code polynomial.
+fn gf_mul(x: u8, y: u8) -> u8 {
+    let mut z: u8 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x1D);
+        z ^= ((y >> i) & 1) * x;
+    }
+    z
+}
+
+/// A buffer that bits can be appended to.
+#[derive(Default)]
+struct BitBuffer {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitBuffer {
+    /// Append the lowest `count` bits of `value`, most significant first.
+    fn push(&mut self, value: u32, count: usize) {
+        for i in (0..count).rev() {
+            if self.len % 8 == 0 {
+                self.bytes.push(0);
+            }
+            if (value >> i) & 1 != 0 {
+                *self.bytes.last_mut().unwrap() |= 1 << (7 - self.len % 8);
+            }
+            self.len += 1;
+        }
+    }
+}
+
+/// Error correction codewords per block, indexed by level and version.
+#[rustfmt::skip]
+const ECC_CODEWORDS_PER_BLOCK: [[u8; 41]; 4] = [
+    [0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28, 30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30],
+    [0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28],
+    [0, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30, 30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30],
+    [0, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30],
+];
+
+/// Number of error correction blocks, indexed by level and version.
+#[rustfmt::skip]
+const NUM_ERROR_CORRECTION_BLOCKS: [[u8; 41]; 4] = [
+    [0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12, 13, 14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25],
+    [0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21, 23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49],
+    [0, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27, 29, 34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68],
+    [0, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32, 35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qrcode_reed_solomon() {
+        // The "HELLO WORLD" 1-M example from the QR code specification.
+        let data =
+            [32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17];
+        let ecc = reed_solomon_remainder(&data, &reed_solomon_divisor(10));
+        assert_eq!(ecc, [196, 35, 39, 119, 235, 215, 231, 226, 93, 23]);
+    }
+
+    #[test]
+    fn test_qrcode_version() {
+        let size =
+            |len: usize, ecl| QrCode::encode(&vec![b'a'; len], ecl).unwrap().size();
+        assert_eq!(size(17, QrErrorCorrection::Low), 21);
+        assert_eq!(size(18, QrErrorCorrection::Low), 25);
+        assert_eq!(size(7, QrErrorCorrection::High), 21);
+        assert_eq!(size(2953, QrErrorCorrection::Low), 177);
+        assert!(QrCode::encode(&[0; 2954], QrErrorCorrection::Low).is_err());
+    }
+
+    #[test]
+    fn test_qrcode_function_patterns() {
+        let code = QrCode::encode(b"typst", QrErrorCorrection::Medium).unwrap();
+        let size = code.size();
+        for (x, y) in [(0, 0), (size - 1, 0), (0, size - 1), (3, 3), (8, size - 8)] {
+            assert!(code.get(x, y));
+        }
+        for (x, y) in [(7, 7), (size - 8, 7), (7, size - 8), (1, 1)] {
+            assert!(!code.get(x, y));
+        }
+    }
+
+    #[test]
+    fn test_qrcode_alignment_positions() {
+        assert_eq!(alignment_positions(2), [6, 18]);
+        assert_eq!(alignment_positions(7), [6, 22, 38]);
+        assert_eq!(alignment_positions(32), [6, 34, 60, 86, 112, 138]);
+    }
+}
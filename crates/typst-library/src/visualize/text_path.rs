@@ -0,0 +1,53 @@
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{elem, Content, NativeElement, Packed, Show, StyleChain};
+use crate::layout::{BlockElem, Length, Rel};
+
+/// Text set along a path.
+///
+/// The path is given as a [`curve`] or another shape and is drawn as usual,
+/// unless its stroke is set to `{none}`. The text's baseline then follows the
+/// outline of the shape, with each glyph rotated to match the direction of
+/// the path at its position. Glyphs that would extend beyond the end of the
+/// path are omitted. Only the text of the body is set along the path, other
+/// content like images is left out.
+///
+/// The glyphs remain regular text, so they can still be selected, copied,
+/// and searched in exported PDFs and SVGs. This is meant for short runs of
+/// text like labels in diagrams. To rotate text by an arbitrary angle
+/// without a path, use the [`rotate`] function.
+///
+/// # Example
+/// ```example
+/// #text-path(
+///   curve(
+///     stroke: gray,
+///     curve.move((0pt, 40pt)),
+///     curve.cubic((40pt, -10pt), (100pt, -10pt), (140pt, 40pt)),
+///   ),
+///   start: 10%,
+/// )[Text along a curve]
+/// ```
+#[elem(title = "Text Path", Show)]
+pub struct TextPathElem {
+    /// The shape whose outline the text follows.
+    #[required]
+    pub path: Content,
+
+    /// Where along the path the text starts, relative to the path's length.
+    #[resolve]
+    #[default(Rel::zero())]
+    pub start: Rel<Length>,
+
+    /// The text to set along the path.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for Packed<TextPathElem> {
+    fn show(&self, engine: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(BlockElem::single_layouter(self.clone(), engine.routines.layout_text_path)
+            .pack()
+            .spanned(self.span()))
+    }
+}
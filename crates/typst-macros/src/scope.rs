@@ -59,7 +59,7 @@ pub fn scope(_: TokenStream, item: syn::Item) -> Result<TokenStream> {
             }
             _ => None,
         }) {
-            def = quote! { #def.deprecated(#message) }
+            def = quote! { #def.deprecated(#foundations::Deprecation::new(#message)) }
         }
 
         definitions.push(def);
@@ -3,6 +3,7 @@ use std::num::NonZeroU64;
 
 use ecow::{eco_format, EcoVec};
 use krilla::annotation::Annotation;
+use krilla::color::ICCProfile;
 use krilla::configure::{Configuration, ValidationError, Validator};
 use krilla::destination::{NamedDestination, XyzDestination};
 use krilla::embed::EmbedError;
@@ -44,7 +45,7 @@ pub fn convert(
         no_device_cs: true,
         ascii_compatible: false,
         xmp_metadata: true,
-        cmyk_profile: None,
+        cmyk_profile: convert_cmyk_profile(options)?,
         configuration: options.standards.config,
         enable_tagging: false,
         render_svg_glyph_fn: render_svg_glyph,
@@ -324,6 +325,19 @@ pub(crate) fn handle_group(
     Ok(())
 }
 
+/// Parse the ICC profile for CMYK colors, if any.
+fn convert_cmyk_profile(options: &PdfOptions) -> SourceResult<Option<ICCProfile<4>>> {
+    let Some(data) = &options.cmyk_profile else { return Ok(None) };
+    match ICCProfile::new(data.as_slice()) {
+        Some(profile) => Ok(Some(profile)),
+        None => bail!(
+            Span::detached(),
+            "failed to load CMYK profile";
+            hint: "the profile must be a valid ICC profile for the CMYK color space"
+        ),
+    }
+}
+
 #[typst_macros::time(name = "finish export")]
 /// Finish a krilla document and handle export errors.
 fn finish(
@@ -1,20 +1,22 @@
 use std::hash::{Hash, Hasher};
 use std::sync::{Arc, OnceLock};
 
-use image::{DynamicImage, EncodableLayout, GenericImageView, Rgba};
+use image::{DynamicImage, EncodableLayout, GenericImageView, Rgb, Rgba};
 use krilla::image::{BitsPerComponent, CustomImage, ImageColorspace};
 use krilla::surface::Surface;
 use krilla_svg::{SurfaceExt, SvgSettings};
-use typst_library::diag::{bail, SourceResult};
+use typst_library::diag::{bail, At, SourceResult};
 use typst_library::foundations::Smart;
 use typst_library::layout::{Abs, Angle, Ratio, Size, Transform};
 use typst_library::visualize::{
-    ExchangeFormat, Image, ImageKind, ImageScaling, RasterFormat, RasterImage,
+    Color, ColorSpace, ExchangeFormat, Image, ImageKind, ImageScaling, RasterFormat,
+    RasterImage,
 };
 use typst_syntax::Span;
 
 use crate::convert::{FrameContext, GlobalContext};
 use crate::util::{SizeExt, TransformExt};
+use crate::PdfColorSpace;
 
 #[typst_macros::time(name = "handle image")]
 pub(crate) fn handle_image(
@@ -38,10 +40,15 @@ pub(crate) fn handle_image(
 
     match image.kind() {
         ImageKind::Raster(raster) => {
+            // Make sure that the pixels are decodable before handing the
+            // image over to krilla, which accesses them infallibly.
+            raster.decoded().at(span)?;
+
             let (exif_transform, new_size) = exif_transform(raster, size);
             surface.push_transform(&exif_transform.to_krilla());
 
-            let image = match convert_raster(raster.clone(), interpolate) {
+            let cmyk = gc.options.color_space == Smart::Custom(PdfColorSpace::Cmyk);
+            let image = match convert_raster(raster.clone(), interpolate, cmyk) {
                 None => bail!(span, "failed to process image"),
                 Some(i) => i,
             };
@@ -54,11 +61,16 @@ pub(crate) fn handle_image(
             surface.pop();
         }
         ImageKind::Svg(svg) => {
-            surface.draw_svg(
-                svg.tree(),
-                size.to_krilla(),
-                SvgSettings { embed_text: true, ..Default::default() },
-            );
+            let mut settings = SvgSettings { embed_text: true, ..Default::default() };
+            if let Some(dpi) = image.raster_fallback() {
+                // Filters are rasterized in the SVG's own units, which are
+                // scaled to the image's size in points.
+                let scale = dpi / 72.0 * size.x.to_pt() / svg.width();
+                if scale.is_finite() && scale > 0.0 {
+                    settings.filter_scale = scale as f32;
+                }
+            }
+            surface.draw_svg(svg.tree(), size.to_krilla(), settings);
         }
     }
 
@@ -75,12 +87,16 @@ pub(crate) fn handle_image(
 struct Repr {
     /// The original, underlying raster image.
     raster: RasterImage,
+    /// Whether to convert the colors of the image into CMYK.
+    cmyk: bool,
     /// The alpha channel of the raster image, if existing.
     alpha_channel: OnceLock<Option<Vec<u8>>>,
     /// A (potentially) converted version of the dynamic image stored `raster` that is
     /// guaranteed to either be in luma8 or rgb8, and thus can be used for the
     /// `color_channel` method of `CustomImage`.
     actual_dynamic: OnceLock<Arc<DynamicImage>>,
+    /// The pixels of the image converted into CMYK, if requested.
+    cmyk_channel: OnceLock<Vec<u8>>,
 }
 
 /// A wrapper around `RasterImage` so that we can implement `CustomImage`.
@@ -88,13 +104,20 @@ struct Repr {
 struct PdfImage(Arc<Repr>);
 
 impl PdfImage {
-    pub fn new(raster: RasterImage) -> Self {
+    pub fn new(raster: RasterImage, cmyk: bool) -> Self {
         Self(Arc::new(Repr {
             raster,
+            cmyk,
             alpha_channel: OnceLock::new(),
             actual_dynamic: OnceLock::new(),
+            cmyk_channel: OnceLock::new(),
         }))
     }
+
+    /// Whether the color channel is converted into CMYK.
+    fn is_cmyk(&self) -> bool {
+        self.0.cmyk && self.0.raster.dynamic().color().has_color()
+    }
 }
 
 impl Hash for PdfImage {
@@ -102,11 +125,28 @@ impl Hash for PdfImage {
         // `alpha_channel` and `actual_dynamic` are generated from the underlying `RasterImage`,
         // so this is enough. Since `raster` is prehashed, this is also very cheap.
         self.0.raster.hash(state);
+        self.0.cmyk.hash(state);
     }
 }
 
 impl CustomImage for PdfImage {
     fn color_channel(&self) -> &[u8] {
+        if self.is_cmyk() {
+            return self.0.cmyk_channel.get_or_init(|| {
+                self.0
+                    .raster
+                    .dynamic()
+                    .to_rgb8()
+                    .pixels()
+                    .flat_map(|&Rgb([r, g, b])| {
+                        Color::from_u8(r, g, b, 255)
+                            .to_space(ColorSpace::Cmyk)
+                            .to_vec4_u8()
+                    })
+                    .collect()
+            });
+        }
+
         self.0
             .actual_dynamic
             .get_or_init(|| {
@@ -152,24 +192,27 @@ impl CustomImage for PdfImage {
     }
 
     fn icc_profile(&self) -> Option<&[u8]> {
-        if matches!(
-            self.0.raster.dynamic().as_ref(),
-            DynamicImage::ImageLuma8(_)
-                | DynamicImage::ImageLumaA8(_)
-                | DynamicImage::ImageRgb8(_)
-                | DynamicImage::ImageRgba8(_)
-        ) {
-            self.0.raster.icc().map(|b| b.as_bytes())
-        } else {
-            // In all other cases, the dynamic will be converted into RGB8 or LUMA8, so the ICC
-            // profile may become invalid, and thus we don't include it.
-            None
+        // Converted pixels are no longer described by the original profile.
+        if self.is_cmyk() {
+            return None;
         }
+
+        // The dynamic is converted into RGB8 or LUMA8, which keeps its color
+        // space even for images with higher bit depths. The ICC profile thus
+        // stays valid as long as it describes that color space, which is
+        // stored in its header.
+        let icc = self.0.raster.icc()?.as_bytes();
+        let space: &[u8] =
+            if self.0.raster.dynamic().color().has_color() { b"RGB " } else { b"GRAY" };
+        (icc.get(16..20) == Some(space)).then_some(icc)
     }
 
     fn color_space(&self) -> ImageColorspace {
-        // Remember that we convert all images to either RGB or luma.
-        if self.0.raster.dynamic().color().has_color() {
+        // Remember that we convert all images to either RGB or luma, unless
+        // CMYK was requested.
+        if self.is_cmyk() {
+            ImageColorspace::Cmyk
+        } else if self.0.raster.dynamic().color().has_color() {
             ImageColorspace::Rgb
         } else {
             ImageColorspace::Luma
@@ -181,8 +224,12 @@ impl CustomImage for PdfImage {
 fn convert_raster(
     raster: RasterImage,
     interpolate: bool,
+    cmyk: bool,
 ) -> Option<krilla::image::Image> {
-    if let RasterFormat::Exchange(ExchangeFormat::Jpg) = raster.format() {
+    // JPEGs can be embedded directly, unless their colors must be converted.
+    if raster.format() == RasterFormat::Exchange(ExchangeFormat::Jpg)
+        && !(cmyk && raster.dynamic().color().has_color())
+    {
         let image_data: Arc<dyn AsRef<[u8]> + Send + Sync> =
             Arc::new(raster.data().clone());
         let icc_profile = raster.icc().map(|i| {
@@ -196,7 +243,7 @@ fn convert_raster(
             interpolate,
         )
     } else {
-        krilla::image::Image::from_custom(PdfImage::new(raster), interpolate)
+        krilla::image::Image::from_custom(PdfImage::new(raster, cmyk), interpolate)
     }
 }
 
@@ -19,7 +19,7 @@ use std::fmt::{self, Debug, Formatter};
 use ecow::eco_format;
 use serde::{Deserialize, Serialize};
 use typst_library::diag::{bail, SourceResult, StrResult};
-use typst_library::foundations::Smart;
+use typst_library::foundations::{Bytes, Smart};
 use typst_library::layout::{PageRanges, PagedDocument};
 
 /// Export a document into a PDF file.
@@ -53,6 +53,31 @@ pub struct PdfOptions<'a> {
     pub page_ranges: Option<PageRanges>,
     /// A list of PDF standards that Typst will enforce conformance with.
     pub standards: PdfStandards,
+    /// The color space that all colors are converted into, including the
+    /// pixels of raster images. When `Auto`, grayscale and CMYK colors keep
+    /// their space and all other colors are converted into RGB. The colors
+    /// of SVG images are always kept as they are.
+    ///
+    /// The conversion into CMYK is the simple, profile-independent one that
+    /// Typst's `cmyk` colors use. It is not an ICC-based conversion, so it
+    /// doesn't account for the ink limits or the gamut of a printing process.
+    pub color_space: Smart<PdfColorSpace>,
+    /// An ICC profile describing the CMYK colors of the document, typically
+    /// the profile of the printing process the PDF is prepared for. When
+    /// `None`, CMYK colors are device-dependent.
+    ///
+    /// The profile only tags the CMYK colors in the PDF. It is not used to
+    /// convert other colors into CMYK.
+    pub cmyk_profile: Option<Bytes>,
+}
+
+/// A color space that all colors in a PDF can be converted into.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum PdfColorSpace {
+    /// Red, green, and blue, for screens.
+    Rgb,
+    /// Cyan, magenta, yellow, and key, for print.
+    Cmyk,
 }
 
 /// Encapsulates a list of compatible PDF standards.
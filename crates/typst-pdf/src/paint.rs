@@ -8,6 +8,7 @@ use krilla::paint::{
 };
 use krilla::surface::Surface;
 use typst_library::diag::SourceResult;
+use typst_library::foundations::Smart;
 use typst_library::layout::{Abs, Angle, Quadrant, Ratio, Size, Transform};
 use typst_library::visualize::{
     Color, ColorSpace, DashPattern, FillRule, FixedStroke, Gradient, Paint, RatioOrAngle,
@@ -17,6 +18,7 @@ use typst_utils::Numeric;
 
 use crate::convert::{handle_frame, FrameContext, GlobalContext, State};
 use crate::util::{AbsExt, FillRuleExt, LineCapExt, LineJoinExt, TransformExt};
+use crate::PdfColorSpace;
 
 pub(crate) fn convert_fill(
     gc: &mut GlobalContext,
@@ -77,21 +79,26 @@ fn convert_paint(
 
     match paint {
         Paint::Solid(c) => {
-            let (c, a) = convert_solid(c);
+            let (c, a) = convert_solid(c, gc.options.color_space);
             Ok((c.into(), a))
         }
-        Paint::Gradient(g) => Ok(convert_gradient(g, on_text, state, size)),
+        Paint::Gradient(g) => {
+            Ok(convert_gradient(g, gc.options.color_space, on_text, state, size))
+        }
         Paint::Tiling(p) => convert_pattern(gc, p, on_text, surface, state),
     }
 }
 
-fn convert_solid(color: &Color) -> (color::Color, u8) {
-    match color.space() {
-        ColorSpace::D65Gray => {
+fn convert_solid(color: &Color, target: Smart<PdfColorSpace>) -> (color::Color, u8) {
+    match (target, color.space()) {
+        (Smart::Custom(PdfColorSpace::Cmyk), _) | (Smart::Auto, ColorSpace::Cmyk) => {
+            let (c, a) = convert_cmyk(color);
+            (c.into(), a)
+        }
+        (Smart::Auto, ColorSpace::D65Gray) => {
             let (c, a) = convert_luma(color);
             (c.into(), a)
         }
-        ColorSpace::Cmyk => (convert_cmyk(color).into(), 255),
         // Convert all other colors in different colors spaces into RGB.
         _ => {
             let (c, a) = convert_rgb(color);
@@ -100,10 +107,15 @@ fn convert_solid(color: &Color) -> (color::Color, u8) {
     }
 }
 
-fn convert_cmyk(color: &Color) -> cmyk::Color {
+/// Convert a color into CMYK.
+///
+/// This uses Typst's naive conversion and not an ICC-based one, even if an
+/// output profile for CMYK is configured.
+fn convert_cmyk(color: &Color) -> (cmyk::Color, u8) {
     let components = color.to_space(ColorSpace::Cmyk).to_vec4_u8();
-
-    cmyk::Color::new(components[0], components[1], components[2], components[3])
+    // CMYK colors have no alpha channel, so we take it from the original.
+    let alpha = color.alpha().map_or(255, |a| (a * 255.0).round() as u8);
+    (cmyk::Color::new(components[0], components[1], components[2], components[3]), alpha)
 }
 
 fn convert_rgb(color: &Color) -> (rgb::Color, u8) {
@@ -143,6 +155,7 @@ fn convert_pattern(
 
 fn convert_gradient(
     gradient: &Gradient,
+    target: Smart<PdfColorSpace>,
     on_text: bool,
     state: &State,
     size: Size,
@@ -154,7 +167,7 @@ fn convert_gradient(
 
     let angle = gradient.angle().unwrap_or_else(Angle::zero);
     let base_transform = correct_transform(state, gradient.unwrap_relative(on_text));
-    let stops = convert_gradient_stops(gradient);
+    let stops = convert_gradient_stops(gradient, target);
     match &gradient {
         Gradient::Linear(_) => {
             let (x1, y1, x2, y2) = {
@@ -249,14 +262,23 @@ fn convert_gradient(
     }
 }
 
-fn convert_gradient_stops(gradient: &Gradient) -> Vec<Stop> {
+fn convert_gradient_stops(
+    gradient: &Gradient,
+    target: Smart<PdfColorSpace>,
+) -> Vec<Stop> {
     let mut stops = vec![];
 
-    let use_cmyk = gradient.stops().iter().all(|s| s.color.space() == ColorSpace::Cmyk);
+    let use_cmyk = match target {
+        Smart::Custom(space) => space == PdfColorSpace::Cmyk,
+        Smart::Auto => {
+            gradient.stops().iter().all(|s| s.color.space() == ColorSpace::Cmyk)
+        }
+    };
 
     let mut add_single = |color: &Color, offset: Ratio| {
         let (color, opacity) = if use_cmyk {
-            (convert_cmyk(color).into(), 255)
+            let (c, a) = convert_cmyk(color);
+            (c.into(), a)
         } else {
             let (c, a) = convert_rgb(color);
             (c.into(), a)
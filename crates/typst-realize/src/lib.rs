@@ -25,8 +25,8 @@ use typst_library::layout::{
 };
 use typst_library::math::{EquationElem, Mathy};
 use typst_library::model::{
-    CiteElem, CiteGroup, DocumentElem, EnumElem, ListElem, ListItemLike, ListLike,
-    ParElem, ParbreakElem, TermsElem,
+    CiteElem, CiteGroup, CollapsibleRef, DocumentElem, EnumElem, ListElem, ListItemLike,
+    ListLike, ParElem, ParbreakElem, RefGroup, TermsElem,
 };
 use typst_library::routines::{Arenas, FragmentKind, Pair, RealizationKind};
 use typst_library::text::{LinebreakElem, SmartQuoteElem, SpaceElem, TextElem};
@@ -436,6 +436,7 @@ fn verdict<'a>(
     let prepared = target.is_prepared();
     let mut map = Styles::new();
     let mut step = None;
+    let mut revoked = false;
 
     // Do pre-synthesis on a cloned element to be able to match on synthesized
     // fields before real synthesis runs (during preparation). It's really
@@ -475,8 +476,14 @@ fn verdict<'a>(
             continue;
         }
 
-        // If we already have a show step, don't look for one.
-        if step.is_some() {
+        // A `show sel: auto` rule revokes all outer show rules for the target.
+        if let Transformation::Auto = recipe.transform() {
+            revoked = true;
+        }
+
+        // If we already have a show step or the outer ones are revoked, don't
+        // look for one.
+        if step.is_some() || revoked {
             continue;
         }
 
@@ -842,21 +849,23 @@ fn finish_innermost_grouping(s: &mut State) -> SourceResult<()> {
 const MAX_GROUP_NESTING: usize = 3;
 
 /// Grouping rules used in layout realization.
-static LAYOUT_RULES: &[&GroupingRule] = &[&TEXTUAL, &PAR, &CITES, &LIST, &ENUM, &TERMS];
+static LAYOUT_RULES: &[&GroupingRule] =
+    &[&TEXTUAL, &PAR, &CITES, &REFS, &LIST, &ENUM, &TERMS];
 
 /// Grouping rules used in paragraph layout realization.
-static LAYOUT_PAR_RULES: &[&GroupingRule] = &[&TEXTUAL, &CITES, &LIST, &ENUM, &TERMS];
+static LAYOUT_PAR_RULES: &[&GroupingRule] =
+    &[&TEXTUAL, &CITES, &REFS, &LIST, &ENUM, &TERMS];
 
 /// Grouping rules used in HTML root realization.
 static HTML_DOCUMENT_RULES: &[&GroupingRule] =
-    &[&TEXTUAL, &PAR, &CITES, &LIST, &ENUM, &TERMS];
+    &[&TEXTUAL, &PAR, &CITES, &REFS, &LIST, &ENUM, &TERMS];
 
 /// Grouping rules used in HTML fragment realization.
 static HTML_FRAGMENT_RULES: &[&GroupingRule] =
-    &[&TEXTUAL, &PAR, &CITES, &LIST, &ENUM, &TERMS];
+    &[&TEXTUAL, &PAR, &CITES, &REFS, &LIST, &ENUM, &TERMS];
 
 /// Grouping rules used in math realization.
-static MATH_RULES: &[&GroupingRule] = &[&CITES, &LIST, &ENUM, &TERMS];
+static MATH_RULES: &[&GroupingRule] = &[&CITES, &REFS, &LIST, &ENUM, &TERMS];
 
 /// Groups adjacent textual elements for text show rule application.
 static TEXTUAL: GroupingRule = GroupingRule {
@@ -912,6 +921,18 @@ static CITES: GroupingRule = GroupingRule {
     finish: finish_cites,
 };
 
+/// Collects `CollapsibleRef`s into `RefGroup`s.
+static REFS: GroupingRule = GroupingRule {
+    priority: 2,
+    tags: false,
+    trigger: |content, _| content.elem() == CollapsibleRef::elem(),
+    inner: |content| content.elem() == SpaceElem::elem(),
+    interrupt: |elem| {
+        elem == RefGroup::elem() || elem == ParElem::elem() || elem == AlignElem::elem()
+    },
+    finish: finish_refs,
+};
+
 /// Builds a `ListElem` from grouped `ListItems`s.
 static LIST: GroupingRule = list_like_grouping::<ListElem>();
 
@@ -1028,6 +1049,24 @@ fn finish_cites(grouped: Grouped) -> SourceResult<()> {
     visit(s, s.store(elem), trunk)
 }
 
+/// Builds the `RefGroup` from `CollapsibleRef`s.
+fn finish_refs(grouped: Grouped) -> SourceResult<()> {
+    // Collect the children.
+    let elems = grouped.get();
+    let span = select_span(elems);
+    let trunk = elems[0].1;
+    let children = elems
+        .iter()
+        .filter_map(|(c, _)| c.to_packed::<CollapsibleRef>())
+        .map(|elem| elem.reference.clone())
+        .collect();
+
+    // Create and visit the reference group.
+    let s = grouped.end();
+    let elem = RefGroup::new(children).pack().spanned(span);
+    visit(s, s.store(elem), trunk)
+}
+
 /// Builds the `ListLike` element from `ListItemLike` elements.
 fn finish_list_like<T: ListLike>(grouped: Grouped) -> SourceResult<()> {
     // Collect the children.
@@ -1157,6 +1196,11 @@ fn find_regex_match_in_str<'a>(
         };
         r += 1;
 
+        // Revoking show rules is only supported for elements.
+        if let Transformation::Auto = recipe.transform() {
+            continue;
+        }
+
         let Some(Selector::Regex(regex)) = recipe.selector() else { continue };
         let Some(m) = regex.find(text) else { continue };
 
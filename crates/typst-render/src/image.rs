@@ -66,7 +66,7 @@ fn build_texture(image: &Image, w: u32, h: u32) -> Option<Arc<sk::Pixmap>> {
             let h = texture.height();
 
             let buf;
-            let dynamic = raster.dynamic();
+            let dynamic = raster.decoded().ok()?;
             let resized = if (w, h) == (dynamic.width(), dynamic.height()) {
                 // Small optimization to not allocate in case image is not resized.
                 dynamic
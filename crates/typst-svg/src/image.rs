@@ -6,29 +6,55 @@ use typst_library::layout::{Abs, Axes};
 use typst_library::visualize::{
     ExchangeFormat, Image, ImageKind, ImageScaling, RasterFormat,
 };
+use typst_utils::hash128;
 
 use crate::SVGRenderer;
 
 impl SVGRenderer {
     /// Render an image element.
+    ///
+    /// The image itself is only written once per size into the definitions,
+    /// so that repeated placements (like a logo on every page) don't embed
+    /// the image data multiple times.
     pub(super) fn render_image(&mut self, image: &Image, size: &Axes<Abs>) {
-        let url = convert_image_to_base64_url(image);
-        self.xml.start_element("image");
-        self.xml.write_attribute("xlink:href", &url);
-        self.xml.write_attribute("width", &size.x.to_pt());
-        self.xml.write_attribute("height", &size.y.to_pt());
-        self.xml.write_attribute("preserveAspectRatio", "none");
-        match image.scaling() {
-            Smart::Auto => {}
-            Smart::Custom(ImageScaling::Smooth) => {
-                // This is still experimental and not implemented in all major browsers.
-                // https://developer.mozilla.org/en-US/docs/Web/CSS/image-rendering#browser_compatibility
-                self.xml.write_attribute("style", "image-rendering: smooth")
-            }
-            Smart::Custom(ImageScaling::Pixelated) => {
-                self.xml.write_attribute("style", "image-rendering: pixelated")
+        let hash = hash128(&(image, size));
+        let id = self.images.insert_with(hash, || (image.clone(), *size));
+        self.xml.start_element("use");
+        self.xml.write_attribute_fmt("xlink:href", format_args!("#{id}"));
+        self.xml.end_element();
+    }
+
+    /// Build the image definitions.
+    pub(super) fn write_image_defs(&mut self) {
+        if self.images.is_empty() {
+            return;
+        }
+
+        self.xml.start_element("defs");
+        self.xml.write_attribute("id", "image");
+
+        for (id, (image, size)) in self.images.iter() {
+            let url = convert_image_to_base64_url(image);
+            self.xml.start_element("image");
+            self.xml.write_attribute("id", &id);
+            self.xml.write_attribute("xlink:href", &url);
+            self.xml.write_attribute("width", &size.x.to_pt());
+            self.xml.write_attribute("height", &size.y.to_pt());
+            self.xml.write_attribute("preserveAspectRatio", "none");
+            match image.scaling() {
+                Smart::Auto => {}
+                Smart::Custom(ImageScaling::Smooth) => {
+                    // This is still experimental and not implemented in all major browsers.
+                    // https://developer.mozilla.org/en-US/docs/Web/CSS/image-rendering#browser_compatibility
+                    self.xml.write_attribute("style", "image-rendering: smooth")
+                }
+                Smart::Custom(ImageScaling::Pixelated) => {
+                    self.xml.write_attribute("style", "image-rendering: pixelated")
+                }
             }
+            self.xml.end_element();
         }
+
         self.xml.end_element();
     }
 }
@@ -40,7 +66,8 @@ pub fn convert_image_to_base64_url(image: &Image) -> EcoString {
     let mut buf;
     let (format, data): (&str, &[u8]) = match image.kind() {
         ImageKind::Raster(raster) => match raster.format() {
-            RasterFormat::Exchange(format) => (
+            // Later frames of animations are encoded on their own below.
+            RasterFormat::Exchange(format) if raster.frame().get() == 1 => (
                 match format {
                     ExchangeFormat::Png => "png",
                     ExchangeFormat::Jpg => "jpeg",
@@ -49,7 +76,7 @@ pub fn convert_image_to_base64_url(image: &Image) -> EcoString {
                 },
                 raster.data(),
             ),
-            RasterFormat::Pixel(_) => ("png", {
+            _ => ("png", {
                 buf = vec![];
                 let mut encoder = PngEncoder::new(&mut buf);
                 if let Some(icc_profile) = raster.icc() {
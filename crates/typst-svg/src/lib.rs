@@ -14,7 +14,7 @@ use typst_library::layout::{
     Abs, Frame, FrameItem, FrameKind, GroupItem, Page, PagedDocument, Point, Ratio, Size,
     Transform,
 };
-use typst_library::visualize::{Geometry, Gradient, Tiling};
+use typst_library::visualize::{Geometry, Gradient, Image, Tiling};
 use typst_utils::hash128;
 use xmlwriter::XmlWriter;
 
@@ -113,6 +113,8 @@ struct SVGRenderer {
     tilings: Deduplicator<Tiling>,
     /// These are the gradients that compose a conic gradient.
     conic_subgradients: Deduplicator<SVGSubGradient>,
+    /// Deduplicated images with the size they are placed at.
+    images: Deduplicator<(Image, Size)>,
 }
 
 /// Contextual information for rendering.
@@ -165,6 +167,7 @@ impl SVGRenderer {
             conic_subgradients: Deduplicator::new('s'),
             tiling_refs: Deduplicator::new('p'),
             tilings: Deduplicator::new('t'),
+            images: Deduplicator::new('i'),
         }
     }
 
@@ -275,6 +278,7 @@ impl SVGRenderer {
         self.write_subgradients();
         self.write_tilings();
         self.write_tiling_refs();
+        self.write_image_defs();
         self.xml.end_document()
     }
 
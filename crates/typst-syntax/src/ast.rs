@@ -2231,6 +2231,12 @@ impl<'a> ModuleImport<'a> {
         }
     }
 
+    /// The names excluded from a wildcard import (`a` and `b` in
+    /// `import "utils.typ": * except a, b`).
+    pub fn exclusions(self) -> Option<ImportExclusions<'a>> {
+        self.0.children().find_map(SyntaxNode::cast)
+    }
+
     /// The name this module was assigned to, if it was renamed with `as`
     /// (`renamed` in `import "..." as renamed`).
     pub fn new_name(self) -> Option<Ident<'a>> {
@@ -2278,6 +2284,18 @@ impl<'a> ImportItems<'a> {
     }
 }
 
+node! {
+    /// Names excluded from a wildcard import: `except a, b`.
+    struct ImportExclusions
+}
+
+impl<'a> ImportExclusions<'a> {
+    /// Returns an iterator over the excluded names.
+    pub fn iter(self) -> impl DoubleEndedIterator<Item = Ident<'a>> {
+        self.0.children().filter_map(SyntaxNode::cast)
+    }
+}
+
 node! {
     /// A path to a submodule's imported name: `a.b.c`.
     struct ImportItemPath
@@ -2364,7 +2382,13 @@ node! {
 impl<'a> ModuleInclude<'a> {
     /// The module or path from which the content should be included.
     pub fn source(self) -> Expr<'a> {
-        self.0.cast_last()
+        self.0.cast_first()
+    }
+
+    /// The label of the part that should be included (`<intro>` in
+    /// `include "book.typ": <intro>`), if any.
+    pub fn part(self) -> Option<Expr<'a>> {
+        self.0.children().filter_map(SyntaxNode::cast).nth(1)
     }
 }
 
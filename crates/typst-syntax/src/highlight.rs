@@ -247,6 +247,7 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
         SyntaxKind::Import => Some(Tag::Keyword),
         SyntaxKind::Include => Some(Tag::Keyword),
         SyntaxKind::As => Some(Tag::Keyword),
+        SyntaxKind::Except => Some(Tag::Keyword),
 
         SyntaxKind::Code => None,
         SyntaxKind::Ident => highlight_ident(node),
@@ -281,6 +282,7 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
         SyntaxKind::ImportItems => None,
         SyntaxKind::ImportItemPath => None,
         SyntaxKind::RenamedImportItem => None,
+        SyntaxKind::ImportExclusions => None,
         SyntaxKind::ModuleInclude => None,
         SyntaxKind::LoopBreak => None,
         SyntaxKind::LoopContinue => None,
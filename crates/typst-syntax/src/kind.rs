@@ -205,6 +205,8 @@ pub enum SyntaxKind {
     Include,
     /// The `as` keyword.
     As,
+    /// The contextual `except` keyword in a wildcard import.
+    Except,
 
     /// The contents of a code block.
     Code,
@@ -272,6 +274,8 @@ pub enum SyntaxKind {
     ImportItemPath,
     /// A renamed import item: `a as d`.
     RenamedImportItem,
+    /// Names excluded from a wildcard import: `except a, b`.
+    ImportExclusions,
     /// A module include: `include "chapter1.typ"`.
     ModuleInclude,
     /// A break from a loop: `break`.
@@ -353,6 +357,7 @@ impl SyntaxKind {
                 | Self::Import
                 | Self::Include
                 | Self::As
+                | Self::Except
         )
     }
 
@@ -472,6 +477,7 @@ impl SyntaxKind {
             Self::Import => "keyword `import`",
             Self::Include => "keyword `include`",
             Self::As => "keyword `as`",
+            Self::Except => "keyword `except`",
             Self::Code => "code",
             Self::Ident => "identifier",
             Self::Bool => "boolean",
@@ -505,6 +511,7 @@ impl SyntaxKind {
             Self::ImportItems => "import items",
             Self::ImportItemPath => "imported item path",
             Self::RenamedImportItem => "renamed import item",
+            Self::ImportExclusions => "import exclusions",
             Self::ModuleInclude => "`include` expression",
             Self::LoopBreak => "`break` expression",
             Self::LoopContinue => "`continue` expression",
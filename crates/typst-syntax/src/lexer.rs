@@ -889,6 +889,7 @@ impl Lexer<'_> {
     }
 
     fn string(&mut self) -> SyntaxKind {
+        let start = self.s.cursor();
         let mut escaped = false;
         self.s.eat_until(|c| {
             let stop = c == '"' && !escaped;
@@ -897,6 +898,10 @@ impl Lexer<'_> {
         });
 
         if !self.s.eat_if('"') {
+            // Recover at the end of the line instead of swallowing the rest of
+            // the file so that the following code still parses while typing.
+            self.s.jump(start);
+            self.s.eat_until(is_newline);
             return self.error("unclosed string");
         }
 
@@ -962,7 +962,12 @@ fn module_import(p: &mut Parser) {
 
                 p.expect_closing_delimiter(m2, SyntaxKind::RightParen);
             });
-        } else if !p.eat_if(SyntaxKind::Star) {
+        } else if p.eat_if(SyntaxKind::Star) {
+            // Exclude names from a wildcard import: `* except a, b`.
+            if p.at(SyntaxKind::Ident) && p.current_text() == "except" {
+                import_exclusions(p);
+            }
+        } else {
             import_items(p);
         }
     }
@@ -970,6 +975,41 @@ fn module_import(p: &mut Parser) {
     p.wrap(m, SyntaxKind::ModuleImport);
 }
 
+/// Parses names excluded from a wildcard import: `except a, b`.
+fn import_exclusions(p: &mut Parser) {
+    let m = p.marker();
+    p.convert_and_eat(SyntaxKind::Except);
+    if p.at(SyntaxKind::LeftParen) {
+        p.with_nl_mode(AtNewline::Continue, |p| {
+            let m2 = p.marker();
+            p.assert(SyntaxKind::LeftParen);
+
+            import_exclusion_names(p);
+
+            p.expect_closing_delimiter(m2, SyntaxKind::RightParen);
+        });
+    } else if p.current().is_terminator() {
+        p.expected("identifier");
+    } else {
+        import_exclusion_names(p);
+    }
+
+    p.wrap(m, SyntaxKind::ImportExclusions);
+}
+
+/// Parses the comma-separated identifiers of import exclusions.
+fn import_exclusion_names(p: &mut Parser) {
+    while !p.current().is_terminator() {
+        if !p.eat_if(SyntaxKind::Ident) {
+            p.unexpected();
+        }
+
+        if !p.current().is_terminator() {
+            p.expect(SyntaxKind::Comma);
+        }
+    }
+}
+
 /// Parses items to import from a module: `a, b, c`.
 fn import_items(p: &mut Parser) {
     let m = p.marker();
@@ -1000,11 +1040,15 @@ fn import_items(p: &mut Parser) {
     p.wrap(m, SyntaxKind::ImportItems);
 }
 
-/// Parses a module include: `include "chapter1.typ"`.
+/// Parses a module include: `include "chapter1.typ"` or
+/// `include "book.typ": <intro>`.
 fn module_include(p: &mut Parser) {
     let m = p.marker();
     p.assert(SyntaxKind::Include);
     code_expr(p);
+    if p.eat_if(SyntaxKind::Colon) {
+        code_expr(p);
+    }
     p.wrap(m, SyntaxKind::ModuleInclude);
 }
 
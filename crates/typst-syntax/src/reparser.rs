@@ -1,7 +1,8 @@
 use std::ops::Range;
 
 use crate::{
-    is_newline, parse, reparse_block, reparse_markup, Span, SyntaxKind, SyntaxNode,
+    is_newline, parse, reparse_block, reparse_markup, Lexer, Span, SyntaxKind,
+    SyntaxMode, SyntaxNode,
 };
 
 /// Refresh the given syntax node with as little parsing as possible.
@@ -18,7 +19,16 @@ pub fn reparse(
     replaced: Range<usize>,
     replacement_len: usize,
 ) -> Range<usize> {
-    try_reparse(text, replaced, replacement_len, None, root, 0).unwrap_or_else(|| {
+    // An unclosed string only extends to the end of its line, but a quote
+    // inserted anywhere after it could still close it. We thus can't reparse
+    // incrementally while there is one.
+    let reparsed = if has_unclosed_string(root) {
+        None
+    } else {
+        try_reparse(text, replaced, replacement_len, None, root, 0)
+    };
+
+    reparsed.unwrap_or_else(|| {
         let id = root.span().id();
         *root = parse(text);
         if let Some(id) = id {
@@ -28,6 +38,24 @@ pub fn reparse(
     })
 }
 
+/// Whether the node contains a string that is missing its closing quote.
+///
+/// The lexer turns such a string into an error node that starts with a quote,
+/// which still doesn't lex to a string on its own.
+fn has_unclosed_string(node: &SyntaxNode) -> bool {
+    if !node.erroneous() {
+        return false;
+    }
+
+    if node.kind() == SyntaxKind::Error {
+        let text = node.text();
+        return text.starts_with('"')
+            && Lexer::new(text, SyntaxMode::Code).next().0 == SyntaxKind::Error;
+    }
+
+    node.children().any(has_unclosed_string)
+}
+
 /// Try to reparse inside the given node.
 fn try_reparse(
     text: &str,
@@ -297,6 +325,8 @@ mod tests {
         test(r#"a ```typst hello```"#, 16..17, "", false);
         test("a{b}c", 1..1, "#", false);
         test("a#{b}c", 1..2, "", false);
+        test("#\"hello\n\nworld", 14..14, "\"", false);
+        test("#\"a\n#[b] c", 7..7, "\"", false);
     }
 
     #[test]
@@ -317,4 +347,19 @@ mod tests {
         test("a #while x {\n g(x) \n}  b", 12..12, "//", true);
         test("a#[]b", 3..3, "[hey]", true);
     }
+
+    #[test]
+    fn test_reparse_utf16() {
+        let mut source = Source::detached("#{ \"😀\" + x }");
+        let range = source.edit_utf16(10..11, "y").unwrap();
+        assert_eq!(source.text(), "#{ \"😀\" + y }");
+        assert_eq!(range, 1..15);
+
+        let mut found = source.root().clone();
+        let mut expected = parse(source.text());
+        found.synthesize(Span::detached());
+        expected.synthesize(Span::detached());
+        assert_eq!(found, expected);
+        assert_eq!(source.edit_utf16(5..20, ""), None);
+    }
 }
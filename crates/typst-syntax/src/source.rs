@@ -103,6 +103,24 @@ impl Source {
         reparse(&mut inner.root, inner.lines.text(), replace, with.len())
     }
 
+    /// Edit the source file by replacing a range given in UTF-16 code units,
+    /// as used by most editors and the language server protocol.
+    ///
+    /// Returns the range in the new source that was ultimately reparsed or
+    /// `None` if the `replace` range is out of bounds.
+    pub fn edit_utf16(
+        &mut self,
+        replace: Range<usize>,
+        with: &str,
+    ) -> Option<Range<usize>> {
+        let start = self.0.lines.utf16_to_byte(replace.start)?;
+        let end = self.0.lines.utf16_to_byte(replace.end)?;
+        if start > end {
+            return None;
+        }
+        Some(self.edit(start..end, with))
+    }
+
     /// Find the node with the given span.
     ///
     /// Returns `None` if the span does not point into this source file.
@@ -43,16 +43,18 @@ use std::collections::HashSet;
 use comemo::{Track, Tracked, Validate};
 use ecow::{eco_format, eco_vec, EcoString, EcoVec};
 use typst_library::diag::{
-    bail, warning, FileError, SourceDiagnostic, SourceResult, Warned,
+    bail, warning, FileError, FileResult, SourceDiagnostic, SourceResult, Warned,
 };
 use typst_library::engine::{Engine, Route, Sink, Traced};
-use typst_library::foundations::{StyleChain, Styles, Value};
+use typst_library::foundations::{Bytes, Datetime, Dict, StyleChain, Styles, Value};
 use typst_library::html::HtmlDocument;
 use typst_library::introspection::Introspector;
 use typst_library::layout::PagedDocument;
 use typst_library::routines::Routines;
-use typst_syntax::{FileId, Span};
+use typst_library::text::{Font, FontBook};
+use typst_syntax::{FileId, Source, Span};
 use typst_timing::{timed, TimingScope};
+use typst_utils::LazyHash;
 
 use crate::foundations::{Target, TargetElem};
 use crate::model::DocumentInfo;
@@ -85,6 +87,94 @@ where
     sink.values()
 }
 
+/// Collects the labelled, numbered elements of a compiled document as
+/// reference targets for another document.
+///
+/// The result maps label names to dictionaries in the form expected by the
+/// [`external`](crate::model::RefElem::external) field of references. See
+/// [`model::external_targets`] for what is and isn't collected.
+pub fn external_targets(
+    world: &dyn World,
+    document: &PagedDocument,
+) -> SourceResult<Dict> {
+    let traced = Traced::default();
+    let mut sink = Sink::new();
+    let mut engine = Engine {
+        world: world.track(),
+        introspector: document.introspector.track(),
+        traced: traced.track(),
+        sink: sink.track_mut(),
+        route: Route::default(),
+        routines: &ROUTINES,
+    };
+    crate::model::external_targets(&mut engine)
+}
+
+/// Compiles the main source file once for each of the given inputs.
+///
+/// This is meant for producing many small documents from the same template,
+/// like the letters of a mail merge. Each document sees its own inputs
+/// through `sys.inputs`, while the world's fonts, images, and parsed sources
+/// are shared between all of them. Memoized work that doesn't depend on the
+/// standard library, like text shaping and image decoding, is reused as well.
+///
+/// Evaluation, however, is not reused: Since every input comes with its own
+/// standard library, the main file and everything it imports, including the
+/// template, are evaluated anew for each document.
+///
+/// The documents are compiled one by one as the returned iterator advances,
+/// so each can be exported and dropped before the next one is compiled.
+pub fn compile_batch<'a, D, I>(
+    world: &'a dyn World,
+    inputs: I,
+) -> impl Iterator<Item = Warned<SourceResult<D>>> + 'a
+where
+    D: Document,
+    I: IntoIterator<Item = Dict>,
+    I::IntoIter: 'a,
+{
+    inputs.into_iter().map(move |inputs| {
+        let library = LazyHash::new(world.library().with_inputs(inputs));
+        compile(&WithLibrary { world, library })
+    })
+}
+
+/// A world whose standard library replaces that of another world.
+struct WithLibrary<'a> {
+    world: &'a dyn World,
+    library: LazyHash<Library>,
+}
+
+impl World for WithLibrary<'_> {
+    fn library(&self) -> &LazyHash<Library> {
+        &self.library
+    }
+
+    fn book(&self) -> &LazyHash<FontBook> {
+        self.world.book()
+    }
+
+    fn main(&self) -> FileId {
+        self.world.main()
+    }
+
+    fn source(&self, id: FileId) -> FileResult<Source> {
+        self.world.source(id)
+    }
+
+    fn file(&self, id: FileId) -> FileResult<Bytes> {
+        self.world.file(id)
+    }
+
+    fn font(&self, index: usize) -> Option<Font> {
+        self.world.font(index)
+    }
+
+    fn today(&self, offset: Option<i64>) -> Option<Datetime> {
+        self.world.today(offset)
+    }
+}
+
 /// The internal implementation of `compile` with a bit lower-level interface
 /// that is also used by `trace`.
 fn compile_impl<D: Document>(
@@ -344,6 +434,8 @@ pub static ROUTINES: Routines = Routines {
     layout_skew: typst_layout::layout_skew,
     layout_repeat: typst_layout::layout_repeat,
     layout_pad: typst_layout::layout_pad,
+    layout_watermark: typst_layout::layout_watermark,
+    layout_slide: typst_layout::layout_slide,
     layout_line: typst_layout::layout_line,
     layout_curve: typst_layout::layout_curve,
     layout_path: typst_layout::layout_path,
@@ -353,6 +445,13 @@ pub static ROUTINES: Routines = Routines {
     layout_ellipse: typst_layout::layout_ellipse,
     layout_circle: typst_layout::layout_circle,
     layout_image: typst_layout::layout_image,
+    layout_qrcode: typst_layout::layout_qrcode,
+    layout_barcode: typst_layout::layout_barcode,
+    layout_chart: typst_layout::layout_chart,
+    layout_fretboard: typst_layout::layout_fretboard,
+    layout_board: typst_layout::layout_board,
+    layout_gantt: typst_layout::layout_gantt,
+    layout_text_path: typst_layout::layout_text_path,
     layout_equation_block: typst_layout::layout_equation_block,
     layout_equation_inline: typst_layout::layout_equation_inline,
 };
@@ -18,8 +18,8 @@ use serde_yaml as yaml;
 use std::sync::LazyLock;
 use typst::diag::{bail, StrResult};
 use typst::foundations::{
-    AutoValue, Binding, Bytes, CastInfo, Func, Module, NoneValue, ParamInfo, Repr, Scope,
-    Smart, Type, Value,
+    AutoValue, Binding, Bytes, CastInfo, Deprecation, Func, Module, NoneValue, ParamInfo,
+    Repr, Scope, Smart, Type, Value,
 };
 use typst::layout::{Abs, Margin, PageElem, PagedDocument};
 use typst::text::{Font, FontBook};
@@ -293,8 +293,13 @@ fn category_page(resolver: &dyn Resolver, category: Category) -> PageModel {
         match binding.read() {
             Value::Func(func) => {
                 let name = func.name().unwrap();
-                let subpage =
-                    func_page(resolver, &route, func, path, binding.deprecation());
+                let subpage = func_page(
+                    resolver,
+                    &route,
+                    func,
+                    path,
+                    binding.deprecation().map(Deprecation::message),
+                );
                 items.push(CategoryItem {
                     name: name.into(),
                     route: subpage.route.clone(),
@@ -523,7 +528,13 @@ fn scope_models(resolver: &dyn Resolver, name: &str, scope: &Scope) -> Vec<FuncM
         .iter()
         .filter_map(|(_, binding)| {
             let Value::Func(func) = binding.read() else { return None };
-            Some(func_model(resolver, func, &[name], true, binding.deprecation()))
+            Some(func_model(
+                resolver,
+                func,
+                &[name],
+                true,
+                binding.deprecation().map(Deprecation::message),
+            ))
         })
         .collect()
 }
@@ -604,7 +615,13 @@ fn group_page(
         let Ok(ref func) = binding.read().clone().cast::<Func>() else {
             panic!("not a function")
         };
-        let func = func_model(resolver, func, &path, true, binding.deprecation());
+        let func = func_model(
+            resolver,
+            func,
+            &path,
+            true,
+            binding.deprecation().map(Deprecation::message),
+        );
         let id_base = urlify(&eco_format!("functions-{}", func.name));
         let children = func_outline(&func, &id_base);
         outline_items.push(OutlineItem {
@@ -739,7 +756,8 @@ fn symbols_model(resolver: &dyn Resolver, group: &GroupData) -> SymbolsModel {
                     .filter(|(other, _, _)| other != &variant)
                     .map(|(other, _, _)| complete(other))
                     .collect(),
-                deprecation: deprecation.or_else(|| binding.deprecation()),
+                deprecation: deprecation
+                    .or_else(|| binding.deprecation().map(Deprecation::message)),
             });
         }
     }
@@ -1,9 +1,10 @@
 use std::fmt::Write;
 
-use typst::foundations::Smart;
+use ecow::EcoString;
+use typst::foundations::{dict, Smart};
 use typst::layout::PagedDocument;
 use typst::model::DocumentInfo;
-use typst::World;
+use typst::{Limits, World};
 
 use crate::collect::Test;
 use crate::world::TestWorld;
@@ -27,6 +28,24 @@ pub fn check(test: &Test, world: &TestWorld, doc: Option<&PagedDocument>) -> Str
             test_eq!(sink, info.author, ["A", "B"]);
             test_eq!(sink, info.date, Smart::Custom(world.today(None)));
         }
+        "document-batch-inputs" => {
+            let inputs = ["A", "B"].map(|name| dict! { "name" => name });
+            let titles: Vec<_> = typst::compile_batch::<PagedDocument>(world, inputs)
+                .map(|warned| info(warned.output.ok().as_ref()).title)
+                .collect();
+            test_eq!(sink, titles, [Some(EcoString::from("A")), Some("B".into())]);
+            test_eq!(sink, info(doc).title, None);
+        }
+        "import-max-depth" => {
+            let limits = Limits { max_import_depth: Some(1), ..Limits::default() };
+            let errors = errors(&world.with_limits(limits));
+            test_eq!(sink, errors, ["maximum import depth exceeded"]);
+        }
+        "image-max-pixels" => {
+            let limits = Limits { max_image_pixels: Some(100), ..Limits::default() };
+            let errors = errors(&world.with_limits(limits));
+            test_eq!(sink, errors, ["image is too large (12×10 pixels)"]);
+        }
         "issue-4065-document-context" => {
             let info = info(doc);
             test_eq!(sink, info.title.as_deref(), Some("Top level"));
@@ -41,6 +60,17 @@ pub fn check(test: &Test, world: &TestWorld, doc: Option<&PagedDocument>) -> Str
     sink
 }
 
+/// Compile the world and return the messages of all errors.
+fn errors(world: &TestWorld) -> Vec<EcoString> {
+    let output = typst::compile::<PagedDocument>(world).output;
+    output
+        .err()
+        .unwrap_or_default()
+        .iter()
+        .map(|e| e.message.clone())
+        .collect()
+}
+
 /// Extract the document information.
 fn info(doc: Option<&PagedDocument>) -> DocumentInfo {
     doc.map(|doc| doc.info.clone()).unwrap_or_default()
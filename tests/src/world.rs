@@ -4,7 +4,7 @@ use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
 use comemo::Tracked;
 use parking_lot::Mutex;
@@ -19,7 +19,7 @@ use typst::syntax::{FileId, Source, Span};
 use typst::text::{Font, FontBook, TextElem, TextSize};
 use typst::utils::{singleton, LazyHash};
 use typst::visualize::Color;
-use typst::{Feature, Library, World};
+use typst::{Feature, Library, Limits, World};
 use typst_syntax::Lines;
 
 /// A world that provides access to the tests environment.
@@ -27,6 +27,7 @@ use typst_syntax::Lines;
 pub struct TestWorld {
     main: Source,
     base: &'static TestBase,
+    library: Option<Arc<LazyHash<Library>>>,
 }
 
 impl TestWorld {
@@ -38,13 +39,24 @@ impl TestWorld {
         Self {
             main: source,
             base: singleton!(TestBase, TestBase::default()),
+            library: None,
+        }
+    }
+
+    /// Create a copy of the world whose library has the given limits.
+    pub fn with_limits(&self, limits: Limits) -> Self {
+        let mut library = library();
+        library.limits = limits;
+        Self {
+            library: Some(Arc::new(LazyHash::new(library))),
+            ..self.clone()
         }
     }
 }
 
 impl World for TestWorld {
     fn library(&self) -> &LazyHash<Library> {
-        &self.base.library
+        self.library.as_deref().unwrap_or(&self.base.library)
     }
 
     fn book(&self) -> &LazyHash<FontBook> {
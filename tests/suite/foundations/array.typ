@@ -362,6 +362,19 @@
 #test(("I", "the", "hi", "text").sorted(by: (x, y) => x.len() < y.len()), ("I", "hi", "the", "text"))
 #test(("I", "the", "hi", "text").sorted(key: x => x.len(), by: (x, y) => y < x), ("text", "the", "hi", "I"))
 
+--- array-sorted-locale ---
+#let words = ("Zoo", "öl", "Äpfel", "apple", "chata", "hrad")
+#test(words.sorted(), ("Zoo", "apple", "chata", "hrad", "Äpfel", "öl"))
+#test(words.sorted(locale: "de"), ("Äpfel", "apple", "chata", "hrad", "öl", "Zoo"))
+#test(words.sorted(locale: "sv"), ("apple", "chata", "hrad", "Zoo", "Äpfel", "öl"))
+#test(words.sorted(locale: "cs"), ("Äpfel", "apple", "hrad", "chata", "öl", "Zoo"))
+#test(("b", "B", "a", "A").sorted(locale: "en"), ("a", "A", "b", "B"))
+#test(("Straße", "Strasse", "Strand").sorted(locale: "de"), ("Strand", "Strasse", "Straße"))
+#test(
+  (("Öz", 2), ("Oz", 1), ("Ob", 3)).sorted(locale: "de"),
+  (("Ob", 3), ("Oz", 1), ("Öz", 2)),
+)
+
 --- array-sorted-invalid-by-function ---
 // Error: 2-39 expected boolean from `by` function, got string
 #(1, 2, 3).sorted(by: (_, _) => "hmm")
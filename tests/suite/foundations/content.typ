@@ -134,3 +134,61 @@
 --- content-try-to-access-internal-field ---
 // Error: 9-15 hide does not have field "hidden"
 #hide[].hidden
+
+--- content-to-dict ---
+#test([Hello].to-dict(), (func: "text", text: "Hello"))
+#test(
+  [= Title].to-dict(),
+  (func: "heading", depth: 1, body: (func: "text", text: "Title")),
+)
+#test(
+  [a *b*].to-dict().children.map(c => c.func),
+  ("text", "space", "strong"),
+)
+#test(list.item[A].to-dict().func, "list.item")
+
+--- content-from-dict ---
+#test(content.from-dict((func: text, text: "Hi")), [Hi])
+#test(content.from-dict((func: "strong", body: [Hi])), strong[Hi])
+#test(content.from-dict((func: "list.item", body: [A])), list.item[A])
+#test(content.from-dict((func: "frac", num: [1], denom: [2])), math.frac[1][2])
+#test(
+  content.from-dict((func: heading, depth: 2, body: "Hi", label: <hi>)).fields(),
+  (depth: 2, body: [Hi], label: <hi>),
+)
+
+--- content-from-dict-roundtrip ---
+#let body = [
+  = Introduction <intro>
+  Some _emphasized_ and *strong* text #h(1cm) with $x^2$.
+
+  + First
+  + Second
+]
+#test(content.from-dict(body.to-dict()), body)
+
+--- content-from-dict-unknown ---
+// Error: 2-35 unknown element `blob`
+#content.from-dict((func: "blob"))
+
+--- content-from-dict-ambiguous ---
+// Error: 2-46 element name `item` is ambiguous
+// Hint: 2-46 use one of `enum.item`, `list.item`, `terms.item`
+#content.from-dict((func: "item", body: [A]))
+
+--- content-from-dict-unknown-field ---
+// Error: 2-50 strong does not have field "bodyy"
+#content.from-dict((func: "strong", bodyy: [Hi]))
+
+--- content-from-dict-missing-func ---
+// Error: 2-34 dictionary does not contain key "func"
+#content.from-dict((body: [Hi]))
+
+--- content-hash ---
+#let a = [Some *strong* text]
+#let b = [Some *strong* text]
+#test(a == b, true)
+#test(a.hash(), b.hash())
+#test(a.hash().len(), 32)
+#test([A].hash() == [B].hash(), false)
+#test([= A <a>].hash(), [= A].hash())
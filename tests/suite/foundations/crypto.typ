@@ -0,0 +1,22 @@
+--- crypto-hash ---
+#test(crypto.sha256(""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+#test(crypto.sha256("abc"), crypto.sha256(bytes("abc")))
+#test(crypto.sha1("abc"), "a9993e364706816aba3e25717850c26c9cd0d89d")
+#test(crypto.md5(""), "d41d8cd98f00b204e9800998ecf8427e")
+
+--- crypto-uuid ---
+#test(crypto.uuid("python.org", namespace: "dns"), "886313e1-3b8a-5372-9b90-0c9aee199e5d")
+#test(
+  crypto.uuid("python.org", namespace: "6ba7b810-9dad-11d1-80b4-00c04fd430c8"),
+  crypto.uuid("python.org", namespace: "dns"),
+)
+#test(crypto.uuid("a"), crypto.uuid("a"))
+#test(crypto.uuid("a").len(), 36)
+
+--- crypto-uuid-invalid-namespace ---
+// Error: 30-37 expected a predefined namespace or a UUID
+#crypto.uuid("a", namespace: "kitty")
+
+--- crypto-uuid-malformed-namespace ---
+// Error: 30-68 expected a predefined namespace or a UUID
+#crypto.uuid("a", namespace: "6ba7b810+9dad-11d1-80b4-00c04fd430c8")
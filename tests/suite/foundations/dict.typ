@@ -236,6 +236,31 @@
   test(dict, (a: 1, b: 9))
 }
 
+--- dict-expect ---
+#let record = (name: "Alice", amount: "30", extra: 1)
+#test(record.expect(name: str, amount: int), (name: "Alice", amount: 30))
+#test(record.expect(vip: (type: bool, default: false)), (vip: false))
+#test(record.expect(extra: (default: none)), (extra: 1))
+#test((ratio: 2).expect(ratio: float), (ratio: 2.0))
+#test((on: "true").expect(on: bool).on, true)
+
+--- dict-expect-missing ---
+// Error: 2-23 missing field "name"
+// Hint: 2-23 the field is required because it has no default value
+#(:).expect(name: str)
+
+--- dict-expect-mistyped ---
+// Error: 2-38 expected integer for field "amount", found "many"
+#(amount: "many").expect(amount: int)
+
+--- dict-expect-bad-spec ---
+// Error: 19-21 expected type or dictionary, found integer
+#(:).expect(name: 12)
+
+--- dict-expect-positional ---
+// Error: 13-16 expected named argument
+#(:).expect(str)
+
 --- issue-3154-dict-syntax-missing-add-assign ---
 #{
   let dict = (a: 1)
@@ -264,3 +289,27 @@
 --- issue-3232-dict-empty ---
 #block(outset: (:), [Hi]) // Ok
 #box(radius: (:), [Hi]) // Ok
+
+--- dict-order ---
+#let dict = (b: 1, a: 2)
+#dict.insert("c", 3)
+#dict.insert("b", 4)
+#test(dict.keys(), ("b", "a", "c"))
+#test(dict.pairs(), (("b", 4), ("a", 2), ("c", 3)))
+#test(repr(dict), "(b: 4, a: 2, c: 3)")
+
+--- dict-sorted-keys ---
+#test((b: 1, a: 2, C: 3).sorted-keys(), ("C", "a", "b"))
+#test((:).sorted-keys(), ())
+
+--- dict-merge ---
+#let a = (x: 1, y: 2)
+#let b = (y: 3, z: 4)
+#test(a.merge(b), (x: 1, y: 3, z: 4))
+#test(a.merge(b).keys(), ("x", "y", "z"))
+#test(a.merge(b, conflict: (key, l, r) => l), (x: 1, y: 2, z: 4))
+#test(a.merge(b, conflict: (key, l, r) => key + str(l + r)), (x: 1, y: "y5", z: 4))
+
+--- dict-merge-conflict-panic ---
+// Error: 50-77 panicked with: "duplicate key y"
+#(x: 1, y: 2).merge((y: 3), conflict: (k, ..) => panic("duplicate key " + k))
@@ -0,0 +1,42 @@
+--- rng-deterministic ---
+#let r = rng(42)
+#test(type(r), rng)
+#test(r.int(1, 6), r.int(1, 6))
+#test(rng(42).int(1, 6, count: 10), r.int(1, 6, count: 10))
+#test(r.int(1, 6, count: 100).all(v => v >= 1 and v <= 6), true)
+#test(r.int(3, 3), 3)
+#test(r.float(count: 5).len(), 5)
+#test(rng("exam").float() < 1.0, true)
+
+--- rng-arrays ---
+#let r = rng(7)
+#test(r.shuffle(range(10)).sorted(), range(10))
+#test(r.sample(range(10), 4).dedup().len(), 4)
+#test(r.choice((1,)), 1)
+
+--- rng-fork ---
+#let r = rng(7)
+#test(r.fork(1), r.fork(1))
+#test(r.fork(1) != r.fork(2), true)
+#test(r.fork("a").int(0, 1000, count: 3), r.fork("a").int(0, 1000, count: 3))
+
+--- rng-int-reversed ---
+// Error: 2-18 minimum must not be larger than maximum
+#rng(1).int(6, 1)
+
+--- rng-sample-too-many ---
+// Error: 2-29 cannot sample 4 elements from an array with 3 elements
+#rng(1).sample((1, 2, 3), 4)
+
+--- rng-choice-empty ---
+// Error: 2-19 cannot pick from an empty array
+#rng(1).choice(())
+
+--- rng-count-too-large ---
+// Error: 2-36 cannot generate more than 100000 values at once
+#rng(1).float(count: 1000000000000)
+
+--- rng-repr ---
+#let r = rng(42).fork("a")
+#test(eval(repr(r)), r)
+#test(repr(rng(-3)), "rng(-3)")
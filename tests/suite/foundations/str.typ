@@ -336,5 +336,11 @@
 #test("ax̂e".rev(), "ex̂a")
 
 --- string-unclosed ---
-// Error: 2-2:1 unclosed string
+// Error: 2-10 unclosed string
 #"hello\"
+
+--- string-unclosed-recovery ---
+// Error: 2-8 unclosed string
+#"hello
+// Error: 2-8 unclosed string
+#"world
@@ -129,3 +129,16 @@ Hallo
 A
 #colbreak(weak: true)
 B
+
+--- columns-balance ---
+#set page(height: 120pt, width: 120pt)
+#columns(2, balance: true)[
+  #set par(justify: true)
+  #lorem(20)
+]
+#rect(width: 100%)[Spanning]
+#columns(2, balance: true, lorem(10))
+
+--- columns-balance-multiple-regions ---
+#set page(height: 80pt, width: 120pt)
+#columns(2, balance: true, lorem(60))
@@ -325,3 +325,11 @@ b
 a
 #block(height: -25pt)[b]
 c
+
+--- box-align ---
+#let icon = box(width: 6pt, height: 6pt, fill: blue)
+Top #box(align: top, icon)
+Horizon #box(align: horizon, icon)
+Bottom #box(align: bottom, icon)
+Shifted #box(align: horizon, baseline: 2pt, icon)
+Formula #box(align: horizon, $sum_(i=1)^n i$)
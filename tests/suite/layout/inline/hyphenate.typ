@@ -112,6 +112,22 @@ Tras el estallido de la contienda Ruiz-Giménez fue detenido junto a sus
 dos hermanos y puesto bajo custodia por las autoridades republicanas, con
 el objetivo de protegerle de las patrullas de milicianos.
 
+--- hyphenate-narrow-container ---
+// Text in narrow containers is hyphenated automatically when enabled.
+#set par(hyphenate-below: 15em)
+#table(
+  columns: (2.4cm, 2.4cm),
+  [Internationalization], [Considerations],
+)
+
+--- hyphenate-overflow-warning ---
+#set par(hyphenate-below: 15em)
+#set text(hyphenate: false)
+// Warning: 17-38 text does not fit into the available width
+// Hint: 17-38 a word is too wide and could not be broken
+// Hint: 17-38 try enabling hyphenation or making the container wider
+#box(width: 1cm)[Incomprehensibilities]
+
 --- costs-widow-orphan ---
 #set page(height: 60pt)
 
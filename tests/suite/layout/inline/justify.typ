@@ -50,6 +50,28 @@ D
 A B C #linebreak(justify: true)
 D E F #linebreak(justify: true)
 
+--- justify-last ---
+#set page(width: 180pt)
+#set par(justify: true, justify-last: true)
+A short epigraph, \
+set in two lines.
+
+--- justify-last-close ---
+// Only the first paragraph's last line is close enough to be justified.
+#set page(width: 180pt)
+#set par(justify: true, justify-last: 75%)
+This paragraph has a last line that is nearly full width.
+
+This one does not.
+
+--- justify-last-line-align ---
+#set page(width: 180pt)
+#set par(justify: true, last-line: center)
+#lorem(20)
+
+#set par(justify: false, last-line: end)
+#lorem(20)
+
 --- justify-basically-empty ---
 // Test that there are no hick-ups with justification enabled and
 // basically empty paragraph.
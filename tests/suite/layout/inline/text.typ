@@ -20,6 +20,10 @@ abstract vs #text(historical-ligatures: true)[abstract] \
 // Test text turning on discretionary ligatures of the font.
 waltz vs #text(discretionary-ligatures: true)[waltz]
 
+--- text-contextual-alternates ---
+// Test turning contextual alternates off.
+a -> b != c vs #text(contextual-alternates: false)[a -> b != c]
+
 --- text-number-type ---
 // Test number type.
 #set text(number-type: "old-style")
@@ -46,6 +46,34 @@
   test((10em).to-absolute(), 640pt)
 }
 
+--- length-to-dynamic-unit ---
+// Test length conversion with a unit given at runtime.
+#let t(a, b) = assert(calc.abs(a - b) < 1e-6)
+#t((1in).to("pt"), 72.0)
+#t((1in).to("mm"), 25.4)
+#t((1in).to("cm"), 2.54)
+#t((2.54cm).to("in"), 1.0)
+#t((3em).abs.to("pt"), 0.0)
+
+#set text(size: 12pt)
+#context {
+  t((3em).to("em"), 3.0)
+  t((6pt).to("em"), 0.5)
+  t((1.5em + 6pt).to("em"), 2.0)
+}
+
+--- length-to-dynamic-unit-em ---
+// Error: 2-16 cannot convert a length with non-zero em units (`2em`) to inches
+// Hint: 2-16 use `length.to-absolute()` to resolve its em component (requires context)
+// Hint: 2-16 or use `length.abs.inches()` instead to ignore its em component
+#(2em).to("in")
+
+--- length-to-dynamic-unit-no-context ---
+// Error: 2-16 can only be used when context is known
+// Hint: 2-16 try wrapping this in a `context` expression
+// Hint: 2-16 the `context` expression should wrap everything that depends on this function
+#(2em).to("em")
+
 --- length-unit-hint ---
 // Error: 17-19 expected length, found integer
 // Hint: 17-19 a length needs a unit - did you mean 12pt?
@@ -230,6 +230,22 @@ Z
 #counter(page).update(53)
 #filler
 
+--- page-numbering-counter ---
+// Plates are numbered separately, while the page counter pauses.
+#set page(height: 80pt, margin: (bottom: 20pt, rest: 10pt), numbering: "1")
+#counter(page).update(37)
+= Chapter <chapter>
+#pagebreak()
+#page(numbering: "P-1", counter: counter("plates"))[
+  Plate <plate>
+  #pagebreak()
+  Plate
+]
+See page #context counter(page).display() and
+#ref(<plate>, form: "page").
+#context test(counter(page).final(), (39,))
+#context test(counter("plates").final(), (2,))
+
 --- page-numbering-hint ---
 = Heading <intro>
 
@@ -376,6 +392,33 @@ Hi
 text
 #pagebreak()
 
+--- page-header-skip-selector ---
+#set page(
+  height: 80pt,
+  header: [Running head],
+  header-skip: heading.where(level: 1),
+)
+
+= Chapter
+First page
+#pagebreak()
+Second page
+
+--- page-footer-skip-func ---
+#set page(
+  height: 60pt,
+  numbering: "1",
+  footer-skip: n => n == 1,
+)
+
+Title
+#pagebreak()
+Contents
+
+--- page-footer-skip-bad-return ---
+// Error: 51-57 expected boolean, found integer
+#set page(height: 40pt, footer: [F], footer-skip: n => n)
+
 --- issue-2326-context-set-page ---
 #context [
   #set page(fill: aqua)
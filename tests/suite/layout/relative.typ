@@ -6,6 +6,32 @@
 #test((100% + 2pt - 2pt).length, 0pt)
 #test((56% + 2pt - 56%).ratio, 0%)
 
+--- relative-constructor ---
+#test(relative(50%), 50% + 0pt)
+#test(relative(2pt).ratio, 0%)
+#test(relative(2pt).length, 2pt)
+#test(relative(30% + 1em).ratio, 30%)
+#test(relative(ratio: 25%, length: 1em), 25% + 1em)
+#test(relative(50% + 2pt, length: 1cm), 50% + 1cm)
+#test(relative(), 0% + 0pt)
+#test(type(relative(50%)), relative)
+
+--- ratio-constructor ---
+#test(ratio(0.25), 25%)
+#test(ratio(2), 200%)
+#test(ratio(0.5) * 10pt, 5pt)
+#test(40% / 100%, 0.4)
+
+--- fraction-constructor ---
+#test(fraction(2), 2fr)
+#test(fraction(0.5) + 1fr, 1.5fr)
+#test(3fr / 1fr, 3.0)
+#test((1, 2).map(fraction), (1fr, 2fr))
+
+--- relative-constructor-invalid ---
+// Error: 11-16 expected relative length, found string
+#relative("50%")
+
 --- double-percent-embedded ---
 // Test for two percent signs in a row.
 // Error: 2-7 invalid number suffix: %%
@@ -0,0 +1,46 @@
+// Test the `watermark` function.
+
+--- watermark-basic ---
+#set page(width: 120pt, height: 160pt, background: watermark[DRAFT])
+#lorem(20)
+
+--- watermark-angle-and-size ---
+#set page(width: 120pt, height: 80pt, background: watermark(
+  angle: 0deg,
+  size: 50%,
+  text(red.lighten(60%))[VOID],
+))
+#lorem(8)
+
+--- watermark-no-margins ---
+#set page(width: 100pt, height: 100pt, foreground: watermark(
+  margins: false,
+  square(size: 10pt, fill: blue.transparentize(60%)),
+))
+
+--- watermark-pages ---
+#set page(width: 80pt, height: 60pt, background: watermark(
+  pages: n => calc.even(n),
+  [DRAFT],
+))
+First
+#pagebreak()
+Second
+#pagebreak()
+Third
+
+--- watermark-two-sided-margins ---
+// The watermark follows the inside margin to the right on even pages.
+#set page(
+  width: 100pt,
+  height: 60pt,
+  margin: (inside: 40pt, outside: 5pt, y: 5pt),
+  background: watermark(angle: 0deg, square(size: 10pt, fill: blue)),
+)
+First
+#pagebreak()
+Second
+
+--- watermark-pages-bad-return ---
+// Error: 37-64 expected boolean, found integer
+#set page(height: 60pt, background: watermark(pages: n => n)[A])
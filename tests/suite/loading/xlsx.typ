@@ -0,0 +1,40 @@
+--- xlsx-invalid-range ---
+// Error: 25-29 invalid cell range "A0"
+#xlsx(bytes(()), range: "A0")
+
+--- xlsx-reversed-range ---
+// Error: 25-32 cell range "D4:A1" is reversed
+#xlsx(bytes(()), range: "D4:A1")
+
+--- xlsx-invalid-sheet ---
+// Error: 25-28 expected integer or string, found float
+#xlsx(bytes(()), sheet: 1.5)
+
+--- xlsx-basic ---
+#let data = xlsx("sales.xlsx")
+#test(data, (
+  ("Product", "Units", "Paid"),
+  ("Pens", 12, true),
+  ("Paper", 2.5, none),
+))
+
+--- xlsx-sheet-and-range ---
+#test(xlsx("sales.xlsx", sheet: "Notes"), ((none, none), (none, "Hi")))
+#test(xlsx("sales.xlsx", sheet: 1, range: "B2"), (("Hi",),))
+#test(xlsx("sales.xlsx", range: "A2:B3"), (("Pens", 12), ("Paper", 2.5)))
+
+--- xlsx-row-type-dict ---
+#let data = xlsx("sales.xlsx", range: "A1:B3", row-type: dictionary)
+#test(data, (
+  (Product: "Pens", Units: 12),
+  (Product: "Paper", Units: 2.5),
+))
+
+--- xlsx-range-too-large ---
+// Error: 28-43 cell range is too large
+// Hint: 28-43 at most 4194304 cells can be read at once, try a smaller range
+#xlsx("sales.xlsx", range: "A1:XFD1048576")
+
+--- xlsx-range-out-of-bounds ---
+// Error: 28-42 invalid cell range "A1:A99999999"
+#xlsx("sales.xlsx", range: "A1:A99999999")
@@ -31,6 +31,38 @@ And quark! @quark
 Now we have multiple bibliographies containing @glacier-melt @keshav2007read
 #bibliography(("/assets/bib/works.bib", "/assets/bib/works_too.bib"))
 
+--- bibliography-multiple-sections ---
+// Each bibliography lists the works cited since the previous one.
+#set page(width: 200pt)
+= Chapter 1
+As noted @netwok @arrgh.
+#bibliography("/assets/bib/works.bib", title: none)
+
+= Chapter 2
+Again @arrgh, and also @quark.
+#bibliography("/assets/bib/works.bib", title: none)
+
+--- bibliography-backrefs ---
+#set page(width: 200pt, height: 120pt, numbering: "1")
+See @netwok and @arrgh.
+#pagebreak()
+Again @arrgh @arrgh.
+#cite(<quark>, form: none)
+#pagebreak()
+#bibliography("/assets/bib/works.bib", full: true, backrefs: true)
+
+--- bibliography-cite-missing-key ---
+// Error: 2-16 key `arrgh2` does not exist in the bibliography
+// Hint: 2-16 did you mean `arrgh`?
+#cite(<arrgh2>)
+#bibliography("/assets/bib/works.bib")
+
+--- bibliography-ref-missing-key ---
+// Error: 1-7 label `<netwk>` does not exist in the document
+// Hint: 1-7 did you mean `netwok`?
+@netwk
+#bibliography("/assets/bib/works.bib")
+
 --- bibliography-duplicate-key ---
 // Error: 15-65 duplicate bibliography keys: netwok, issue201, arrgh, quark, distress, glacier-melt, tolkien54, DBLP:books/lib/Knuth86a, sharing, restful, mcintosh_anxiety, psychology25
 #bibliography(("/assets/bib/works.bib", "/assets/bib/works.bib"))
@@ -43,6 +75,28 @@ Now we have multiple bibliographies containing @glacier-melt @keshav2007read
 
 #bibliography("/assets/bib/works.bib")
 
+--- bibliography-sorting-citation ---
+#set page(width: 300pt)
+
+@psychology25
+@arrgh
+@netwok
+
+#bibliography("/assets/bib/works.bib", style: "apa", sorting: "citation")
+
+--- bibliography-sorting-func ---
+#set page(width: 300pt)
+
+@psychology25
+@arrgh
+@netwok
+
+#bibliography(
+  "/assets/bib/works.bib",
+  style: "apa",
+  sorting: work => work.key.len(),
+)
+
 --- bibliography-full ---
 #set page(paper: "a6", height: auto)
 #bibliography("/assets/bib/works_too.bib", full: true)
@@ -0,0 +1,86 @@
+--- calendar ---
+#set text(6pt)
+#calendar(datetime(year: 2025, month: 3, day: 1))
+
+--- timetable ---
+#set text(6pt)
+#let t(h) = datetime(hour: h, minute: 0, second: 0)
+#timetable(
+  days: ("monday", "tuesday"),
+  start: t(8),
+  end: t(11),
+  (day: "monday", start: t(8), end: t(10), body: [Algebra]),
+  (day: "tuesday", start: t(9), end: t(10), body: [Physics]),
+)
+
+--- calendar-week-start ---
+#let march = datetime(year: 2025, month: 3, day: 14)
+#show table: it => {
+  let (header, first, ..rest) = it.children
+  test(header.children.first().body, [Mo])
+  test(first.x, 5)
+  test(rest.len(), 30)
+  none
+}
+#set text(lang: "de")
+#calendar(march, labels: ([Mo], [Tu], [We], [Th], [Fr], [Sa], [Su]))
+
+--- calendar-week-start-region ---
+#show table: it => {
+  let (header, first, ..) = it.children
+  test(header.children.map(cell => cell.body).first(), [Su])
+  test(first.x, 6)
+  none
+}
+#set text(lang: "en", region: "us")
+#calendar(datetime(year: 2025, month: 3, day: 1))
+
+--- calendar-day-func ---
+#show table: it => {
+  test(it.children.at(1).body.text, "2025-02-01")
+  test(it.children.len(), 1 + 28)
+  none
+}
+#calendar(
+  datetime(year: 2025, month: 2, day: 1),
+  week-start: "sunday",
+  day: date => date.display(),
+)
+
+--- calendar-labels-wrong-count ---
+// Error: 2-70 expected 7 weekday labels, found 2
+#calendar(datetime(year: 2025, month: 1, day: 1), labels: ([A], [B]))
+
+--- calendar-time ---
+// Error: 2-51 calendar month must be a date
+#calendar(datetime(hour: 1, minute: 0, second: 0))
+
+--- timetable-events ---
+#let t(h) = datetime(hour: h, minute: 0, second: 0)
+#show table: it => {
+  let (header, ..rest) = it.children
+  test(header.children.len(), 3)
+  // Four time slots, then the events.
+  let (algebra, physics) = rest.slice(4)
+  test((algebra.x, algebra.y, algebra.rowspan), (1, 1, 2))
+  test((physics.x, physics.y, physics.rowspan), (2, 4, 1))
+  test(rest.len(), 6)
+  none
+}
+#timetable(
+  days: ("monday", "tuesday"),
+  start: t(8),
+  end: t(12),
+  (day: "monday", start: t(8), end: t(10), body: [Algebra]),
+  (day: "tuesday", start: t(11), end: t(13), body: [Physics]),
+  (day: "friday", start: t(8), end: t(9), body: [Hidden]),
+  (
+    start: datetime(year: 2025, month: 3, day: 4, hour: 7, minute: 0, second: 0),
+    end: t(8),
+    body: [Too early],
+  ),
+)
+
+--- timetable-event-no-day ---
+// Error: 12-106 event must have a day or a start date
+#timetable((start: datetime(hour: 8, minute: 0, second: 0), end: datetime(hour: 9, minute: 0, second: 0)))
@@ -32,6 +32,13 @@ A @netwok @arrgh @quark, B.
 #set text(0pt)
 #bibliography("/assets/bib/works.bib", style: "american-physics-society")
 
+--- cite-affixes ---
+#cite(<netwok>, prefix: [see], supplement: [pp.~14--18])
+#cite(<arrgh>, prefix: [cf.], suffix: [for a summary])
+
+#show bibliography: none
+#bibliography("/assets/bib/works.bib", style: "chicago-author-date")
+
 --- cite-grouping-and-ordering ---
 @mcintosh_anxiety
 @psychology25
@@ -0,0 +1,66 @@
+--- cv ---
+#cv(
+  sort: true,
+  cv.entry(
+    [Assistant],
+    organization: [University],
+    start: "2019-10",
+    end: "2021-03",
+  )[Typesetting research.],
+  (title: "Engineer", organization: "Corp", start: "2021-04", end: "present"),
+)
+
+--- cv-theme-compact ---
+#set cv(theme: "compact")
+#cv(
+  (title: "Tutor", start: 2018, end: 2019),
+  (title: "Engineer", start: 2021),
+)
+
+--- cv-entry-from-dict ---
+#let entry = cv(
+  (title: "Engineer", organization: "Corp", start: "2021-04", end: "present"),
+).children.first()
+#test(entry.func(), cv.entry)
+#test(entry.title, [Engineer])
+#test(entry.organization, [Corp])
+#test(entry.start, datetime(year: 2021, month: 4, day: 1))
+#test(entry.end, none)
+
+--- cv-entry-year ---
+#let entry = cv.entry([Award], start: "2020")
+#test(entry.start, 2020)
+
+--- cv-theme-func ---
+#set cv(theme: entry => {
+  test(entry.title, [Job])
+  test(entry.body, none)
+  test(type(entry.dates), content)
+  test(entry.tags, ("a",))
+  []
+})
+#cv((title: "Job", start: 2019, end: 2021, tags: ("a",)))
+
+--- cv-tags-filter ---
+#set cv(theme: "compact")
+#let entries = (
+  (title: "Tutor", tags: ("teaching",)),
+  (title: "Developer", tags: ("engineering",)),
+)
+#context test(
+  measure(cv(..entries, tags: ("teaching",))).height,
+  measure(cv(entries.first())).height,
+)
+#context test(measure(cv(..entries, tags: ())).height, 0pt)
+
+--- cv-entry-unknown-key ---
+// Error: 5-38 unexpected key "role", valid keys are "title", "organization", "location", "start", "end", "tags", and "body"
+#cv((title: "Engineer", role: "Lead"))
+
+--- cv-entry-missing-title ---
+// Error: 5-27 dictionary does not contain key "title"
+#cv((organization: "Corp"))
+
+--- cv-date-invalid ---
+// Error: 25-34 invalid date `2021-13`
+#cv.entry([Job], start: "2021-13")
@@ -7,6 +7,9 @@ What's up?
 --- document-set-author-date ---
 #set document(author: ("A", "B"), date: datetime.today())
 
+--- document-batch-inputs ---
+#set document(title: sys.inputs.at("name", default: none))
+
 --- document-date-bad ---
 // Error: 21-28 expected datetime, none, or auto, found string
 #set document(date: "today")
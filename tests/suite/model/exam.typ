@@ -0,0 +1,33 @@
+--- question-total ---
+#question(points: 2)[First]
+#question[Without points]
+#question(points: 3.5)[Third]
+#context test(question.total(), 5.5)
+
+--- question-numbering ---
+#show question: it => it.body
+#context test(counter(question).get(), (0,))
+#question[A]
+#context test(counter(question).get(), (1,))
+#set question(numbering: none)
+#question[B]
+#context test(counter(question).get(), (1,))
+
+--- question-total-no-context ---
+// Error: 2-18 can only be used when context is known
+// Hint: 2-18 try wrapping this in a `context` expression
+// Hint: 2-18 the `context` expression should wrap everything that depends on this function
+#question.total()
+
+--- solution-visible ---
+#context test(solution.visible, false)
+#set solution(visible: true)
+#context test(solution.visible, true)
+
+--- solution-hidden ---
+#let square = box(width: 1cm, height: 1cm)
+#context {
+  test(measure(solution(square)).height, 0pt)
+  test(measure(solution(visible: true, square)).height, 1cm)
+  test(measure(solution(placeholder: box(height: 2cm), square)).height, 2cm)
+}
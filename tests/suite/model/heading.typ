@@ -109,6 +109,13 @@ comment spans lines
 #set heading(numbering: "1.1.a.", hanging-indent: 2em)
 = State of the Art In Multi-Line
 
+--- heading-numbering-gap ---
+#set page(width: 120pt)
+#set heading(numbering: "1.1.a.", numbering-gap: 1em)
+#show heading.where(level: 2): set heading(numbering-gap: 0pt, hanging-indent: 0pt)
+= State of the Art
+== State of the Art In Multi-Line
+
 --- heading-offset-and-level ---
 // Passing level directly still overrides all other set values
 #set heading(numbering: "1.1", offset: 1)
@@ -133,6 +140,34 @@ Cannot be used as @intro
 #show par: highlight
 = Heading
 
+--- heading-numbering-parts ---
+// Chapter numbers restart in each part.
+#set heading(numbering: (..nums) => {
+  let nums = nums.pos()
+  if nums.len() == 1 [Part #numbering("I", ..nums)]
+  else { numbering("1.1", ..nums.slice(1)) }
+})
+
+= Foundations
+== Basics
+== Methods
+= Applications
+== Examples <examples>
+
+#context test(counter(heading).at(<examples>), (2, 1))
+
+--- heading-numbering-appendix ---
+#set heading(numbering: "1.1")
+= Results
+== Discussion
+
+#counter(heading).update(0)
+#set heading(numbering: "A.1")
+= Raw Data
+== Measurements <measurements>
+
+See @measurements.
+
 --- heading-html-basic html ---
 // level 1 => h2
 // ...
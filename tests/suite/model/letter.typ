@@ -0,0 +1,30 @@
+--- letter ---
+#show: letter.with(
+  sender: [Jane Doe · Main Street 1 · 12345 Town],
+  recipient: [Example Corp. \ Market Square 2 \ 54321 City],
+  info: [Date: March 3, 2025],
+  subject: [Your inquiry],
+)
+Dear John,
+
+#lorem(20)
+
+--- letter-fields ---
+#let l = letter(
+  standard: "us-10",
+  sender: [Jane Doe],
+  recipient: [John Smith],
+  subject: [Hello],
+)[Body]
+#test(l.standard, "us-10")
+#test(l.sender, [Jane Doe])
+#test(l.subject, [Hello])
+#test(l.body, [Body])
+
+--- letter-defaults ---
+#context test(letter.standard, "din-5008-b")
+#context test(letter.fold-marks, true)
+
+--- letter-standard-invalid ---
+// Error: 19-27 expected "din-5008-a", "din-5008-b", or "us-10"
+#letter(standard: "din-a4")[]
@@ -120,6 +120,21 @@
 
 = Heading
 
+--- outline-filter ---
+#set heading(numbering: "1.")
+#outline(depth: 2, filter: it => it.element.numbering != none)
+
+= Introduction
+== Background
+#heading(level: 2, numbering: none)[Unnumbered]
+=== Too deep
+#heading(numbering: none)[Appendix]
+
+--- outline-filter-bad-return ---
+// Error: 2-26 expected boolean, found integer
+#outline(filter: it => 1)
+= Heading
+
 --- outline-entry ---
 #set page(width: 150pt)
 #set heading(numbering: "1.")
@@ -182,6 +197,19 @@
 = A
 = B
 
+--- outline-entry-page-width ---
+#set page(width: 150pt)
+#set outline.entry(page-gap: 2pt, page-width: 2em)
+#show heading: none
+
+#outline()
+
+= Short
+#counter(page).update(99)
+= Hundred
+#counter(page).update(999)
+= Thousand
+
 --- outline-heading-start-of-page ---
 #set page(width: 140pt, height: 200pt, margin: (bottom: 20pt), numbering: "1")
 #set heading(numbering: "(1/a)")
@@ -237,6 +265,20 @@ A
 = Analysis
 == Setup
 
+--- outline-figure-short-caption ---
+#set page(width: 150pt)
+#outline(target: figure)
+
+#figure(
+  rect[A],
+  caption: figure.caption(
+    short: [Short],
+    [A much longer caption that would not fit on a single line],
+  ),
+)
+
+#figure(rect[B], caption: [Plain])
+
 --- outline-bad-element ---
 // Error: 2-27 cannot outline metadata
 #outline(target: metadata)
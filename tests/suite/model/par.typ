@@ -309,6 +309,37 @@ A
 
 #par[ B ]
 
+--- par-numbering ---
+#set par(numbering: "1.", hanging-indent: 1.2em)
+#lorem(10)
+
+#lorem(6)
+
+#context test(counter(par).get(), (2,))
+
+--- par-numbering-ref ---
+#set par(numbering: "§1")
+#par[The parties agree on the terms below.] <terms>
+
+#par(supplement: [Clause])[Nothing in @terms applies on holidays.] <holidays>
+
+See @terms and @holidays.
+
+--- par-numbering-none-not-counted ---
+#lorem(4)
+
+#set par(numbering: "1.")
+#lorem(4)
+
+#context test(counter(par).get(), (1,))
+
+--- par-numbering-show ---
+#set par(numbering: "(a)")
+#show par: set text(fill: eastern)
+#lorem(8)
+
+#par(numbering: none)[#lorem(5)]
+
 --- issue-4278-par-trim-before-equation ---
 #set par(justify: true)
 #lorem(6) aa $a = c + b$
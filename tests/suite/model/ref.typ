@@ -47,6 +47,59 @@ $ A = 1 $ <eq2>
 #set ref(supplement: none)
 @fig1, @fig2, @eq1, @eq2
 
+--- ref-range ---
+#set heading(numbering: "1.")
+#show heading: none
+
+= A <a>
+= B
+= C <c>
+
+#ref(<a>, to: <c>) \
+#ref(<a>, to: <c>, supplement: [Parts])
+
+#set text(lang: "de")
+#figure(rect(), caption: [X]) <x>
+#figure(rect(), caption: [Y]) <y>
+#ref(<x>, to: <y>)
+
+--- ref-collapse ---
+#set heading(numbering: "1.")
+#set ref(collapse: true)
+#show heading: none
+
+= A <a>
+= B <b>
+= C <c>
+= D <d>
+#figure(rect(), caption: [X]) <x>
+
+@a @b @c @x \
+@a @b @d \
+@a @c[Part] \
+@a, @b
+
+#set text(lang: "de")
+@a @b @c @d
+
+--- ref-collapse-disabled ---
+#set heading(numbering: "1.")
+#show heading: none
+
+= A <a>
+= B <b>
+
+@a @b
+
+--- ref-range-without-numbering ---
+#set heading(numbering: "1.")
+= A <a>
+#heading(numbering: none)[B] <b>
+
+// Error: 2-19 cannot reference heading without numbering
+// Hint: 2-19 you can enable heading numbering with `#set heading(numbering: "1.")`
+#ref(<a>, to: <b>)
+
 --- ref-ambiguous ---
 // Test ambiguous reference.
 = Introduction <arrgh>
@@ -97,3 +150,29 @@ Text seen on #ref(<text>, form: "page", supplement: "Page").
 // Error: 6-7 unexpected less-than operator
 // Error: 7-8 unexpected greater-than operator
 #ref(<>)
+
+--- ref-external ---
+#set page(numbering: "1")
+#set heading(numbering: "1.")
+#set ref(external: (
+  intro: (supplement: "Section", numbers: "1", page: 3),
+  fig: (supplement: "Figure", numbers: "4", page: 12),
+))
+
+= Methods <methods>
+As shown in @intro and @fig on #ref(<fig>, form: "page"), we refer to
+@methods as usual.
+
+--- ref-external-page-numbering ---
+#set page(numbering: "1")
+#set ref(external: (preface: (supplement: "Section", numbers: "1", page: "iv")))
+See #ref(<preface>, form: "page").
+
+--- ref-external-supplement ---
+#set ref(external: (intro: (supplement: "Section", numbers: "1", page: 3)))
+@intro[Chapter] and #ref(<intro>, supplement: none)
+
+--- ref-external-missing-key ---
+#set ref(external: (intro: (supplement: "Section", page: 3)))
+// Error: 1-7 dictionary does not contain key "numbers"
+@intro
@@ -0,0 +1,31 @@
+--- slide-steps-cast ---
+#test(uncover(2)[A].steps, "2")
+#test(only((1, 3))[A].steps, "1, 3")
+#test(only("1-2,4-")[A].steps, "1-2, 4-")
+
+--- slide-steps-invalid ---
+// Error: 10-13 invalid step `x`
+#uncover("x")[A]
+
+--- slide-steps-reversed ---
+// Error: 7-12 step range `3-1` is reversed
+#only("3-1")[A]
+
+--- slide-steps-zero ---
+// Error: 10-11 number must be positive
+#uncover(0)[A]
+
+--- slide-notes-field ---
+#let s = slide(notes: [Speak slowly.])[Hello]
+#test(s.notes, [Speak slowly.])
+#context test(slide.show-notes, false)
+
+--- slide-steps-introspection ---
+#set page(height: auto)
+#set heading(numbering: "1.")
+#slide[
+  = Intro <intro>
+  #uncover(2)[See @intro.]
+]
+#context test(counter(heading).get(), (1,))
+#context test(query(<intro>).len(), 1)
@@ -204,5 +204,5 @@
 
 --- call-args-unclosed-string ---
 // Error: 6-7 unclosed delimiter
-// Error: 1:7-2:1 unclosed string
+// Error: 7-9 unclosed string
 #func("]
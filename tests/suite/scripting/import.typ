@@ -34,6 +34,35 @@
 // It exists now!
 #test(d, 3)
 
+--- import-wildcard-except ---
+// A wildcard import with excluded names.
+#let b = "mine"
+#import "module.typ": * except (b, c)
+#test(b, "mine")
+#test(d, 3)
+
+--- import-wildcard-except-unparenthesized ---
+#{
+  let a = "mine"
+  import "module.typ": * except a, value
+  test(a, "mine")
+  test(b, 1)
+}
+
+--- import-wildcard-except-reexport ---
+// Imported names are re-exported from a module.
+#import "modules/chap2.typ" as orig-chap2
+#import "module.typ": * except item
+#test(chap2, orig-chap2)
+
+--- import-wildcard-except-unresolved ---
+// Error: 32-35 unresolved import
+#import "module.typ": * except foo
+
+--- import-wildcard-except-empty ---
+// Error: 31 expected identifier
+#import "module.typ": * except
+
 --- import-item-renamed ---
 // A renamed item import.
 #import "module.typ": item as something
@@ -483,3 +512,8 @@ This is never reached.
 --- import-from-file-package-lookalike ---
 // Error: 9-28 file not found (searched at tests/suite/scripting/#test/mypkg:1.0.0)
 #import "#test/mypkg:1.0.0": *
+
+--- import-max-depth ---
+// Compiled again with a maximum import depth of 1 in `custom.rs`, which
+// fails because the second chapter imports the first one.
+#import "modules/chap2.typ"
@@ -25,6 +25,28 @@
 // Error: 2-6 unknown variable: name
 #name
 
+--- include-part-heading ---
+#let part = include "modules/book.typ": <methods>
+#let headings = part.children.filter(it => it.func() == heading)
+#test(headings.map(it => it.body), ([Methods], [Sampling]))
+
+--- include-part-element ---
+#let part = include "modules/book.typ": <status>
+#test(part.func(), emph)
+#test(part.body, [Draft])
+
+--- include-part-render ---
+#set page(width: 200pt)
+#include "modules/book.typ": <results>
+
+--- include-part-not-found ---
+// Error: 41-50 label `<missing>` does not exist in the included content
+#let part = include "modules/book.typ": <missing>
+
+--- include-part-not-label ---
+// Error: 41-48 expected label, found string
+#let part = include "modules/book.typ": "intro"
+
 --- include-semicolon-or-linebreak ---
 // Error: 18 expected semicolon or line break
 #include "hi.typ" Hi
@@ -0,0 +1,17 @@
+// SKIP
+_Draft_ <status>
+
+#set heading(numbering: "1.1")
+
+= Introduction <intro>
+This book is about tests.
+
+= Methods <methods>
+We used several methods.
+
+== Sampling
+Samples were taken.
+
+= Results <results>
+#set text(fill: blue)
+Everything went fine.
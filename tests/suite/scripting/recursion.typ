@@ -38,9 +38,38 @@
 
 --- recursion-maximum-depth ---
 // Error: 15-21 maximum function call depth exceeded
+// Hint: 15-21 maybe a function calls itself without a base case
+// Hint: 15-21 the limit is 80 nested calls
 #let rec(n) = rec(n) + 1
 #rec(1)
 
+--- recursion-tail-call ---
+// A returned call doesn't count towards the call depth.
+#let count(n, acc: 0) = {
+  if n == 0 { return acc }
+  return count(n - 1, acc: acc + 1)
+}
+#test(count(5000), 5000)
+
+--- recursion-tail-call-limit ---
+// Exactly at the limit of consecutive tail calls.
+#let count(n, acc: 0) = if n == 0 { acc } else { return count(n - 1, acc: acc + 1) }
+#test(count(10000), 10000)
+
+--- recursion-tail-call-limit-exceeded ---
+// Error: 57-83 maximum number of tail calls exceeded
+// Hint: 57-83 maybe a function returns a call of itself without a base case
+// Hint: 57-83 the limit is 10000 consecutive tail calls
+#let count(n, acc: 0) = if n == 0 { acc } else { return count(n - 1, acc: acc + 1) }
+#count(10001)
+
+--- recursion-tail-call-infinite ---
+// Error: 24-30 maximum number of tail calls exceeded
+// Hint: 24-30 maybe a function returns a call of itself without a base case
+// Hint: 24-30 the limit is 10000 consecutive tail calls
+#let rec(n) = { return rec(n) }
+#rec(1)
+
 --- recursion-via-include-in-layout ---
 // Test cyclic imports during layout.
 // Error: 2-38 maximum show rule depth exceeded
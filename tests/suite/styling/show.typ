@@ -86,12 +86,34 @@ Another text.
 #show text: none
 Hey
 
+--- show-auto-revokes-outer-rules ---
+#show heading: it => underline(it.body)
+#show heading.where(level: 2): emph
+= Underlined
+#[
+  #show heading: auto
+  = Plain
+  == Plain
+]
+== Emphasized
+
+--- show-auto-keeps-show-set ---
+#show heading: set text(red)
+#show heading: [Replaced]
+#show heading: auto
+= Red
+
+--- show-auto-without-selector ---
+// Error: 8-12 show rule with `auto` requires a selector
+// Hint: 8-12 use `show sel: auto` to revoke the show rules for `sel`
+#show: auto
+
 --- show-selector-not-an-element-function ---
 // Error: 7-12 only element functions can be used as selectors
 #show upper: it => {}
 
 --- show-bad-replacement-type ---
-// Error: 16-20 expected content or function, found integer
+// Error: 16-20 expected content, function, or auto, found integer
 #show heading: 1234
 = Heading
 
@@ -0,0 +1,52 @@
+--- currency-locale ---
+#let price = decimal("1234.5")
+#context test(currency(price, "USD"), "$1,234.50")
+#context test(currency(-3, "EUR", display: "code"), "−EUR\u{a0}3.00")
+#set text(lang: "de")
+#context test(currency(price, "EUR"), "1.234,50\u{a0}€")
+#set text(region: "ch")
+#context test(currency(price, "CHF"), "CHF\u{a0}1’234.50")
+
+--- currency-rounding ---
+#context test(currency(decimal("2.345"), "EUR"), "€2.35")
+#context test(currency(decimal("2.345"), "EUR", rounding: "half-even"), "€2.34")
+#context test(currency(decimal("2.349"), "EUR", rounding: "down"), "€2.34")
+#context test(currency(decimal("1234.5"), "JPY"), "¥1,235")
+#context test(currency(7, "EUR", places: 0), "€7")
+
+--- currency-vat ---
+#test(currency.vat(decimal("49.99"), 19%), decimal("9.50"))
+#test(currency.vat(100, decimal("0.077")), decimal("7.70"))
+
+--- currency-total ---
+#let totals = currency.total(
+  (
+    (price: decimal("12.50"), quantity: 3),
+    (price: decimal("4.20"), vat: 7%),
+    (price: decimal("0.99")),
+  ),
+  vat: 19%,
+)
+#test(totals.net, decimal("42.69"))
+#test(totals.vat, decimal("7.60"))
+#test(totals.gross, decimal("50.29"))
+#test(totals.rates.len(), 2)
+#test(totals.rates.at(1).vat, decimal("0.29"))
+
+--- currency-float ---
+// Error: 19-23 expected decimal or integer, found float
+#context currency(2.50, "EUR")
+
+--- currency-code-invalid ---
+// Error: 22-27 currency code must consist of three uppercase letters
+#context currency(1, "eur")
+
+--- currency-total-unexpected-key ---
+// Error: 17-40 unexpected key "tax", valid keys are "price", "quantity", and "vat"
+#currency.total(((price: 1, tax: 19%),))
+
+--- currency-no-context ---
+// Error: 2-20 can only be used when context is known
+// Hint: 2-20 try wrapping this in a `context` expression
+// Hint: 2-20 the `context` expression should wrap everything that depends on this function
+#currency(1, "EUR")
@@ -46,6 +46,26 @@ There might be #highlight-custom[redacted] things.
 #set strike(background: true, stroke: 5pt + red)
 #strike[This is in the background]
 
+--- underline-continuous ---
+// The line continues across styled spans, spacing, and equations, and keeps
+// the position of the largest text.
+#underline[Mixed *bold* and #text(14pt)[large] text]
+
+#underline[With $x^2 + y$ in it and #h(1cm) spacing]
+
+#underline(evade: false)[Sub#sub[script] and super#super[script]]
+
+// Shifted text gets a line of its own, relative to the unshifted baseline.
+#underline[Raised #text(baseline: -3pt)[text] here]
+
+#overline[A #text(8pt)[small] gap]
+
+--- underline-interrupted ---
+// Separate decorations don't merge and boxes keep their own lines.
+#underline[One]#h(1cm)#overline[Two] #underline[Three #box[four] five]
+
+#underline[Hidden #hide[parts] stay hidden]
+
 --- highlight ---
 // Test highlight.
 This is the built-in #highlight[highlight with default color].
@@ -15,6 +15,13 @@
 #set text(font: "Noto Serif CJK TC", lang: "zh", region: "TW")
 #outline()
 
+--- text-lang-mixed ---
+// Text in several languages is exported with the predominant one as the
+// document's language.
+#set text(lang: "en")
+The German word #text(lang: "de")[Fingerspitzengefühl] has no direct
+translation, neither has the French #text(lang: "fr")[l'esprit de l'escalier].
+
 --- text-lang-hyphenate ---
 // Ensure that setting the language does have effects.
 #set text(hyphenate: true)
@@ -83,3 +90,41 @@
 // Error: 17-24 expected two or three letter language code (ISO 639-1/2/3)
 // Hint: 17-24 you should leave only "en" in the `lang` parameter and specify "gb" in the `region` parameter
 #set text(lang: "en-gb")
+
+--- text-translations ---
+#set text(translations: (en: (figure: "Fig.", outline: "Contents")))
+#outline(target: figure)
+#figure(rect(), caption: [A box])
+
+--- text-translations-region ---
+#set text(lang: "de", translations: (
+  de: (figure: "Abb.", caption-separator: ". "),
+  de-AT: (figure: "Abbildung"),
+))
+#figure(rect(), caption: [Eine Box])
+#set text(region: "AT")
+#figure(rect(), caption: [Noch eine Box])
+
+--- text-translations-fold ---
+#set text(translations: (en: (figure: "Fig.")))
+#set text(translations: (en: (table: "Tab.")))
+#figure(rect(), caption: [Figure])
+#figure(table[A], caption: [Table])
+
+--- text-translations-unknown-term ---
+// Error: 25-49 unknown term `figures`
+#set text(translations: (en: (figures: "Figs.")))
+
+--- text-translations-bad-lang ---
+// Error: 25-43 expected two or three letter language code (ISO 639-1/2/3)
+#set text(translations: (ӛ: (figure: "F")))
+
+--- text-translations-builtin ---
+#set text(lang: "ko")
+#figure(rect(), caption: [상자])
+
+--- text-translations-unsupported-lang ---
+#set text(lang: "sw", translations: toml(bytes(
+  "[sw]\nfigure = \"Kielelezo\"\ncaption-separator = \" - \"",
+)))
+#figure(rect(), caption: [Kisanduku])
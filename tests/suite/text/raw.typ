@@ -594,6 +594,25 @@ print(x)
 print(y)
 ```
 
+--- raw-diff ---
+#set raw(diff: true)
+
+```rust
+ fn main() {
+-    println!("Hello");
++    println!("Hello, world!");
+ }
+```
+
+--- raw-diff-lines ---
+#show raw: code => {
+  test(code.lines.map(line => line.change), (none, none, "removed", "added"))
+  test(code.lines.at(1).text, " x = 1")
+  test(code.lines.at(2).text, "-y = 2")
+}
+
+#raw(diff: true, lang: "py", "@@ -1,2 +1,2 @@\n x = 1\n-y = 2\n+y = 3")
+
 --- issue-3601-empty-raw ---
 // Test that empty raw block with `typ` language doesn't cause a crash.
 ```typ
@@ -716,3 +735,29 @@ a b c --------------------
 //
 // Error: 1-2:1 unclosed raw text
 `endless
+
+--- raw-font-and-size ---
+#set raw(font: "Libertinus Serif", size: 1em)
+Call `compute()` here.
+
+#set raw(size: 8pt)
+Call `compute()` here.
+
+--- raw-fill-inset ---
+#set raw(fill: luma(230), inset: (x: 2pt))
+Call `compute()` here.
+
+```
+A block with a fill.
+```
+
+--- raw-justify ---
+#set par(justify: true)
+#set raw(justify: false)
+Call `f(a, b, c)` to compute the result from its three arguments and then
+call `g(x, y)` on it.
+
+--- raw-fill-inline-break ---
+#set page(width: 120pt)
+#set raw(fill: luma(230), inset: (x: 1pt))
+Call `compute(first, second, third)` here.
@@ -12,6 +12,12 @@
 #set super(typographic: false, baseline: -0.25em, size: 0.7em)
 n#super[1], n#sub[2], ... n#super[N]
 
+--- sub-super-font-features ---
+// Libertinus Serif has `sups` and `subs` glyphs for letters, which have no
+// Unicode codepoints.
+1#super[st] 2#super[nd] 3#super[rd] \
+H#sub[2]O, CO#sub[2], x#sub[a]
+
 --- super-underline ---
 #set underline(stroke: 0.5pt, offset: 0.15em)
 #underline[The claim#super[\[4\]]] has been disputed. \
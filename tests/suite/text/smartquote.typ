@@ -2,6 +2,14 @@
 #set text(lang: "en")
 "The horse eats no cucumber salad" was the first sentence ever uttered on the 'telephone.'
 
+--- smartquote-en-gb ---
+#set text(lang: "en", region: "GB")
+"The horse eats no cucumber salad" was the first sentence ever uttered on the 'telephone.'
+
+--- smartquote-pt-pt ---
+#set text(lang: "pt", region: "PT")
+"O cavalo não come salada de pepino" foi a primeira frase dita ao 'telefone'.
+
 --- smartquote-de ---
 #set text(lang: "de")
 "Das Pferd frisst keinen Gurkensalat" war der erste jemals am 'Fernsprecher' gesagte Satz.
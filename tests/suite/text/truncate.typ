@@ -0,0 +1,21 @@
+--- truncate-fits ---
+#context test(truncate(100pt)[Short], [Short])
+
+--- truncate-ellipsis ---
+#context {
+  let short = truncate(50pt)[A rather long caption for a figure]
+  test(short.children.last(), […])
+  test(measure(short).width <= 50pt, true)
+}
+
+--- truncate-custom-ellipsis ---
+#set page(width: 120pt, height: auto)
+#table(
+  columns: 1,
+  ..("Introduction", "Typesetting Mathematics and Tables").map(title => layout(
+    size => truncate(size.width, ellipsis: [ ...], title),
+  )),
+)
+
+--- truncate-too-narrow ---
+#context test(truncate(1pt)[Hello], […])
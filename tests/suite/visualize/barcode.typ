@@ -0,0 +1,21 @@
+// Test barcodes.
+
+--- barcode ---
+#barcode("Typst 0.13", module: 0.5pt, height: 1cm)
+#barcode("400638133393", kind: "ean13", module: 0.75pt, fill: eastern)
+
+--- barcode-empty ---
+// Error: 2-13 barcode data must not be empty
+#barcode("")
+
+--- barcode-code128-unsupported-character ---
+// Error: 2-17 Code 128 cannot encode the character '\t'
+#barcode("a\tb")
+
+--- barcode-ean13-wrong-length ---
+// Error: 2-31 EAN-13 data must consist of 12 or 13 digits
+#barcode("123", kind: "ean13")
+
+--- barcode-ean13-invalid-check-digit ---
+// Error: 2-41 EAN-13 check digit is invalid (expected 1, found 2)
+#barcode("4006381333932", kind: "ean13")
@@ -0,0 +1,31 @@
+--- board-chess ---
+#board("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R", size: 3cm, coordinates: true)
+
+--- board-checkers-kings ---
+// Kings keep the color of their side.
+#board(kind: "checkers", size: 2cm, (
+  (none, "b", none, "B"),
+  ("w", none, "W", none),
+  (none, "W", none, "b"),
+  ("B", none, "w", none),
+))
+
+--- board-position ---
+#test(board("2k5/8").position, ((none, none, "k") + (none,) * 5, (none,) * 8))
+#test(board(kind: "go", (("b", none), (none, "w"))).position, (("b", none), (none, "w")))
+
+--- board-fen-invalid-piece ---
+// Error: 8-14 invalid piece `x` in FEN
+#board("8/x7")
+
+--- board-uneven-rows ---
+// Error: 8-30 row 2 has 1 squares, expected 2
+#board(((none, none), ("b",)))
+
+--- board-empty ---
+// Error: 8-10 board must not be empty
+#board("")
+
+--- board-invalid-piece ---
+// Error: 2-30 invalid piece `K` for this kind of board
+#board(kind: "go", (("K",),))
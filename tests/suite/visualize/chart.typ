@@ -0,0 +1,37 @@
+// Test charts.
+
+--- chart-kinds ---
+#set page(width: 240pt)
+#set text(6pt)
+#let data = (3, 5, 4, 7)
+#grid(
+  columns: 2,
+  gutter: 8pt,
+  chart(width: 3.5cm, height: 2.5cm, labels: ([A], [B], [C], [D]), data),
+  chart(width: 3.5cm, height: 2.5cm, kind: "bar", (label: [X], data: data)),
+  chart(width: 3.5cm, height: 2.5cm, kind: "scatter", ((1, 2), (2, 4.5), (4, 3))),
+  chart(width: 3.5cm, height: 2.5cm, kind: "pie", labels: ([A], [B], [C]), (1, 2, 3)),
+)
+
+--- chart-labels-explicit-x ---
+// The labels sit at the explicit x coordinates of the points, so the gap
+// between 2020 and 2024 is wider than the others.
+#set text(6pt)
+#chart(
+  width: 3.5cm,
+  height: 3cm,
+  labels: ([2019], [2020], [2024]),
+  ((2019, 1), (2020, 3), (2024, 2)),
+)
+
+--- chart-palette-empty ---
+// Error: 2-28 chart palette must not be empty
+#chart(palette: (), (1, 2))
+
+--- chart-pie-negative ---
+// Error: 2-29 pie chart values must not be negative
+#chart(kind: "pie", (1, -2))
+
+--- chart-too-small ---
+// Error: 2-30 chart is too small to fit its labels
+#chart(width: 1pt, (1, 2, 3))
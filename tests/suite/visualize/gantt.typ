@@ -0,0 +1,45 @@
+// Test Gantt charts.
+
+--- gantt-task-fields ---
+#let task = (label: [Draft], start: datetime(year: 2025, month: 3, day: 3))
+#test(gantt(task).tasks.first().end, none)
+#test(gantt(task).tasks.first().label, [Draft])
+
+--- gantt-task-time-only ---
+// Error: 8-68 task dates must include a date
+#gantt((label: [A], start: datetime(hour: 9, minute: 0, second: 0)))
+
+--- gantt-task-end-before-start ---
+// Error: 8-5:2 task must not end before it starts
+#gantt((
+  label: [A],
+  start: datetime(year: 2025, month: 3, day: 3),
+  end: datetime(year: 2025, month: 3, day: 1),
+))
+
+--- gantt-task-unknown-key ---
+// Error: 8-82 unexpected key "progress", valid keys are "label", "start", "end", and "fill"
+#gantt((label: [A], start: datetime(year: 2025, month: 3, day: 3), progress: 50%))
+
+--- gantt-too-narrow ---
+// Error: 2-83 gantt chart is too narrow to fit its labels
+#gantt(width: 1pt, (label: [Task], start: datetime(year: 2025, month: 3, day: 3)))
+
+--- gantt-basic ---
+#set page(width: 12cm, height: auto)
+#gantt(
+  (label: [Kickoff], start: datetime(year: 2025, month: 3, day: 3)),
+  (
+    label: [Draft],
+    start: datetime(year: 2025, month: 3, day: 3),
+    end: datetime(year: 2025, month: 3, day: 14),
+  ),
+  (
+    label: [Review],
+    start: datetime(year: 2025, month: 3, day: 12),
+    end: datetime(year: 2025, month: 3, day: 21),
+    fill: orange,
+  ),
+  (label: [Release], start: datetime(year: 2025, month: 3, day: 21)),
+)
+
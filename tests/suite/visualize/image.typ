@@ -34,6 +34,39 @@
   image("/assets/images/monkey.svg", width: 100%, height: 100%, fit: "stretch"),
 )
 
+--- image-crop ---
+// Test cropping with and without a fit.
+#set page(height: 60pt, margin: 0pt)
+#grid(
+  columns: (1fr, 1fr, 1fr),
+  rows: 100%,
+  gutter: 3pt,
+  image("/assets/images/tiger.jpg", height: 100%, crop: (x: 25%, width: 50%)),
+  image(
+    "/assets/images/tiger.jpg",
+    width: 100%,
+    height: 100%,
+    crop: (y: 50%),
+    fit: "contain",
+  ),
+  image(
+    "/assets/images/monkey.svg",
+    width: 100%,
+    height: 100%,
+    crop: (x: 10%, y: 10%, width: 30%, height: 30%),
+  ),
+)
+
+--- image-crop-rounding ---
+// The offset and size add up to slightly more than 100% with floats.
+#let crop = (x: 7.57%, width: 92.43%)
+#image("/assets/images/tiger.jpg", width: 2cm, crop: crop)
+
+--- image-crop-out-of-bounds ---
+// Error: 42-62 crop must lie within the image
+// Hint: 42-62 offsets and sizes are relative to the image's size
+#image("/assets/images/tiger.jpg", crop: (x: 50%, width: 60%))
+
 --- image-jump-to-next-page ---
 // Does not fit to remaining height of page.
 #set page(height: 60pt)
@@ -65,6 +98,17 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
   caption: [Bilingual text]
 )
 
+--- image-svg-text-generic-family ---
+// Generic font families map to Typst's default fonts.
+#image(bytes(
+  ```
+  <svg xmlns="http://www.w3.org/2000/svg" width="120" height="40">
+    <text x="4" y="16" font-family="serif">Serif text</text>
+    <text x="4" y="34" font-family="monospace">Mono text</text>
+  </svg>
+  ```.text
+))
+
 --- image-svg-auto-detection ---
 #image(bytes(
   ```
@@ -76,6 +120,16 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
   ```.text
 ))
 
+--- image-svg-relative-href ---
+// Images referenced by an SVG given as bytes are resolved relative to this file.
+#image(bytes(
+  ```
+  <svg xmlns="http://www.w3.org/2000/svg" width="120" height="80">
+    <image href="../../../assets/images/rhino.png" width="120" height="80"/>
+  </svg>
+  ```.text
+), width: 3cm)
+
 --- image-pixmap-rgb8 ---
 #image(
   bytes((
@@ -158,10 +212,116 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
 // width, but rather max out at its natural size.
 #image("/assets/images/f2t.jpg")
 
+--- image-dpi-override ---
+// Test overriding the resolution from the image's metadata.
+#box(image("/assets/images/f2t.jpg"))
+#box(image("/assets/images/f2t.jpg", dpi: 110))
+#box(image("/assets/images/rhino.png", dpi: 300))
+
+--- image-dpi-zero ---
+// Error: 2-41 image resolution must be positive
+#image("/assets/images/f2t.jpg", dpi: 0)
+
+--- image-info ---
+#let info = image.info("/assets/images/f2t.jpg")
+#test(info.width, 48.0)
+#test(info.height, 80.0)
+#test(info.format, "jpg")
+#test(info.vector, false)
+#test(info.dpi, 220.0)
+
+--- image-info-svg ---
+#let info = image.info(read("/assets/images/diagram.svg", encoding: none))
+#test(info.format, "svg")
+#test(info.vector, true)
+
+--- image-data-uri ---
+#let uri = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAIAAAACCAIAAAD91JpzAAAAEElEQVR4nGP4z8AARAwQCgAf7gP9i18U1AAAAABJRU5ErkJggg=="
+#test(image.info(uri).width, 2.0)
+#test(image.info(uri).format, "png")
+#box(image(uri, width: 1cm))
+#box(image("data:image/svg+xml,%3Csvg%20xmlns='http://www.w3.org/2000/svg'%20width='10'%20height='10'%3E%3Crect%20width='10'%20height='10'%20fill='blue'/%3E%3C/svg%3E", width: 1cm))
+
+--- image-data-uri-invalid ---
+// Error: 8-36 failed to decode data URI (Invalid symbol 33, offset 3.)
+#image("data:image/png;base64,abc!")
+
+--- image-frame ---
+// An animated GIF with a red and a blue frame.
+#let gif = "data:image/gif;base64,R0lGODlhAgACAIAAAP8AAAAA/yH/C05FVFNDQVBFMi4wAwEAAAAh+QQACgAAACwAAAAAAgACAAACAoRRACH5BAAKAAAALAAAAAACAAIAAAICjFMAOw=="
+#box(image(gif, width: 1cm))
+#box(image(gif, frame: 2, width: 1cm))
+
+--- image-frame-missing ---
+#let gif = "data:image/gif;base64,R0lGODlhAgACAIAAAP8AAAAA/yH/C05FVFNDQVBFMi4wAwEAAAAh+QQACgAAACwAAAAAAgACAAACAoRRACH5BAAKAAAALAAAAAACAAIAAAICjFMAOw=="
+// Error: 2-22 image has no frame 3
+#image(gif, frame: 3)
+
+--- image-frame-svg ---
+// Error: 2-47 SVG images have only one frame
+#image("/assets/images/diagram.svg", frame: 2)
+
 --- image-file-not-found ---
 // Error: 8-29 file not found (searched at tests/suite/visualize/path/does/not/exist)
 #image("path/does/not/exist")
 
+--- image-placeholder-file-not-found ---
+// Warning: 8-29 file not found (searched at tests/suite/visualize/path/does/not/exist)
+// Hint: 8-29 the image was replaced by a placeholder
+#image("path/does/not/exist", placeholder: true)
+
+--- image-placeholder-set-rule-file-not-found ---
+#set image(placeholder: true)
+// Warning: 8-29 file not found (searched at tests/suite/visualize/path/does/not/exist)
+// Hint: 8-29 the image was replaced by a placeholder
+#image("path/does/not/exist")
+
+--- image-placeholder-bad-format ---
+#set image(placeholder: true)
+// Warning: 2-62 unknown image format
+// Hint: 2-62 the image was replaced by a placeholder
+#image("/assets/plugins/hello.wasm", width: 3cm, height: 2cm)
+
+--- image-svg-unsupported-elements ---
+// Warning: 2-198 image contains unsupported SVG elements: `animate` and `script`
+// Hint: 2-198 these elements are ignored when rendering the image
+#image(bytes(`<svg xmlns="http://www.w3.org/2000/svg" width="20" height="20"><rect width="20" height="20"><animate attributeName="x" to="10" dur="1s"/></rect><script>alert(1)</script></svg>`.text))
+
+--- image-svg-raster-fallback ---
+#let svg = bytes(```
+<svg xmlns="http://www.w3.org/2000/svg" width="40" height="20">
+  <filter id="blur"><feGaussianBlur stdDeviation="2"/></filter>
+  <rect x="5" y="5" width="30" height="10" fill="blue" filter="url(#blur)"/>
+</svg>
+```.text)
+#image(svg, width: 2cm)
+#image(svg, width: 2cm, raster-fallback: 72)
+
+--- image-svg-raster-fallback-negative ---
+// Error: 2-70 raster fallback resolution must be positive
+#image("/assets/images/diagram.svg", raster-fallback: -1, width: 2cm)
+
+--- image-svg-raster-fallback-nan ---
+// Error: 2-77 raster fallback resolution must be positive
+#image("/assets/images/diagram.svg", raster-fallback: float.nan, width: 2cm)
+
+--- image-svg-raster-fallback-too-high ---
+#let svg = bytes(```
+<svg xmlns="http://www.w3.org/2000/svg" width="40" height="20">
+  <filter id="blur"><feGaussianBlur stdDeviation="2"/></filter>
+  <rect x="5" y="5" width="30" height="10" fill="blue" filter="url(#blur)"/>
+</svg>
+```.text)
+// Error: 2-48 raster fallback resolution is too high
+// Hint: 2-48 the image would have 40000000×20000000 pixels
+// Hint: 2-48 at most 67108864 pixels are allowed
+#image(svg, raster-fallback: 9.6e7, width: 2cm)
+
+--- image-pdf-unsupported ---
+// Error: 2-35 PDF documents are not supported as images
+// Hint: 2-35 convert the page to SVG or PNG to use it as an image or page background
+#image(bytes("%PDF-1.7\n%%EOF"))
+
 --- image-bad-format ---
 // Error: 2-37 unknown image format
 #image("/assets/plugins/hello.wasm")
@@ -247,6 +407,14 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
   format: "rgba8",
 )
 
+--- image-max-pixels ---
+// Compiled again with a limit of 100 pixels in `custom.rs`.
+#image(
+  bytes(range(120)),
+  format: (encoding: "luma8", width: 12, height: 10),
+  width: 1cm,
+)
+
 --- issue-measure-image ---
 // Test that image measurement doesn't turn `inf / some-value` into 0pt.
 #context {
@@ -0,0 +1,44 @@
+--- chord-lyrics ---
+#set par(leading: 1.4em)
+#chord("G")[A]mazing #chord("G7")[grace], how
+#chord("C")[sweet] the #chord("Bb")[sound]
+
+--- fretboard ---
+#stack(
+  dir: ltr,
+  spacing: 1em,
+  fretboard("x32010", name: [C]),
+  fretboard((3, 2, 0, 0, 0, 3), name: [G], rows: 5),
+  fretboard("x-5-7-7-6-5", name: [D], base: 5),
+)
+
+--- chord-fields ---
+#let c = chord("Bb")[sound]
+#test(c.name, "Bb")
+#test(c.body, [sound])
+
+--- fretboard-frets ---
+#test(fretboard("x32010").frets, "x32010".clusters().map(c => if c == "x" { none } else { int(c) }))
+#test(fretboard("x-10-12-12-11-10").frets, (none, 10, 12, 12, 11, 10))
+#test(fretboard((none, 0, 2)).frets, (none, 0, 2))
+
+--- fretboard-invalid-fret ---
+// Error: 12-20 invalid fret `q`
+#fretboard("x32q10")
+
+--- fretboard-empty ---
+// Error: 12-14 fingering must have at least one string
+#fretboard("")
+
+--- fretboard-fret-outside ---
+// Error: 2-30 fret 2 is not within the diagram
+#fretboard("x32010", base: 3)
+
+--- fretboard-fret-outside-rows ---
+// Error: 2-30 fret 3 is not within the diagram
+#fretboard("x32010", rows: 2)
+
+--- fretboard-too-many-rows ---
+// Error: 2-32 fretboard cannot show more than 36 frets
+// Hint: 2-32 use `base` to start the diagram at a higher fret
+#fretboard("x32010", rows: 100)
@@ -0,0 +1,13 @@
+// Test QR codes.
+
+--- qrcode ---
+#qrcode("https://typst.app", size: 2cm)
+#qrcode("Typst", ec-level: "high", fill: blue, quiet-zone: false)
+
+--- qrcode-data-too-long ---
+// Error: 2-20 data is too long for a QR code
+#qrcode("a" * 3000)
+
+--- qrcode-ec-level-invalid ---
+// Error: 24-29 expected "low", "medium", "quartile", or "high"
+#qrcode("a", ec-level: "max")
@@ -0,0 +1,27 @@
+// Test text along a path.
+
+--- text-path-curve ---
+#text-path(
+  curve(
+    stroke: gray,
+    curve.move((0pt, 40pt)),
+    curve.cubic((40pt, -10pt), (100pt, -10pt), (140pt, 40pt)),
+  ),
+  start: 10%,
+)[Text along a curve]
+
+--- text-path-circle ---
+#text-path(circle(radius: 30pt, stroke: none))[Around and around we go]
+
+--- text-path-too-short ---
+// The glyphs beyond the end of the line are left out.
+#text-path(line(length: 30pt))[Too long for the line]
+
+--- text-path-fields ---
+#let elem = text-path(line(length: 30pt), start: 5pt)[A]
+#test(elem.start, 5pt)
+#test(elem.body, [A])
+
+--- text-path-no-shape ---
+// Error: 2-23 text path must contain a shape
+#text-path([Hi])[Body]